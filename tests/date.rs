@@ -3,7 +3,7 @@ use std::collections::HashSet;
 
 use time::ext::{NumericalDuration, NumericalStdDuration};
 use time::macros::{date, datetime, time};
-use time::{util, Date, Duration, Month, Weekday};
+use time::{util, Date, Duration, Month, Quarter, SerialDateSystem, Weekday};
 
 #[test]
 fn debug() {
@@ -471,6 +471,43 @@ fn test_sunday_based_week_dominical_gf() {
     assert_eq!(date!(2024-03-07).sunday_based_week(), 9);
 }
 
+#[test]
+fn week_of_month() {
+    use Weekday::*;
+    assert_eq!(date!(2024-03-01).week_of_month(Monday, 1), 1);
+    assert_eq!(date!(2024-03-03).week_of_month(Monday, 1), 1);
+    assert_eq!(date!(2024-03-04).week_of_month(Monday, 1), 2);
+    assert_eq!(date!(2024-03-31).week_of_month(Monday, 1), 5);
+    assert_eq!(date!(2024-03-01).week_of_month(Monday, 4), 0);
+    assert_eq!(date!(2024-03-04).week_of_month(Monday, 4), 1);
+    assert_eq!(date!(2024-03-31).week_of_month(Monday, 4), 4);
+    assert_eq!(date!(2024-03-02).week_of_month(Sunday, 1), 1);
+    assert_eq!(date!(2024-03-03).week_of_month(Sunday, 1), 2);
+    assert_eq!(date!(2024-03-31).week_of_month(Sunday, 1), 6);
+    assert_eq!(date!(2024-03-01).week_of_month(Friday, 7), 1);
+    assert_eq!(date!(2024-03-01).week_of_month(Friday, 0), 1);
+    assert_eq!(date!(2024-03-01).week_of_month(Saturday, 8), 0);
+}
+
+#[test]
+fn weekday_in_month() {
+    assert_eq!(date!(2024-03-01).weekday_in_month(), 1);
+    assert_eq!(date!(2024-03-07).weekday_in_month(), 1);
+    assert_eq!(date!(2024-03-08).weekday_in_month(), 2);
+    assert_eq!(date!(2024-03-29).weekday_in_month(), 5);
+    assert_eq!(date!(2024-03-31).weekday_in_month(), 5);
+}
+
+#[test]
+fn weekday_in_month_from_end() {
+    assert_eq!(date!(2024-03-31).weekday_in_month_from_end(), 1);
+    assert_eq!(date!(2024-03-25).weekday_in_month_from_end(), 1);
+    assert_eq!(date!(2024-03-24).weekday_in_month_from_end(), 2);
+    assert_eq!(date!(2024-03-01).weekday_in_month_from_end(), 5);
+    assert_eq!(date!(2024-02-29).weekday_in_month_from_end(), 1);
+    assert_eq!(date!(2023-02-01).weekday_in_month_from_end(), 4);
+}
+
 #[test]
 fn from_iso_week_date() {
     use Weekday::*;
@@ -502,6 +539,15 @@ fn month() {
     assert_eq!(date!(2020-060).month(), Month::February);
 }
 
+#[test]
+fn quarter() {
+    assert_eq!(date!(2019-01-01).quarter(), Quarter::First);
+    assert_eq!(date!(2019-03-31).quarter(), Quarter::First);
+    assert_eq!(date!(2019-04-01).quarter(), Quarter::Second);
+    assert_eq!(date!(2020-08-15).quarter(), Quarter::Third);
+    assert_eq!(date!(2019-12-31).quarter(), Quarter::Fourth);
+}
+
 #[test]
 fn day() {
     assert_eq!(date!(2019-002).day(), 2);
@@ -567,6 +613,60 @@ fn to_ordinal_date() {
     assert_eq!(date!(2019-01-01).to_ordinal_date(), (2019, 1));
 }
 
+#[test]
+fn from_quarter_date() {
+    assert_eq!(
+        Date::from_quarter_date(2019, Quarter::First, 1),
+        Ok(date!(2019-01-01))
+    );
+    assert_eq!(
+        Date::from_quarter_date(2019, Quarter::Second, 1),
+        Ok(date!(2019-04-01))
+    );
+    assert_eq!(
+        Date::from_quarter_date(2020, Quarter::Second, 1),
+        Ok(date!(2020-04-01))
+    );
+    assert_eq!(
+        Date::from_quarter_date(2020, Quarter::First, 91),
+        Ok(date!(2020-03-31))
+    );
+    assert_eq!(
+        Date::from_quarter_date(2020, Quarter::Fourth, 92),
+        Ok(date!(2020-12-31))
+    );
+    assert!(matches!(
+        Date::from_quarter_date(2019, Quarter::First, 91),
+        Err(err) if err.name() == "day"
+    ));
+    assert!(Date::from_quarter_date(2019, Quarter::Third, 0).is_err());
+    assert!(Date::from_quarter_date(1_000_000, Quarter::First, 1).is_err());
+}
+
+#[test]
+fn to_quarter_date() {
+    assert_eq!(
+        date!(2019-01-01).to_quarter_date(),
+        (2019, Quarter::First, 1)
+    );
+    assert_eq!(
+        date!(2020-03-31).to_quarter_date(),
+        (2020, Quarter::First, 91)
+    );
+    assert_eq!(
+        date!(2019-05-01).to_quarter_date(),
+        (2019, Quarter::Second, 31)
+    );
+    assert_eq!(
+        date!(2019-12-31).to_quarter_date(),
+        (2019, Quarter::Fourth, 92)
+    );
+    for date in [date!(2019-01-01), date!(2020-02-29), date!(2020-06-30), date!(2021-11-05)] {
+        let (year, quarter, day) = date.to_quarter_date();
+        assert_eq!(Date::from_quarter_date(year, quarter, day), Ok(date));
+    }
+}
+
 #[test]
 fn to_iso_week_date() {
     use Weekday::*;
@@ -640,6 +740,106 @@ fn from_julian_day() {
     assert!(Date::from_julian_day(i32::MAX).is_err());
 }
 
+#[test]
+fn to_modified_julian_day() {
+    assert_eq!(date!(-9999 - 01 - 01).to_modified_julian_day(), -4_331_000);
+    assert_eq!(date!(1858-11-16).to_modified_julian_day(), -1);
+    assert_eq!(date!(1858-11-17).to_modified_julian_day(), 0);
+    assert_eq!(date!(2000-01-01).to_modified_julian_day(), 51_544);
+    assert_eq!(date!(2019-12-31).to_modified_julian_day(), 58_848);
+}
+
+#[test]
+fn from_modified_julian_day() {
+    assert_eq!(
+        Date::from_modified_julian_day(-4_331_000),
+        Ok(date!(-9999 - 01 - 01))
+    );
+    assert_eq!(Date::from_modified_julian_day(-1), Ok(date!(1858-11-16)));
+    assert_eq!(Date::from_modified_julian_day(0), Ok(date!(1858-11-17)));
+    assert_eq!(Date::from_modified_julian_day(51_544), Ok(date!(2000-01-01)));
+    assert_eq!(Date::from_modified_julian_day(58_848), Ok(date!(2019-12-31)));
+    assert!(Date::from_modified_julian_day(i32::MAX).is_err());
+    assert!(Date::from_modified_julian_day(i32::MIN).is_err());
+}
+
+#[test]
+fn to_serial_date() {
+    use SerialDateSystem::*;
+
+    assert_eq!(date!(1899-12-30).to_serial_date(Excel1900), -1);
+    assert_eq!(date!(1899-12-31).to_serial_date(Excel1900), 0);
+    assert_eq!(date!(1900-01-01).to_serial_date(Excel1900), 1);
+    assert_eq!(date!(1900-02-28).to_serial_date(Excel1900), 59);
+    assert_eq!(date!(1900-03-01).to_serial_date(Excel1900), 61);
+    assert_eq!(date!(2024-01-01).to_serial_date(Excel1900), 45_292);
+
+    assert_eq!(date!(1903-12-31).to_serial_date(Excel1904), -1);
+    assert_eq!(date!(1904-01-01).to_serial_date(Excel1904), 0);
+    assert_eq!(date!(2024-01-01).to_serial_date(Excel1904), 43_830);
+
+    assert_eq!(date!(1899-12-29).to_serial_date(OleAutomation), -1);
+    assert_eq!(date!(1899-12-30).to_serial_date(OleAutomation), 0);
+    assert_eq!(date!(1900-01-01).to_serial_date(OleAutomation), 2);
+    assert_eq!(date!(1900-02-28).to_serial_date(OleAutomation), 60);
+    assert_eq!(date!(1900-03-01).to_serial_date(OleAutomation), 61);
+    assert_eq!(date!(2024-01-01).to_serial_date(OleAutomation), 45_292);
+}
+
+#[test]
+fn from_serial_date() {
+    use SerialDateSystem::*;
+
+    assert_eq!(Date::from_serial_date(-1, Excel1900), Ok(date!(1899-12-30)));
+    assert_eq!(Date::from_serial_date(0, Excel1900), Ok(date!(1899-12-31)));
+    assert_eq!(Date::from_serial_date(1, Excel1900), Ok(date!(1900-01-01)));
+    assert_eq!(Date::from_serial_date(59, Excel1900), Ok(date!(1900-02-28)));
+    assert_eq!(
+        Date::from_serial_date(60, Excel1900).map_err(|err| err.name()),
+        Err("day")
+    );
+    assert_eq!(Date::from_serial_date(61, Excel1900), Ok(date!(1900-03-01)));
+    assert_eq!(Date::from_serial_date(45_292, Excel1900), Ok(date!(2024-01-01)));
+
+    assert_eq!(Date::from_serial_date(-1, Excel1904), Ok(date!(1903-12-31)));
+    assert_eq!(Date::from_serial_date(0, Excel1904), Ok(date!(1904-01-01)));
+    assert_eq!(Date::from_serial_date(43_830, Excel1904), Ok(date!(2024-01-01)));
+
+    assert_eq!(Date::from_serial_date(-1, OleAutomation), Ok(date!(1899-12-29)));
+    assert_eq!(Date::from_serial_date(0, OleAutomation), Ok(date!(1899-12-30)));
+    assert_eq!(Date::from_serial_date(2, OleAutomation), Ok(date!(1900-01-01)));
+    assert_eq!(Date::from_serial_date(60, OleAutomation), Ok(date!(1900-02-28)));
+    assert_eq!(Date::from_serial_date(61, OleAutomation), Ok(date!(1900-03-01)));
+
+    for system in [Excel1900, Excel1904, OleAutomation] {
+        assert_eq!(
+            Date::from_serial_date(i32::MAX, system).map_err(|err| err.name()),
+            Err("serial")
+        );
+        assert_eq!(
+            Date::from_serial_date(i32::MIN, system).map_err(|err| err.name()),
+            Err("serial")
+        );
+        assert_eq!(
+            Date::from_serial_date(Date::MIN.to_serial_date(system), system),
+            Ok(Date::MIN)
+        );
+        assert_eq!(
+            Date::from_serial_date(Date::MAX.to_serial_date(system), system),
+            Ok(Date::MAX)
+        );
+
+        let mut date = date!(1899-12-01);
+        while date < date!(1904-02-01) {
+            assert_eq!(
+                Date::from_serial_date(date.to_serial_date(system), system),
+                Ok(date)
+            );
+            date = date.next_day().expect("date is in range");
+        }
+    }
+}
+
 #[test]
 fn midnight() {
     assert_eq!(date!(1970-01-01).midnight(), datetime!(1970-01-01 0:00));
@@ -1,8 +1,8 @@
 use std::error::Error as _;
-use std::io;
+use std::{fmt, io};
 
 use time::error::{
-    ComponentRange, ConversionRange, DifferentVariant, Error, Format, IndeterminateOffset,
+    ComponentRange, ConversionRange, DifferentVariant, Error, Format, FormatCore, IndeterminateOffset,
     InvalidFormatDescription, InvalidVariant, Parse, ParseFromDescription, TryFromParsed,
 };
 use time::macros::format_description;
@@ -108,6 +108,16 @@ fn display() {
         Error::from(invalid_format_description())
     );
     assert_display_eq!(io_error(), Format::from(io_error()));
+    assert_display_eq!(fmt::Error, Format::from(fmt::Error));
+    assert_display_eq!(component_range(), FormatCore::from(component_range()));
+    assert_display_eq!(
+        FormatCore::InvalidComponent("a"),
+        Format::from(FormatCore::InvalidComponent("a"))
+    );
+    assert_display_eq!(
+        FormatCore::InsufficientBufferSize,
+        Error::from(FormatCore::InsufficientBufferSize)
+    );
     assert_display_eq!(DifferentVariant, Error::from(DifferentVariant));
     assert_display_eq!(InvalidVariant, Error::from(InvalidVariant));
 }
@@ -148,6 +158,12 @@ fn source() {
         InvalidFormatDescription
     );
     assert_source!(Format::from(io_error()), io::Error);
+    assert_source!(Format::from(fmt::Error), fmt::Error);
+    assert_source!(Format::InsufficientBufferSize, None);
+    assert_source!(FormatCore::from(component_range()), ComponentRange);
+    assert_source!(FormatCore::from(fmt::Error), fmt::Error);
+    assert_source!(FormatCore::InsufficientBufferSize, None);
+    assert_source!(Error::from(FormatCore::InsufficientBufferSize), FormatCore);
     assert_source!(Error::from(DifferentVariant), DifferentVariant);
     assert_source!(Error::from(InvalidVariant), InvalidVariant);
 }
@@ -177,10 +193,14 @@ fn conversion() {
     assert!(TryFromParsed::try_from(Error::from(TryFromParsed::InsufficientInformation)).is_ok());
     assert!(TryFromParsed::try_from(Parse::from(TryFromParsed::InsufficientInformation)).is_ok());
     assert!(io::Error::try_from(Format::from(io_error())).is_ok());
+    assert!(FormatCore::try_from(Error::from(FormatCore::InsufficientBufferSize)).is_ok());
+    assert!(ComponentRange::try_from(FormatCore::from(component_range())).is_ok());
 
     assert!(ComponentRange::try_from(Error::from(IndeterminateOffset)).is_err());
     assert!(ConversionRange::try_from(Error::from(IndeterminateOffset)).is_err());
     assert!(Format::try_from(Error::from(IndeterminateOffset)).is_err());
+    assert!(FormatCore::try_from(Error::from(IndeterminateOffset)).is_err());
+    assert!(ComponentRange::try_from(FormatCore::InsufficientBufferSize).is_err());
     assert!(IndeterminateOffset::try_from(Error::from(ConversionRange)).is_err());
     assert!(InvalidFormatDescription::try_from(Error::from(IndeterminateOffset)).is_err());
     assert!(ParseFromDescription::try_from(Error::from(IndeterminateOffset)).is_err());
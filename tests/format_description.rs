@@ -1,4 +1,9 @@
-use time::format_description::{modifier, BorrowedFormatItem, Component, OwnedFormatItem};
+use rstest::rstest;
+use time::format_description::analysis::{analyze, analyze_owned, Issue, Target};
+use time::format_description::{
+    self, modifier, BorrowedFormatItem, Component, Condition, FormatBuilder, OwnedFormatItem,
+};
+use time::macros::format_description as fd;
 
 #[test]
 fn borrowed_format_item_component_conversions() {
@@ -66,3 +71,497 @@ fn owned_format_item_equality() {
         compound
     );
 }
+
+#[rstest]
+#[case("")]
+#[case("foo bar")]
+#[case(r"\[escaped\] \\")]
+#[case("[day] [day padding:space] [day padding:none]")]
+#[case("[end] [extension_annotations] [time_zone_annotation]")]
+#[case("[hour repr:12] [minute padding:none] [second padding:space]")]
+#[case("[day width:5] [hour padding:space width:4] [year padding:space width:10]")]
+#[case("[ignore count:3]")]
+#[case("[month] [month repr:short case:upper] [month repr:long case:lower case_sensitive:false]")]
+#[case("[offset_hour sign:mandatory padding:none]:[offset_minute]:[offset_second]")]
+#[case("[ordinal] [period] [period case:lower case_sensitive:false]")]
+#[case("[month repr:narrow case:upper] [weekday repr:narrow] [period repr:narrow]")]
+#[case("[subsecond] [subsecond digits:3]")]
+#[case("[unix_timestamp precision:nanosecond sign:mandatory]")]
+#[case("[weekday repr:short one_indexed:false case:upper case_sensitive:false]")]
+#[case("[week_number] [week_number padding:none repr:sunday]")]
+#[case("[week_of_month first_weekday:sunday minimal_days:4] [weekday_in_month from_end:true]")]
+#[case("[quarter] [quarter repr:short case_sensitive:false]")]
+#[case("[year era_based:true] [era] [era repr:common case_sensitive:false]")]
+#[case("[era repr:long] [era repr:narrow]")]
+#[case("[julian_day] [julian_day repr:modified digits:5]")]
+#[case("[year repr:last_two range:standard base:iso_week sign:mandatory pivot:1950]")]
+#[case("[year repr:last_two pivot:posix]")]
+#[case("[year repr:last_two pivot:sliding]")]
+#[case("[optional [.[subsecond]]]")]
+#[case(r"[first [[year]] [[month]-[day]] [\]]]")]
+#[case("[if offset_is_utc [Z] [[offset_hour sign:mandatory]:[offset_minute]]]")]
+#[case("[if second_is_zero [] [:[second]]]")]
+#[case("[if offset_minute_is_zero [] [[offset_minute]]] [if offset_second_is_zero [a] [b]]")]
+fn display_canonical(#[case] description: &str) -> time::Result<()> {
+    let owned = format_description::parse_owned::<2>(description)?;
+    assert_eq!(owned.to_string(), description);
+    Ok(())
+}
+
+#[rstest]
+#[case("[month repr:numerical padding:zero]", "[month]")]
+#[case("[ year  repr:full base:calendar ]", "[year]")]
+#[case("[day width:0]", "[day]")]
+#[case("[weekday repr:long one_indexed:true case:title]", "[weekday]")]
+#[case("[first [a][b]]", "[first [a] [b]]")]
+#[case("[if  subsecond_is_zero [][.[subsecond]] ]", "[if subsecond_is_zero [] [.[subsecond]]]")]
+fn display_normalized(#[case] description: &str, #[case] expected: &str) -> time::Result<()> {
+    let owned = format_description::parse_owned::<2>(description)?;
+    assert_eq!(owned.to_string(), expected);
+    Ok(())
+}
+
+#[rstest]
+fn display_borrowed() {
+    let format = fd!(version = 2, "[year]-[month repr:short] \\[[optional [[day]]]\\]");
+    assert_eq!(
+        BorrowedFormatItem::Compound(format).to_string(),
+        r"[year]-[month repr:short] \[[optional [[day]]]\]"
+    );
+    assert_eq!(
+        BorrowedFormatItem::Literal(b"a\xFFb").to_string(),
+        "a\u{FFFD}b"
+    );
+    assert_eq!(BorrowedFormatItem::First(&[]).to_string(), "");
+}
+
+#[rstest]
+#[case("%Y-%m-%d %H:%M:%S")]
+#[case("%a %A %^a %^A %b %B %^b %^B")]
+#[case("%e %-d %_m %k %l %-I %_M %-S %-j %U %V %W %u %w")]
+#[case("%C %y %g %G %-Y %p %P %s %3N %N")]
+#[case("%z %:z %::z %:::z")]
+#[case("100%% ")]
+#[case("%Y-%m-%dT%H:%M:%S%.f%:z")]
+#[case("%10d %_6H %10Y %_5G %3C")]
+fn strftime_round_trip(#[case] strftime: &str) -> time::Result<()> {
+    let owned = format_description::parse_strftime_owned(strftime)?;
+    assert_eq!(owned.to_strftime().as_deref(), Some(strftime));
+
+    let borrowed = format_description::parse_strftime_borrowed(strftime)?;
+    assert_eq!(
+        BorrowedFormatItem::Compound(&borrowed).to_strftime().as_deref(),
+        Some(strftime)
+    );
+    Ok(())
+}
+
+#[rstest]
+#[case("%_d", "%e")]
+#[case("%0e", "%d")]
+#[case("%n%t", "\n\t")]
+#[case("%h", "%b")]
+#[case("%#B", "%^B")]
+#[case("%F", "%Y-%m-%d")]
+#[case("%D", "%m/%d/%y")]
+#[case("%r", "%I:%M:%S %p")]
+#[case("%Z", "%:z")]
+#[case("%+", "%Y-%m-%dT%H:%M:%S%.f%:z")]
+fn strftime_normalized(#[case] strftime: &str, #[case] expected: &str) -> time::Result<()> {
+    let owned = format_description::parse_strftime_owned(strftime)?;
+    assert_eq!(owned.to_strftime().as_deref(), Some(expected));
+    Ok(())
+}
+
+#[rstest]
+#[case("[period case:lower]", Some("%P"))]
+#[case("[day]/[month]/[year] [period case:lower]", Some("%d/%m/%Y %P"))]
+#[case("[month repr:short case_sensitive:false]", Some("%b"))]
+#[case("[year range:standard pivot:posix repr:last_two]", Some("%y"))]
+#[case("[optional [[hour]]]", Some("%H"))]
+#[case("[first [[hour]] [[minute]]]", Some("%H"))]
+#[case("[month repr:long case:lower]", None)]
+#[case("[month repr:narrow]", None)]
+#[case("[weekday repr:narrow]", None)]
+#[case("[period repr:narrow]", None)]
+#[case("[weekday repr:sunday one_indexed:false]", None)]
+#[case("[year sign:mandatory]", None)]
+#[case("[year repr:century base:iso_week]", None)]
+#[case("[subsecond]", None)]
+#[case("[offset_minute]", None)]
+#[case("[offset_hour]", None)]
+#[case("[unix_timestamp precision:millisecond]", None)]
+#[case("[ignore count:1]", None)]
+#[case("[end]", None)]
+#[case("[if subsecond_is_zero [] [.[subsecond]]]", Some("%.f"))]
+#[case("[if subsecond_is_zero [.0] [.[subsecond]]]", None)]
+#[case("[if offset_is_utc [Z] [[offset_hour sign:mandatory]:[offset_minute]]]", None)]
+fn description_to_strftime(
+    #[case] description: &str,
+    #[case] expected: Option<&str>,
+) -> time::Result<()> {
+    let owned = format_description::parse_owned::<2>(description)?;
+    assert_eq!(owned.to_strftime().as_deref(), expected);
+    Ok(())
+}
+
+#[rstest]
+#[case("[year]-[month]-[day]", Target::Date)]
+#[case("[year]-[ordinal]", Target::Date)]
+#[case("[year base:iso_week]-W[week_number]-[weekday repr:monday]", Target::Date)]
+#[case("[year] [week_number repr:sunday] [weekday]", Target::Date)]
+#[case("[year repr:century][year repr:last_two]-[ordinal]", Target::Date)]
+#[case("[year repr:last_two pivot:posix][month][day]", Target::Date)]
+#[case("[hour]", Target::Time)]
+#[case("[hour repr:12]:[minute] [period]", Target::Time)]
+#[case("[hour padding:none]:[minute padding:none]:[second]", Target::Time)]
+#[case("[hour][minute][second][subsecond]", Target::Time)]
+#[case("[year][month repr:short padding:none][day]", Target::Date)]
+#[case("[offset_hour sign:mandatory]:[offset_minute]", Target::UtcOffset)]
+#[case("[year]-[month]-[day]T[hour]:[minute]", Target::PrimitiveDateTime)]
+#[case("[year]-[month]-[day]T[hour][offset_hour sign:mandatory]", Target::OffsetDateTime)]
+#[case("[unix_timestamp]", Target::OffsetDateTime)]
+#[case("[unix_timestamp].[subsecond]", Target::UtcDateTime)]
+#[case("[year]-[month]-[day] [hour][end]", Target::UtcDateTime)]
+#[case("[optional [[year]]]-[month]-[day]", Target::Date)]
+#[case("[first [[ordinal]] [[month]]][year]", Target::Date)]
+#[case("[year]-[month] [weekday_in_month] [weekday]", Target::Date)]
+#[case("[year]-[month] [week_of_month] [weekday repr:monday]", Target::Date)]
+#[case("[year]-[quarter repr:short]", Target::Date)]
+#[case("[year era_based:true] [era]-[ordinal]", Target::Date)]
+#[case("[julian_day] [weekday]", Target::Date)]
+#[case("[julian_day] [hour]:[minute]", Target::PrimitiveDateTime)]
+#[case("[julian_day digits:14]", Target::UtcDateTime)]
+#[case("[hour]:[minute][if second_is_zero [] [:[second]]]", Target::Time)]
+#[case("[if offset_is_utc [Z] [[offset_hour sign:mandatory]]]", Target::UtcOffset)]
+fn analyze_ok(#[case] description: &str, #[case] target: Target) -> time::Result<()> {
+    let owned = format_description::parse_owned::<2>(description)?;
+    assert_eq!(analyze_owned(&owned, target), Ok(()));
+    if let Ok(borrowed) = format_description::parse_borrowed::<2>(description) {
+        assert_eq!(analyze(&borrowed, target), Ok(()));
+    }
+    Ok(())
+}
+
+#[rstest]
+#[case("[year]-[month]", Target::Date, Issue::MissingComponent("day"))]
+#[case("[year]-[month repr:narrow]-[day]", Target::Date, Issue::MissingComponent("month"))]
+#[case("[month]-[day]", Target::Date, Issue::MissingComponent("year"))]
+#[case("[year] [weekday_in_month] [weekday]", Target::Date, Issue::MissingComponent("month"))]
+#[case("[year]-[month] [week_of_month]", Target::Date, Issue::MissingComponent("weekday"))]
+#[case("[year repr:last_two]-[ordinal]", Target::Date, Issue::MissingComponent("century"))]
+#[case("[year era_based:true]-[ordinal]", Target::Date, Issue::MissingComponent("era"))]
+#[case("[julian_day digits:1]", Target::PrimitiveDateTime, Issue::UnavailableComponent(
+    Component::JulianDay(modifier::JulianDay::default().with_digits(1))
+))]
+#[case("[julian_day] [year]", Target::Date, Issue::ConflictingComponents(
+    Component::Year(modifier::Year::default())
+))]
+#[case("[year]-[month]-[day] [julian_day]", Target::Date, Issue::ConflictingComponents(
+    Component::JulianDay(modifier::JulianDay::default())
+))]
+#[case("[julian_day digits:1] [hour]", Target::OffsetDateTime, Issue::ConflictingComponents(
+    Component::Hour(modifier::Hour::default())
+))]
+#[case("[year]-W[week_number]-[weekday]", Target::Date, Issue::MissingComponent("ISO year"))]
+#[case("[minute]", Target::Time, Issue::MissingComponent("hour"))]
+#[case("[hour repr:12]:[minute]", Target::Time, Issue::MissingComponent("period"))]
+#[case("[hour]:[second]", Target::Time, Issue::MissingComponent("minute"))]
+#[case("[offset_minute]", Target::UtcOffset, Issue::MissingComponent("offset hour"))]
+#[case("[offset_hour][offset_second]", Target::UtcOffset, Issue::MissingComponent("offset minute"))]
+#[case(
+    "[year]-[month]-[day] [hour]",
+    Target::OffsetDateTime,
+    Issue::MissingComponent("offset hour")
+)]
+#[case("[unix_timestamp]", Target::PrimitiveDateTime, Issue::UnavailableComponent(
+    Component::UnixTimestamp(modifier::UnixTimestamp::default())
+))]
+#[case("[hour]", Target::Date, Issue::UnavailableComponent(
+    Component::Hour(modifier::Hour::default())
+))]
+#[case("[hour][minute padding:none][second]", Target::Time, Issue::AmbiguousBoundary(
+    Component::Minute(modifier!(Minute { padding: modifier::Padding::None }))
+))]
+#[case("[hour][subsecond]1", Target::Time, Issue::AmbiguousBoundary(
+    Component::Subsecond(modifier::Subsecond::default())
+))]
+#[case("[year]-[ordinal]-[day]", Target::Date, Issue::ConflictingComponents(
+    Component::Day(modifier::Day::default())
+))]
+#[case("[hour][hour repr:12][period]", Target::Time, Issue::ConflictingComponents(
+    Component::Hour(modifier!(Hour { is_12_hour_clock: true }))
+))]
+#[case("[hour] [ignore count:2]", Target::Time, Issue::Ignore(
+    modifier::Ignore::count(core::num::NonZero::new(2).expect("nonzero"))
+))]
+#[case("[hour][end]:", Target::Time, Issue::ItemAfterEnd)]
+fn analyze_issue(
+    #[case] description: &str,
+    #[case] target: Target,
+    #[case] expected: Issue,
+) -> time::Result<()> {
+    let owned = format_description::parse_owned::<2>(description)?;
+    assert_eq!(analyze_owned(&owned, target), Err(expected));
+    let borrowed = format_description::parse_borrowed::<2>(description)?;
+    assert_eq!(analyze(&borrowed, target), Err(expected));
+    Ok(())
+}
+
+#[rstest]
+fn analyze_issue_display() {
+    assert_eq!(
+        Issue::MissingComponent("day").to_string(),
+        "the day is necessary to construct the target type, but is not present"
+    );
+    assert_eq!(
+        Issue::AmbiguousBoundary(Component::Hour(
+            modifier!(Hour { padding: modifier::Padding::None })
+        ))
+        .to_string(),
+        "the component [hour padding:none] has a variable width and is followed by an item that \
+         may begin with a digit"
+    );
+    assert_eq!(
+        Issue::ItemAfterEnd.to_string(),
+        Issue::ItemAfterEnd.message()
+    );
+}
+
+#[rstest]
+fn analyze_macro() {
+    let format = fd!(round_trip = Date, "[year]-[month]-[day]");
+    assert_eq!(format, fd!("[year]-[month]-[day]"));
+    let format = fd!(version = 2, round_trip = OffsetDateTime, "[unix_timestamp]");
+    assert_eq!(format, fd!(version = 2, "[unix_timestamp]"));
+}
+
+#[rstest]
+fn modifier_constructors() {
+    use modifier::*;
+
+    assert_eq!(Month::numerical(), Month::default());
+    assert_eq!(Month::short().repr, MonthRepr::Short);
+    assert_eq!(Month::long().repr, MonthRepr::Long);
+    assert_eq!(Month::narrow().repr, MonthRepr::Narrow);
+    assert_eq!(Weekday::long(), Weekday::default());
+    assert_eq!(Weekday::short().repr, WeekdayRepr::Short);
+    assert_eq!(Weekday::narrow().repr, WeekdayRepr::Narrow);
+    assert_eq!(Weekday::sunday_based().repr, WeekdayRepr::Sunday);
+    assert_eq!(Weekday::monday_based().repr, WeekdayRepr::Monday);
+    assert_eq!(WeekNumber::iso(), WeekNumber::default());
+    assert_eq!(WeekNumber::sunday_based().repr, WeekNumberRepr::Sunday);
+    assert_eq!(WeekNumber::monday_based().repr, WeekNumberRepr::Monday);
+    assert_eq!(Year::full(), Year::default());
+    assert_eq!(Year::century().repr, YearRepr::Century);
+    assert_eq!(Year::last_two().repr, YearRepr::LastTwo);
+    assert_eq!(Hour::twenty_four_hour(), Hour::default());
+    assert!(Hour::twelve_hour().is_12_hour_clock);
+
+    let year = Year::last_two()
+        .with_padding(Padding::Space)
+        .with_range(YearRange::Standard)
+        .with_iso_week_based(true)
+        .with_sign_is_mandatory(true)
+        .with_pivot(YearPivot::POSIX);
+    assert_eq!(year.padding, Padding::Space);
+    assert_eq!(year.repr, YearRepr::LastTwo);
+    assert_eq!(year.range, YearRange::Standard);
+    assert!(year.iso_week_based);
+    assert!(year.sign_is_mandatory);
+    assert_eq!(year.pivot, YearPivot::POSIX);
+
+    let weekday = Weekday::monday_based()
+        .with_one_indexed(false)
+        .with_case(TextCase::Upper)
+        .with_case_sensitive(false);
+    assert!(!weekday.one_indexed);
+    assert_eq!(weekday.case, TextCase::Upper);
+    assert!(!weekday.case_sensitive);
+
+    assert_eq!(
+        Subsecond::default().with_digits(SubsecondDigits::Three).digits,
+        SubsecondDigits::Three
+    );
+    assert!(!Period::default().with_is_uppercase(false).is_uppercase);
+    assert_eq!(
+        Period::default().with_repr(PeriodRepr::Narrow).repr,
+        PeriodRepr::Narrow
+    );
+    let week_of_month = WeekOfMonth::default()
+        .with_first_weekday(time::Weekday::Sunday)
+        .with_minimal_days(4);
+    assert_eq!(week_of_month.first_weekday, time::Weekday::Sunday);
+    assert_eq!(week_of_month.minimal_days, 4);
+    assert!(WeekdayInMonth::default().with_from_end(true).from_end);
+    assert_eq!(Quarter::short().repr, QuarterRepr::Short);
+    assert!(!Quarter::numerical().with_case_sensitive(false).case_sensitive);
+    assert_eq!(Era::short().repr, EraRepr::Short);
+    assert_eq!(Era::common().repr, EraRepr::Common);
+    assert_eq!(Era::long().repr, EraRepr::Long);
+    assert_eq!(Era::narrow().repr, EraRepr::Narrow);
+    assert!(Year::year_of_era().era_based);
+    assert_eq!(JulianDay::julian().repr, JulianDayRepr::Julian);
+    assert_eq!(JulianDay::modified().repr, JulianDayRepr::Modified);
+    assert_eq!(JulianDay::modified().with_digits(5).digits, 5);
+    assert_eq!(
+        UnixTimestamp::default()
+            .with_precision(UnixTimestampPrecision::Millisecond)
+            .precision,
+        UnixTimestampPrecision::Millisecond
+    );
+}
+
+#[rstest]
+fn builder_const() {
+    use modifier::*;
+
+    const SUBSECOND: [BorrowedFormatItem<'_>; 2] = FormatBuilder::new()
+        .literal(".")
+        .subsecond(Subsecond::default())
+        .build();
+    const OFFSETS: [BorrowedFormatItem<'_>; 2] = [
+        BorrowedFormatItem::Compound(&[
+            BorrowedFormatItem::Component(Component::OffsetHour(
+                OffsetHour::default().with_sign_is_mandatory(true),
+            )),
+            BorrowedFormatItem::Literal(b":"),
+            BorrowedFormatItem::Component(Component::OffsetMinute(OffsetMinute::default())),
+        ]),
+        BorrowedFormatItem::Literal(b"Z"),
+    ];
+    const FORMAT: [BorrowedFormatItem<'_>; 14] = FormatBuilder::for_target(Target::OffsetDateTime)
+        .year(Year::full())
+        .literal("-")
+        .month(Month::numerical())
+        .literal("-")
+        .day(Day::default())
+        .literal("T")
+        .hour(Hour::twenty_four_hour())
+        .literal(":")
+        .minute(Minute::default())
+        .literal(":")
+        .second(Second::default())
+        .optional(&BorrowedFormatItem::Compound(&SUBSECOND))
+        .first(&OFFSETS)
+        .end()
+        .build();
+
+    assert_eq!(
+        OwnedFormatItem::from(FORMAT.as_slice()).to_string(),
+        "[year]-[month]-[day]T[hour]:[minute]:[second][optional [.[subsecond]]]\
+         [first [[offset_hour sign:mandatory]:[offset_minute]] [Z]][end]"
+    );
+}
+
+#[rstest]
+fn builder_conditional() {
+    use modifier::*;
+
+    const SECOND: [BorrowedFormatItem<'_>; 2] = FormatBuilder::new()
+        .literal(":")
+        .second(Second::default())
+        .build();
+    const FORMAT: [BorrowedFormatItem<'_>; 4] = FormatBuilder::for_target(Target::Time)
+        .hour(Hour::twenty_four_hour())
+        .literal(":")
+        .minute(Minute::default())
+        .conditional(
+            Condition::SecondIsZero,
+            &BorrowedFormatItem::Compound(&[]),
+            &BorrowedFormatItem::Compound(&SECOND),
+        )
+        .build();
+
+    assert_eq!(
+        FORMAT.as_slice(),
+        fd!(version = 2, "[hour]:[minute][if second_is_zero [] [:[second]]]")
+    );
+}
+
+#[rstest]
+fn builder_runtime() -> time::Result<()> {
+    use modifier::*;
+
+    let builder = FormatBuilder::<8>::new();
+    assert!(builder.is_empty());
+    let builder = builder
+        .hour(Hour::twelve_hour().with_padding(Padding::None))
+        .literal(":")
+        .minute(Minute::default())
+        .literal(" ")
+        .period(Period::default().with_is_uppercase(false));
+    assert_eq!(builder.len(), 5);
+    assert!(!builder.is_empty());
+    assert_eq!(
+        builder.as_slice(),
+        fd!("[hour repr:12 padding:none]:[minute] [period case:lower]")
+    );
+    assert_eq!(builder.validate(), Ok(()));
+    assert_eq!(
+        builder.build_owned(),
+        Ok(format_description::parse_owned::<2>(
+            "[hour repr:12 padding:none]:[minute] [period case:lower]"
+        )?)
+    );
+
+    let ignore = Ignore::count(core::num::NonZero::new(1).expect("nonzero"));
+    let builder = FormatBuilder::<4>::default()
+        .component(Component::UnixTimestamp(UnixTimestamp::default()))
+        .time_zone_annotation()
+        .extension_annotations()
+        .ignore(ignore);
+    assert_eq!(
+        builder.as_slice(),
+        [
+            BorrowedFormatItem::Component(Component::UnixTimestamp(
+                UnixTimestamp::default()
+            )),
+            BorrowedFormatItem::Component(Component::TimeZoneAnnotation(
+                TimeZoneAnnotation::default()
+            )),
+            BorrowedFormatItem::Component(Component::ExtensionAnnotations(
+                ExtensionAnnotations::default()
+            )),
+            BorrowedFormatItem::Component(Component::Ignore(ignore)),
+        ]
+    );
+    assert!(format!("{builder:?}").starts_with("FormatBuilder { items: ["));
+
+    Ok(())
+}
+
+#[rstest]
+fn builder_validation() {
+    use modifier::*;
+
+    let builder = FormatBuilder::<3>::for_target(Target::Date)
+        .year(Year::full())
+        .literal("-")
+        .month(Month::numerical());
+    assert_eq!(builder.validate(), Err(Issue::MissingComponent("day")));
+    assert_eq!(
+        builder.clone().build_owned(),
+        Err(Issue::MissingComponent("day"))
+    );
+    assert_panic!(builder.build());
+
+    let builder = FormatBuilder::<2>::for_target(Target::Time)
+        .hour(Hour::default().with_padding(Padding::None))
+        .minute(Minute::default());
+    assert!(matches!(
+        builder.validate(),
+        Err(Issue::AmbiguousBoundary(Component::Hour(_)))
+    ));
+
+    // Without a target, no analysis is performed.
+    let builder = FormatBuilder::<1>::new().month(Month::long());
+    assert_eq!(builder.validate(), Ok(()));
+    assert_eq!(builder.build().len(), 1);
+}
+
+#[rstest]
+fn builder_capacity() {
+    assert_panic!(FormatBuilder::<1>::new().literal("a").literal("b"));
+    assert_panic!(FormatBuilder::<2>::new().literal("a").build());
+}
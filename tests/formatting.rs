@@ -2,8 +2,11 @@ use std::io;
 use std::num::NonZero;
 
 use time::format_description::well_known::iso8601::{DateKind, OffsetPrecision, TimePrecision};
-use time::format_description::well_known::{iso8601, Iso8601, Rfc2822, Rfc3339};
+use time::format_description::well_known::{
+    iso8601, HttpDate, Iso8601, Rfc2822, Rfc3339, Rfc9557,
+};
 use time::format_description::{self, BorrowedFormatItem, OwnedFormatItem};
+use time::interval::{Endpoint, Interval, NominalDuration, RepeatingInterval};
 use time::macros::{date, datetime, format_description as fd, offset, time, utc_datetime};
 use time::{OffsetDateTime, Time};
 
@@ -26,12 +29,21 @@ fn rfc_2822() -> time::Result<()> {
         "Sat, 02 Jan 2021 03:04:05 -0607"
     );
 
+    assert_eq!(
+        datetime!(1885-01-01 01:01:01 UTC).format(&Rfc2822)?,
+        "Thu, 01 Jan 1885 01:01:01 +0000"
+    );
+    assert_eq!(
+        utc_datetime!(0001-01-01 01:01:01).format(&Rfc2822)?,
+        "Mon, 01 Jan 0001 01:01:01 +0000"
+    );
+
     assert!(matches!(
-        datetime!(1885-01-01 01:01:01 UTC).format(&Rfc2822),
+        datetime!(-0001-01-01 01:01:01 UTC).format(&Rfc2822),
         Err(time::error::Format::InvalidComponent("year"))
     ));
     assert!(matches!(
-        utc_datetime!(1885-01-01 01:01:01).format(&Rfc2822),
+        utc_datetime!(+10_000-01-01 01:01:01).format(&Rfc2822),
         Err(time::error::Format::InvalidComponent("year"))
     ));
     assert!(matches!(
@@ -42,6 +54,72 @@ fn rfc_2822() -> time::Result<()> {
     Ok(())
 }
 
+#[test]
+fn rfc_9557() -> time::Result<()> {
+    assert_eq!(
+        datetime!(2021-01-02 03:04:05 UTC).format(&Rfc9557)?,
+        "2021-01-02T03:04:05+00:00[+00:00]"
+    );
+    assert_eq!(
+        datetime!(2021-01-02 03:04:05.123 +01:00).format(&Rfc9557)?,
+        "2021-01-02T03:04:05.123+01:00[+01:00]"
+    );
+    assert_eq!(
+        datetime!(2021-01-02 03:04:05 -00:30).format(&Rfc9557)?,
+        "2021-01-02T03:04:05-00:30[-00:30]"
+    );
+
+    assert!(matches!(
+        datetime!(2021-01-02 03:04:05 +00:00:01).format(&Rfc9557),
+        Err(time::error::Format::InvalidComponent("offset_second"))
+    ));
+    assert!(matches!(
+        date!(2021-01-02).format(&Rfc9557),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn http_date() -> time::Result<()> {
+    assert_eq!(
+        datetime!(1994-11-06 08:49:37 UTC).format(&HttpDate)?,
+        "Sun, 06 Nov 1994 08:49:37 GMT"
+    );
+    assert_eq!(
+        utc_datetime!(1994-11-06 08:49:37.5).format(&HttpDate)?,
+        "Sun, 06 Nov 1994 08:49:37 GMT"
+    );
+    assert_eq!(
+        datetime!(1994-11-06 02:49:37 -06:00).format(&HttpDate)?,
+        "Sun, 06 Nov 1994 08:49:37 GMT"
+    );
+    assert_eq!(
+        datetime!(1994-11-07 00:49:37 +16:00).format(&HttpDate)?,
+        "Sun, 06 Nov 1994 08:49:37 GMT"
+    );
+    assert_eq!(
+        datetime!(0100-01-01 00:00:00 UTC).format(&HttpDate)?,
+        "Fri, 01 Jan 0100 00:00:00 GMT"
+    );
+
+    assert!(matches!(
+        datetime!(-0001-01-01 00:00:00 UTC).format(&HttpDate),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        datetime!(0000-01-01 00:00:00 +01:00).format(&HttpDate),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        datetime!(1994-11-06 08:49:37).format(&HttpDate),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+
+    Ok(())
+}
+
 #[test]
 fn rfc_3339() -> time::Result<()> {
     assert_eq!(
@@ -253,6 +331,141 @@ fn iso_8601_issue_678() -> time::Result<()> {
     Ok(())
 }
 
+#[test]
+fn iso_8601_nominal_duration() -> time::Result<()> {
+    assert_eq!(NominalDuration::ZERO.to_string(), "PT0S");
+    assert_eq!(
+        NominalDuration::ZERO
+            .with_years(1)
+            .with_months(2)
+            .with_weeks(3)
+            .with_days(4)
+            .with_hours(5)
+            .with_minutes(6)
+            .with_seconds(7)
+            .to_string(),
+        "P1Y2M3W4DT5H6M7S"
+    );
+    assert_eq!(NominalDuration::ZERO.with_months(1).to_string(), "P1M");
+    assert_eq!(NominalDuration::ZERO.with_minutes(1).to_string(), "PT1M");
+    assert_eq!(
+        NominalDuration::ZERO.with_days(1).with_seconds(30).to_string(),
+        "P1DT30S"
+    );
+    assert_eq!(
+        NominalDuration::ZERO
+            .with_nanoseconds(500_000_000)?
+            .to_string(),
+        "PT0.5S"
+    );
+    assert_eq!(
+        NominalDuration::ZERO
+            .with_seconds(1)
+            .with_nanoseconds(1_000)?
+            .to_string(),
+        "PT1.000001S"
+    );
+
+    Ok(())
+}
+
+#[test]
+fn iso_8601_interval() -> time::Result<()> {
+    const FORMAT: Iso8601<
+        {
+            iso8601::Config::DEFAULT
+                .set_time_precision(TimePrecision::Minute {
+                    decimal_digits: None,
+                })
+                .encode()
+        },
+    > = Iso8601;
+    const BASIC: Iso8601<
+        {
+            iso8601::Config::DEFAULT
+                .set_use_separators(false)
+                .set_time_precision(TimePrecision::Minute {
+                    decimal_digits: None,
+                })
+                .encode()
+        },
+    > = Iso8601;
+
+    let start_end = Interval::StartEnd {
+        start: Endpoint::Date(date!(2024-02-15)),
+        end: Endpoint::PrimitiveDateTime(datetime!(2024-02-18 12:00)),
+    };
+    assert_eq!(start_end.format(&FORMAT)?, "2024-02-15/2024-02-18T12:00");
+    assert_eq!(start_end.format(&BASIC)?, "20240215/20240218T1200");
+    assert_eq!(
+        start_end.format(&Iso8601::DEFAULT)?,
+        "2024-02-15/2024-02-18T12:00:00.000000000"
+    );
+
+    assert_eq!(
+        Interval::StartDuration {
+            start: Endpoint::OffsetDateTime(datetime!(2024-01-01 0:00 UTC)),
+            duration: NominalDuration::ZERO.with_days(1),
+        }
+        .format(&FORMAT)?,
+        "2024-01-01T00:00Z/P1D"
+    );
+    assert_eq!(
+        Interval::DurationEnd {
+            duration: NominalDuration::ZERO.with_hours(36),
+            end: Endpoint::OffsetDateTime(datetime!(2024-01-03 0:00 -05:00)),
+        }
+        .format(&FORMAT)?,
+        "PT36H/2024-01-03T00:00-05:00"
+    );
+    assert_eq!(
+        Interval::Duration(NominalDuration::ZERO.with_weeks(1)).format(&FORMAT)?,
+        "P1W"
+    );
+    assert!(matches!(
+        Interval::StartEnd {
+            start: Endpoint::Date(date!(-10_000-01-01)),
+            end: Endpoint::Date(date!(2024-01-01)),
+        }
+        .format(&FORMAT),
+        Err(time::error::Format::InvalidComponent("year"))
+    ));
+
+    let mut buf = Vec::new();
+    assert_eq!(start_end.format_into(&mut buf, &FORMAT)?, 27);
+    assert_eq!(buf, b"2024-02-15/2024-02-18T12:00");
+    let mut buf = String::new();
+    assert_eq!(start_end.format_into_fmt(&mut buf, &FORMAT)?, 27);
+    assert_eq!(buf, "2024-02-15/2024-02-18T12:00");
+
+    Ok(())
+}
+
+#[test]
+fn iso_8601_repeating_interval() -> time::Result<()> {
+    let interval = Interval::StartDuration {
+        start: Endpoint::Date(date!(2024-01-01)),
+        duration: NominalDuration::ZERO.with_days(1),
+    };
+    assert_eq!(
+        RepeatingInterval::new(Some(5), interval).format(&Iso8601::DEFAULT)?,
+        "R5/2024-01-01/P1D"
+    );
+    assert_eq!(
+        RepeatingInterval::new(None, interval).format(&Iso8601::DEFAULT)?,
+        "R/2024-01-01/P1D"
+    );
+
+    let mut buf = Vec::new();
+    assert_eq!(
+        RepeatingInterval::new(Some(12), interval).format_into(&mut buf, &Iso8601::DEFAULT)?,
+        18
+    );
+    assert_eq!(buf, b"R12/2024-01-01/P1D");
+
+    Ok(())
+}
+
 #[test]
 fn format_time() -> time::Result<()> {
     let format_output = [
@@ -271,6 +484,8 @@ fn format_time() -> time::Result<()> {
         (fd!("[period]"), "PM"),
         (fd!("[period case:upper]"), "PM"),
         (fd!("[period case:lower]"), "pm"),
+        (fd!("[period repr:narrow]"), "P"),
+        (fd!("[period case:lower repr:narrow]"), "p"),
         (fd!("[second]"), "03"),
         (fd!("[second padding:none]"), "3"),
         (fd!("[second padding:space]"), " 3"),
@@ -377,9 +592,16 @@ fn format_date() -> time::Result<()> {
         (fd!("[month]"), "12"),
         (fd!("[month repr:short]"), "Dec"),
         (fd!("[month repr:long]"), "December"),
+        (fd!("[month repr:short case:upper]"), "DEC"),
+        (fd!("[month repr:long case:lower]"), "december"),
+        (fd!("[month repr:narrow]"), "D"),
+        (fd!("[month repr:narrow case:lower]"), "d"),
         (fd!("[ordinal]"), "365"),
         (fd!("[weekday]"), "Tuesday"),
         (fd!("[weekday repr:short]"), "Tue"),
+        (fd!("[weekday case:upper]"), "TUESDAY"),
+        (fd!("[weekday repr:short case:lower]"), "tue"),
+        (fd!("[weekday repr:narrow]"), "T"),
         (fd!("[weekday repr:sunday]"), "3"),
         (fd!("[weekday repr:sunday one_indexed:false]"), "2"),
         (fd!("[weekday repr:monday]"), "2"),
@@ -389,6 +611,13 @@ fn format_date() -> time::Result<()> {
         (fd!("[week_number padding:space]"), " 1"),
         (fd!("[week_number repr:sunday]"), "52"),
         (fd!("[week_number repr:monday]"), "52"),
+        (fd!("[week_of_month]"), "6"),
+        (fd!("[week_of_month minimal_days:4]"), "5"),
+        (fd!("[week_of_month first_weekday:sunday]"), "5"),
+        (fd!("[weekday_in_month]"), "5"),
+        (fd!("[weekday_in_month from_end:true]"), "1"),
+        (fd!("[quarter]"), "4"),
+        (fd!("[quarter repr:short]"), "Q4"),
         (fd!("[year]"), "2019"),
         (fd!("[year base:iso_week]"), "2020"),
         (fd!("[year sign:mandatory]"), "+2019"),
@@ -399,6 +628,13 @@ fn format_date() -> time::Result<()> {
         (fd!("[year range:standard]"), "2019"),
         (fd!("[year range:standard repr:century]"), "20"),
         (fd!("[year range:standard repr:last_two]"), "19"),
+        (fd!("[year era_based:true]"), "2019"),
+        (fd!("[era]"), "AD"),
+        (fd!("[era repr:common]"), "CE"),
+        (fd!("[era repr:long]"), "Anno Domini"),
+        (fd!("[era repr:narrow]"), "A"),
+        (fd!("[julian_day]"), "2458849"),
+        (fd!("[julian_day repr:modified]"), "58848"),
     ];
 
     for &(format_description, output) in &format_output {
@@ -415,6 +651,23 @@ fn format_date() -> time::Result<()> {
             .is_ok());
     }
 
+    assert_eq!(
+        date!(0000-01-01).format(fd!("[year era_based:true padding:none] [era]"))?,
+        "1 BC"
+    );
+    assert_eq!(
+        date!(-0043-03-15).format(fd!("[year era_based:true padding:none] [era repr:common]"))?,
+        "44 BCE"
+    );
+    assert_eq!(
+        date!(0001-01-01).format(fd!("[year era_based:true sign:mandatory] [era]"))?,
+        "0001 AD"
+    );
+    assert_eq!(
+        date!(-0005-01-01).format(&format_description::parse_icu_owned("yyyy G GGGG GGGGG uuuu")?)?,
+        "0006 BC Before Christ B -0005"
+    );
+
     Ok(())
 }
 
@@ -428,6 +681,18 @@ fn format_date_err() {
         date!(+10_000-01-01).format(fd!("[year repr:century range:standard]")),
         Err(time::error::Format::ComponentRange(cr)) if cr.name() == "year"
     ));
+    assert!(matches!(
+        date!(+10_000-01-01).format_to_slice(&mut [0; 16], fd!("[year range:standard]")),
+        Err(time::error::FormatCore::ComponentRange(cr)) if cr.name() == "year"
+    ));
+    assert!(matches!(
+        date!(-0001-01-01).format(fd!("[year era_based:true]")),
+        Err(time::error::Format::ComponentRange(cr)) if cr.name() == "year"
+    ));
+    assert!(matches!(
+        date!(0000-01-01).format_into_fmt(&mut String::new(), fd!("[year era_based:true]-[month]")),
+        Err(time::error::FormatCore::ComponentRange(cr)) if cr.name() == "year"
+    ));
 }
 
 #[test]
@@ -773,6 +1038,141 @@ fn failed_write() -> time::Result<()> {
     Ok(())
 }
 
+#[test]
+fn format_to_slice() -> time::Result<()> {
+    macro_rules! assert_slice {
+        ($val:expr, $format:expr) => {{
+            let val = $val;
+            let format = $format;
+            let expected = val.format(&format)?;
+            let mut buf = [0; 64];
+            let len = val.format_to_slice(&mut buf, &format)?;
+            assert_eq!(&buf[..len], expected.as_bytes());
+            for len in 0..expected.len() {
+                assert!(matches!(
+                    val.format_to_slice(&mut buf[..len], &format),
+                    Err(time::error::FormatCore::InsufficientBufferSize)
+                ));
+            }
+        }};
+    }
+
+    assert_slice!(Time::MIDNIGHT, fd!("[hour]:[minute]:[second].[subsecond]"));
+    assert_slice!(date!(2021-001), fd!("[year]-[month]-[day] [weekday]"));
+    assert_slice!(offset!(-1:02:03), fd!("[offset_hour]:[offset_minute]:[offset_second]"));
+    assert_slice!(datetime!(2021-001 0:00), fd!("[year]-[ordinal] [hour]"));
+    assert_slice!(datetime!(2021-001 0:00:00.1 +0:01), Rfc3339);
+    assert_slice!(utc_datetime!(2021-001 0:00), Rfc2822);
+    assert_slice!(OffsetDateTime::UNIX_EPOCH, Iso8601::DEFAULT);
+    assert_slice!(OffsetDateTime::UNIX_EPOCH, HttpDate);
+    assert_slice!(
+        date!(2021-001),
+        OwnedFormatItem::from(fd!("[year]-[month]-[day]"))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn format_into_fmt() -> time::Result<()> {
+    macro_rules! assert_fmt {
+        ($val:expr, $format:expr) => {{
+            let val = $val;
+            let format = $format;
+            let mut output = String::new();
+            let len = val.format_into_fmt(&mut output, &format)?;
+            assert_eq!(output, val.format(&format)?);
+            assert_eq!(len, output.len());
+        }};
+    }
+
+    assert_fmt!(Time::MIDNIGHT, fd!("[hour]:[minute]:[second].[subsecond]"));
+    assert_fmt!(date!(2021-001), fd!("[year]-[month]-[day] [weekday]"));
+    assert_fmt!(offset!(-1:02:03), fd!("[offset_hour]:[offset_minute]:[offset_second]"));
+    assert_fmt!(datetime!(2021-001 0:00), fd!("[year]-[ordinal] [hour]"));
+    assert_fmt!(datetime!(2021-001 0:00:00.1 +0:01), Rfc3339);
+    assert_fmt!(utc_datetime!(2021-001 0:00), Rfc2822);
+    assert_fmt!(OffsetDateTime::UNIX_EPOCH, Iso8601::DEFAULT);
+
+    let mut output = String::new();
+    assert!(matches!(
+        Time::MIDNIGHT.format_into_fmt(&mut output, &BorrowedFormatItem::Literal(b"\xff")),
+        Err(time::error::FormatCore::Fmt(_))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn format_inline() -> time::Result<()> {
+    const SIX_DIGIT_YEAR: iso8601::EncodedConfig = iso8601::Config::DEFAULT
+        .set_year_is_six_digits(true)
+        .encode();
+    const MAX_LEN: iso8601::EncodedConfig = iso8601::Config::DEFAULT
+        .set_year_is_six_digits(true)
+        .set_date_kind(DateKind::Week)
+        .set_time_precision(TimePrecision::Second {
+            decimal_digits: NonZero::new(u8::MAX),
+        })
+        .encode();
+
+    let odt = datetime!(-123_456-12-31 23:59:59.999_999_999 -23:59);
+    let udt = utc_datetime!(2021-01-02 03:04:05.6);
+
+    assert_eq!(
+        datetime!(2021-01-02 03:04:05.6 +06:07).format_rfc3339_inline()?,
+        "2021-01-02T03:04:05.6+06:07"
+    );
+    assert_eq!(
+        datetime!(9999-12-31 23:59:59.999_999_999 -23:59)
+            .format_rfc3339_inline()?
+            .len(),
+        35
+    );
+    assert_eq!(
+        udt.format_rfc3339_inline()?.as_str(),
+        udt.format(&Rfc3339)?
+    );
+    assert_eq!(
+        datetime!(2021-01-02 03:04:05 +06:07).format_rfc2822_inline()?,
+        "Sat, 02 Jan 2021 03:04:05 +0607"
+    );
+    assert_eq!(
+        datetime!(2021-12-31 23:59:59 -23:59)
+            .format_rfc2822_inline()?
+            .len(),
+        31
+    );
+    assert_eq!(
+        udt.format_rfc2822_inline()?.as_bytes(),
+        udt.format(&Rfc2822)?.as_bytes()
+    );
+    assert_eq!(
+        udt.format_iso8601_inline::<{ iso8601::Config::DEFAULT.encode() }>()?,
+        "2021-01-02T03:04:05.600000000Z"
+    );
+    assert_eq!(
+        odt.format_iso8601_inline::<SIX_DIGIT_YEAR>()?.to_string(),
+        odt.format(&Iso8601::<SIX_DIGIT_YEAR>)?
+    );
+    assert_eq!(
+        &*odt.format_iso8601_inline::<MAX_LEN>()?,
+        odt.format(&Iso8601::<MAX_LEN>)?
+    );
+    assert_eq!(odt.format_iso8601_inline::<MAX_LEN>()?.len(), 284);
+
+    assert!(matches!(
+        odt.format_rfc3339_inline(),
+        Err(time::error::FormatCore::InvalidComponent("year"))
+    ));
+    assert!(matches!(
+        datetime!(2021-01-02 03:04:05 +0:00:01).format_rfc2822_inline(),
+        Err(time::error::FormatCore::InvalidComponent("offset_second"))
+    ));
+
+    Ok(())
+}
+
 #[test]
 fn first() -> time::Result<()> {
     assert_eq!(Time::MIDNIGHT.format(&BorrowedFormatItem::First(&[]))?, "");
@@ -803,6 +1203,39 @@ fn ignore() -> time::Result<()> {
     Ok(())
 }
 
+#[test]
+fn time_zone_annotation() -> time::Result<()> {
+    assert_eq!(
+        offset!(+01:30).format(fd!("[time_zone_annotation]"))?,
+        "[+01:30]"
+    );
+    assert_eq!(
+        offset!(-00:30).format(fd!("[time_zone_annotation]"))?,
+        "[-00:30]"
+    );
+    assert_eq!(
+        offset!(UTC).format(fd!("[time_zone_annotation]"))?,
+        "[+00:00]"
+    );
+    assert!(matches!(
+        offset!(+00:00:01).format(fd!("[time_zone_annotation]")),
+        Err(time::error::Format::InvalidComponent("time_zone_annotation"))
+    ));
+    assert!(matches!(
+        Time::MIDNIGHT.format(fd!("[time_zone_annotation]")),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn extension_annotations() -> time::Result<()> {
+    assert_eq!(Time::MIDNIGHT.format(fd!("[extension_annotations]"))?, "");
+
+    Ok(())
+}
+
 #[test]
 fn end() -> time::Result<()> {
     assert_eq!(Time::MIDNIGHT.format(fd!("[end]"))?, "");
@@ -838,3 +1271,138 @@ fn unix_timestamp() -> time::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn julian_day() -> time::Result<()> {
+    assert_eq!(
+        datetime!(2000-01-01 12:00 UTC).format(&fd!("[julian_day digits:1]"))?,
+        "2451545.0"
+    );
+    assert_eq!(
+        datetime!(2000-01-01 0:00 UTC).format(&fd!("[julian_day digits:5]"))?,
+        "2451544.50000"
+    );
+    assert_eq!(
+        datetime!(2000-01-01 6:00 -6:00).format(&fd!("[julian_day repr:modified digits:2]"))?,
+        "51544.50"
+    );
+    assert_eq!(
+        datetime!(2009-02-13 23:31:30.123456789 UTC)
+            .format(&fd!("[julian_day repr:modified digits:14]"))?,
+        "54875.98020976223135"
+    );
+    assert_eq!(
+        datetime!(1858-11-16 18:00 UTC).format(&fd!("[julian_day repr:modified digits:2]"))?,
+        "-0.25"
+    );
+    assert_eq!(
+        date!(1858-11-16).format(&fd!("[julian_day repr:modified]"))?,
+        "-1"
+    );
+    assert_eq!(
+        date!(-4713 - 11 - 23).format(&fd!("[julian_day]"))?,
+        "-1"
+    );
+    assert!(matches!(
+        datetime!(2000-01-01 0:00).format(&fd!("[julian_day digits:1]")),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn conditional() -> time::Result<()> {
+    let format = fd!(
+        version = 2,
+        "[hour]:[minute][if second_is_zero [] [:[second]]]\
+         [if subsecond_is_zero [] [.[subsecond]]]\
+         [if offset_is_utc [Z] [[offset_hour sign:mandatory]:[offset_minute]]]"
+    );
+    assert_eq!(datetime!(2024-01-02 03:04 UTC).format(format)?, "03:04Z");
+    assert_eq!(datetime!(2024-01-02 03:04:05 UTC).format(format)?, "03:04:05Z");
+    assert_eq!(
+        datetime!(2024-01-02 03:04:00.5 +01:00).format(format)?,
+        "03:04.5+01:00"
+    );
+    assert_eq!(
+        datetime!(2024-01-02 03:04:05.25 -00:30).format(format)?,
+        "03:04:05.25-00:30"
+    );
+
+    let format = format_description::parse_owned::<2>(
+        "[if offset_is_utc [Z] [[offset_hour sign:mandatory]]]",
+    )?;
+    assert_eq!(offset!(UTC).format(&format)?, "Z");
+    assert_eq!(offset!(-2).format(&format)?, "-02");
+    assert!(matches!(
+        time!(0:00).format(&format),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+    assert!(matches!(
+        offset!(UTC).format(fd!(version = 2, "[if second_is_zero [] []]")),
+        Err(time::error::Format::InsufficientTypeInformation { .. })
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn icu_offset() -> time::Result<()> {
+    // The examples in the documentation of Java's `DateTimeFormatter`.
+    let format = |pattern| -> time::Result<[String; 4]> {
+        let format = format_description::parse_icu_owned(pattern)?;
+        Ok([
+            offset!(UTC).format(&format)?,
+            offset!(-8).format(&format)?,
+            offset!(-8:30).format(&format)?,
+            offset!(-8:30:15).format(&format)?,
+        ])
+    };
+
+    assert_eq!(format("X")?, ["Z", "-08", "-0830", "-0830"]);
+    assert_eq!(format("XX")?, ["Z", "-0800", "-0830", "-0830"]);
+    assert_eq!(format("XXX")?, ["Z", "-08:00", "-08:30", "-08:30"]);
+    assert_eq!(format("XXXX")?, ["Z", "-0800", "-0830", "-083015"]);
+    assert_eq!(format("XXXXX")?, ["Z", "-08:00", "-08:30", "-08:30:15"]);
+    assert_eq!(format("x")?, ["+00", "-08", "-0830", "-0830"]);
+    assert_eq!(format("xx")?, ["+0000", "-0800", "-0830", "-0830"]);
+    assert_eq!(format("xxx")?, ["+00:00", "-08:00", "-08:30", "-08:30"]);
+    assert_eq!(format("xxxx")?, ["+0000", "-0800", "-0830", "-083015"]);
+    assert_eq!(format("xxxxx")?, ["+00:00", "-08:00", "-08:30", "-08:30:15"]);
+    assert_eq!(format("Z")?, ["+0000", "-0800", "-0830", "-0830"]);
+    assert_eq!(format("ZZZZZ")?, ["Z", "-08:00", "-08:30", "-08:30:15"]);
+
+    Ok(())
+}
+
+#[test]
+fn strftime() -> time::Result<()> {
+    let format = format_description::parse_strftime_owned;
+    let dt = datetime!(2024-01-05 06:07:08.25 +01:30);
+
+    assert_eq!(dt.format(&format("%^a %#b %-d %_m")?)?, "FRI JAN 5  1");
+    assert_eq!(dt.format(&format("%p %#p %P %^P")?)?, "AM am am am");
+    assert_eq!(dt.format(&format("%10d|%_6H|%1M|%3u")?)?, "0000000005|     6|7|005");
+    assert_eq!(date!(-0001-01-01).format(&format("%Y|%1Y")?)?, "-0001|-1");
+    assert_eq!(
+        date!(2024-01-05).format(&format("%10Y|%_10Y|%4C|%6G")?)?,
+        "0000002024|      2024|0020|002024"
+    );
+    assert_eq!(
+        date!(-0005-01-05).format(&format("%10Y|%_10Y|%-10Y|%4C")?)?,
+        "-000000005|        -5|-5|-000"
+    );
+    assert_eq!(dt.format(&format("%N|%3N|%.f|%.6f")?)?, "250000000|250|.25|.250000");
+    assert_eq!(
+        dt.format(&format("%z|%:z|%::z|%:::z|%Z")?)?,
+        "+0130|+01:30|+01:30:00|+01|+01:30"
+    );
+    assert_eq!(dt.format(&format("%+")?)?, "2024-01-05T06:07:08.25+01:30");
+    assert_eq!(
+        datetime!(2024-01-05 06:07:08 UTC).format(&format("%+")?)?,
+        "2024-01-05T06:07:08+00:00"
+    );
+
+    Ok(())
+}
@@ -2,7 +2,7 @@ use core::num::NonZero;
 
 use rstest::rstest;
 use time::format_description::modifier::*;
-use time::format_description::{BorrowedFormatItem, Component};
+use time::format_description::{BorrowedFormatItem, Component, Condition};
 use time::macros::{date, format_description, time};
 use time::{Date, Time};
 
@@ -162,6 +162,57 @@ fn first() {
     );
 }
 
+#[rstest]
+fn conditional() {
+    assert_eq!(
+        format_description!(version = 2, "[if offset_is_utc [Z] [[offset_hour]]]"),
+        &[BorrowedFormatItem::Conditional {
+            condition: Condition::OffsetIsUtc,
+            then: &BorrowedFormatItem::Literal(b"Z"),
+            otherwise: &BorrowedFormatItem::Component(Component::OffsetHour(modifier!(
+                OffsetHour
+            ))),
+        }]
+    );
+    assert_eq!(
+        format_description!(version = 2, "[if subsecond_is_zero [] [.[subsecond]]]"),
+        &[BorrowedFormatItem::Conditional {
+            condition: Condition::SubsecondIsZero,
+            then: &BorrowedFormatItem::Compound(&[]),
+            otherwise: &BorrowedFormatItem::Compound(&[
+                BorrowedFormatItem::Literal(b"."),
+                BorrowedFormatItem::Component(Component::Subsecond(modifier!(Subsecond))),
+            ]),
+        }]
+    );
+    assert_eq!(
+        format_description!(version = 2, "[ if second_is_zero [a] [b] ]"),
+        &[BorrowedFormatItem::Conditional {
+            condition: Condition::SecondIsZero,
+            then: &BorrowedFormatItem::Literal(b"a"),
+            otherwise: &BorrowedFormatItem::Literal(b"b"),
+        }]
+    );
+    assert_eq!(
+        format_description!(
+            version = 2,
+            "[if offset_minute_is_zero [a] [b]][if offset_second_is_zero [c] [d]]"
+        ),
+        &[
+            BorrowedFormatItem::Conditional {
+                condition: Condition::OffsetMinuteIsZero,
+                then: &BorrowedFormatItem::Literal(b"a"),
+                otherwise: &BorrowedFormatItem::Literal(b"b"),
+            },
+            BorrowedFormatItem::Conditional {
+                condition: Condition::OffsetSecondIsZero,
+                then: &BorrowedFormatItem::Literal(b"c"),
+                otherwise: &BorrowedFormatItem::Literal(b"d"),
+            }
+        ]
+    );
+}
+
 #[rstest]
 fn backslash_escape() {
     assert_eq!(
@@ -238,6 +289,16 @@ fn format_description_coverage() {
             })))
         ]
     );
+    assert_eq!(
+        format_description!("[day width:3][year padding:space width:10]"),
+        &[
+            BorrowedFormatItem::Component(Component::Day(modifier!(Day { width: 3 }))),
+            BorrowedFormatItem::Component(Component::Year(modifier!(Year {
+                padding: Padding::Space,
+                width: 10,
+            })))
+        ]
+    );
     assert_eq!(
         format_description!(
             "[offset_minute padding:space][offset_minute padding:zero][offset_minute padding:none]"
@@ -322,6 +383,33 @@ fn format_description_coverage() {
             }
         )))]
     );
+    assert_eq!(
+        format_description!("[year repr:last_two pivot:posix]"),
+        &[BorrowedFormatItem::Component(Component::Year(modifier!(
+            Year {
+                repr: YearRepr::LastTwo,
+                pivot: YearPivot::POSIX,
+            }
+        )))]
+    );
+    assert_eq!(
+        format_description!("[year repr:last_two pivot:1950]"),
+        &[BorrowedFormatItem::Component(Component::Year(modifier!(
+            Year {
+                repr: YearRepr::LastTwo,
+                pivot: YearPivot::fixed(1950).expect("in range"),
+            }
+        )))]
+    );
+    assert_eq!(
+        format_description!("[year repr:last_two pivot:sliding]"),
+        &[BorrowedFormatItem::Component(Component::Year(modifier!(
+            Year {
+                repr: YearRepr::LastTwo,
+                pivot: YearPivot::SLIDING,
+            }
+        )))]
+    );
     assert_eq!(
         format_description!("[[ "),
         &[
@@ -128,6 +128,7 @@ require_all_features! {
     mod parsed;
     mod parsing;
     mod primitive_date_time;
+    mod quarter;
     #[path = "quickcheck.rs"]
     mod quickcheck_mod;
     mod rand;
@@ -14,14 +14,17 @@ use quickcheck::Arbitrary;
 use rand::distributions::{Distribution, Standard};
 use serde::{Deserialize, Serialize};
 use time::format_description::well_known::iso8601;
-use time::format_description::{modifier, well_known, BorrowedFormatItem, Component};
-use time::formatting::Formattable;
-use time::parsing::{Parsable, Parsed};
+use time::format_description::{
+    analysis, modifier, well_known, BorrowedFormatItem, Component, Condition, FormatBuilder,
+};
+use time::formatting::{Formattable, InlineString};
+use time::interval::{Endpoint, Interval, NominalDuration, RepeatingInterval};
+use time::parsing::{Assumptions, DateOrder, Defaults, Heuristic, Parsable, Parsed};
 #[expect(deprecated)]
 use time::Instant;
 use time::{
-    error, ext, Date, Duration, Error, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcDateTime,
-    UtcOffset, Weekday,
+    error, ext, Date, Duration, Error, Month, OffsetDateTime, PrimitiveDateTime, Quarter,
+    SerialDateSystem, Time, UtcDateTime, UtcOffset, Weekday,
 };
 
 #[expect(clippy::cognitive_complexity, reason = "all test the same thing")]
@@ -41,6 +44,7 @@ fn alignment() {
     }
 
     assert_alignment!(Date, 4);
+    assert_alignment!(InlineString<35>, 8);
     assert_alignment!(Duration, 8);
     assert_alignment!(OffsetDateTime, 4);
     assert_alignment!(PrimitiveDateTime, 4);
@@ -63,10 +67,17 @@ fn alignment() {
     assert_alignment!(modifier::Second, 1);
     assert_alignment!(modifier::Subsecond, 1);
     assert_alignment!(modifier::WeekNumber, 1);
+    assert_alignment!(modifier::WeekOfMonth, 1);
+    assert_alignment!(modifier::WeekdayInMonth, 1);
+    assert_alignment!(modifier::Quarter, 1);
+    assert_alignment!(modifier::Era, 1);
+    assert_alignment!(modifier::JulianDay, 1);
     assert_alignment!(modifier::Weekday, 1);
     assert_alignment!(modifier::Year, 1);
+    assert_alignment!(well_known::HttpDate, 1);
     assert_alignment!(well_known::Rfc2822, 1);
     assert_alignment!(well_known::Rfc3339, 1);
+    assert_alignment!(well_known::Rfc9557, 1);
     assert_alignment!(
         well_known::Iso8601<{ iso8601::Config::DEFAULT.encode() }>,
         1
@@ -78,21 +89,34 @@ fn alignment() {
     assert_alignment!(iso8601::TimePrecision, 1);
     assert_alignment!(Parsed, align_of::<u128>());
     assert_alignment!(Month, 1);
+    assert_alignment!(Quarter, 1);
+    assert_alignment!(SerialDateSystem, 1);
     assert_alignment!(Weekday, 1);
     assert_alignment!(Error, 8);
     assert_alignment!(error::Format, 8);
+    assert_alignment!(error::FormatCore, 8);
     assert_alignment!(error::InvalidFormatDescription, 8);
     assert_alignment!(error::Parse, 8);
+    assert_alignment!(error::ParseAny, 8);
     assert_alignment!(error::ParseFromDescription, 8);
     assert_alignment!(error::TryFromParsed, 8);
     assert_alignment!(Component, 2);
     assert_alignment!(BorrowedFormatItem<'_>, 8);
+    assert_alignment!(Condition, 1);
+    assert_alignment!(analysis::Target, 1);
+    assert_alignment!(analysis::Issue, 8);
     assert_alignment!(modifier::MonthRepr, 1);
+    assert_alignment!(modifier::QuarterRepr, 1);
+    assert_alignment!(modifier::EraRepr, 1);
+    assert_alignment!(modifier::JulianDayRepr, 1);
     assert_alignment!(modifier::Padding, 1);
+    assert_alignment!(modifier::PeriodRepr, 1);
     assert_alignment!(modifier::SubsecondDigits, 1);
+    assert_alignment!(modifier::TextCase, 1);
     assert_alignment!(modifier::WeekNumberRepr, 1);
     assert_alignment!(modifier::WeekdayRepr, 1);
     assert_alignment!(modifier::YearRepr, 1);
+    assert_alignment!(modifier::YearPivot, 1);
 }
 
 #[expect(clippy::cognitive_complexity, reason = "all test the same thing")]
@@ -130,22 +154,29 @@ fn size() {
     assert_size!(error::ConversionRange, 0, 1);
     assert_size!(error::DifferentVariant, 0, 1);
     assert_size!(error::IndeterminateOffset, 0, 1);
-    assert_size!(modifier::Day, 1, 1);
-    assert_size!(modifier::Hour, 2, 2);
-    assert_size!(modifier::Minute, 1, 1);
-    assert_size!(modifier::Month, 3, 3);
+    assert_size!(modifier::Day, 2, 2);
+    assert_size!(modifier::Hour, 3, 3);
+    assert_size!(modifier::Minute, 2, 2);
+    assert_size!(modifier::Month, 5, 5);
     assert_size!(modifier::OffsetHour, 2, 2);
     assert_size!(modifier::OffsetMinute, 1, 1);
     assert_size!(modifier::OffsetSecond, 1, 1);
-    assert_size!(modifier::Ordinal, 1, 1);
-    assert_size!(modifier::Period, 2, 2);
-    assert_size!(modifier::Second, 1, 1);
+    assert_size!(modifier::Ordinal, 2, 2);
+    assert_size!(modifier::Period, 3, 3);
+    assert_size!(modifier::Second, 2, 2);
     assert_size!(modifier::Subsecond, 1, 1);
-    assert_size!(modifier::WeekNumber, 2, 2);
-    assert_size!(modifier::Weekday, 3, 3);
-    assert_size!(modifier::Year, 5, 5);
+    assert_size!(modifier::WeekNumber, 3, 3);
+    assert_size!(modifier::WeekOfMonth, 2, 2);
+    assert_size!(modifier::WeekdayInMonth, 1, 1);
+    assert_size!(modifier::Quarter, 2, 2);
+    assert_size!(modifier::Era, 2, 2);
+    assert_size!(modifier::JulianDay, 2, 2);
+    assert_size!(modifier::Weekday, 4, 4);
+    assert_size!(modifier::Year, 8, 8);
+    assert_size!(well_known::HttpDate, 0, 1);
     assert_size!(well_known::Rfc2822, 0, 1);
     assert_size!(well_known::Rfc3339, 0, 1);
+    assert_size!(well_known::Rfc9557, 0, 1);
     assert_size!(
         well_known::Iso8601<{ iso8601::Config::DEFAULT.encode() }>,
         0,
@@ -156,23 +187,37 @@ fn size() {
     assert_size!(iso8601::FormattedComponents, 1, 1);
     assert_size!(iso8601::OffsetPrecision, 1, 1);
     assert_size!(iso8601::TimePrecision, 2, 2);
-    assert_size!(Parsed, 64, 64);
+    assert_size!(Parsed, 80, 80);
+    assert_size!(InlineString<35>, 48, 56);
     assert_size!(Month, 1, 1);
+    assert_size!(Quarter, 1, 1);
+    assert_size!(SerialDateSystem, 1, 1);
     assert_size!(Weekday, 1, 1);
     assert_size!(Error, 64, 64);
     assert_size!(error::Format, 24, 24);
+    assert_size!(error::FormatCore, 64, 64);
     assert_size!(error::InvalidFormatDescription, 48, 48);
     assert_size!(error::Parse, 64, 64);
+    assert_size!(error::ParseAny, 16, 16);
     assert_size!(error::ParseFromDescription, 24, 24);
     assert_size!(error::TryFromParsed, 56, 64);
-    assert_size!(Component, 6, 6);
+    assert_size!(Component, 8, 8);
     assert_size!(BorrowedFormatItem<'_>, 24, 24);
+    assert_size!(Condition, 1, 1);
+    assert_size!(analysis::Target, 1, 1);
+    assert_size!(analysis::Issue, 24, 24);
     assert_size!(modifier::MonthRepr, 1, 1);
+    assert_size!(modifier::QuarterRepr, 1, 1);
+    assert_size!(modifier::EraRepr, 1, 1);
+    assert_size!(modifier::JulianDayRepr, 1, 1);
     assert_size!(modifier::Padding, 1, 1);
+    assert_size!(modifier::PeriodRepr, 1, 1);
     assert_size!(modifier::SubsecondDigits, 1, 1);
+    assert_size!(modifier::TextCase, 1, 1);
     assert_size!(modifier::WeekNumberRepr, 1, 1);
     assert_size!(modifier::WeekdayRepr, 1, 1);
     assert_size!(modifier::YearRepr, 1, 1);
+    assert_size!(modifier::YearPivot, 1, 2);
 }
 
 macro_rules! assert_obj_safe {
@@ -687,6 +732,71 @@ assert_impl! { modifier::WeekNumber:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { modifier::WeekOfMonth:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<modifier::WeekOfMonth>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { modifier::WeekdayInMonth:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<modifier::WeekdayInMonth>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { modifier::Quarter:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<modifier::Quarter>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { modifier::Era:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<modifier::Era>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { modifier::JulianDay:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<modifier::JulianDay>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { modifier::Weekday:
     Clone,
     Debug,
@@ -727,6 +837,20 @@ assert_impl! { well_known::Rfc2822:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { well_known::HttpDate:
+    Clone,
+    Debug,
+    PartialEq<well_known::HttpDate>,
+    Copy,
+    Eq,
+    Formattable,
+    Parsable,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { well_known::Rfc3339:
     Clone,
     Debug,
@@ -741,6 +865,20 @@ assert_impl! { well_known::Rfc3339:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { well_known::Rfc9557:
+    Clone,
+    Debug,
+    PartialEq<well_known::Rfc9557>,
+    Copy,
+    Eq,
+    Formattable,
+    Parsable,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { well_known::Iso8601::<{ iso8601::Config::DEFAULT.encode() }>:
     Clone,
     Debug,
@@ -821,6 +959,76 @@ assert_impl! { Parsed:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { Heuristic:
+    Clone,
+    Debug,
+    PartialEq<Heuristic>,
+    Copy,
+    Eq,
+    Parsable,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { InlineString<35>:
+    AsRef<[u8]>,
+    AsRef<str>,
+    Borrow<str>,
+    Clone,
+    Copy,
+    Debug,
+    Display,
+    Eq,
+    Hash,
+    PartialEq<InlineString<35>>,
+    PartialEq<str>,
+    PartialEq<&'static str>,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { Defaults:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<Defaults>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { DateOrder:
+    Clone,
+    Debug,
+    PartialEq<DateOrder>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { Assumptions:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<Assumptions>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { @'a; Month:
     Arbitrary,
     Clone,
@@ -838,6 +1046,90 @@ assert_impl! { @'a; Month:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { @'a; Quarter:
+    Clone,
+    Debug,
+    Deserialize<'a>,
+    Display,
+    Hash,
+    Ord,
+    PartialEq<Quarter>,
+    Serialize,
+    TryFrom<u8, Error = error::ComponentRange>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { Endpoint:
+    Clone,
+    Debug,
+    Hash,
+    PartialEq<Endpoint>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { Interval:
+    Clone,
+    Debug,
+    Hash,
+    PartialEq<Interval>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { NominalDuration:
+    Clone,
+    Debug,
+    Display,
+    Hash,
+    PartialEq<NominalDuration>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { RepeatingInterval:
+    Clone,
+    Debug,
+    Hash,
+    PartialEq<RepeatingInterval>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { SerialDateSystem:
+    Clone,
+    Debug,
+    Hash,
+    PartialEq<SerialDateSystem>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { @'a; Weekday:
     Arbitrary,
     Clone,
@@ -863,9 +1155,11 @@ assert_impl! { Error:
     From<error::ConversionRange>,
     From<error::DifferentVariant>,
     From<error::Format>,
+    From<error::FormatCore>,
     From<error::IndeterminateOffset>,
     From<error::InvalidFormatDescription>,
     From<error::Parse>,
+    From<error::ParseAny>,
     From<error::ParseFromDescription>,
     From<error::TryFromParsed>,
     Send,
@@ -882,6 +1176,23 @@ assert_impl! { error::Format:
     Sync,
     Unpin,
 }
+assert_impl! { error::FormatCore:
+    Clone,
+    Debug,
+    Display,
+    StdError,
+    From<error::ComponentRange>,
+    From<std::fmt::Error>,
+    PartialEq<error::FormatCore>,
+    TryFrom<Error, Error = error::DifferentVariant>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { error::InvalidFormatDescription:
     Clone,
     Debug,
@@ -913,6 +1224,49 @@ assert_impl! { error::Parse:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { error::ParseDiagnostic<'static>:
+    Clone,
+    Debug,
+    Display,
+    StdError,
+    From<error::Parse>,
+    From<error::TryFromParsed>,
+    PartialEq<error::ParseDiagnostic<'static>>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { error::Expected<'static>:
+    Clone,
+    Debug,
+    Display,
+    PartialEq<error::Expected<'static>>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { error::ParseAny:
+    Clone,
+    Debug,
+    Display,
+    StdError,
+    PartialEq<error::ParseAny>,
+    Eq,
+    TryFrom<Error, Error = error::DifferentVariant>,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { error::ParseFromDescription:
     Clone,
     Debug,
@@ -949,6 +1303,7 @@ assert_impl! { error::TryFromParsed:
 assert_impl! { @'a; Component:
     Clone,
     Debug,
+    Display,
     PartialEq<Component>,
     PartialEq<BorrowedFormatItem<'a>>,
     TryFrom<BorrowedFormatItem<'a>, Error = error::DifferentVariant>,
@@ -960,9 +1315,24 @@ assert_impl! { @'a; Component:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { Condition:
+    Clone,
+    Debug,
+    Display,
+    Hash,
+    PartialEq<Condition>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { @'a; BorrowedFormatItem<'_>:
     Clone,
     Debug,
+    Display,
     From<&'a [BorrowedFormatItem<'a>]>,
     From<Component>,
     PartialEq<&'a [BorrowedFormatItem<'a>]>,
@@ -977,6 +1347,42 @@ assert_impl! { @'a; BorrowedFormatItem<'_>:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { FormatBuilder<'_, 1>:
+    Clone,
+    Debug,
+    Default,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { analysis::Target:
+    Clone,
+    Debug,
+    PartialEq<analysis::Target>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { analysis::Issue:
+    Clone,
+    Debug,
+    Display,
+    StdError,
+    PartialEq<analysis::Issue>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { @'a; &[BorrowedFormatItem<'_>]:
     PartialEq<BorrowedFormatItem<'a>>,
     TryFrom<BorrowedFormatItem<'a>, Error = error::DifferentVariant>,
@@ -994,6 +1400,45 @@ assert_impl! { modifier::MonthRepr:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { modifier::QuarterRepr:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<modifier::QuarterRepr>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { modifier::EraRepr:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<modifier::EraRepr>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { modifier::JulianDayRepr:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<modifier::JulianDayRepr>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { modifier::Padding:
     Clone,
     Debug,
@@ -1033,6 +1478,32 @@ assert_impl! { modifier::WeekNumberRepr:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { modifier::TextCase:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<modifier::TextCase>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
+assert_impl! { modifier::PeriodRepr:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<modifier::PeriodRepr>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { modifier::WeekdayRepr:
     Clone,
     Debug,
@@ -1059,6 +1530,19 @@ assert_impl! { modifier::YearRepr:
     Unpin,
     UnwindSafe,
 }
+assert_impl! { modifier::YearPivot:
+    Clone,
+    Debug,
+    Default,
+    PartialEq<modifier::YearPivot>,
+    Copy,
+    Eq,
+    RefUnwindSafe,
+    Send,
+    Sync,
+    Unpin,
+    UnwindSafe,
+}
 assert_impl! { Standard:
     Distribution<Date>,
     Distribution<Duration>,
@@ -1111,6 +1595,7 @@ assert_impl! { i32:
 assert_impl! { u8:
     Mul<Duration>,
     From<Month>,
+    From<Quarter>,
 }
 assert_impl! { u16:
     Mul<Duration>,
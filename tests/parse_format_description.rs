@@ -4,7 +4,9 @@ use rstest::rstest;
 use rstest_reuse::{apply, template};
 use time::error::InvalidFormatDescription;
 use time::format_description::modifier::*;
-use time::format_description::{self, BorrowedFormatItem, Component, OwnedFormatItem};
+use time::format_description::{
+    self, BorrowedFormatItem, Component, Condition, OwnedFormatItem,
+};
 use time::macros::format_description;
 
 /// Identical to `modifier!`, but obtains the value from `M<T>` automagically.
@@ -45,12 +47,28 @@ fn modifiers(
         (false, "case:lower"),
     )]
     period_is_uppercase: _,
+    #[values(
+        (PeriodRepr::Short, "repr:short"),
+        (PeriodRepr::Narrow, "repr:narrow"),
+    )]
+    period_repr: _,
     #[values(
         (MonthRepr::Numerical, "repr:numerical"),
         (MonthRepr::Long, "repr:long"),
         (MonthRepr::Short, "repr:short"),
+        (MonthRepr::Narrow, "repr:narrow"),
     )]
     month_repr: _,
+    #[values(
+        (QuarterRepr::Numerical, "repr:numerical"),
+        (QuarterRepr::Short, "repr:short"),
+    )]
+    quarter_repr: _,
+    #[values(
+        (EraRepr::Short, "repr:short"),
+        (EraRepr::Common, "repr:common"),
+    )]
+    era_repr: _,
     #[values(
         (SubsecondDigits::One, "digits:1"),
         (SubsecondDigits::Two, "digits:2"),
@@ -67,6 +85,7 @@ fn modifiers(
     #[values(
         (WeekdayRepr::Short, "repr:short"),
         (WeekdayRepr::Long, "repr:long"),
+        (WeekdayRepr::Narrow, "repr:narrow"),
         (WeekdayRepr::Sunday, "repr:sunday"),
         (WeekdayRepr::Monday, "repr:monday"),
     )]
@@ -77,6 +96,23 @@ fn modifiers(
         (WeekNumberRepr::Monday, "repr:monday"),
     )]
     week_number_repr: _,
+    #[values(
+        (time::Weekday::Monday, "first_weekday:monday"),
+        (time::Weekday::Wednesday, "first_weekday:wednesday"),
+        (time::Weekday::Sunday, "first_weekday:sunday"),
+    )]
+    week_of_month_first_weekday: _,
+    #[values(
+        (1, "minimal_days:1"),
+        (4, "minimal_days:4"),
+        (7, "minimal_days:7"),
+    )]
+    week_of_month_minimal_days: _,
+    #[values(
+        (false, "from_end:false"),
+        (true, "from_end:true"),
+    )]
+    weekday_in_month_from_end: _,
     #[values(
         (YearRepr::Full, "repr:full"),
         (YearRepr::Century, "repr:century"),
@@ -93,6 +129,11 @@ fn modifiers(
         (true, "base:iso_week"),
     )]
     year_is_iso_week_based: _,
+    #[values(
+        (false, "era_based:false"),
+        (true, "era_based:true"),
+    )]
+    year_is_era_based: _,
     #[values(
         (false, "sign:automatic"),
         (true, "sign:mandatory"),
@@ -108,6 +149,12 @@ fn modifiers(
         (false, "case_sensitive:false"),
     )]
     case_sensitive: _,
+    #[values(
+        (TextCase::Title, "case:title"),
+        (TextCase::Upper, "case:upper"),
+        (TextCase::Lower, "case:lower"),
+    )]
+    text_case: _,
     #[values(
         (NonZero::new(1).unwrap(), "count:1"),
         (NonZero::new(2).unwrap(), "count:2"),
@@ -124,6 +171,17 @@ fn modifiers(
         (UnixTimestampPrecision::Nanosecond, "precision:nanosecond"),
     )]
     unix_timestamp_precision: _,
+    #[values(
+        (JulianDayRepr::Julian, "repr:julian"),
+        (JulianDayRepr::Modified, "repr:modified"),
+    )]
+    julian_day_repr: _,
+    #[values(
+        (0, "digits:0"),
+        (5, "digits:5"),
+        (14, "digits:14"),
+    )]
+    julian_day_digits: _,
 ) {}
 
 #[rstest]
@@ -155,7 +213,13 @@ fn only_literal<const N: usize>(#[case] format_description: &str, #[case] expect
 #[rstest]
 #[case("[day]", Component::Day(modifier!(Day)))]
 #[case("[end]", Component::End(modifier!(End)))]
+#[case("[era]", Component::Era(modifier!(Era)))]
+#[case(
+    "[extension_annotations]",
+    Component::ExtensionAnnotations(modifier!(ExtensionAnnotations))
+)]
 #[case("[hour]", Component::Hour(modifier!(Hour)))]
+#[case("[julian_day]", Component::JulianDay(modifier!(JulianDay)))]
 #[case("[minute]", Component::Minute(modifier!(Minute)))]
 #[case("[month]", Component::Month(modifier!(Month)))]
 #[case("[offset_hour]", Component::OffsetHour(modifier!(OffsetHour)))]
@@ -163,11 +227,18 @@ fn only_literal<const N: usize>(#[case] format_description: &str, #[case] expect
 #[case("[offset_second]", Component::OffsetSecond(modifier!(OffsetSecond)))]
 #[case("[ordinal]", Component::Ordinal(modifier!(Ordinal)))]
 #[case("[period]", Component::Period(modifier!(Period)))]
+#[case("[quarter]", Component::Quarter(modifier!(Quarter)))]
 #[case("[second]", Component::Second(modifier!(Second)))]
 #[case("[subsecond]", Component::Subsecond(modifier!(Subsecond)))]
+#[case(
+    "[time_zone_annotation]",
+    Component::TimeZoneAnnotation(modifier!(TimeZoneAnnotation))
+)]
 #[case("[unix_timestamp]", Component::UnixTimestamp(modifier!(UnixTimestamp)))]
 #[case("[weekday]", Component::Weekday(modifier!(Weekday)))]
 #[case("[week_number]", Component::WeekNumber(modifier!(WeekNumber)))]
+#[case("[week_of_month]", Component::WeekOfMonth(modifier!(WeekOfMonth)))]
+#[case("[weekday_in_month]", Component::WeekdayInMonth(modifier!(WeekdayInMonth)))]
 #[case("[year]", Component::Year(modifier!(Year)))]
 fn simple_component(#[case] format_description: &str, #[case] component: Component) {
     assert_eq!(
@@ -205,6 +276,13 @@ fn errors() {
         "[day padding:invalid]", InvalidModifier { value, index: 13, .. } if value == "invalid",
         "[ignore]", MissingRequiredModifier { name: "count", index: 1, .. },
         "[ignore count:70000]", InvalidModifier { value, index: 14, .. } if value == "70000",
+        "[year pivot:invalid]", InvalidModifier { value, index: 12, .. } if value == "invalid",
+        "[year pivot:1899]", InvalidModifier { value, index: 12, .. } if value == "1899",
+        "[year pivot:2153]", InvalidModifier { value, index: 12, .. } if value == "2153",
+        "[week_of_month minimal_days:0]", InvalidModifier { value, index: 28, .. } if value == "0",
+        "[julian_day digits:15]", InvalidModifier { value, index: 19, .. } if value == "15",
+        "[week_of_month first_weekday:mon]",
+            InvalidModifier { value, index: 29, .. } if value == "mon",
     }
 }
 
@@ -304,13 +382,19 @@ fn hour_component(padding: M<Padding>, hour_is_12_hour_clock: M<bool>) {
 }
 
 #[apply(modifiers)]
-fn month_component(padding: M<Padding>, case_sensitive: M<bool>, month_repr: M<MonthRepr>) {
+fn month_component(
+    padding: M<Padding>,
+    case_sensitive: M<bool>,
+    month_repr: M<MonthRepr>,
+    text_case: M<TextCase>,
+) {
     assert_eq!(
-        parse_with_modifiers!("month", padding, case_sensitive, month_repr),
+        parse_with_modifiers!("month", padding, case_sensitive, month_repr, text_case),
         Ok(vec![BorrowedFormatItem::Component(Component::Month(
             modifier_m!(Month {
                 padding,
                 repr: month_repr,
+                case: text_case,
                 case_sensitive
             })
         ))])
@@ -318,12 +402,17 @@ fn month_component(padding: M<Padding>, case_sensitive: M<bool>, month_repr: M<M
 }
 
 #[apply(modifiers)]
-fn period_component(case_sensitive: M<bool>, period_is_uppercase: M<bool>) {
+fn period_component(
+    case_sensitive: M<bool>,
+    period_is_uppercase: M<bool>,
+    period_repr: M<PeriodRepr>,
+) {
     assert_eq!(
-        parse_with_modifiers!("period", period_is_uppercase, case_sensitive),
+        parse_with_modifiers!("period", period_is_uppercase, period_repr, case_sensitive),
         Ok(vec![BorrowedFormatItem::Component(Component::Period(
             modifier_m!(Period {
                 is_uppercase: period_is_uppercase,
+                repr: period_repr,
                 case_sensitive
             })
         ))])
@@ -335,18 +424,21 @@ fn weekday_component(
     case_sensitive: M<bool>,
     weekday_is_one_indexed: M<bool>,
     weekday_repr: M<WeekdayRepr>,
+    text_case: M<TextCase>,
 ) {
     assert_eq!(
         parse_with_modifiers!(
             "weekday",
             case_sensitive,
             weekday_is_one_indexed,
-            weekday_repr
+            weekday_repr,
+            text_case
         ),
         Ok(vec![BorrowedFormatItem::Component(Component::Weekday(
             modifier_m!(Weekday {
                 repr: weekday_repr,
                 one_indexed: weekday_is_one_indexed,
+                case: text_case,
                 case_sensitive
             })
         ))])
@@ -366,6 +458,64 @@ fn week_number_component(padding: M<Padding>, week_number_repr: M<WeekNumberRepr
     );
 }
 
+#[apply(modifiers)]
+fn week_of_month_component(
+    week_of_month_first_weekday: M<time::Weekday>,
+    week_of_month_minimal_days: M<u8>,
+) {
+    assert_eq!(
+        parse_with_modifiers!(
+            "week_of_month",
+            week_of_month_first_weekday,
+            week_of_month_minimal_days
+        ),
+        Ok(vec![BorrowedFormatItem::Component(Component::WeekOfMonth(
+            modifier_m!(WeekOfMonth {
+                first_weekday: week_of_month_first_weekday,
+                minimal_days: week_of_month_minimal_days
+            })
+        ))])
+    );
+}
+
+#[apply(modifiers)]
+fn weekday_in_month_component(weekday_in_month_from_end: M<bool>) {
+    assert_eq!(
+        parse_with_modifiers!("weekday_in_month", weekday_in_month_from_end),
+        Ok(vec![BorrowedFormatItem::Component(
+            Component::WeekdayInMonth(modifier_m!(WeekdayInMonth {
+                from_end: weekday_in_month_from_end
+            }))
+        )])
+    );
+}
+
+#[apply(modifiers)]
+fn quarter_component(quarter_repr: M<QuarterRepr>, case_sensitive: M<bool>) {
+    assert_eq!(
+        parse_with_modifiers!("quarter", quarter_repr, case_sensitive),
+        Ok(vec![BorrowedFormatItem::Component(Component::Quarter(
+            modifier_m!(Quarter {
+                repr: quarter_repr,
+                case_sensitive
+            })
+        ))])
+    );
+}
+
+#[apply(modifiers)]
+fn era_component(era_repr: M<EraRepr>, case_sensitive: M<bool>) {
+    assert_eq!(
+        parse_with_modifiers!("era", era_repr, case_sensitive),
+        Ok(vec![BorrowedFormatItem::Component(Component::Era(
+            modifier_m!(Era {
+                repr: era_repr,
+                case_sensitive
+            })
+        ))])
+    );
+}
+
 #[apply(modifiers)]
 fn offset_hour_component(padding: M<Padding>, sign_is_mandatory: M<bool>) {
     assert_eq!(
@@ -386,6 +536,7 @@ fn year_component(
     year_range: M<YearRange>,
     year_is_iso_week_based: M<bool>,
     sign_is_mandatory: M<bool>,
+    year_is_era_based: M<bool>,
 ) {
     assert_eq!(
         parse_with_modifiers!(
@@ -394,7 +545,8 @@ fn year_component(
             year_repr,
             year_range,
             year_is_iso_week_based,
-            sign_is_mandatory
+            sign_is_mandatory,
+            year_is_era_based
         ),
         Ok(vec![BorrowedFormatItem::Component(Component::Year(
             modifier_m!(Year {
@@ -402,12 +554,28 @@ fn year_component(
                 repr: year_repr,
                 range: year_range,
                 iso_week_based: year_is_iso_week_based,
-                sign_is_mandatory
+                sign_is_mandatory,
+                era_based: year_is_era_based
             })
         ))])
     );
 }
 
+#[rstest]
+#[case("[year repr:last_two pivot:none]", YearPivot::NONE)]
+#[case("[year repr:last_two pivot:posix]", YearPivot::POSIX)]
+#[case("[year repr:last_two pivot:POSIX]", YearPivot::POSIX)]
+#[case("[year repr:last_two pivot:1950]", YearPivot::fixed(1950).expect("in range"))]
+#[case("[year repr:last_two pivot:2152]", YearPivot::fixed(2152).expect("in range"))]
+#[case("[year repr:last_two pivot:sliding]", YearPivot::SLIDING)]
+fn year_pivot(#[case] format_description: &str, #[case] pivot: YearPivot) {
+    let expected = vec![BorrowedFormatItem::Component(Component::Year(modifier!(Year {
+        repr: YearRepr::LastTwo,
+        pivot,
+    })))];
+    assert_eq!(format_description::parse(format_description), Ok(expected));
+}
+
 #[apply(modifiers)]
 fn unix_timestamp_component(
     sign_is_mandatory: M<bool>,
@@ -428,6 +596,19 @@ fn unix_timestamp_component(
     );
 }
 
+#[apply(modifiers)]
+fn julian_day_component(julian_day_repr: M<JulianDayRepr>, julian_day_digits: M<u8>) {
+    assert_eq!(
+        parse_with_modifiers!("julian_day", julian_day_repr, julian_day_digits),
+        Ok(vec![BorrowedFormatItem::Component(Component::JulianDay(
+            modifier_m!(JulianDay {
+                repr: julian_day_repr,
+                digits: julian_day_digits,
+            })
+        ))])
+    );
+}
+
 #[apply(modifiers)]
 fn subsecond_component(subsecond_digits: M<SubsecondDigits>) {
     assert_eq!(
@@ -485,6 +666,55 @@ fn optional() {
     );
 }
 
+#[rstest]
+fn conditional() {
+    assert_eq!(
+        format_description::parse_owned::<2>("[if offset_is_utc [Z] [[offset_hour]]]"),
+        Ok(OwnedFormatItem::Conditional {
+            condition: Condition::OffsetIsUtc,
+            then: Box::new(OwnedFormatItem::Literal(Box::new(*b"Z"))),
+            otherwise: Box::new(OwnedFormatItem::Component(Component::OffsetHour(
+                modifier!(OffsetHour)
+            ))),
+        })
+    );
+    assert_eq!(
+        format_description::parse_owned::<2>("[if second_is_zero [] [:[second]]]"),
+        Ok(OwnedFormatItem::Conditional {
+            condition: Condition::SecondIsZero,
+            then: Box::new(OwnedFormatItem::Compound(Box::new([]))),
+            otherwise: Box::new(OwnedFormatItem::Compound(Box::new([
+                OwnedFormatItem::Literal(Box::new(*b":")),
+                OwnedFormatItem::Component(Component::Second(modifier!(Second))),
+            ]))),
+        })
+    );
+    assert_eq!(
+        format_description::parse_owned::<2>("[ if  subsecond_is_zero  [a][b] ]"),
+        Ok(OwnedFormatItem::Conditional {
+            condition: Condition::SubsecondIsZero,
+            then: Box::new(OwnedFormatItem::Literal(Box::new(*b"a"))),
+            otherwise: Box::new(OwnedFormatItem::Literal(Box::new(*b"b"))),
+        })
+    );
+    assert_eq!(
+        format_description::parse_owned::<2>("[if offset_minute_is_zero [a] [b]]"),
+        Ok(OwnedFormatItem::Conditional {
+            condition: Condition::OffsetMinuteIsZero,
+            then: Box::new(OwnedFormatItem::Literal(Box::new(*b"a"))),
+            otherwise: Box::new(OwnedFormatItem::Literal(Box::new(*b"b"))),
+        })
+    );
+    assert_eq!(
+        format_description::parse_owned::<2>("[if offset_second_is_zero [a] [b]]"),
+        Ok(OwnedFormatItem::Conditional {
+            condition: Condition::OffsetSecondIsZero,
+            then: Box::new(OwnedFormatItem::Literal(Box::new(*b"a"))),
+            otherwise: Box::new(OwnedFormatItem::Literal(Box::new(*b"b"))),
+        })
+    );
+}
+
 #[rstest]
 fn first() {
     assert_eq!(
@@ -661,6 +891,59 @@ fn nested_error() {
             ..
         })
     ));
+    assert!(matches!(
+        format_description::parse("[if second_is_zero [] []]"),
+        Err(NotSupported {
+            what: "conditional item",
+            context: "runtime-parsed format descriptions",
+            index: 0,
+            ..
+        })
+    ));
+    assert!(matches!(
+        format_description::parse_owned::<2>("[if[]]"),
+        Err(Expected {
+            what: "whitespace after `if`",
+            index: 2,
+            ..
+        })
+    ));
+    assert!(matches!(
+        format_description::parse_owned::<2>("[if ]"),
+        Err(Expected {
+            what: "condition",
+            index: 3,
+            ..
+        })
+    ));
+    assert!(matches!(
+        format_description::parse_owned::<2>("[if second_is_zero[] []]"),
+        Err(Expected {
+            what: "whitespace after condition",
+            index: 17,
+            ..
+        })
+    ));
+    assert!(matches!(
+        format_description::parse_owned::<2>("[if minute_is_zero [] []]"),
+        Err(Expected {
+            what: "valid condition",
+            index: 4,
+            ..
+        })
+    ));
+    assert!(matches!(
+        format_description::parse_owned::<2>("[if second_is_zero []]"),
+        Err(Expected {
+            what: "opening bracket",
+            index: 18,
+            ..
+        })
+    ));
+    assert!(matches!(
+        format_description::parse_owned::<2>("[if second_is_zero [] [] []]"),
+        Err(UnclosedOpeningBracket { index: 0, .. })
+    ));
     assert!(matches!(
         format_description::parse_owned::<2>("[year [month]]"),
         Err(InvalidModifier { value, index: 6, .. }) if value == "["
@@ -863,7 +1146,6 @@ fn strftime_equivalence(
 #[case("%T", "[hour]:[minute]:[second]")]
 #[case("%x", "[month]/[day]/[year repr:last_two]")]
 #[case("%X", "[hour]:[minute]:[second]")]
-#[case("%z", "[offset_hour sign:mandatory][offset_minute]")]
 fn strftime_compound_equivalence(#[case] strftime: &str, #[case] custom: &str) -> time::Result<()> {
     let borrowed = format_description::parse_strftime_borrowed(strftime)?;
     let owned = format_description::parse_strftime_owned(strftime)?;
@@ -876,3 +1158,300 @@ fn strftime_compound_equivalence(#[case] strftime: &str, #[case] custom: &str) -
 
     Ok(())
 }
+
+#[rstest]
+#[case("%z", "[first [[offset_hour sign:mandatory][offset_minute]]\
+    [[offset_hour sign:mandatory]:[offset_minute]][[offset_hour sign:mandatory]]]")]
+#[case("%:z", "[first [[offset_hour sign:mandatory]:[offset_minute]]\
+    [[offset_hour sign:mandatory][offset_minute]][[offset_hour sign:mandatory]]]")]
+#[case("%::z", "[first [[offset_hour sign:mandatory]:[offset_minute]:[offset_second]]\
+    [[offset_hour sign:mandatory]:[offset_minute]]]")]
+#[case("%:::z", "[offset_hour sign:mandatory]")]
+#[case("%Z", "[first [[offset_hour sign:mandatory]:[offset_minute]]\
+    [[if offset_is_utc [[first [UTC][GMT][Z]]] [[offset_hour sign:mandatory]:[offset_minute]]]]]")]
+#[case("%.f", "[if subsecond_is_zero [] [.[subsecond]]]")]
+#[case("%.3f", ".[subsecond digits:3]")]
+#[case("%+", "[year]-[month]-[day]T[hour]:[minute]:[second]\
+    [if subsecond_is_zero [] [.[subsecond]]]\
+    [first [[offset_hour sign:mandatory]:[offset_minute]]\
+    [[offset_hour sign:mandatory][offset_minute]][[offset_hour sign:mandatory]]]")]
+fn strftime_nested_equivalence(#[case] strftime: &str, #[case] custom: &str) -> time::Result<()> {
+    let owned = format_description::parse_strftime_owned(strftime)?;
+    let custom = format_description::parse_owned::<2>(custom)?;
+    // The strftime description is a single item, which may itself be a compound.
+    let custom = OwnedFormatItem::Compound(Box::new([custom]));
+
+    assert_eq!(owned, custom);
+
+    Ok(())
+}
+
+#[rstest]
+#[case("%-d", "[day padding:none]")]
+#[case("%_d", "[day padding:space]")]
+#[case("%0e", "[day padding:zero]")]
+#[case("%-_0d", "[day padding:zero]")]
+#[case("%1d", "[day padding:none]")]
+#[case("%2d", "[day]")]
+#[case("%-5d", "[day padding:none]")]
+#[case("%3j", "[ordinal]")]
+#[case("%_Y", "[year padding:space]")]
+#[case("%1Y", "[year padding:none]")]
+#[case("%10d", "[day width:10]")]
+#[case("%_6H", "[hour padding:space width:6]")]
+#[case("%10Y", "[year width:10]")]
+#[case("%_5G", "[year base:iso_week padding:space width:5]")]
+#[case("%3C", "[year repr:century width:3]")]
+#[case("%3u", "00[weekday repr:monday]")]
+#[case("%N", "[subsecond digits:9]")]
+#[case("%3N", "[subsecond digits:3]")]
+#[case("%6f", "[subsecond digits:6]")]
+#[case("%^a", "[weekday repr:short case:upper]")]
+#[case("%#A", "[weekday case:upper]")]
+#[case("%^b", "[month repr:short case:upper]")]
+#[case("%^h", "[month repr:short case:upper]")]
+#[case("%#B", "[month repr:long case:upper]")]
+#[case("%#p", "[period case:lower]")]
+#[case("%^p", "[period]")]
+#[case("%^P", "[period case:lower]")]
+fn strftime_flag_equivalence(#[case] strftime: &str, #[case] custom: &str) -> time::Result<()> {
+    let borrowed = format_description::parse_strftime_borrowed(strftime)?;
+    let owned = format_description::parse_strftime_owned(strftime)?;
+    let custom = format_description::parse_borrowed::<2>(custom)?;
+
+    assert_eq!(borrowed, custom);
+    assert_eq!(owned, OwnedFormatItem::from(custom));
+
+    Ok(())
+}
+
+#[rstest]
+fn strftime_error() {
+    use InvalidFormatDescription::*;
+
+    assert!(matches!(
+        format_description::parse_strftime_borrowed("%Y-%-"),
+        Err(Expected {
+            what: "valid escape sequence",
+            index: 3,
+            ..
+        })
+    ));
+    assert!(matches!(
+        format_description::parse_strftime_borrowed("%q"),
+        Err(InvalidComponentName { name, index: 1, .. }) if name == "q"
+    ));
+    assert!(matches!(
+        format_description::parse_strftime_borrowed("%Od"),
+        Err(NotSupported {
+            what: "modifier",
+            index: 1,
+            ..
+        })
+    ));
+    assert!(matches!(
+        format_description::parse_strftime_borrowed("%:d"),
+        Err(NotSupported {
+            what: "colon flag",
+            index: 1,
+            ..
+        })
+    ));
+    assert!(matches!(
+        format_description::parse_strftime_borrowed("%::::z"),
+        Err(NotSupported {
+            what: "colon flag",
+            index: 1,
+            ..
+        })
+    ));
+    assert!(matches!(
+        format_description::parse_strftime_borrowed("%.d"),
+        Err(NotSupported {
+            what: "period flag",
+            index: 1,
+            ..
+        })
+    ));
+    assert!(matches!(
+        format_description::parse_strftime_borrowed("%2j"),
+        Err(NotSupported {
+            what: "field width",
+            index: 1,
+            ..
+        })
+    ));
+    assert!(matches!(
+        format_description::parse_strftime_borrowed("%10a"),
+        Err(NotSupported {
+            what: "field width",
+            index: 1,
+            ..
+        })
+    ));
+    assert!(matches!(
+        format_description::parse_strftime_borrowed("%10N"),
+        Err(NotSupported {
+            what: "field width",
+            index: 1,
+            ..
+        })
+    ));
+    assert!(matches!(
+        format_description::parse_strftime_borrowed("%256d"),
+        Err(NotSupported {
+            what: "field width",
+            index: 1,
+            ..
+        })
+    ));
+}
+
+#[rstest]
+#[case("yyyy-MM-dd", "[year]-[month]-[day]")]
+#[case("y/M/d", "[year padding:none]/[month padding:none]/[day padding:none]")]
+#[case("yy", "[year repr:last_two pivot:sliding]")]
+#[case("uuuu YYYY", "[year] [year base:iso_week]")]
+#[case("YYYY-'W'ww-EEE", "[year base:iso_week]-W[week_number]-[weekday repr:short]")]
+#[case("MMM MMMM LLL", "[month repr:short] [month repr:long] [month repr:short]")]
+#[case("D DDD", "[ordinal padding:none] [ordinal]")]
+#[case("E EE EEE EEEE ccc eeee", "[weekday repr:short] [weekday repr:short] \
+    [weekday repr:short] [weekday] [weekday repr:short] [weekday]")]
+#[case("MMMMM LLLLL", "[month repr:narrow] [month repr:narrow]")]
+#[case("EEEEE ccccc", "[weekday repr:narrow] [weekday repr:narrow]")]
+#[case("W F", "[week_of_month minimal_days:4] [weekday_in_month]")]
+#[case("Q QQQ qqqqq", "[quarter] [quarter repr:short] [quarter]")]
+#[case("h:mm a", "[hour repr:12 padding:none]:[minute] [period]")]
+#[case("aaaa aaaaa", "[period] [period case:lower repr:narrow]")]
+#[case("HH:mm:ss.SSS", "[hour]:[minute]:[second].[subsecond digits:3]")]
+#[case("H m s S SSSSSSSSS", "[hour padding:none] [minute padding:none] \
+    [second padding:none] [subsecond digits:1] [subsecond digits:9]")]
+#[case("'T' 'o''clock' '' ''''", "T o'clock ' '")]
+#[case("yyyy[-MM[-dd]]", "[year][optional [-[month][optional [-[day]]]]]")]
+#[case("G GGG GGGG GGGGG", "[era] [era] [era repr:long] [era repr:narrow]")]
+#[case("y yyyy uuuu G", "[year padding:none era_based:true] [year era_based:true] [year] [era]")]
+#[case("yyyy 'G'", "[year] G")]
+#[case("ZZ xx xxx", "[offset_hour sign:mandatory][offset_minute] \
+    [offset_hour sign:mandatory][offset_minute] [offset_hour sign:mandatory]:[offset_minute]")]
+#[case("x", "[if offset_minute_is_zero [[offset_hour sign:mandatory]] \
+    [[offset_hour sign:mandatory][offset_minute]]]")]
+#[case("XXX", "[if offset_is_utc [Z] [[offset_hour sign:mandatory]:[offset_minute]]]")]
+#[case("XXXX", "[if offset_is_utc [Z] [[if offset_second_is_zero \
+    [[offset_hour sign:mandatory][offset_minute]] \
+    [[offset_hour sign:mandatory][offset_minute][offset_second]]]]]")]
+#[case("ZZZZZ", "[if offset_is_utc [Z] [[if offset_second_is_zero \
+    [[offset_hour sign:mandatory]:[offset_minute]] \
+    [[offset_hour sign:mandatory]:[offset_minute]:[offset_second]]]]]")]
+#[case("xxxxx", "[if offset_second_is_zero [[offset_hour sign:mandatory]:[offset_minute]] \
+    [[offset_hour sign:mandatory]:[offset_minute]:[offset_second]]]")]
+fn icu_equivalence(#[case] pattern: &str, #[case] custom: &str) -> time::Result<()> {
+    // The pattern may be nested differently, so compare the canonical descriptions.
+    assert_eq!(
+        format_description::parse_icu_owned(pattern)?.to_string(),
+        format_description::parse_owned::<2>(custom)?.to_string()
+    );
+    Ok(())
+}
+
+#[test]
+fn icu_error() {
+    use InvalidFormatDescription::*;
+
+    assert!(matches!(
+        format_description::parse_icu_owned("yyyy 'T"),
+        Err(Expected {
+            what: "closing quote",
+            index: 5,
+            ..
+        })
+    ));
+    assert!(matches!(
+        format_description::parse_icu_owned("yyyy[-MM"),
+        Err(UnclosedOpeningBracket { index: 4, .. })
+    ));
+    assert!(matches!(
+        format_description::parse_icu_owned("yyyy]"),
+        Err(Expected {
+            what: "opening bracket before closing bracket",
+            index: 4,
+            ..
+        })
+    ));
+    assert!(matches!(
+        format_description::parse_icu_owned("yyyy-ppp"),
+        Err(InvalidComponentName { name, index: 5, .. }) if name == "ppp"
+    ));
+    assert!(matches!(
+        format_description::parse_icu_owned("yyyy-MMMMMM"),
+        Err(NotSupported {
+            what: "number of pattern letters",
+            index: 5,
+            ..
+        })
+    ));
+    assert!(matches!(
+        format_description::parse_icu_owned("yyyy-QQ"),
+        Err(NotSupported {
+            what: "number of pattern letters",
+            index: 5,
+            ..
+        })
+    ));
+    assert!(matches!(
+        format_description::parse_icu_owned("yyy"),
+        Err(NotSupported {
+            what: "number of pattern letters",
+            index: 0,
+            ..
+        })
+    ));
+    assert!(matches!(
+        format_description::parse_icu_owned("SSSSSSSSSS"),
+        Err(NotSupported {
+            what: "number of pattern letters",
+            index: 0,
+            ..
+        })
+    ));
+    assert!(matches!(
+        format_description::parse_icu_owned("WW"),
+        Err(NotSupported {
+            what: "number of pattern letters",
+            index: 0,
+            ..
+        })
+    ));
+    assert!(matches!(
+        format_description::parse_icu_owned("GGGGGG yyyy"),
+        Err(NotSupported {
+            what: "number of pattern letters",
+            index: 0,
+            ..
+        })
+    ));
+    assert!(matches!(
+        format_description::parse_icu_owned("HH:mm z"),
+        Err(NotSupported {
+            what: "time zone name",
+            index: 6,
+            ..
+        })
+    ));
+    assert!(matches!(
+        format_description::parse_icu_owned("EEEEEE"),
+        Err(NotSupported {
+            what: "two-letter day of the week name",
+            index: 0,
+            ..
+        })
+    ));
+    assert!(matches!(
+        format_description::parse_icu_owned("e"),
+        Err(NotSupported {
+            what: "numeric localized day of the week",
+            index: 0,
+            ..
+        })
+    ));
+}
+
@@ -1,9 +1,15 @@
 use std::num::NonZero;
 
 use time::format_description::modifier::WeekNumberRepr;
-use time::format_description::{BorrowedFormatItem, Component};
-use time::parsing::Parsed;
-use time::{error, Month, Time, Weekday};
+use time::format_description::well_known::{HttpDate, Iso8601, Rfc2822, Rfc3339, Rfc9557};
+use time::error::Expected;
+use time::format_description::{modifier, BorrowedFormatItem, Component};
+use time::macros::{date, datetime, format_description, offset, time, utc_datetime};
+use time::parsing::{Defaults, Parsed};
+use time::{
+    error, Date, Month, OffsetDateTime, PrimitiveDateTime, Quarter, Time, UtcDateTime, UtcOffset,
+    Weekday,
+};
 
 #[test]
 fn getters_setters() {
@@ -21,15 +27,22 @@ fn getters_setters() {
     getters_setters! {
         set_year year 5;
         set_year_last_two year_last_two 5;
+        set_year_of_era year_of_era NonZero::new(5).expect("valid value");
+        set_era_is_bce era_is_bce true;
         set_iso_year iso_year 5;
         set_iso_year_last_two iso_year_last_two 5;
         set_month month Month::May;
+        set_quarter quarter Quarter::Second;
         set_sunday_week_number sunday_week_number 5;
         set_monday_week_number monday_week_number 5;
         set_iso_week_number iso_week_number NonZero::new(5).expect("valid value");
         set_weekday weekday Weekday::Monday;
+        set_week_of_month week_of_month 5;
+        set_weekday_in_month weekday_in_month NonZero::new(5).expect("valid value");
+        set_weekday_in_month_from_end weekday_in_month_from_end NonZero::new(5).expect("valid value");
         set_ordinal ordinal NonZero::new(5).expect("valid value");
         set_day day NonZero::new(5).expect("valid value");
+        set_julian_day julian_day 5;
         set_hour_24 hour_24 5;
         set_hour_12 hour_12 NonZero::new(5).expect("valid value");
         set_hour_12_is_pm hour_12_is_pm true;
@@ -51,6 +64,80 @@ fn getters_setters() {
         parsed.set_offset_second(200);
         assert_eq!(parsed.offset_second(), None);
     }
+
+    let mut parsed = Parsed::new();
+    assert_eq!(parsed.week_of_month_first_weekday(), Weekday::Monday);
+    assert_eq!(parsed.week_of_month_minimal_days(), 1);
+    parsed.set_week_of_month_first_weekday(Weekday::Sunday);
+    assert_eq!(parsed.week_of_month_first_weekday(), Weekday::Sunday);
+    parsed.set_week_of_month_minimal_days(4);
+    assert_eq!(parsed.week_of_month_minimal_days(), 4);
+    parsed.set_week_of_month_minimal_days(0);
+    assert_eq!(parsed.week_of_month_minimal_days(), 1);
+    parsed.set_week_of_month_minimal_days(10);
+    assert_eq!(parsed.week_of_month_minimal_days(), 7);
+    assert_eq!(parsed.set_week_of_month(7), None);
+    assert_eq!(parsed.set_weekday_in_month_from_end(NonZero::new(6).expect("valid value")), None);
+}
+
+#[test]
+fn annotations() -> time::Result<()> {
+    let parsed = Parsed::new();
+    let (remaining, annotations) = parsed.parse_annotations(b"")?;
+    assert!(remaining.is_empty());
+    assert_eq!(annotations.time_zone_annotation(), None);
+    assert_eq!(annotations.time_zone_annotation_is_critical(), None);
+    assert_eq!(annotations.annotations().count(), 0);
+
+    let (remaining, annotations) =
+        parsed.parse_annotations(b"[!America/New_York][u-ca=gregory][!u-ca=gregory][foo=bar-baz] x")?;
+    assert_eq!(remaining, b" x");
+    assert_eq!(annotations.time_zone_annotation(), Some("America/New_York"));
+    assert_eq!(annotations.time_zone_annotation_is_critical(), Some(true));
+    assert_eq!(annotations.annotation("u-ca"), Some("gregory"));
+    assert_eq!(annotations.annotation("foo"), Some("bar-baz"));
+    assert_eq!(annotations.annotation("bar"), None);
+    assert_eq!(
+        annotations
+            .annotations()
+            .map(|annotation| (annotation.key(), annotation.value(), annotation.is_critical()))
+            .collect::<Vec<_>>(),
+        [
+            ("u-ca", "gregory", false),
+            ("u-ca", "gregory", true),
+            ("foo", "bar-baz", false),
+        ]
+    );
+
+    let (_, annotations) = parsed.parse_annotations(b"[-05:00][u-ca=japanese]")?;
+    assert_eq!(annotations.time_zone_annotation(), Some("-05:00"));
+    assert_eq!(annotations.time_zone_annotation_is_critical(), Some(false));
+    assert_eq!(annotations.annotation("u-ca"), Some("japanese"));
+
+    // A critical numeric time zone annotation must agree with the parsed offset.
+    let parsed = Parsed::new()
+        .with_offset_hour(-5)
+        .expect("valid value");
+    assert!(parsed.parse_annotations(b"[!-05:00]").is_ok());
+    assert!(parsed.parse_annotations(b"[-04:00]").is_ok());
+    assert!(parsed.parse_annotations(b"[!-04:00]").is_err());
+
+    // Critical annotations that cannot be acted on are rejected.
+    assert!(Parsed::new().parse_annotations(b"[!u-ca=japanese]").is_err());
+    assert!(Parsed::new().parse_annotations(b"[!foo=bar]").is_err());
+    assert!(Parsed::new().parse_annotations(b"[foo=bar][!foo=baz]").is_err());
+
+    // There is no limit on the length of the annotations.
+    let long = format!("[America/Argentina/Buenos_Aires]{}", "[foo=abcdefgh-abcdefgh]".repeat(16));
+    let (remaining, annotations) = parsed.parse_annotations(long.as_bytes())?;
+    assert!(remaining.is_empty());
+    assert_eq!(
+        annotations.time_zone_annotation(),
+        Some("America/Argentina/Buenos_Aires")
+    );
+    assert_eq!(annotations.annotations().count(), 16);
+
+    Ok(())
 }
 
 #[test]
@@ -59,15 +146,26 @@ fn builder_methods() {
     let parsed = Parsed::new()
         .with_year(5)
         .and_then(|parsed| parsed.with_year_last_two(5))
+        .and_then(|parsed| parsed.with_year_of_era(NonZero::new(5).expect("valid value")))
+        .and_then(|parsed| parsed.with_era_is_bce(true))
         .and_then(|parsed| parsed.with_iso_year(5))
         .and_then(|parsed| parsed.with_iso_year_last_two(5))
         .and_then(|parsed| parsed.with_month(Month::May))
+        .and_then(|parsed| parsed.with_quarter(Quarter::Second))
         .and_then(|parsed| parsed.with_sunday_week_number(5))
         .and_then(|parsed| parsed.with_monday_week_number(5))
         .and_then(|parsed| parsed.with_iso_week_number(NonZero::new(5).expect("valid value")))
         .and_then(|parsed| parsed.with_weekday(Weekday::Monday))
+        .and_then(|parsed| parsed.with_week_of_month(5))
+        .and_then(|parsed| parsed.with_week_of_month_first_weekday(Weekday::Sunday))
+        .and_then(|parsed| parsed.with_week_of_month_minimal_days(4))
+        .and_then(|parsed| parsed.with_weekday_in_month(NonZero::new(5).expect("valid value")))
+        .and_then(|parsed| {
+            parsed.with_weekday_in_month_from_end(NonZero::new(5).expect("valid value"))
+        })
         .and_then(|parsed| parsed.with_ordinal(NonZero::new(5).expect("valid value")))
         .and_then(|parsed| parsed.with_day(NonZero::new(5).expect("valid value")))
+        .and_then(|parsed| parsed.with_julian_day(5))
         .and_then(|parsed| parsed.with_hour_24(5))
         .and_then(|parsed| parsed.with_hour_12(NonZero::new(5).expect("valid value")))
         .and_then(|parsed| parsed.with_hour_12_is_pm(true))
@@ -81,9 +179,15 @@ fn builder_methods() {
 
     assert_eq!(parsed.year(), Some(5));
     assert_eq!(parsed.year_last_two(), Some(5));
+    assert_eq!(
+        parsed.year_of_era(),
+        Some(NonZero::new(5).expect("valid value"))
+    );
+    assert_eq!(parsed.era_is_bce(), Some(true));
     assert_eq!(parsed.iso_year(), Some(5));
     assert_eq!(parsed.iso_year_last_two(), Some(5));
     assert_eq!(parsed.month(), Some(Month::May));
+    assert_eq!(parsed.quarter(), Some(Quarter::Second));
     assert_eq!(parsed.sunday_week_number(), Some(5));
     assert_eq!(parsed.monday_week_number(), Some(5));
     assert_eq!(
@@ -91,11 +195,23 @@ fn builder_methods() {
         Some(NonZero::new(5).expect("valid value"))
     );
     assert_eq!(parsed.weekday(), Some(Weekday::Monday));
+    assert_eq!(parsed.week_of_month(), Some(5));
+    assert_eq!(parsed.week_of_month_first_weekday(), Weekday::Sunday);
+    assert_eq!(parsed.week_of_month_minimal_days(), 4);
+    assert_eq!(
+        parsed.weekday_in_month(),
+        Some(NonZero::new(5).expect("valid value"))
+    );
+    assert_eq!(
+        parsed.weekday_in_month_from_end(),
+        Some(NonZero::new(5).expect("valid value"))
+    );
     assert_eq!(
         parsed.ordinal(),
         Some(NonZero::new(5).expect("valid value"))
     );
     assert_eq!(parsed.day(), Some(NonZero::new(5).expect("valid value")));
+    assert_eq!(parsed.julian_day(), Some(5));
     assert_eq!(parsed.hour_24(), Some(5));
     assert_eq!(
         parsed.hour_12(),
@@ -133,6 +249,329 @@ fn single_item_parse() {
     assert!(Time::parse("b", &BorrowedFormatItem::Literal(b"a")).is_err());
 }
 
+#[test]
+fn parse_any() -> time::Result<()> {
+    let us = format_description!("[month]/[day]/[year]");
+    let eu = format_description!("[day].[month].[year]");
+    let owned = time::format_description::parse_owned::<2>("[year]-[month]-[day]")?;
+
+    let Ok((index, parsed)) = Parsed::parse_any(b"2024-12-31T00:00:00Z", &[&Rfc3339, &us, &eu])
+    else {
+        panic!("the first candidate should match");
+    };
+    assert_eq!(index, 0);
+    assert_eq!(
+        OffsetDateTime::try_from(parsed)?,
+        datetime!(2024-12-31 00:00 UTC)
+    );
+
+    let Ok((index, parsed)) = Parsed::parse_any(b"12/31/2024", &[&Rfc3339, &us, &eu]) else {
+        panic!("the second candidate should match");
+    };
+    assert_eq!(index, 1);
+    assert_eq!(Date::try_from(parsed)?, date!(2024-12-31));
+
+    let Ok((index, _)) = Parsed::parse_any(b"2024-12-31", &[&us, &eu, &owned]) else {
+        panic!("the third candidate should match");
+    };
+    assert_eq!(index, 2);
+
+    // The first successful candidate is used, even if a later one would also succeed.
+    let Ok((index, _)) = Parsed::parse_any(b"12/31/2024", &[&us, &us]) else {
+        panic!("the first candidate should match");
+    };
+    assert_eq!(index, 0);
+
+    // The entire input must be consumed.
+    let Err(err) = Parsed::parse_any(b"12/31/2024 ", &[&us]) else {
+        panic!("no candidate should match");
+    };
+    assert!(matches!(
+        err.errors(),
+        [error::Parse::ParseFromDescription(
+            error::ParseFromDescription::UnexpectedTrailingCharacters { .. }
+        )]
+    ));
+
+    let Err(err) = Parsed::parse_any(b"2024-12-31", &[&Rfc3339, &us, &eu]) else {
+        panic!("no candidate should match");
+    };
+    assert!(matches!(
+        err.errors(),
+        [
+            error::Parse::ParseFromDescription(error::ParseFromDescription::InvalidComponent(
+                "separator"
+            )),
+            error::Parse::ParseFromDescription(error::ParseFromDescription::InvalidComponent(
+                "month"
+            )),
+            error::Parse::ParseFromDescription(error::ParseFromDescription::InvalidLiteral { .. }),
+        ]
+    ));
+    assert_eq!(
+        err.to_string(),
+        "none of the 3 candidates could parse the input; candidate 0: the 'separator' component \
+         could not be parsed; candidate 1: the 'month' component could not be parsed; candidate \
+         2: a character literal was not valid"
+    );
+
+    let Err(err) = Parsed::parse_any(b"", &[]) else {
+        panic!("no candidate should match");
+    };
+    assert!(err.errors().is_empty());
+    assert!(err.into_errors().is_empty());
+
+    // The error can be converted into the crate-wide error type.
+    let err = time::Error::from(
+        Parsed::parse_any(b"", &[&us]).expect_err("no candidate should match"),
+    );
+    assert!(matches!(
+        error::ParseAny::try_from(err).map(|err| err.errors().len()),
+        Ok(1)
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn parse_with_diagnostic() -> time::Result<()> {
+    let format = format_description!("[year]-[month]-[day]T[hour]:[minute]");
+
+    let parsed = Parsed::parse_with_diagnostic(b"2024-01-02T03:04", format)?;
+    assert_eq!(
+        PrimitiveDateTime::try_from(parsed)?,
+        datetime!(2024-01-02 03:04)
+    );
+
+    let err = Parsed::parse_with_diagnostic(b"2024-13-02T03:04", format).expect_err("parsing should fail");
+    assert!(matches!(
+        err.error(),
+        error::Parse::ParseFromDescription(error::ParseFromDescription::InvalidComponent("month"))
+    ));
+    assert_eq!(err.span(), Some(5..7));
+    assert_eq!(
+        err.expected(),
+        Some(Expected::Component(Component::Month(modifier::Month::default())))
+    );
+    assert_eq!(
+        err.to_string(),
+        "the 'month' component could not be parsed at byte 5; expected [month]"
+    );
+    assert_eq!(
+        err.render("2024-13-02T03:04").to_string(),
+        "2024-13-02T03:04\n     ^^ the 'month' component could not be parsed; expected [month]"
+    );
+
+    let err = Parsed::parse_with_diagnostic(b"2024-01-02 03:04", format).expect_err("parsing should fail");
+    assert_eq!(err.span(), Some(10..11));
+    assert_eq!(err.expected(), Some(Expected::Literal(b"T")));
+
+    // The span is empty if the input ends early, but the caret is still rendered.
+    let err = Parsed::parse_with_diagnostic(b"2024-01-02T03", format).expect_err("parsing should fail");
+    assert_eq!(err.span(), Some(13..13));
+    assert_eq!(err.expected(), Some(Expected::Literal(b":")));
+    assert_eq!(
+        err.render("2024-01-02T03").to_string(),
+        "2024-01-02T03\n             ^ a character literal was not valid; expected `:`"
+    );
+
+    let err = Parsed::parse_with_diagnostic(b"2024-01-02T03:04 UTC", format).expect_err("parsing should fail");
+    assert!(matches!(
+        err.error(),
+        error::Parse::ParseFromDescription(
+            error::ParseFromDescription::UnexpectedTrailingCharacters { .. }
+        )
+    ));
+    assert_eq!(err.span(), Some(16..20));
+    assert_eq!(err.expected(), Some(Expected::End));
+
+    // Columns are counted in characters when rendering.
+    let format = format_description!("[day] → [month]");
+    let err = Parsed::parse_with_diagnostic("01 → 1x".as_bytes(), format).expect_err("parsing should fail");
+    assert_eq!(err.span(), Some(7..9));
+    assert_eq!(
+        err.render("01 → 1x").to_string(),
+        "01 → 1x\n     ^^ the 'month' component could not be parsed; expected [month]"
+    );
+
+    // Errors within nested items are located.
+    let format = format_description!(version = 2, "[year] [first [[month]-[day]] [[ordinal]]]");
+    let err = Parsed::parse_with_diagnostic(b"2024 01-x", format).expect_err("parsing should fail");
+    assert_eq!(err.span(), Some(8..9));
+    let err = Parsed::parse_with_diagnostic(b"2024 x", format).expect_err("parsing should fail");
+    assert_eq!(err.span(), Some(5..6));
+
+    let owned = time::format_description::parse_owned::<2>("[hour]:[minute]")?;
+    let err = Parsed::parse_with_diagnostic(b"12-00", &owned).expect_err("parsing should fail");
+    assert_eq!(err.span(), Some(2..3));
+    assert_eq!(err.expected(), Some(Expected::Literal(b":")));
+
+    // An optional item that fails to parse leaves the input in place.
+    let format = format_description!("[year][optional [-[month]]]");
+    let err = Parsed::parse_with_diagnostic(b"2024-13", format).expect_err("parsing should fail");
+    assert_eq!(err.span(), Some(4..7));
+    assert_eq!(err.expected(), Some(Expected::End));
+
+    // Well-known formats locate errors, but do not know what was expected.
+    let err = Parsed::parse_with_diagnostic(b"2024-01-02", &Rfc3339).expect_err("parsing should fail");
+    assert_eq!(err.span(), Some(10..10));
+    assert_eq!(err.expected(), None);
+    assert_eq!(
+        err.render("2024-01-02").to_string(),
+        "2024-01-02\n          ^ the 'separator' component could not be parsed"
+    );
+    let err = Parsed::parse_with_diagnostic(b"2024-01-02T03:04:05+25:00", &Rfc3339)
+        .expect_err("parsing should fail");
+    assert_eq!(err.span(), Some(20..22));
+    let err = Parsed::parse_with_diagnostic(b"2024-01-02T03:04:05Z x", &Rfc3339)
+        .expect_err("parsing should fail");
+    assert_eq!(err.span(), Some(20..22));
+    assert_eq!(err.expected(), Some(Expected::End));
+    let err = Parsed::parse_with_diagnostic(b"Tue, 02 Jam 2024 03:04:05 GMT", &Rfc2822)
+        .expect_err("parsing should fail");
+    assert_eq!(err.span(), Some(8..11));
+    let err = Parsed::parse_with_diagnostic(b"Mon, 02 Jan 2024 03:04:05 GMT", &HttpDate)
+        .expect_err("parsing should fail");
+    assert!(matches!(
+        err.error(),
+        error::Parse::ParseFromDescription(error::ParseFromDescription::InvalidComponent(
+            "weekday"
+        ))
+    ));
+    assert_eq!(err.span(), Some(29..29));
+    let err = Parsed::parse_with_diagnostic(b"2024-01-02T03:04:05Z[!u-ca=hebrew]", &Rfc9557)
+        .expect_err("parsing should fail");
+    assert_eq!(err.span(), Some(20..21));
+    let err = Parsed::parse_with_diagnostic(b"x", &Iso8601::DEFAULT).expect_err("parsing should fail");
+    assert_eq!(err.span(), Some(0..1));
+
+    // Each type can be parsed with a diagnostic.
+    let format = format_description!("[year]-[month]-[day]");
+    assert_eq!(Date::parse_with_diagnostic("2024-01-02", format)?, date!(2024-01-02));
+    let err = Date::parse_with_diagnostic("2024-13-02", format).expect_err("parsing should fail");
+    assert_eq!(err.span(), Some(5..7));
+    let err = Date::parse_with_diagnostic("2024-02-30", format).expect_err("parsing should fail");
+    assert!(matches!(err.error(), error::Parse::TryFromParsed(_)));
+    assert_eq!(err.span(), None);
+    assert_eq!(
+        Time::parse_with_diagnostic("03:04", format_description!("[hour]:[minute]"))?,
+        time!(03:04)
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse_with_diagnostic(
+            "2024-01-02 03:04",
+            format_description!("[year]-[month]-[day] [hour]:[minute]")
+        )?,
+        datetime!(2024-01-02 03:04)
+    );
+    assert_eq!(
+        OffsetDateTime::parse_with_diagnostic("2024-01-02T03:04:05+01:00", &Rfc3339)?,
+        datetime!(2024-01-02 03:04:05 +1)
+    );
+    assert_eq!(
+        UtcDateTime::parse_with_diagnostic("2024-01-02T03:04:05Z", &Rfc3339)?,
+        utc_datetime!(2024-01-02 03:04:05)
+    );
+    assert_eq!(
+        UtcOffset::parse_with_diagnostic("+01", format_description!("[offset_hour]"))?,
+        offset!(+1)
+    );
+
+    // Conversion errors can be combined with diagnostics.
+    let result = (|| -> Result<Date, error::ParseDiagnostic<'static>> {
+        Ok(Date::try_from(Parsed::parse_with_diagnostic(
+            b"2024-02-30",
+            format_description!("[year]-[month]-[day]"),
+        )?)?)
+    })();
+    assert!(matches!(
+        result,
+        Err(err) if err.span().is_none() && matches!(err.error(), error::Parse::TryFromParsed(_))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn defaults() -> time::Result<()> {
+    let defaults = Defaults::NONE
+        .set_date(date!(2024-06-15))
+        .set_time(Time::MIDNIGHT)
+        .set_offset(UtcOffset::UTC);
+
+    // Time-only input uses the reference date.
+    let parsed = Parsed::new()
+        .with_hour_24(13)
+        .and_then(|p| p.with_minute(30))
+        .expect("valid components");
+    assert_eq!(
+        defaults.resolve::<OffsetDateTime>(parsed)?,
+        datetime!(2024-06-15 13:30 UTC)
+    );
+
+    // A missing year uses the year of the reference date.
+    let parsed = Parsed::new()
+        .with_month(Month::March)
+        .and_then(|p| p.with_day(NonZero::new(3).expect("non-zero")))
+        .expect("valid components");
+    assert_eq!(
+        defaults.resolve::<PrimitiveDateTime>(parsed)?,
+        datetime!(2024-03-03 0:00)
+    );
+    assert_eq!(
+        defaults.set_year(2023).resolve::<Date>(parsed)?,
+        date!(2023-03-03)
+    );
+    assert_eq!(
+        Defaults::NONE.set_year(2023).resolve::<Date>(parsed)?,
+        date!(2023-03-03)
+    );
+
+    // An ISO week date without a year receives an ISO year.
+    let parsed = Parsed::new()
+        .with_iso_week_number(NonZero::new(1).expect("non-zero"))
+        .and_then(|p| p.with_weekday(Weekday::Monday))
+        .expect("valid components");
+    assert_eq!(defaults.resolve::<Date>(parsed)?, date!(2024-01-01));
+
+    // Components that are present are never overridden.
+    let parsed = Parsed::new()
+        .with_year(2020)
+        .and_then(|p| p.with_month(Month::January))
+        .and_then(|p| p.with_day(NonZero::new(2).expect("non-zero")))
+        .and_then(|p| p.with_hour_24(3))
+        .and_then(|p| p.with_minute(4))
+        .and_then(|p| p.with_offset_hour(5))
+        .expect("valid components");
+    assert_eq!(
+        defaults.resolve::<OffsetDateTime>(parsed)?,
+        datetime!(2020-01-02 3:04 +5)
+    );
+    let parsed = Parsed::new()
+        .with_unix_timestamp_nanos(0)
+        .expect("valid component");
+    assert_eq!(defaults.apply(parsed)?.hour_24(), None);
+
+    // Without defaults, conversion behaves as `TryFrom`.
+    let parsed = Parsed::new().with_hour_24(13).expect("valid component");
+    assert!(matches!(
+        Defaults::NONE.resolve::<OffsetDateTime>(parsed),
+        Err(error::TryFromParsed::InsufficientInformation)
+    ));
+
+    // An out-of-range default year is an error.
+    let parsed = Parsed::new()
+        .with_month(Month::March)
+        .and_then(|p| p.with_day(NonZero::new(3).expect("non-zero")))
+        .expect("valid components");
+    assert!(matches!(
+        Defaults::NONE.set_year(1_000_000).resolve::<Date>(parsed),
+        Err(error::TryFromParsed::ComponentRange(err)) if err.name() == "year"
+    ));
+
+    Ok(())
+}
+
 #[test]
 fn component_err() {
     macro_rules! input_or_empty {
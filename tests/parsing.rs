@@ -1,10 +1,11 @@
 use std::num::NonZero;
 
 use time::format_description::modifier::Ignore;
-use time::format_description::well_known::{Iso8601, Rfc2822, Rfc3339};
+use time::format_description::well_known::{HttpDate, Iso8601, Rfc2822, Rfc3339, Rfc9557};
 use time::format_description::{modifier, BorrowedFormatItem, Component, OwnedFormatItem};
+use time::interval::{Endpoint, Interval, NominalDuration, RepeatingInterval};
 use time::macros::{date, datetime, offset, time, utc_datetime};
-use time::parsing::Parsed;
+use time::parsing::{DateOrder, Defaults, Heuristic, Parsed};
 use time::{
     error, format_description as fd, Date, Month, OffsetDateTime, PrimitiveDateTime, Time,
     UtcDateTime, UtcOffset, Weekday,
@@ -158,9 +159,87 @@ fn rfc_2822() -> time::Result<()> {
         time!(03:04:05)
     );
 
+    assert_eq!(
+        Date::parse("Sat, 02 Jan 121 03:04:05 GMT", &Rfc2822)?,
+        date!(2021-01-02)
+    );
+    assert_eq!(
+        Time::parse("Sat, 02 Jan 2021 03:04:05 GMT (c)", &Rfc2822)?,
+        time!(03:04:05)
+    );
+    assert_eq!(
+        Date::parse("Mon, 02 Jan 1899 03:04:05 GMT", &Rfc2822)?,
+        date!(1899-01-02)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Sat , 02 Jan 2021 03:04:05 GMT", &Rfc2822)?,
+        datetime!(2021-01-02 03:04:05 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse(
+            "Sat (a), (b) 02 (c) Jan (d) 2021 (e) 03 (f):(g) 04 (h):(i) 05 (j) +0000 (k)",
+            &Rfc2822
+        )?,
+        datetime!(2021-01-02 03:04:05 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Sat, 02 Jan 2021 03:04:05 -0000", &Rfc2822)?,
+        datetime!(2021-01-02 03:04:05 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Sat, 02 Jan 2021 03:04:05 EST (c)", &Rfc2822)?,
+        datetime!(2021-01-02 03:04:05 -5),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Sat, 02 Jan 2021 03:04:05 Z", &Rfc2822)?,
+        datetime!(2021-01-02 03:04:05 UTC),
+    );
+
     Ok(())
 }
 
+#[test]
+fn rfc_2822_unknown_offset() {
+    let offset_is_unknown = |input: &str| {
+        Parsed::parse_with_diagnostic(input.as_bytes(), &Rfc2822)
+            .map(|parsed| (parsed.offset_hour(), parsed.offset_is_unknown()))
+            .ok()
+    };
+
+    assert_eq!(
+        offset_is_unknown("Sat, 02 Jan 2021 03:04:05 -0000"),
+        Some((Some(0), true))
+    );
+    assert_eq!(
+        offset_is_unknown("Sat, 02 Jan 2021 03:04:05 Z"),
+        Some((Some(0), true))
+    );
+    assert_eq!(
+        offset_is_unknown("Sat, 02 Jan 2021 03:04:05 +0000"),
+        Some((Some(0), false))
+    );
+    assert_eq!(
+        offset_is_unknown("Sat, 02 Jan 2021 03:04:05 -0030"),
+        Some((Some(0), false))
+    );
+    assert_eq!(
+        offset_is_unknown("Sat, 02 Jan 2021 03:04:05 GMT"),
+        Some((Some(0), false))
+    );
+    assert_eq!(
+        offset_is_unknown("Sat, 02 Jan 2021 03:04:05 EST"),
+        Some((Some(-5), false))
+    );
+
+    let format = fd::parse("[offset_hour sign:mandatory]:[offset_minute]").expect("valid format");
+    let parsed = Parsed::parse_with_diagnostic(b"-00:00", &format).expect("valid input");
+    assert!(parsed.offset_is_unknown());
+    let parsed = Parsed::parse_with_diagnostic(b"+00:00", &format).expect("valid input");
+    assert!(!parsed.offset_is_unknown());
+    let parsed = Parsed::parse_with_diagnostic(b"-00:30", &format).expect("valid input");
+    assert!(!parsed.offset_is_unknown());
+}
+
 #[test]
 fn issue_661() -> time::Result<()> {
     assert_eq!(
@@ -213,9 +292,13 @@ fn rfc_2822_err() {
         invalid_component!("year")
     ));
     assert!(matches!(
-        OffsetDateTime::parse("Mon, 02 Jan 1899", &Rfc2822),
+        OffsetDateTime::parse("Mon, 02 Jan 1", &Rfc2822),
         invalid_component!("year")
     ));
+    assert!(matches!(
+        OffsetDateTime::parse("Mon, 02 Jan 20210", &Rfc2822),
+        invalid_literal!()
+    ));
     assert!(matches!(
         OffsetDateTime::parse("Mon, 02 Jan 2021_", &Rfc2822),
         invalid_literal!()
@@ -578,6 +661,253 @@ fn rfc_3339_err() {
     ));
 }
 
+#[test]
+fn http_date() -> time::Result<()> {
+    assert_eq!(
+        OffsetDateTime::parse("Sun, 06 Nov 1994 08:49:37 GMT", &HttpDate)?,
+        datetime!(1994-11-06 08:49:37 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Sunday, 06-Nov-94 08:49:37 GMT", &HttpDate)?,
+        datetime!(1994-11-06 08:49:37 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Sun Nov  6 08:49:37 1994", &HttpDate)?,
+        datetime!(1994-11-06 08:49:37 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Wed Nov 16 08:49:37 1994", &HttpDate)?,
+        datetime!(1994-11-16 08:49:37 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Wednesday, 30-Jun-21 23:59:60 GMT", &HttpDate)?,
+        datetime!(2021-06-30 23:59:59.999_999_999 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Fri, 01 Jan 0100 00:00:00 GMT", &HttpDate)?,
+        datetime!(0100-01-01 00:00:00 UTC),
+    );
+    assert_eq!(
+        Date::parse("Sun, 06 Nov 1994 08:49:37 GMT", &HttpDate)?,
+        date!(1994-11-06),
+    );
+
+    assert_eq!(
+        OffsetDateTime::parse("Friday, 31-Dec-99 00:00:00 GMT", &HttpDate)?,
+        datetime!(1999-12-31 00:00:00 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Saturday, 05-Jan-30 00:00:00 GMT", &HttpDate)?,
+        datetime!(2030-01-05 00:00:00 UTC),
+    );
+    // A two-digit year is resolved to no more than 50 years after the current year.
+    let format = fd::parse(
+        "[weekday], [day]-[month repr:short]-[year repr:last_two] [hour]:[minute]:[second] GMT",
+    )?;
+    let year = OffsetDateTime::now_utc().year();
+    for date in [
+        Date::from_calendar_date(year + 50, Month::December, 31)?,
+        Date::from_calendar_date(year - 49, Month::January, 1)?,
+    ] {
+        let datetime = date.midnight().assume_utc();
+        assert_eq!(
+            OffsetDateTime::parse(&datetime.format(&format)?, &HttpDate)?,
+            datetime
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn http_date_err() {
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 06 Nov 1994 08:49:37 UTC", &HttpDate),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("sun, 06 Nov 1994 08:49:37 GMT", &HttpDate),
+        invalid_component!("weekday")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 06 nov 1994 08:49:37 GMT", &HttpDate),
+        invalid_component!("month")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 6 Nov 1994 08:49:37 GMT", &HttpDate),
+        invalid_component!("day")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 06 Nov 94 08:49:37 GMT", &HttpDate),
+        invalid_component!("year")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 06 Nov 1994 08:49 GMT", &HttpDate),
+        invalid_component!("time")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun,  06 Nov 1994 08:49:37 GMT", &HttpDate),
+        invalid_component!("day")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sunday 06-Nov-94 08:49:37 GMT", &HttpDate),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sunday, 06-Nov-1994 08:49:37 GMT", &HttpDate),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun Nov 6 08:49:37 1994", &HttpDate),
+        invalid_component!("day")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun Nov  6 08:49:37 1994 GMT", &HttpDate),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::UnexpectedTrailingCharacters { .. }
+        ))
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 06 Nov 1994 08:49:37 +0000", &HttpDate),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Mon, 06 Nov 1994 08:49:37 GMT", &HttpDate),
+        invalid_component!("weekday")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Monday, 06-Nov-94 08:49:37 GMT", &HttpDate),
+        invalid_component!("weekday")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Mon Nov  6 08:49:37 1994", &HttpDate),
+        invalid_component!("weekday")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("Sun, 31 Nov 1994 08:49:37 GMT", &HttpDate),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::ComponentRange { .. }
+        ))
+    ));
+}
+
+#[test]
+fn rfc_9557() -> time::Result<()> {
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05Z", &Rfc9557)?,
+        datetime!(2021-01-02 03:04:05 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05+01:00[Europe/Paris]", &Rfc9557)?,
+        datetime!(2021-01-02 03:04:05 +01:00),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05+01:00[!+01:00][u-ca=hebrew]", &Rfc9557)?,
+        datetime!(2021-01-02 03:04:05 +01:00),
+    );
+    // A `Z` means the local offset is unknown, so the annotation cannot be inconsistent.
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05Z[!+01:00]", &Rfc9557)?,
+        datetime!(2021-01-02 03:04:05 UTC),
+    );
+    // Elective annotations may disagree with the offset.
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05+01:00[+02:00]", &Rfc9557)?,
+        datetime!(2021-01-02 03:04:05 +01:00),
+    );
+    // Unknown elective annotations are ignored.
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05Z[foo=bar][_x-1=a-b-c]", &Rfc9557)?,
+        datetime!(2021-01-02 03:04:05 UTC),
+    );
+    // Conflicting elective annotations use the first value.
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05Z[u-ca=hebrew][u-ca=iso8601]", &Rfc9557)?,
+        datetime!(2021-01-02 03:04:05 UTC),
+    );
+    // Critical calendar annotations are accepted if they request the ISO 8601 calendar.
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05Z[!u-ca=iso8601]", &Rfc9557)?,
+        datetime!(2021-01-02 03:04:05 UTC),
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05Z[!u-ca=gregory]", &Rfc9557)?,
+        datetime!(2021-01-02 03:04:05 UTC),
+    );
+
+    assert_eq!(
+        OffsetDateTime::parse("2021-01-02T03:04:05-00:30[!-00:30]", &Rfc9557)?,
+        datetime!(2021-01-02 03:04:05 -00:30),
+    );
+
+    let mut parsed = Parsed::new();
+    let (remaining, annotations) = parsed.parse_rfc9557(
+        b"2021-01-02T03:04:05Z[America/Argentina/Buenos_Aires][foo=abcdefgh-abcdefgh-abcdefgh-abcd] x",
+    )?;
+    assert_eq!(remaining, b" x");
+    assert_eq!(
+        annotations.time_zone_annotation(),
+        Some("America/Argentina/Buenos_Aires")
+    );
+    assert_eq!(annotations.time_zone_annotation_is_critical(), Some(false));
+    assert_eq!(
+        annotations.annotation("foo"),
+        Some("abcdefgh-abcdefgh-abcdefgh-abcd")
+    );
+    assert_eq!(
+        OffsetDateTime::try_from(parsed)?,
+        datetime!(2021-01-02 03:04:05 UTC)
+    );
+
+    // The offset is only stored if parsing succeeds.
+    let mut parsed = Parsed::new();
+    assert!(parsed.parse_rfc9557(b"2021-01-02T03:04:05+01:00[!+02:00]").is_err());
+    assert_eq!(parsed.offset_hour(), None);
+
+    Ok(())
+}
+
+#[test]
+fn rfc_9557_err() {
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02T03:04:05+01:00[!+02:00]", &Rfc9557),
+        invalid_component!("time zone annotation")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02T03:04:05+01:00[!foo=bar]", &Rfc9557),
+        invalid_component!("annotation")
+    ));
+    // The date cannot be interpreted in any calendar other than ISO 8601.
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02T03:04:05Z[!u-ca=hebrew]", &Rfc9557),
+        invalid_component!("annotation")
+    ));
+    // Conflicting annotations are an error if either is critical.
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02T03:04:05Z[u-ca=hebrew][!u-ca=iso8601]", &Rfc9557),
+        invalid_component!("annotation")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02T03:04:05Z[Europe/..]", &Rfc9557),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::UnexpectedTrailingCharacters { .. }
+        ))
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02T03:04:05Z[Foo=bar]", &Rfc9557),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::UnexpectedTrailingCharacters { .. }
+        ))
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2021-01-02T03:04:05Z[+24:00]", &Rfc9557),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::UnexpectedTrailingCharacters { .. }
+        ))
+    ));
+}
+
+
 #[test]
 fn iso_8601() {
     assert_eq!(
@@ -803,76 +1133,585 @@ fn iso_8601_error() {
 }
 
 #[test]
-fn parse_time() -> time::Result<()> {
-    let format_input_output = [
-        (fd::parse("[hour repr:12] [period]")?, "01 PM", time!(1 PM)),
-        (fd::parse("[hour]")?, "12", time!(12:00)),
-        (
-            fd::parse("[hour]:[minute]:[second]")?,
-            "13:02:03",
-            time!(13:02:03),
-        ),
-        (
-            fd::parse("[hour repr:12]:[minute] [period]")?,
-            "01:02 PM",
-            time!(1:02 PM),
-        ),
-        (fd::parse("[hour]:[minute]")?, "01:02", time!(1:02)),
-        (
-            fd::parse("[hour repr:12]:[minute] [period]")?,
-            "01:02 AM",
-            time!(1:02 AM),
-        ),
-        (fd::parse("[hour]:[minute]")?, "01:02", time!(1:02)),
-        (fd::parse("[hour repr:12] [period]")?, "12 AM", time!(12 AM)),
-        (fd::parse("[hour repr:12] [period]")?, "12 PM", time!(12 PM)),
-    ];
-
-    for (format_description, input, output) in &format_input_output {
-        assert_eq!(&Time::parse(input, format_description)?, output);
-        assert_eq!(
-            &Time::parse(input, &OwnedFormatItem::from(format_description))?,
-            output
-        );
-        assert_eq!(
-            &Time::parse(
-                input,
-                [OwnedFormatItem::from(format_description)].as_slice()
-            )?,
-            output
-        );
-    }
-
-    Ok(())
-}
-
-#[expect(clippy::cognitive_complexity, reason = "all test the same thing")]
-#[test]
-fn parse_time_err() -> time::Result<()> {
+fn iso_8601_nominal_duration() -> time::Result<()> {
     assert_eq!(
-        Time::try_from(Parsed::new()),
-        Err(error::TryFromParsed::InsufficientInformation)
+        NominalDuration::parse("P1Y2M3W4DT5H6M7S")?,
+        NominalDuration::ZERO
+            .with_years(1)
+            .with_months(2)
+            .with_weeks(3)
+            .with_days(4)
+            .with_hours(5)
+            .with_minutes(6)
+            .with_seconds(7)
     );
     assert_eq!(
-        Time::parse("", &fd::parse("")?),
-        Err(error::Parse::TryFromParsed(
-            error::TryFromParsed::InsufficientInformation
-        ))
+        NominalDuration::parse("P1M")?,
+        NominalDuration::ZERO.with_months(1)
     );
     assert_eq!(
-        Time::parse("12:34", &fd::parse("[hour]:[second]")?),
-        Err(error::Parse::TryFromParsed(
-            error::TryFromParsed::InsufficientInformation
-        ))
+        NominalDuration::parse("PT1M")?,
+        NominalDuration::ZERO.with_minutes(1)
     );
     assert_eq!(
-        Time::parse("12:34", &fd::parse("[hour]:[subsecond]")?),
-        Err(error::Parse::TryFromParsed(
-            error::TryFromParsed::InsufficientInformation
-        ))
+        NominalDuration::parse("PT0S")?,
+        NominalDuration::ZERO
     );
-    assert!(matches!(
-        Time::parse("13 PM", &fd::parse("[hour repr:12] [period]")?),
+    assert_eq!(
+        NominalDuration::parse("PT1.5S")?,
+        NominalDuration::ZERO
+            .with_seconds(1)
+            .with_nanoseconds(500_000_000)?
+    );
+    assert_eq!(
+        NominalDuration::parse("PT0,123456789123S")?,
+        NominalDuration::ZERO.with_nanoseconds(123_456_789)?
+    );
+    assert_eq!(
+        NominalDuration::parse("PT1.5H")?,
+        NominalDuration::ZERO.with_hours(1).with_minutes(30)
+    );
+    assert_eq!(
+        NominalDuration::parse("PT2.25M")?,
+        NominalDuration::ZERO.with_minutes(2).with_seconds(15)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn iso_8601_nominal_duration_err() {
+    assert!(matches!(NominalDuration::parse(""), invalid_literal!()));
+    assert!(matches!(NominalDuration::parse("1D"), invalid_literal!()));
+    assert!(matches!(
+        NominalDuration::parse("P"),
+        invalid_component!("duration")
+    ));
+    assert!(matches!(
+        NominalDuration::parse("PT"),
+        invalid_component!("duration")
+    ));
+    assert!(matches!(
+        NominalDuration::parse("P1DT"),
+        invalid_component!("duration")
+    ));
+    assert!(matches!(
+        NominalDuration::parse("P1D1Y"),
+        invalid_component!("duration")
+    ));
+    assert!(matches!(
+        NominalDuration::parse("P1H"),
+        invalid_component!("duration")
+    ));
+    assert!(matches!(
+        NominalDuration::parse("PT1D"),
+        invalid_component!("duration")
+    ));
+    assert!(matches!(
+        NominalDuration::parse("P1.5D"),
+        invalid_component!("duration")
+    ));
+    assert!(matches!(
+        NominalDuration::parse("PT1.5H30M"),
+        invalid_component!("duration")
+    ));
+    assert!(matches!(
+        NominalDuration::parse("PT1.S"),
+        invalid_component!("duration")
+    ));
+    assert!(matches!(
+        NominalDuration::parse("P99999999999D"),
+        invalid_component!("duration")
+    ));
+}
+
+#[test]
+fn iso_8601_interval() -> time::Result<()> {
+    assert_eq!(
+        Interval::parse("2024-02-15/2024-02-18", &Iso8601::DEFAULT)?,
+        Interval::StartEnd {
+            start: Endpoint::Date(date!(2024-02-15)),
+            end: Endpoint::Date(date!(2024-02-18)),
+        }
+    );
+    assert_eq!(
+        Interval::parse("2024-01-01T00:00Z/2024-01-02T12:30+01:00", &Iso8601::DEFAULT)?,
+        Interval::StartEnd {
+            start: Endpoint::OffsetDateTime(datetime!(2024-01-01 0:00 UTC)),
+            end: Endpoint::OffsetDateTime(datetime!(2024-01-02 12:30 +01:00)),
+        }
+    );
+    assert_eq!(
+        Interval::parse("2024-02-15T09:00/09:00", &Iso8601::DEFAULT)?,
+        Interval::StartEnd {
+            start: Endpoint::PrimitiveDateTime(datetime!(2024-02-15 9:00)),
+            end: Endpoint::PrimitiveDateTime(datetime!(2024-02-15 9:00)),
+        }
+    );
+    assert_eq!(
+        Interval::parse("2024-01-01T00:00/P1Y2M", &Iso8601::DEFAULT)?,
+        Interval::StartDuration {
+            start: Endpoint::PrimitiveDateTime(datetime!(2024-01-01 0:00)),
+            duration: NominalDuration::ZERO.with_years(1).with_months(2),
+        }
+    );
+    assert_eq!(
+        Interval::parse("PT36H/2024-01-03", &Iso8601::DEFAULT)?,
+        Interval::DurationEnd {
+            duration: NominalDuration::ZERO.with_hours(36),
+            end: Endpoint::Date(date!(2024-01-03)),
+        }
+    );
+    assert_eq!(
+        Interval::parse("P1W", &Iso8601::DEFAULT)?,
+        Interval::Duration(NominalDuration::ZERO.with_weeks(1))
+    );
+    assert_eq!(
+        Interval::parse("20240215T0900Z/P1D", &Iso8601::DEFAULT)?,
+        Interval::StartDuration {
+            start: Endpoint::OffsetDateTime(datetime!(2024-02-15 9:00 UTC)),
+            duration: NominalDuration::ZERO.with_days(1),
+        }
+    );
+
+    Ok(())
+}
+
+#[test]
+fn iso_8601_interval_abbreviated_end() -> time::Result<()> {
+    macro_rules! assert_end {
+        ($input:literal, $end:expr) => {
+            assert!(matches!(
+                Interval::parse($input, &Iso8601::DEFAULT)?,
+                Interval::StartEnd { end, .. } if end == Endpoint::from($end)
+            ));
+        };
+    }
+
+    assert_end!("2024-02-15/18", date!(2024-02-18));
+    assert_end!("2024-02-15/03-01", date!(2024-03-01));
+    assert_end!("20240215/18", date!(2024-02-18));
+    assert_end!("20240215/0301", date!(2024-03-01));
+    assert_end!("2024-046/050", date!(2024-050));
+    assert_end!("2024-W07-1/5", date!(2024-W 07-5));
+    assert_end!("2024-W07-1/W08-1", date!(2024-W 08-1));
+    assert_end!("+002024-02-15/18", date!(2024-02-18));
+    assert_end!("2024-02-15T09:00/17:00", datetime!(2024-02-15 17:00));
+    assert_end!("2024-02-15T09:00/16T17:00", datetime!(2024-02-16 17:00));
+    assert_end!("2024-02-15T09:00Z/17:00", datetime!(2024-02-15 17:00 UTC));
+    assert_end!("2024-02-15T09:00Z/17:00+01:00", datetime!(2024-02-15 17:00 +01:00));
+    assert_end!("2024-02-15T09:00+05:30/16T01:00", datetime!(2024-02-16 1:00 +05:30));
+    assert_end!("2024-02-15T0900/1700", datetime!(2024-02-15 17:00));
+
+    Ok(())
+}
+
+#[test]
+fn iso_8601_repeating_interval() -> time::Result<()> {
+    assert_eq!(
+        RepeatingInterval::parse("R5/2024-01-01T00:00Z/P1D", &Iso8601::DEFAULT)?,
+        RepeatingInterval::new(
+            Some(5),
+            Interval::StartDuration {
+                start: Endpoint::OffsetDateTime(datetime!(2024-01-01 0:00 UTC)),
+                duration: NominalDuration::ZERO.with_days(1),
+            }
+        )
+    );
+    assert_eq!(
+        RepeatingInterval::parse("R/P1D/2024-01-10", &Iso8601::DEFAULT)?,
+        RepeatingInterval::new(
+            None,
+            Interval::DurationEnd {
+                duration: NominalDuration::ZERO.with_days(1),
+                end: Endpoint::Date(date!(2024-01-10)),
+            }
+        )
+    );
+    assert_eq!(
+        RepeatingInterval::parse("R0/2024-01-01/02", &Iso8601::DEFAULT)?,
+        RepeatingInterval::new(
+            Some(0),
+            Interval::StartEnd {
+                start: Endpoint::Date(date!(2024-01-01)),
+                end: Endpoint::Date(date!(2024-01-02)),
+            }
+        )
+    );
+    assert_eq!(
+        RepeatingInterval::parse("R/PT12H", &Iso8601::DEFAULT)?,
+        RepeatingInterval::new(
+            None,
+            Interval::Duration(NominalDuration::ZERO.with_hours(12))
+        )
+    );
+
+    Ok(())
+}
+
+#[test]
+fn iso_8601_interval_err() {
+    assert!(matches!(
+        Interval::parse("", &Iso8601::DEFAULT),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        Interval::parse("2024-01-01", &Iso8601::DEFAULT),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        Interval::parse("2024-01-01/", &Iso8601::DEFAULT),
+        invalid_component!("year")
+    ));
+    assert!(matches!(
+        Interval::parse("/2024-01-01", &Iso8601::DEFAULT),
+        invalid_component!("year")
+    ));
+    assert!(matches!(
+        Interval::parse("P1D/P2D", &Iso8601::DEFAULT),
+        invalid_component!("year")
+    ));
+    assert!(matches!(
+        Interval::parse("2024-01-01/2024-01-02/2024-01-03", &Iso8601::DEFAULT),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::UnexpectedTrailingCharacters { .. }
+        ))
+    ));
+    assert!(matches!(
+        Interval::parse("2024-01-01Z/2024-01-02", &Iso8601::DEFAULT),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::UnexpectedTrailingCharacters { .. }
+        ))
+    ));
+    assert!(matches!(
+        Interval::parse("2024-02-15/32", &Iso8601::DEFAULT),
+        invalid_component!("day")
+    ));
+    assert!(matches!(
+        Interval::parse("2023-02-15/29", &Iso8601::DEFAULT),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::ComponentRange(_)
+        ))
+    ));
+    assert!(matches!(
+        Interval::parse("2024-02-15T09:00/25:00", &Iso8601::DEFAULT),
+        invalid_component!("hour")
+    ));
+    assert!(matches!(
+        Interval::parse("2024-02-15/2024-02-14", &Iso8601::DEFAULT),
+        invalid_component!("end")
+    ));
+    assert!(matches!(
+        Interval::parse("2024-02-15T10:00/09:00", &Iso8601::DEFAULT),
+        invalid_component!("end")
+    ));
+    assert!(matches!(
+        Interval::parse("2024-01-01T00:00Z/2024-01-01T00:00+01:00", &Iso8601::DEFAULT),
+        invalid_component!("end")
+    ));
+    assert!(matches!(
+        Interval::parse("2024-01-01T00:00/2024-01-02T00:00Z", &Iso8601::DEFAULT),
+        invalid_component!("offset hour")
+    ));
+    assert!(matches!(
+        Interval::parse("2024-02-15/2024-02-16T10:00Z", &Iso8601::DEFAULT),
+        invalid_component!("offset hour")
+    ));
+    assert!(matches!(
+        RepeatingInterval::parse("5/P1D", &Iso8601::DEFAULT),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        RepeatingInterval::parse("R5P1D", &Iso8601::DEFAULT),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        RepeatingInterval::parse("R99999999999/P1D", &Iso8601::DEFAULT),
+        invalid_component!("repetitions")
+    ));
+}
+
+#[test]
+fn heuristic() -> time::Result<()> {
+    let day_first = Heuristic::DEFAULT.set_date_order(DateOrder::DayFirst);
+    let month_first = Heuristic::DEFAULT.set_date_order(DateOrder::MonthFirst);
+
+    assert_eq!(
+        PrimitiveDateTime::parse("March 3, 2024 5pm", &Heuristic::DEFAULT)?,
+        datetime!(2024-03-03 17:00)
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse("2024/03/03 17:00", &Heuristic::DEFAULT)?,
+        datetime!(2024-03-03 17:00)
+    );
+    assert_eq!(
+        Date::parse("3 Mar 24", &Heuristic::DEFAULT)?,
+        date!(2024-03-03)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("20240303T170000Z", &Heuristic::DEFAULT)?,
+        datetime!(2024-03-03 17:00 UTC)
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse("20240303170000", &Heuristic::DEFAULT)?,
+        datetime!(2024-03-03 17:00)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2024-03-03T17:00:00.123+01:00", &Heuristic::DEFAULT)?,
+        datetime!(2024-03-03 17:00:00.123 +1)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("Sun, 03 Mar 2024 17:00:00 -0500", &Heuristic::DEFAULT)?,
+        datetime!(2024-03-03 17:00 -5)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("sunday march 3rd 2024 at 5:30 p.m. UTC", &Heuristic::DEFAULT)?,
+        datetime!(2024-03-03 17:30 UTC)
+    );
+    assert_eq!(
+        Date::parse("3-Mar-2024", &Heuristic::DEFAULT)?,
+        date!(2024-03-03)
+    );
+    assert_eq!(
+        Date::parse("2024-Mar-03", &Heuristic::DEFAULT)?,
+        date!(2024-03-03)
+    );
+    assert_eq!(
+        Date::parse("Sept. 30 1999", &Heuristic::DEFAULT)?,
+        date!(1999-09-30)
+    );
+    assert_eq!(
+        Date::parse("03/03/2024", &Heuristic::DEFAULT)?,
+        date!(2024-03-03)
+    );
+    assert_eq!(
+        Date::parse("31.12.99", &Heuristic::DEFAULT)?,
+        date!(1999-12-31)
+    );
+    assert_eq!(
+        Date::parse("12/31/2068", &Heuristic::DEFAULT)?,
+        date!(2068-12-31)
+    );
+    assert_eq!(Date::parse("03/04/2024", &day_first)?, date!(2024-04-03));
+    assert_eq!(Date::parse("03/04/2024", &month_first)?, date!(2024-03-04));
+    assert_eq!(
+        Time::parse("12:30:15,5 AM", &Heuristic::DEFAULT)?,
+        time!(00:30:15.5)
+    );
+    assert_eq!(Time::parse("12 pm", &Heuristic::DEFAULT)?, time!(12:00));
+
+    let (parsed, assumptions) = Heuristic::DEFAULT.parse("March 2024")?;
+    assert_eq!(parsed.year(), Some(2024));
+    assert_eq!(parsed.month(), Some(Month::March));
+    assert_eq!(parsed.day(), None);
+    assert!(assumptions.is_certain());
+
+    let (parsed, assumptions) = Heuristic::DEFAULT.parse("Sun 3 Mar 24")?;
+    assert_eq!(parsed.weekday(), Some(Weekday::Sunday));
+    assert!(!assumptions.date_order());
+    assert!(assumptions.century());
+    assert!(!assumptions.is_certain());
+
+    let (_, assumptions) = day_first.parse("03/04/2024")?;
+    assert!(assumptions.date_order());
+    assert!(!assumptions.century());
+    let (_, assumptions) = day_first.parse("13/04/2024")?;
+    assert!(assumptions.is_certain());
+
+    Ok(())
+}
+
+#[test]
+fn heuristic_err() {
+    assert!(matches!(
+        Date::parse("03/04/2024", &Heuristic::DEFAULT),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::AmbiguousComponent("day")
+        ))
+    ));
+    assert!(matches!(
+        Date::parse("13/13/2024", &Heuristic::DEFAULT),
+        invalid_component!("month")
+    ));
+    assert!(matches!(
+        Date::parse("Mon, 03 Mar 2024", &Heuristic::DEFAULT),
+        invalid_component!("weekday")
+    ));
+    assert!(matches!(
+        Date::parse("March April 2024", &Heuristic::DEFAULT),
+        invalid_component!("month")
+    ));
+    assert!(matches!(
+        Date::parse("3 2024", &Heuristic::DEFAULT),
+        invalid_component!("month")
+    ));
+    assert!(matches!(
+        Date::parse("3 Mar 2024 5", &Heuristic::DEFAULT),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        Date::parse("3 Mar 2024 yesterday", &Heuristic::DEFAULT),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        Time::parse("13pm", &Heuristic::DEFAULT),
+        invalid_component!("hour")
+    ));
+    assert!(matches!(
+        Time::parse("pm", &Heuristic::DEFAULT),
+        invalid_component!("period")
+    ));
+    assert!(matches!(
+        Date::parse("3 Mar 5", &Heuristic::DEFAULT),
+        invalid_component!("year")
+    ));
+    assert!(matches!(
+        Date::parse("2024-03", &Heuristic::DEFAULT),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        Date::parse("2024-03/03", &Heuristic::DEFAULT),
+        invalid_literal!()
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse("2024-03-03 17:00 Z UTC", &Heuristic::DEFAULT),
+        invalid_component!("offset hour")
+    ));
+    assert!(matches!(
+        Date::parse("2024-03-03 \u{e9}", &Heuristic::DEFAULT),
+        invalid_literal!()
+    ));
+}
+
+#[test]
+fn parse_prefix() -> time::Result<()> {
+    let format = fd::parse("[year]-[month]-[day]")?;
+    assert_eq!(
+        Date::parse_prefix(b"2021-01-02 rest", &format)?,
+        (date!(2021-01-02), b" rest".as_slice())
+    );
+    assert_eq!(
+        Date::parse_prefix(b"2021-01-02", &format)?,
+        (date!(2021-01-02), b"".as_slice())
+    );
+    assert_eq!(
+        Time::parse_prefix(b"03:04:05.6|", &Iso8601::TIME)?,
+        (time!(03:04:05.6), b"|".as_slice())
+    );
+    assert_eq!(
+        UtcOffset::parse_prefix(b"+01:02 x", &fd::parse_owned::<2>("[offset_hour]:[offset_minute]")?)?,
+        (offset!(+01:02), b" x".as_slice())
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse_prefix(
+            b"2021-01-02T03:04:05 x",
+            &Iso8601::PARSING
+        )?,
+        (datetime!(2021-01-02 03:04:05), b" x".as_slice())
+    );
+    assert_eq!(
+        UtcDateTime::parse_prefix(b"2021-01-02T03:04:05+01:00,", &Rfc3339)?,
+        (utc_datetime!(2021-01-02 02:04:05), b",".as_slice())
+    );
+    assert_eq!(
+        OffsetDateTime::parse_prefix(b"2021-01-02T03:04:05Z INFO message", &Rfc3339)?,
+        (datetime!(2021-01-02 03:04:05 UTC), b" INFO message".as_slice())
+    );
+    // Trailing comments and whitespace are part of the RFC 2822 format.
+    assert_eq!(
+        OffsetDateTime::parse_prefix(b"Sat, 02 Jan 2021 03:04:05 GMT (c) INFO", &Rfc2822)?,
+        (datetime!(2021-01-02 03:04:05 UTC), b"INFO".as_slice())
+    );
+    assert_eq!(
+        OffsetDateTime::parse_prefix(b"Sat, 02 Jan 2021 03:04:05 GMT\tINFO", &HttpDate)?,
+        (datetime!(2021-01-02 03:04:05 UTC), b"\tINFO".as_slice())
+    );
+
+    assert!(matches!(
+        Date::parse_prefix(b"2021-13-02 rest", &format),
+        invalid_component!("month")
+    ));
+    assert!(matches!(
+        OffsetDateTime::parse_prefix(b"2021-01-02 rest", &format),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation
+        ))
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn parse_time() -> time::Result<()> {
+    let format_input_output = [
+        (fd::parse("[hour repr:12] [period]")?, "01 PM", time!(1 PM)),
+        (fd::parse("[hour]")?, "12", time!(12:00)),
+        (
+            fd::parse("[hour]:[minute]:[second]")?,
+            "13:02:03",
+            time!(13:02:03),
+        ),
+        (
+            fd::parse("[hour repr:12]:[minute] [period]")?,
+            "01:02 PM",
+            time!(1:02 PM),
+        ),
+        (fd::parse("[hour]:[minute]")?, "01:02", time!(1:02)),
+        (
+            fd::parse("[hour repr:12]:[minute] [period]")?,
+            "01:02 AM",
+            time!(1:02 AM),
+        ),
+        (fd::parse("[hour]:[minute]")?, "01:02", time!(1:02)),
+        (fd::parse("[hour repr:12] [period]")?, "12 AM", time!(12 AM)),
+        (fd::parse("[hour repr:12] [period]")?, "12 PM", time!(12 PM)),
+    ];
+
+    for (format_description, input, output) in &format_input_output {
+        assert_eq!(&Time::parse(input, format_description)?, output);
+        assert_eq!(
+            &Time::parse(input, &OwnedFormatItem::from(format_description))?,
+            output
+        );
+        assert_eq!(
+            &Time::parse(
+                input,
+                [OwnedFormatItem::from(format_description)].as_slice()
+            )?,
+            output
+        );
+    }
+
+    Ok(())
+}
+
+#[expect(clippy::cognitive_complexity, reason = "all test the same thing")]
+#[test]
+fn parse_time_err() -> time::Result<()> {
+    assert_eq!(
+        Time::try_from(Parsed::new()),
+        Err(error::TryFromParsed::InsufficientInformation)
+    );
+    assert_eq!(
+        Time::parse("", &fd::parse("")?),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation
+        ))
+    );
+    assert_eq!(
+        Time::parse("12:34", &fd::parse("[hour]:[second]")?),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation
+        ))
+    );
+    assert_eq!(
+        Time::parse("12:34", &fd::parse("[hour]:[subsecond]")?),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::InsufficientInformation
+        ))
+    );
+    assert!(matches!(
+        Time::parse("13 PM", &fd::parse("[hour repr:12] [period]")?),
         Err(error::Parse::ParseFromDescription(
             error::ParseFromDescription::InvalidComponent("hour")
         ))
@@ -970,6 +1809,16 @@ fn parse_date() -> time::Result<()> {
             date!(2021-01-02),
         ),
         (fd::parse("[year]-[ordinal]")?, "2021-002", date!(2021-002)),
+        (
+            fd::parse("[day][month repr:short case:upper][year repr:last_two pivot:2000]")?,
+            "03MAR24",
+            date!(2024-03-03),
+        ),
+        (
+            fd::parse("[weekday repr:narrow] [year]-[month]-[day]")?,
+            "S 2021-01-02",
+            date!(2021-01-02),
+        ),
         (
             fd::parse("[year base:iso_week]-W[week_number]-[weekday repr:monday]")?,
             "2020-W53-6",
@@ -1023,6 +1872,72 @@ fn parse_date() -> time::Result<()> {
             " 201-W01-2",
             date!(201-01-06),
         ),
+        (
+            fd::parse("[year]-[month] [weekday_in_month] [weekday repr:short]")?,
+            "2024-03 2 Fri",
+            date!(2024-03-08),
+        ),
+        (
+            fd::parse("[year]-[month] [weekday_in_month from_end:true] [weekday repr:short]")?,
+            "2024-03 1 Fri",
+            date!(2024-03-29),
+        ),
+        (
+            fd::parse("[year]-[month] [week_of_month] [weekday repr:short]")?,
+            "2024-03 2 Mon",
+            date!(2024-03-04),
+        ),
+        (
+            fd::parse("[year]-[month] [week_of_month minimal_days:4] [weekday repr:short]")?,
+            "2024-03 0 Fri",
+            date!(2024-03-01),
+        ),
+        (
+            fd::parse(
+                "[year]-[month] [week_of_month first_weekday:sunday] [weekday repr:short]",
+            )?,
+            "2024-03 6 Sun",
+            date!(2024-03-31),
+        ),
+        (
+            fd::parse("[year]-[quarter repr:short]")?,
+            "2024-Q3",
+            date!(2024-07-01),
+        ),
+        (
+            fd::parse("[year][quarter repr:short case_sensitive:false]")?,
+            "2024q3",
+            date!(2024-07-01),
+        ),
+        (fd::parse("[year] [quarter]")?, "2024 4", date!(2024-10-01)),
+        (
+            fd::parse("[year era_based:true padding:none] [era]-[ordinal]")?,
+            "1 BC-001",
+            date!(0000-01-01),
+        ),
+        (
+            fd::parse("[month]/[day]/[year era_based:true] [era repr:common]")?,
+            "03/15/0044 BCE",
+            date!(-0043-03-15),
+        ),
+        (
+            fd::parse("[year era_based:true]-[month]-[day] [era case_sensitive:false]")?,
+            "2024-03-15 ad",
+            date!(2024-03-15),
+        ),
+        (fd::parse("[year era_based:true]-[month]-[day]")?, "2024-03-15", date!(2024-03-15)),
+        (
+            fd::parse("[year era_based:true] [era repr:long]-[ordinal]")?,
+            "0044 Before Christ-074",
+            date!(-0043-074),
+        ),
+        (
+            fd::parse("[era repr:narrow][year era_based:true]-[ordinal]")?,
+            "A2024-074",
+            date!(2024-074),
+        ),
+        (fd::parse("[julian_day]")?, "2458849", date!(2019-12-31)),
+        (fd::parse("[julian_day repr:modified]")?, "-1", date!(1858-11-16)),
     ];
 
     for (format_description, input, output) in &format_input_output {
@@ -1116,30 +2031,116 @@ fn parse_date_err() -> time::Result<()> {
         ))
     ));
     assert!(matches!(
-        Date::parse("2019-W53-1", &fd::parse("[year]-W[week_number repr:monday]-[weekday repr:monday]")?),
+        Date::parse("2019-W53-1", &fd::parse("[year]-W[week_number repr:monday]-[weekday repr:monday]")?),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::ComponentRange(component)
+        )) if component.name() == "ordinal"
+    ));
+    assert!(matches!(
+        Date::parse(
+            "2021-W54-1",
+            &fd::parse("[year]-W[week_number repr:monday]-[weekday repr:monday]")?
+        ),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("week number")
+        ))
+    ));
+    assert!(matches!(
+        Date::parse("Ja", &fd::parse("[month repr:short]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("month")
+        ))
+    ));
+    assert!(matches!(
+        Date::parse("2024-B-05", &fd::parse("[year]-[month repr:narrow]-[day]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("month")
+        ))
+    ));
+    assert!(matches!(
+        Date::parse("0000 BC-001", &fd::parse("[year era_based:true] [era]-[ordinal]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("year")
+        ))
+    ));
+    assert!(matches!(
+        Date::parse("-0001 BC-001", &fd::parse("[year era_based:true] [era]-[ordinal]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("year")
+        ))
+    ));
+    assert!(matches!(
+        Date::parse("0001 XX-001", &fd::parse("[year era_based:true] [era]-[ordinal]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("era")
+        ))
+    ));
+    assert!(matches!(
+        Date::parse("2024 BC-001", &fd::parse("[year] [era]-[ordinal]")?),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(cr)))
+            if cr.name() == "year" && cr.is_conditional()
+    ));
+    assert!(matches!(
+        Date::parse("-0001 AD-001", &fd::parse("[year] [era]-[ordinal]")?),
+        Err(error::Parse::TryFromParsed(error::TryFromParsed::ComponentRange(cr)))
+            if cr.name() == "year" && cr.is_conditional()
+    ));
+    assert_eq!(
+        Date::parse("-0001 BC-001", &fd::parse("[year] [era]-[ordinal]")?)?,
+        date!(-0001-001)
+    );
+    assert!(matches!(
+        Date::parse("2024-Q5", &fd::parse("[year]-[quarter repr:short]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("quarter")
+        ))
+    ));
+    assert!(matches!(
+        Date::parse("2024 0", &fd::parse("[year] [quarter]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("quarter")
+        ))
+    ));
+    assert!(matches!(
+        Date::parse("2024Q1 05", &fd::parse("[year]Q[quarter] [day]")?),
         Err(error::Parse::TryFromParsed(
-            error::TryFromParsed::ComponentRange(component)
-        )) if component.name() == "ordinal"
+            error::TryFromParsed::InsufficientInformation
+        ))
     ));
     assert!(matches!(
-        Date::parse(
-            "2021-W54-1",
-            &fd::parse("[year]-W[week_number repr:monday]-[weekday repr:monday]")?
-        ),
+        Date::parse("t", &fd::parse("[weekday repr:narrow case:upper]")?),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("week number")
+            error::ParseFromDescription::InvalidComponent("weekday")
         ))
     ));
     assert!(matches!(
-        Date::parse("Ja", &fd::parse("[month repr:short]")?),
+        Date::parse("  2a21", &fd::parse("[year padding:space]")?),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("month")
+            error::ParseFromDescription::InvalidComponent("year")
         ))
     ));
     assert!(matches!(
-        Date::parse("  2a21", &fd::parse("[year padding:space]")?),
+        Date::parse(
+            "2024-02 5 Fri",
+            &fd::parse("[year]-[month] [weekday_in_month] [weekday repr:short]")?
+        ),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::ComponentRange(component)
+        )) if component.name() == "day"
+    ));
+    assert!(matches!(
+        Date::parse(
+            "2024-03 0 Fri",
+            &fd::parse("[year]-[month] [week_of_month] [weekday repr:short]")?
+        ),
+        Err(error::Parse::TryFromParsed(
+            error::TryFromParsed::ComponentRange(component)
+        )) if component.name() == "day"
+    ));
+    assert!(matches!(
+        Date::parse("2024-03 6 Fri", &fd::parse("[year]-[month] [weekday_in_month] [weekday]")?),
         Err(error::Parse::ParseFromDescription(
-            error::ParseFromDescription::InvalidComponent("year")
+            error::ParseFromDescription::InvalidComponent("weekday in month")
         ))
     ));
 
@@ -1328,6 +2329,72 @@ fn parse_utc_date_time_err() -> time::Result<()> {
     Ok(())
 }
 
+#[test]
+fn year_pivot() -> time::Result<()> {
+    let format = fd::parse("[day]/[month]/[year repr:last_two pivot:posix]")?;
+    assert_eq!(Date::parse("01/02/69", &format)?, date!(1969-02-01));
+    assert_eq!(Date::parse("01/02/68", &format)?, date!(2068-02-01));
+
+    let format = fd::parse("[month]/[year repr:last_two pivot:2000]")?;
+    let mut parsed = Parsed::new();
+    parsed.parse_items(b"12/27", &format)?;
+    parsed.set_day(NonZero::new(31).expect("non-zero"));
+    assert_eq!(Date::try_from(parsed)?, date!(2027-12-31));
+
+    let format = fd::parse("[day]/[month]/[year repr:last_two pivot:1950]")?;
+    assert_eq!(Date::parse("01/02/49", &format)?, date!(2049-02-01));
+    assert_eq!(Date::parse("01/02/50", &format)?, date!(1950-02-01));
+
+    // The century takes precedence over the pivot.
+    let format = fd::parse(
+        "[day]/[month]/[year repr:century][year repr:last_two pivot:posix]"
+    )?;
+    assert_eq!(Date::parse("01/02/1850", &format)?, date!(1850-02-01));
+
+    let format = fd::parse(
+        "[year repr:last_two base:iso_week pivot:posix]-W[week_number]-[weekday repr:monday]"
+    )?;
+    assert_eq!(Date::parse("20-W53-5", &format)?, date!(2021-01-01));
+
+    // The pivot can be set on `Parsed` directly.
+    let format = fd::parse("[day]/[month]/[year repr:last_two]")?;
+    let mut parsed = Parsed::new();
+    parsed.parse_items(b"01/02/75", &format)?;
+    assert!(matches!(
+        Date::try_from(parsed),
+        Err(error::TryFromParsed::InsufficientInformation)
+    ));
+    parsed.set_year_pivot(modifier::YearPivot::relative_to(2024, 50).expect("in range"));
+    assert_eq!(parsed.year_pivot().fixed_start(), Some(1975));
+    assert_eq!(Date::try_from(parsed)?, date!(1975-02-01));
+    parsed.set_year_pivot(modifier::YearPivot::relative_to(2025, 50).expect("in range"));
+    assert_eq!(Date::try_from(parsed)?, date!(2075-02-01));
+
+    // A sliding pivot spans 80 years before the reference year and 19 years after it.
+    let format = fd::parse("[day]/[month]/[year repr:last_two pivot:sliding]")?;
+    let mut parsed = Parsed::new();
+    parsed.parse_items(b"01/02/44", &format)?;
+    assert_eq!(parsed.year_pivot(), modifier::YearPivot::SLIDING);
+    assert_eq!(
+        Defaults::NONE.set_year(2024).resolve::<Date>(parsed)?,
+        date!(1944-02-01)
+    );
+    let mut parsed = Parsed::new();
+    parsed.parse_items(b"01/02/43", &format)?;
+    assert_eq!(
+        Defaults::NONE.set_year(2024).resolve::<Date>(parsed)?,
+        date!(2043-02-01)
+    );
+    assert_eq!(modifier::YearPivot::relative_to(1900, 50), None);
+    assert_eq!(modifier::YearPivot::SLIDING.resolve(43), None);
+    assert_eq!(
+        modifier::YearPivot::SLIDING.resolve_with_reference(43, 2024),
+        Some(2043)
+    );
+
+    Ok(())
+}
+
 #[expect(clippy::cognitive_complexity, reason = "all test the same thing")]
 #[test]
 fn parse_components() -> time::Result<()> {
@@ -1451,6 +2518,34 @@ fn parse_components() -> time::Result<()> {
         b"jAnUaRy",
         _.month() == Some(Month::January)
     );
+    parse_component!(
+        Component::Month(modifier!(Month {
+            padding: modifier::Padding::None,
+            repr: modifier::MonthRepr::Short,
+            case: modifier::TextCase::Upper,
+            case_sensitive: true,
+        })),
+        b"JAN",
+        _.month() == Some(Month::January)
+    );
+    parse_component!(
+        Component::Month(modifier!(Month {
+            padding: modifier::Padding::None,
+            repr: modifier::MonthRepr::Long,
+            case: modifier::TextCase::Lower,
+            case_sensitive: true,
+        })),
+        b"january",
+        _.month() == Some(Month::January)
+    );
+    parse_component!(
+        Component::Month(modifier!(Month {
+            repr: modifier::MonthRepr::Narrow,
+            case_sensitive: false,
+        })),
+        b"j",
+        _.month() == None
+    );
     parse_component!(
         Component::Ordinal(modifier!(Ordinal {
             padding: modifier::Padding::Zero,
@@ -1494,6 +2589,25 @@ fn parse_components() -> time::Result<()> {
         b"sUnDaY",
         _.weekday() == Some(Weekday::Sunday)
     );
+    parse_component!(
+        Component::Weekday(modifier!(Weekday {
+            repr: modifier::WeekdayRepr::Short,
+            one_indexed: false,
+            case: modifier::TextCase::Upper,
+            case_sensitive: true,
+        })),
+        b"SUN",
+        _.weekday() == Some(Weekday::Sunday)
+    );
+    parse_component!(
+        Component::Weekday(modifier!(Weekday {
+            repr: modifier::WeekdayRepr::Narrow,
+            case: modifier::TextCase::Lower,
+            case_sensitive: true,
+        })),
+        b"s",
+        _.weekday() == None
+    );
     parse_component!(
         Component::Weekday(modifier!(Weekday {
             repr: modifier::WeekdayRepr::Sunday,
@@ -1636,6 +2750,15 @@ fn parse_components() -> time::Result<()> {
         b"aM",
         _.hour_12_is_pm() == Some(false)
     );
+    parse_component!(
+        Component::Period(modifier!(Period {
+            is_uppercase: true,
+            repr: modifier::PeriodRepr::Narrow,
+            case_sensitive: true,
+        })),
+        b"P",
+        _.hour_12_is_pm() == Some(true)
+    );
     let mut parsed = Parsed::new();
     let result = parsed.parse_component(
         b"abcdef",
@@ -1918,6 +3041,65 @@ fn parse_unix_timestamp_err() -> time::Result<()> {
     Ok(())
 }
 
+#[test]
+fn parse_julian_day() -> time::Result<()> {
+    assert_eq!(
+        OffsetDateTime::parse("2451545.0", &fd::parse("[julian_day digits:1]")?)?,
+        datetime!(2000-01-01 12:00 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2451544.50000", &fd::parse("[julian_day digits:5]")?)?,
+        datetime!(2000-01-01 0:00 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse(
+            "54875.98020976223135",
+            &fd::parse("[julian_day repr:modified digits:14]")?
+        )?,
+        datetime!(2009-02-13 23:31:30.123456789 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("-0.25", &fd::parse("[julian_day repr:modified digits:2]")?)?,
+        datetime!(1858-11-16 18:00 UTC)
+    );
+    assert_eq!(
+        UtcDateTime::parse("2451545.333", &fd::parse("[julian_day digits:3]")?)?,
+        utc_datetime!(2000-01-01 19:59:31.200)
+    );
+
+    Ok(())
+}
+
+#[test]
+fn parse_julian_day_err() -> time::Result<()> {
+    assert_eq!(
+        OffsetDateTime::parse("2451545", &fd::parse("[julian_day digits:1]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("julian_day")
+        ))
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2451545.0", &fd::parse("[julian_day digits:2]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("julian_day")
+        ))
+    );
+    assert_eq!(
+        Date::parse("+2451545", &fd::parse("[julian_day]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("julian_day")
+        ))
+    );
+    assert!(matches!(
+        Date::parse("999999999", &fd::parse("[julian_day]")?),
+        Err(error::Parse::ParseFromDescription(
+            error::ParseFromDescription::InvalidComponent("julian_day")
+        ))
+    ));
+
+    Ok(())
+}
+
 #[test]
 fn issue_601() {
     let date = OffsetDateTime::parse(
@@ -1928,6 +3110,76 @@ fn issue_601() {
     assert_eq!(date, Ok(datetime!(2009-02-13 23:31:30.123 +00:00:00)));
 }
 
+#[test]
+fn time_zone_annotation() -> time::Result<()> {
+    let mut parsed = Parsed::new();
+    let remaining_input = parsed.parse_item(
+        b"[!Europe/Paris][u-ca=hebrew]",
+        &BorrowedFormatItem::Component(Component::TimeZoneAnnotation(
+            modifier::TimeZoneAnnotation::default(),
+        )),
+    );
+    assert_eq!(remaining_input, Ok(b"[u-ca=hebrew]".as_slice()));
+
+    assert_eq!(
+        OffsetDateTime::parse(
+            "2021-01-02 03:04:05 +01:00[+01:00]",
+            &fd::parse(
+                "[year]-[month]-[day] [hour]:[minute]:[second] \
+                 [offset_hour sign:mandatory]:[offset_minute][time_zone_annotation]"
+            )?
+        ),
+        Ok(datetime!(2021-01-02 03:04:05 +01:00))
+    );
+    assert!(matches!(
+        UtcOffset::parse(
+            "+01:00[!+02:00]",
+            &fd::parse("[offset_hour sign:mandatory]:[offset_minute][time_zone_annotation]")?
+        ),
+        invalid_component!("time zone annotation")
+    ));
+    assert!(matches!(
+        UtcOffset::parse(
+            "+01:00[]",
+            &fd::parse("[offset_hour sign:mandatory]:[offset_minute][time_zone_annotation]")?
+        ),
+        invalid_component!("time zone annotation")
+    ));
+
+    Ok(())
+}
+
+#[test]
+fn extension_annotations() -> time::Result<()> {
+    let mut parsed = Parsed::new();
+    let remaining_input = parsed.parse_item(
+        b"[u-ca=gregory][!u-ca=gregory][foo=bar-baz] rest",
+        &BorrowedFormatItem::Component(Component::ExtensionAnnotations(
+            modifier::ExtensionAnnotations::default(),
+        )),
+    );
+    assert_eq!(remaining_input, Ok(b" rest".as_slice()));
+
+    let mut parsed = Parsed::new();
+    let remaining_input = parsed.parse_item(
+        b"",
+        &BorrowedFormatItem::Component(Component::ExtensionAnnotations(
+            modifier::ExtensionAnnotations::default(),
+        )),
+    );
+    assert_eq!(remaining_input, Ok(b"".as_slice()));
+
+    assert!(matches!(
+        Time::parse(
+            "00:00[!foo=bar]",
+            &fd::parse("[hour]:[minute][extension_annotations]")?
+        ),
+        invalid_component!("annotation")
+    ));
+
+    Ok(())
+}
+
 #[test]
 fn end() -> time::Result<()> {
     let mut parsed = Parsed::new();
@@ -1960,3 +3212,199 @@ fn end() -> time::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn strftime() -> time::Result<()> {
+    let format = fd::parse_strftime_borrowed("%^a %-d %^b %Y %H:%M:%S%.f %z")?;
+    let expected = datetime!(2024-01-05 06:07:08.25 +01:30);
+    assert_eq!(
+        OffsetDateTime::parse("FRI 5 JAN 2024 06:07:08.25 +0130", &format)?,
+        expected
+    );
+    assert_eq!(
+        OffsetDateTime::parse("FRI 5 JAN 2024 06:07:08.25 +01:30", &format)?,
+        expected
+    );
+    assert_eq!(
+        OffsetDateTime::parse("FRI 5 JAN 2024 06:07:08 +01", &format)?,
+        datetime!(2024-01-05 06:07:08 +01)
+    );
+    assert!(matches!(
+        OffsetDateTime::parse("Fri 5 JAN 2024 06:07:08 +01", &format),
+        invalid_component!("weekday")
+    ));
+
+    let format = fd::parse_strftime_borrowed("%+")?;
+    assert_eq!(
+        OffsetDateTime::parse("2024-01-05T06:07:08.25+01:30", &format)?,
+        expected
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2024-01-05T06:07:08+0130", &format)?,
+        datetime!(2024-01-05 06:07:08 +01:30)
+    );
+
+    let format = fd::parse_strftime_borrowed("%F %T %Z")?;
+    assert_eq!(
+        PrimitiveDateTime::parse("2024-01-05 06:07:08 UTC", &format)?,
+        datetime!(2024-01-05 06:07:08)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2024-01-05 06:07:08 UTC", &format)?,
+        datetime!(2024-01-05 06:07:08 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2024-01-05 06:07:08 Z", &format)?,
+        datetime!(2024-01-05 06:07:08 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2024-01-05 06:07:08 -05:00", &format)?,
+        datetime!(2024-01-05 06:07:08 -5)
+    );
+
+    let format = fd::parse_strftime_borrowed("%Y-%5j %H:%M:%S.%3N")?;
+    assert_eq!(
+        PrimitiveDateTime::parse("2024-00005 06:07:08.250", &format)?,
+        datetime!(2024-005 06:07:08.25)
+    );
+
+    let format = fd::parse_strftime_borrowed("%10Y-%3m-%_4d")?;
+    assert_eq!(Date::parse("0000002024-001-   5", &format)?, date!(2024-01-05));
+    assert_eq!(Date::parse("-000000005-001-  15", &format)?, date!(-0005-01-15));
+    assert!(Date::parse("0000002024-01-   5", &format).is_err());
+    assert!(Date::parse("00002024-001-   5", &format).is_err());
+
+    let format = fd::parse_strftime_borrowed("%_10Y-%j")?;
+    assert_eq!(Date::parse("      2024-005", &format)?, date!(2024-005));
+    assert_eq!(Date::parse("        -5-005", &format)?, date!(-0005-005));
+
+    for date in [date!(2024-01-05), date!(-0005-12-31), date!(0000-06-15)] {
+        for description in ["%10Y-%m-%d", "%_10Y-%m-%d", "%6Y-%m-%d"] {
+            let format = fd::parse_strftime_borrowed(description)?;
+            assert_eq!(Date::parse(&date.format(&format)?, &format)?, date);
+        }
+    }
+
+    Ok(())
+}
+
+#[test]
+fn conditional() -> time::Result<()> {
+    let format = fd::parse_owned::<2>(
+        "[year]-[month]-[day]T[hour]:[minute][if second_is_zero [] [:[second]]]\
+         [if subsecond_is_zero [] [.[subsecond]]]\
+         [if offset_is_utc [Z] [[offset_hour sign:mandatory]:[offset_minute]]]",
+    )?;
+    assert_eq!(
+        OffsetDateTime::parse("2024-01-02T03:04Z", &format)?,
+        datetime!(2024-01-02 03:04 UTC)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2024-01-02T03:04:05.25+01:00", &format)?,
+        datetime!(2024-01-02 03:04:05.25 +01:00)
+    );
+    // The branch used when the condition does not hold is accepted even if the condition holds.
+    assert_eq!(
+        OffsetDateTime::parse("2024-01-02T03:04:00.0+00:00", &format)?,
+        datetime!(2024-01-02 03:04 UTC)
+    );
+    assert_eq!(
+        UtcDateTime::parse("2024-01-02T03:04.5Z", &format)?,
+        utc_datetime!(2024-01-02 03:04:00.5)
+    );
+    assert!(matches!(
+        OffsetDateTime::parse("2024-01-02T03:04:05.+01:00", &format),
+        invalid_component!("offset hour")
+    ));
+
+    let mut parsed = Parsed::new();
+    let remaining = parsed.parse_item(
+        b"Z",
+        &BorrowedFormatItem::Conditional {
+            condition: fd::Condition::OffsetIsUtc,
+            then: &BorrowedFormatItem::Literal(b"Z"),
+            otherwise: &BorrowedFormatItem::Literal(b"+00:00"),
+        },
+    )?;
+    assert!(remaining.is_empty());
+    assert_eq!(parsed.offset_hour(), Some(0));
+    assert_eq!(parsed.offset_minute_signed(), Some(0));
+    assert_eq!(parsed.offset_second_signed(), Some(0));
+
+    let mut parsed = Parsed::new();
+    let remaining = parsed.parse_item(
+        b":30",
+        &BorrowedFormatItem::Conditional {
+            condition: fd::Condition::SecondIsZero,
+            then: &BorrowedFormatItem::Compound(&[]),
+            otherwise: &BorrowedFormatItem::Literal(b"x"),
+        },
+    )?;
+    assert_eq!(remaining, b":30");
+    assert_eq!(parsed.second(), Some(0));
+    assert_eq!(parsed.subsecond(), None);
+
+    Ok(())
+}
+
+#[test]
+fn icu() -> time::Result<()> {
+    let format = fd::parse_icu_owned("yyyy-MM-dd'T'HH:mm:ss.SSSXXX")?;
+    assert_eq!(
+        OffsetDateTime::parse("2024-01-05T06:07:08.250+01:30", &format)?,
+        datetime!(2024-01-05 06:07:08.25 +01:30)
+    );
+    assert_eq!(
+        PrimitiveDateTime::parse("2024-01-05T06:07:08.250Z", &format)?,
+        datetime!(2024-01-05 06:07:08.25)
+    );
+    assert_eq!(
+        OffsetDateTime::parse("2024-01-05T06:07:08.250Z", &format)?,
+        datetime!(2024-01-05 06:07:08.25 UTC)
+    );
+
+    for (pattern, input, offset) in [
+        ("x", "-08", offset!(-8)),
+        ("x", "-0830", offset!(-8:30)),
+        ("X", "Z", offset!(UTC)),
+        ("xxxx", "-0830", offset!(-8:30)),
+        ("xxxx", "-083015", offset!(-8:30:15)),
+        ("XXXXX", "-08:30", offset!(-8:30)),
+        ("XXXXX", "-08:30:15", offset!(-8:30:15)),
+        ("ZZZZZ", "Z", offset!(UTC)),
+    ] {
+        assert_eq!(UtcOffset::parse(input, &fd::parse_icu_owned(pattern)?)?, offset);
+    }
+
+    let format = fd::parse_icu_owned("d MMM y G")?;
+    assert_eq!(Date::parse("15 Mar 44 BC", &format)?, date!(-0043-03-15));
+    assert_eq!(Date::parse("15 Mar 2024 AD", &format)?, date!(2024-03-15));
+
+    let format = fd::parse_icu_owned("EEE, d MMM yy h:mm a[ xx]")?;
+    assert_eq!(
+        OffsetDateTime::parse("Fri, 5 Jan 24 6:07 PM -0500", &format)?,
+        datetime!(2024-01-05 18:07 -5)
+    );
+    let mut parsed = Parsed::new();
+    parsed.parse_item(b"Tue, 5 Jan 99 6:07 AM", &format)?;
+    assert_eq!(
+        Defaults::NONE
+            .set_year(2024)
+            .resolve::<PrimitiveDateTime>(parsed)?,
+        datetime!(1999-01-05 06:07)
+    );
+    let mut parsed = Parsed::new();
+    parsed.parse_item(b"Sun, 5 Jan 43 6:07 AM", &format)?;
+    assert_eq!(
+        Defaults::NONE
+            .set_year(2024)
+            .resolve::<PrimitiveDateTime>(parsed)?,
+        datetime!(2043-01-05 06:07)
+    );
+    assert!(matches!(
+        PrimitiveDateTime::parse("Fri, 5 Jan 24 6:07 pm", &format),
+        invalid_component!("period")
+    ));
+
+    Ok(())
+}
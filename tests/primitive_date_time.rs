@@ -2,7 +2,7 @@ use std::cmp::Ordering;
 
 use time::ext::{NumericalDuration, NumericalStdDuration};
 use time::macros::{date, datetime, offset, time};
-use time::{Duration, Month, PrimitiveDateTime, Weekday};
+use time::{Duration, Month, PrimitiveDateTime, SerialDateSystem, Weekday};
 
 #[test]
 fn new() {
@@ -136,6 +136,126 @@ fn to_julian_day() {
     assert_eq!(datetime!(2019-12-31 0:00).to_julian_day(), 2_458_849);
 }
 
+#[test]
+fn to_serial_date() {
+    use SerialDateSystem::*;
+
+    assert_eq!(datetime!(1899-12-31 12:00).to_serial_date(Excel1900), 0.5);
+    assert_eq!(datetime!(1900-01-01 12:00).to_serial_date(Excel1900), 1.5);
+    assert_eq!(datetime!(2024-01-01 18:00).to_serial_date(Excel1900), 45_292.75);
+    assert_eq!(datetime!(1903-12-30 6:00).to_serial_date(Excel1904), -1.75);
+    assert_eq!(datetime!(2024-01-01 6:00).to_serial_date(Excel1904), 43_830.25);
+    assert_eq!(datetime!(1899-12-29 18:00).to_serial_date(OleAutomation), -1.75);
+    assert_eq!(datetime!(1899-12-30 12:00).to_serial_date(OleAutomation), 0.5);
+    assert_eq!(datetime!(1900-01-01 12:00).to_serial_date(OleAutomation), 2.5);
+}
+
+#[test]
+fn from_serial_date() {
+    use SerialDateSystem::*;
+
+    assert_eq!(
+        PrimitiveDateTime::from_serial_date(0.5, Excel1900),
+        Ok(datetime!(1899-12-31 12:00))
+    );
+    assert_eq!(
+        PrimitiveDateTime::from_serial_date(61.25, Excel1900),
+        Ok(datetime!(1900-03-01 6:00))
+    );
+    assert_eq!(
+        PrimitiveDateTime::from_serial_date(45_292.75, Excel1900),
+        Ok(datetime!(2024-01-01 18:00))
+    );
+    assert_eq!(
+        PrimitiveDateTime::from_serial_date(-1.75, Excel1904),
+        Ok(datetime!(1903-12-30 6:00))
+    );
+    assert_eq!(
+        PrimitiveDateTime::from_serial_date(-1.75, OleAutomation),
+        Ok(datetime!(1899-12-29 18:00))
+    );
+    assert_eq!(
+        PrimitiveDateTime::from_serial_date(-0.5, OleAutomation),
+        Ok(datetime!(1899-12-30 12:00))
+    );
+    assert_eq!(
+        PrimitiveDateTime::from_serial_date(0.5, OleAutomation),
+        Ok(datetime!(1899-12-30 12:00))
+    );
+
+    // The time is rounded to the nearest millisecond.
+    assert_eq!(
+        PrimitiveDateTime::from_serial_date(45_292. + 0.4 / 86_400_000., Excel1900),
+        Ok(datetime!(2024-01-01 0:00))
+    );
+    assert_eq!(
+        PrimitiveDateTime::from_serial_date(45_292. + 0.6 / 86_400_000., Excel1900),
+        Ok(datetime!(2024-01-01 0:00:00.001))
+    );
+    assert_eq!(
+        PrimitiveDateTime::from_serial_date(45_293. - 0.4 / 86_400_000., Excel1900),
+        Ok(datetime!(2024-01-02 0:00))
+    );
+
+    // Negative OLE Automation dates round the time of day, carrying a full day forward.
+    assert_eq!(
+        PrimitiveDateTime::from_serial_date(-1., OleAutomation),
+        Ok(datetime!(1899-12-29 0:00))
+    );
+    assert_eq!(
+        PrimitiveDateTime::from_serial_date(-2., OleAutomation),
+        Ok(datetime!(1899-12-28 0:00))
+    );
+    assert_eq!(
+        PrimitiveDateTime::from_serial_date(-1.000_000_000_1, OleAutomation),
+        Ok(datetime!(1899-12-29 0:00))
+    );
+    assert_eq!(
+        PrimitiveDateTime::from_serial_date(-0.999_999_999_9, OleAutomation),
+        Ok(datetime!(1899-12-31 0:00))
+    );
+    assert_eq!(
+        PrimitiveDateTime::from_serial_date(-1.999_999_999_9, OleAutomation),
+        Ok(datetime!(1899-12-30 0:00))
+    );
+    assert_eq!(
+        PrimitiveDateTime::from_serial_date(-1.0 - 0.6 / 86_400_000., OleAutomation),
+        Ok(datetime!(1899-12-29 0:00:00.001))
+    );
+
+    for system in [Excel1900, Excel1904, OleAutomation] {
+        for datetime in [
+            datetime!(2024-06-15 13:37:42.123),
+            datetime!(1899-12-29 13:37:42.123),
+            datetime!(1899-12-29 23:59:59.999),
+            datetime!(1800-01-01 0:00:00.001),
+        ] {
+            assert_eq!(
+                PrimitiveDateTime::from_serial_date(datetime.to_serial_date(system), system),
+                Ok(datetime)
+            );
+        }
+    }
+}
+
+#[test]
+fn from_serial_date_err() {
+    use SerialDateSystem::*;
+
+    assert_eq!(
+        PrimitiveDateTime::from_serial_date(60.5, Excel1900).map_err(|err| err.name()),
+        Err("day")
+    );
+    for serial in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY, 1e300, -1e300] {
+        for system in [Excel1900, Excel1904, OleAutomation] {
+            assert_eq!(
+                PrimitiveDateTime::from_serial_date(serial, system).map_err(|err| err.name()),
+                Err("serial")
+            );
+        }
+    }
+}
+
 #[test]
 fn as_hms() {
     assert_eq!(datetime!(2020-01-01 1:02:03).as_hms(), (1, 2, 3));
@@ -0,0 +1,89 @@
+use rstest::rstest;
+use time::Month;
+use time::Quarter::{self, *};
+
+#[rstest]
+#[case(First, Fourth)]
+#[case(Second, First)]
+#[case(Third, Second)]
+#[case(Fourth, Third)]
+fn previous(#[case] quarter: Quarter, #[case] expected: Quarter) {
+    assert_eq!(quarter.previous(), expected);
+}
+
+#[rstest]
+#[case(First, Second)]
+#[case(Second, Third)]
+#[case(Third, Fourth)]
+#[case(Fourth, First)]
+fn next(#[case] quarter: Quarter, #[case] expected: Quarter) {
+    assert_eq!(quarter.next(), expected);
+}
+
+#[rstest]
+#[case(First, Month::January, Month::March)]
+#[case(Second, Month::April, Month::June)]
+#[case(Third, Month::July, Month::September)]
+#[case(Fourth, Month::October, Month::December)]
+fn months(#[case] quarter: Quarter, #[case] first: Month, #[case] last: Month) {
+    assert_eq!(quarter.first_month(), first);
+    assert_eq!(quarter.last_month(), last);
+    assert_eq!(first.quarter(), quarter);
+    assert_eq!(first.next().quarter(), quarter);
+    assert_eq!(last.quarter(), quarter);
+}
+
+#[rstest]
+#[case(First, 2019, 90)]
+#[case(First, 2020, 91)]
+#[case(Second, 2019, 91)]
+#[case(Second, 2020, 91)]
+#[case(Third, 2019, 92)]
+#[case(Fourth, 2020, 92)]
+fn length(#[case] quarter: Quarter, #[case] year: i32, #[case] expected: u8) {
+    assert_eq!(quarter.length(year), expected);
+}
+
+#[rstest]
+#[case(First, "Q1")]
+#[case(Second, "Q2")]
+#[case(Third, "Q3")]
+#[case(Fourth, "Q4")]
+fn display(#[case] quarter: Quarter, #[case] expected: &str) {
+    assert_eq!(quarter.to_string(), expected);
+}
+
+#[rstest]
+#[case("Q1", Ok(First))]
+#[case("Q2", Ok(Second))]
+#[case("Q3", Ok(Third))]
+#[case("Q4", Ok(Fourth))]
+#[case("Q5", Err(time::error::InvalidVariant))]
+fn from_str(#[case] s: &str, #[case] expected: Result<Quarter, time::error::InvalidVariant>) {
+    assert_eq!(s.parse::<Quarter>(), expected);
+}
+
+#[rstest]
+#[case(First, 1)]
+#[case(Second, 2)]
+#[case(Third, 3)]
+#[case(Fourth, 4)]
+fn to_u8(#[case] quarter: Quarter, #[case] expected: u8) {
+    assert_eq!(u8::from(quarter), expected);
+}
+
+#[rstest]
+#[case(1, First)]
+#[case(2, Second)]
+#[case(3, Third)]
+#[case(4, Fourth)]
+fn try_from_u8_success(#[case] input: u8, #[case] expected: Quarter) {
+    assert_eq!(Quarter::try_from(input), Ok(expected));
+}
+
+#[rstest]
+#[case(0)]
+#[case(5)]
+fn try_from_u8_error(#[case] input: u8) {
+    assert!(matches!(Quarter::try_from(input), Err(err) if err.name() == "quarter"));
+}
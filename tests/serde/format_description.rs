@@ -0,0 +1,100 @@
+use serde::Deserialize;
+use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+use time::format_description::{self, modifier, Component, OwnedFormatItem};
+
+#[test]
+fn owned_format_item() -> time::Result<()> {
+    let description = "[year]-[month repr:short]-[day padding:none][optional [ \\[[hour]\\]]]";
+    assert_tokens(
+        &format_description::parse_owned::<2>(description)?,
+        &[Token::Str(description)],
+    );
+    assert_tokens(
+        &format_description::parse_owned::<2>("[first [[year]] [[unix_timestamp]]]")?,
+        &[Token::Str("[first [[year]] [[unix_timestamp]]]")],
+    );
+
+    assert_de_tokens_error::<OwnedFormatItem>(
+        &[Token::Str("[year")],
+        "unclosed opening bracket at byte index 0",
+    );
+    assert_de_tokens_error::<OwnedFormatItem>(
+        &[Token::Str("[year repr:foo]")],
+        "invalid modifier `foo` at byte index 11",
+    );
+    assert_de_tokens_error::<OwnedFormatItem>(
+        &[Token::U8(0)],
+        "invalid type: integer `0`, expected a version 2 format description",
+    );
+    Ok(())
+}
+
+#[test]
+fn component() {
+    assert_tokens(
+        &Component::Year(modifier::Year::last_two().with_pivot(modifier::YearPivot::POSIX)),
+        &[Token::Str("[year repr:last_two pivot:posix]")],
+    );
+    assert_tokens(
+        &Component::End(modifier::End::default()),
+        &[Token::Str("[end]")],
+    );
+    assert_de_tokens_error::<Component>(
+        &[Token::Str("[year][month]")],
+        "invalid value: string \"[year][month]\", expected a version 2 format description \
+         consisting of a single component",
+    );
+    assert_de_tokens_error::<Component>(
+        &[Token::Str("[bad]")],
+        "invalid component name `bad` at byte index 1",
+    );
+}
+
+#[test]
+fn modifiers() {
+    assert_tokens(&modifier::Day::default(), &[Token::Str("[day]")]);
+    assert_tokens(
+        &modifier::Month::short().with_case(modifier::TextCase::Upper),
+        &[Token::Str("[month repr:short case:upper]")],
+    );
+    assert_tokens(
+        &modifier::Hour::twelve_hour(),
+        &[Token::Str("[hour repr:12]")],
+    );
+    assert_tokens(
+        &modifier::Subsecond::default().with_digits(modifier::SubsecondDigits::Three),
+        &[Token::Str("[subsecond digits:3]")],
+    );
+    assert_tokens(
+        &modifier::Ignore::count(core::num::NonZero::new(4).expect("nonzero")),
+        &[Token::Str("[ignore count:4]")],
+    );
+    assert_tokens(
+        &modifier::TimeZoneAnnotation::default(),
+        &[Token::Str("[time_zone_annotation]")],
+    );
+    assert_de_tokens_error::<modifier::Day>(
+        &[Token::Str("[month]")],
+        "invalid value: string \"[month]\", expected a version 2 format description consisting \
+         of a single `Day` component",
+    );
+}
+
+#[test]
+fn json_location() {
+    #[derive(Debug, Deserialize)]
+    struct Config {
+        #[expect(dead_code)]
+        format: OwnedFormatItem,
+    }
+
+    let config = serde_json::from_str::<Config>("{\"format\": \"[year]-[month]\"}");
+    assert!(config.is_ok());
+
+    let err = serde_json::from_str::<Config>("{\n  \"format\": \"[year]-[mnth]\"\n}")
+        .expect_err("the format description is invalid");
+    assert_eq!(
+        err.to_string(),
+        "invalid component name `mnth` at byte index 8 at line 2 column 27"
+    );
+}
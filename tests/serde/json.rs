@@ -3,7 +3,7 @@ use std::error::Error;
 use serde::{Deserialize, Serialize};
 use serde_test::Configure;
 use time::macros::{date, datetime, time};
-use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, Weekday};
+use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Quarter, Time, Weekday};
 
 enum Format {
     Compact,
@@ -56,6 +56,18 @@ fn month_json() -> Result<(), Box<dyn Error>> {
     Ok(())
 }
 
+#[test]
+fn quarter_json() -> Result<(), Box<dyn Error>> {
+    assert_eq!(serialize(Quarter::Third.compact())?, "3");
+    assert_eq!(deserialize::<Quarter>("3", Compact)?, Quarter::Third);
+
+    assert_eq!(serialize(Quarter::Third.readable())?, "\"Q3\"");
+    assert_eq!(deserialize::<Quarter>("\"Q3\"", Readable)?, Quarter::Third);
+    assert_eq!(deserialize::<Quarter>("3", Readable)?, Quarter::Third);
+
+    Ok(())
+}
+
 #[test]
 fn time_json() -> Result<(), Box<dyn Error>> {
     let time = time!(12:40:20);
@@ -2,9 +2,12 @@ use serde_test::{
     assert_de_tokens, assert_de_tokens_error, assert_tokens, Compact, Configure, Readable, Token,
 };
 use time::macros::{date, datetime, offset, time};
-use time::{Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset, Weekday};
+use time::{
+    Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Quarter, Time, UtcOffset, Weekday,
+};
 
 mod error_conditions;
+mod format_description;
 mod iso8601;
 mod json;
 mod macros;
@@ -994,3 +997,29 @@ fn month_error() {
         "invalid type: boolean `false`, expected a `Month`",
     );
 }
+
+#[test]
+fn quarter() {
+    use Quarter::*;
+    assert_tokens(&First.compact(), &[Token::U8(1)]);
+    assert_tokens(&Second.compact(), &[Token::U8(2)]);
+    assert_tokens(&Third.compact(), &[Token::U8(3)]);
+    assert_tokens(&Fourth.compact(), &[Token::U8(4)]);
+
+    assert_tokens(&First.readable(), &[Token::BorrowedStr("Q1")]);
+    assert_tokens(&Second.readable(), &[Token::BorrowedStr("Q2")]);
+    assert_tokens(&Third.readable(), &[Token::BorrowedStr("Q3")]);
+    assert_tokens(&Fourth.readable(), &[Token::BorrowedStr("Q4")]);
+}
+
+#[test]
+fn quarter_error() {
+    assert_de_tokens_error::<Compact<Quarter>>(
+        &[Token::U8(5)],
+        "invalid value: integer `5`, expected a value in the range 1..=4",
+    );
+    assert_de_tokens_error::<Readable<Quarter>>(
+        &[Token::BorrowedStr("Q5")],
+        r#"invalid value: string "Q5", expected a `Quarter`"#,
+    );
+}
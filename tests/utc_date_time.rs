@@ -194,6 +194,112 @@ fn to_julian_day() {
     assert_eq!(utc_datetime!(2019-12-31 0:00).to_julian_day(), 2_458_849);
 }
 
+#[test]
+fn julian_date() {
+    assert_eq!(
+        utc_datetime!(2000-01-01 12:00).to_julian_date(),
+        (2_451_545, 0)
+    );
+    assert_eq!(
+        utc_datetime!(2000-01-01 0:00).to_julian_date(),
+        (2_451_544, 43_200_000_000_000)
+    );
+    assert_eq!(
+        utc_datetime!(2000-01-01 11:59:59.999_999_999).to_julian_date(),
+        (2_451_544, 86_399_999_999_999)
+    );
+    assert_eq!(
+        utc_datetime!(-4713-11-24 12:00).to_julian_date(),
+        (0, 0)
+    );
+    assert_eq!(
+        UtcDateTime::from_julian_date(2_451_545, 0),
+        Ok(utc_datetime!(2000-01-01 12:00))
+    );
+    assert_eq!(
+        UtcDateTime::from_julian_date(2_451_544, 86_399_999_999_999),
+        Ok(utc_datetime!(2000-01-01 11:59:59.999_999_999))
+    );
+    assert_eq!(
+        UtcDateTime::from_julian_date(-1, 43_200_000_000_000),
+        Ok(utc_datetime!(-4713-11-24 0:00))
+    );
+
+    for datetime in [
+        UtcDateTime::MIN,
+        UtcDateTime::MAX,
+        utc_datetime!(2000-01-01 12:00),
+        utc_datetime!(1858-11-17 0:00),
+        utc_datetime!(2024-02-29 23:59:59.123_456_789),
+    ] {
+        let (julian_day, nanosecond) = datetime.to_julian_date();
+        assert_eq!(
+            UtcDateTime::from_julian_date(julian_day, nanosecond),
+            Ok(datetime)
+        );
+    }
+}
+
+#[test]
+fn julian_date_err() {
+    let (min_day, min_nanosecond) = UtcDateTime::MIN.to_julian_date();
+    let (max_day, max_nanosecond) = UtcDateTime::MAX.to_julian_date();
+    assert!(matches!(
+        UtcDateTime::from_julian_date(min_day, min_nanosecond - 1),
+        Err(err) if err.name() == "julian_day" && err.is_conditional()
+    ));
+    assert!(matches!(
+        UtcDateTime::from_julian_date(max_day, max_nanosecond + 1),
+        Err(err) if err.name() == "julian_day" && err.is_conditional()
+    ));
+    assert!(matches!(
+        UtcDateTime::from_julian_date(min_day - 1, 0),
+        Err(err) if err.name() == "julian_day" && !err.is_conditional()
+    ));
+    assert!(matches!(
+        UtcDateTime::from_julian_date(2_451_545, 86_400_000_000_000),
+        Err(err) if err.name() == "nanosecond"
+    ));
+}
+
+#[test]
+fn modified_julian_date() {
+    assert_eq!(
+        utc_datetime!(2000-01-01 12:00).to_modified_julian_date(),
+        (51_544, 43_200_000_000_000)
+    );
+    assert_eq!(
+        utc_datetime!(1858-11-16 6:00).to_modified_julian_date(),
+        (-1, 21_600_000_000_000)
+    );
+    assert_eq!(
+        UtcDateTime::from_modified_julian_date(51_544, 43_200_000_000_000),
+        Ok(utc_datetime!(2000-01-01 12:00))
+    );
+
+    for datetime in [
+        UtcDateTime::MIN,
+        UtcDateTime::MAX,
+        utc_datetime!(2000-01-01 12:00),
+        utc_datetime!(1858-11-16 23:59:59.999_999_999),
+    ] {
+        let (modified_julian_day, nanosecond) = datetime.to_modified_julian_date();
+        assert_eq!(
+            UtcDateTime::from_modified_julian_date(modified_julian_day, nanosecond),
+            Ok(datetime)
+        );
+    }
+
+    assert!(matches!(
+        UtcDateTime::from_modified_julian_date(51_544, 86_400_000_000_000),
+        Err(err) if err.name() == "nanosecond"
+    ));
+    assert!(matches!(
+        UtcDateTime::from_modified_julian_date(i32::MAX, 0),
+        Err(err) if err.name() == "modified_julian_day"
+    ));
+}
+
 #[test]
 fn as_hms() {
     assert_eq!(utc_datetime!(2020-01-01 1:02:03).as_hms(), (1, 2, 3));
@@ -32,6 +32,17 @@ pub(super) enum Item<'a> {
         nested_format_descriptions: Box<[NestedFormatDescription<'a>]>,
         closing_bracket: Location,
     },
+    Conditional {
+        opening_bracket: Location,
+        _leading_whitespace: Unused<Option<Spanned<&'a [u8]>>>,
+        _if_kw: Unused<Spanned<&'a [u8]>>,
+        _whitespace: Unused<Spanned<&'a [u8]>>,
+        condition: Spanned<&'a [u8]>,
+        _condition_whitespace: Unused<Spanned<&'a [u8]>>,
+        then: NestedFormatDescription<'a>,
+        otherwise: NestedFormatDescription<'a>,
+        closing_bracket: Location,
+    },
 }
 
 pub(super) struct NestedFormatDescription<'a> {
@@ -179,6 +190,39 @@ fn parse_component<'a, I: Iterator<Item = Result<lexer::Token<'a>, Error>>, cons
         });
     }
 
+    if *name == b"if" {
+        let Some(whitespace) = tokens.next_if_whitespace() else {
+            return Err(name.span.error("expected whitespace after `if`"));
+        };
+
+        let Some(condition) = tokens.next_if_not_whitespace() else {
+            return Err(whitespace.span.error("expected condition"));
+        };
+
+        let Some(condition_whitespace) = tokens.next_if_whitespace() else {
+            return Err(condition.span.error("expected whitespace after condition"));
+        };
+
+        let then = parse_nested::<_, VERSION>(condition_whitespace.span.end, tokens)?;
+        let otherwise = parse_nested::<_, VERSION>(condition_whitespace.span.end, tokens)?;
+
+        let Some(closing_bracket) = tokens.next_if_closing_bracket() else {
+            return Err(opening_bracket.error("unclosed bracket"));
+        };
+
+        return Ok(Item::Conditional {
+            opening_bracket,
+            _leading_whitespace: unused(leading_whitespace),
+            _if_kw: unused(name),
+            _whitespace: unused(whitespace),
+            condition,
+            _condition_whitespace: unused(condition_whitespace),
+            then,
+            otherwise,
+            closing_bracket,
+        });
+    }
+
     let mut modifiers = Vec::new();
     let trailing_whitespace = loop {
         let Some(whitespace) = tokens.next_if_whitespace() else {
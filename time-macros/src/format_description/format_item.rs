@@ -1,6 +1,7 @@
 use std::num::NonZero;
 use std::str::{self, FromStr};
 
+use super::public::Condition;
 use super::{ast, unused, Error, Span, Spanned, Unused};
 
 pub(super) fn parse<'a>(
@@ -20,6 +21,12 @@ pub(super) enum Item<'a> {
         value: Box<[Box<[Self]>]>,
         _span: Unused<Span>,
     },
+    Conditional {
+        condition: Condition,
+        then: Box<[Self]>,
+        otherwise: Box<[Self]>,
+        _span: Unused<Span>,
+    },
 }
 
 impl Item<'_> {
@@ -82,6 +89,42 @@ impl Item<'_> {
                     _span: unused(opening_bracket.to(closing_bracket)),
                 }
             }
+            ast::Item::Conditional {
+                opening_bracket,
+                _leading_whitespace: _,
+                _if_kw: _,
+                _whitespace: _,
+                condition,
+                _condition_whitespace: _,
+                then,
+                otherwise,
+                closing_bracket,
+            } => {
+                let condition = match *condition {
+                    b"offset_is_utc" => Condition::OffsetIsUtc,
+                    b"offset_minute_is_zero" => Condition::OffsetMinuteIsZero,
+                    b"offset_second_is_zero" => Condition::OffsetSecondIsZero,
+                    b"second_is_zero" => Condition::SecondIsZero,
+                    b"subsecond_is_zero" => Condition::SubsecondIsZero,
+                    _ => return Err(condition.span.error("invalid condition")),
+                };
+                Item::Conditional {
+                    condition,
+                    then: then
+                        .items
+                        .into_vec()
+                        .into_iter()
+                        .map(Item::from_ast)
+                        .collect::<Result<_, _>>()?,
+                    otherwise: otherwise
+                        .items
+                        .into_vec()
+                        .into_iter()
+                        .map(Item::from_ast)
+                        .collect::<Result<_, _>>()?,
+                    _span: unused(opening_bracket.to(closing_bracket)),
+                }
+            }
         })
     }
 }
@@ -95,6 +138,16 @@ impl From<Item<'_>> for crate::format_description::public::OwnedFormatItem {
             Item::First { value, _span: _ } => {
                 Self::First(value.into_vec().into_iter().map(Into::into).collect())
             }
+            Item::Conditional {
+                condition,
+                then,
+                otherwise,
+                _span: _,
+            } => Self::Conditional {
+                condition,
+                then: Box::new(then.into()),
+                otherwise: Box::new(otherwise.into()),
+            },
         }
     }
 }
@@ -206,22 +259,36 @@ component_definition! {
     pub(super) enum Component {
         Day = "day" {
             padding = "padding": Option<Padding> => padding,
+            width = "width": Option<#[from_str] u8> => width,
         },
         End = "end" {},
+        Era = "era" {
+            repr = "repr": Option<EraRepr> => repr,
+            case_sensitive = "case_sensitive": Option<EraCaseSensitive> => case_sensitive,
+        },
+        ExtensionAnnotations = "extension_annotations" {},
         Hour = "hour" {
             padding = "padding": Option<Padding> => padding,
+            width = "width": Option<#[from_str] u8> => width,
             base = "repr": Option<HourBase> => is_12_hour_clock,
         },
         Ignore = "ignore" {
             #[required]
             count = "count": Option<#[from_str] NonZero<u16>> => count,
         },
+        JulianDay = "julian_day" {
+            repr = "repr": Option<JulianDayRepr> => repr,
+            digits = "digits": Option<JulianDayDigits> => digits,
+        },
         Minute = "minute" {
             padding = "padding": Option<Padding> => padding,
+            width = "width": Option<#[from_str] u8> => width,
         },
         Month = "month" {
             padding = "padding": Option<Padding> => padding,
+            width = "width": Option<#[from_str] u8> => width,
             repr = "repr": Option<MonthRepr> => repr,
+            case = "case": Option<TextCase> => case,
             case_sensitive = "case_sensitive": Option<MonthCaseSensitive> => case_sensitive,
         },
         OffsetHour = "offset_hour" {
@@ -236,17 +303,25 @@ component_definition! {
         },
         Ordinal = "ordinal" {
             padding = "padding": Option<Padding> => padding,
+            width = "width": Option<#[from_str] u8> => width,
         },
         Period = "period" {
             case = "case": Option<PeriodCase> => is_uppercase,
+            repr = "repr": Option<PeriodRepr> => repr,
             case_sensitive = "case_sensitive": Option<PeriodCaseSensitive> => case_sensitive,
         },
+        Quarter = "quarter" {
+            repr = "repr": Option<QuarterRepr> => repr,
+            case_sensitive = "case_sensitive": Option<QuarterCaseSensitive> => case_sensitive,
+        },
         Second = "second" {
             padding = "padding": Option<Padding> => padding,
+            width = "width": Option<#[from_str] u8> => width,
         },
         Subsecond = "subsecond" {
             digits = "digits": Option<SubsecondDigits> => digits,
         },
+        TimeZoneAnnotation = "time_zone_annotation" {},
         UnixTimestamp = "unix_timestamp" {
             precision = "precision": Option<UnixTimestampPrecision> => precision,
             sign_behavior = "sign": Option<SignBehavior> => sign_is_mandatory,
@@ -254,18 +329,30 @@ component_definition! {
         Weekday = "weekday" {
             repr = "repr": Option<WeekdayRepr> => repr,
             one_indexed = "one_indexed": Option<WeekdayOneIndexed> => one_indexed,
+            case = "case": Option<TextCase> => case,
             case_sensitive = "case_sensitive": Option<WeekdayCaseSensitive> => case_sensitive,
         },
         WeekNumber = "week_number" {
             padding = "padding": Option<Padding> => padding,
+            width = "width": Option<#[from_str] u8> => width,
             repr = "repr": Option<WeekNumberRepr> => repr,
         },
+        WeekOfMonth = "week_of_month" {
+            first_weekday = "first_weekday": Option<FirstWeekday> => first_weekday,
+            minimal_days = "minimal_days": Option<MinimalDays> => minimal_days,
+        },
+        WeekdayInMonth = "weekday_in_month" {
+            from_end = "from_end": Option<WeekdayInMonthFromEnd> => from_end,
+        },
         Year = "year" {
             padding = "padding": Option<Padding> => padding,
+            width = "width": Option<#[from_str] u8> => width,
             repr = "repr": Option<YearRepr> => repr,
             range = "range": Option<YearRange> => range,
             base = "base": Option<YearBase> => iso_week_based,
             sign_behavior = "sign": Option<SignBehavior> => sign_is_mandatory,
+            pivot = "pivot": Option<YearPivot> => pivot,
+            era_based = "era_based": Option<YearEraBased> => era_based,
         },
     }
 }
@@ -324,12 +411,73 @@ macro_rules! modifier {
 }
 
 modifier! {
+    enum EraCaseSensitive(bool) {
+        False(false) = b"false",
+        #[default]
+        True(true) = b"true",
+    }
+
+    enum EraRepr {
+        #[default]
+        Short = b"short",
+        Common = b"common",
+        Long = b"long",
+        Narrow = b"narrow",
+    }
+
+    enum FirstWeekday {
+        #[default]
+        Monday = b"monday",
+        Tuesday = b"tuesday",
+        Wednesday = b"wednesday",
+        Thursday = b"thursday",
+        Friday = b"friday",
+        Saturday = b"saturday",
+        Sunday = b"sunday",
+    }
+
     enum HourBase(bool) {
         Twelve(true) = b"12",
         #[default]
         TwentyFour(false) = b"24",
     }
 
+    enum JulianDayDigits(u8) {
+        #[default]
+        Zero(0) = b"0",
+        One(1) = b"1",
+        Two(2) = b"2",
+        Three(3) = b"3",
+        Four(4) = b"4",
+        Five(5) = b"5",
+        Six(6) = b"6",
+        Seven(7) = b"7",
+        Eight(8) = b"8",
+        Nine(9) = b"9",
+        Ten(10) = b"10",
+        Eleven(11) = b"11",
+        Twelve(12) = b"12",
+        Thirteen(13) = b"13",
+        Fourteen(14) = b"14",
+    }
+
+    enum JulianDayRepr {
+        #[default]
+        Julian = b"julian",
+        Modified = b"modified",
+    }
+
+    enum MinimalDays(u8) {
+        #[default]
+        One(1) = b"1",
+        Two(2) = b"2",
+        Three(3) = b"3",
+        Four(4) = b"4",
+        Five(5) = b"5",
+        Six(6) = b"6",
+        Seven(7) = b"7",
+    }
+
     enum MonthCaseSensitive(bool) {
         False(false) = b"false",
         #[default]
@@ -341,6 +489,7 @@ modifier! {
         Numerical = b"numerical",
         Long = b"long",
         Short = b"short",
+        Narrow = b"narrow",
     }
 
     enum Padding {
@@ -362,6 +511,24 @@ modifier! {
         True(true) = b"true",
     }
 
+    enum PeriodRepr {
+        #[default]
+        Short = b"short",
+        Narrow = b"narrow",
+    }
+
+    enum QuarterCaseSensitive(bool) {
+        False(false) = b"false",
+        #[default]
+        True(true) = b"true",
+    }
+
+    enum QuarterRepr {
+        #[default]
+        Numerical = b"numerical",
+        Short = b"short",
+    }
+
     enum SignBehavior(bool) {
         #[default]
         Automatic(false) = b"automatic",
@@ -382,6 +549,13 @@ modifier! {
         OneOrMore = b"1+",
     }
 
+    enum TextCase {
+        #[default]
+        Title = b"title",
+        Upper = b"upper",
+        Lower = b"lower",
+    }
+
     enum UnixTimestampPrecision {
         #[default]
         Second = b"second",
@@ -403,6 +577,12 @@ modifier! {
         True(true) = b"true",
     }
 
+    enum WeekdayInMonthFromEnd(bool) {
+        #[default]
+        False(false) = b"false",
+        True(true) = b"true",
+    }
+
     enum WeekdayOneIndexed(bool) {
         False(false) = b"false",
         #[default]
@@ -413,6 +593,7 @@ modifier! {
         Short = b"short",
         #[default]
         Long = b"long",
+        Narrow = b"narrow",
         Sunday = b"sunday",
         Monday = b"monday",
     }
@@ -423,6 +604,12 @@ modifier! {
         IsoWeek(true) = b"iso_week",
     }
 
+    enum YearEraBased(bool) {
+        #[default]
+        False(false) = b"false",
+        True(true) = b"true",
+    }
+
     enum YearRepr {
         #[default]
         Full = b"full",
@@ -437,6 +624,49 @@ modifier! {
     }
 }
 
+#[derive(Default)]
+enum YearPivot {
+    #[default]
+    None,
+    Fixed(i32),
+    Posix,
+    Sliding,
+}
+
+impl YearPivot {
+    /// The range of years a fixed window can begin with.
+    const FIXED_RANGE: std::ops::RangeInclusive<i32> = 1900..=2152;
+
+    fn from_modifier_value(value: &Spanned<&[u8]>) -> Result<Option<Self>, Error> {
+        if value.eq_ignore_ascii_case(b"none") {
+            return Ok(Some(Self::None));
+        }
+        if value.eq_ignore_ascii_case(b"posix") {
+            return Ok(Some(Self::Posix));
+        }
+        if value.eq_ignore_ascii_case(b"sliding") {
+            return Ok(Some(Self::Sliding));
+        }
+        match parse_from_modifier_value::<i32>(value)? {
+            Some(year) if !Self::FIXED_RANGE.contains(&year) => {
+                Err(value.span.error("invalid modifier value"))
+            }
+            year => Ok(year.map(Self::Fixed)),
+        }
+    }
+}
+
+impl From<YearPivot> for super::public::modifier::YearPivot {
+    fn from(modifier: YearPivot) -> Self {
+        match modifier {
+            YearPivot::None => Self::None,
+            YearPivot::Fixed(year) => Self::Fixed(year),
+            YearPivot::Posix => Self::Posix,
+            YearPivot::Sliding => Self::Sliding,
+        }
+    }
+}
+
 fn parse_from_modifier_value<T: FromStr>(value: &Spanned<&[u8]>) -> Result<Option<T>, Error> {
     str::from_utf8(value)
         .ok()
@@ -35,6 +35,10 @@ declare_component! {
     Ordinal
     Weekday
     WeekNumber
+    WeekOfMonth
+    WeekdayInMonth
+    Quarter
+    Era
     Year
     Hour
     Minute
@@ -46,5 +50,8 @@ declare_component! {
     OffsetSecond
     Ignore
     UnixTimestamp
+    JulianDay
     End
+    TimeZoneAnnotation
+    ExtensionAnnotations
 }
@@ -1,7 +1,7 @@
 mod component;
 pub(super) mod modifier;
 
-use proc_macro::{Literal, TokenStream};
+use proc_macro::{Ident, Literal, Span, TokenStream};
 
 pub(crate) use self::component::Component;
 use crate::to_tokens::ToTokenStream;
@@ -12,6 +12,36 @@ pub(crate) enum OwnedFormatItem {
     Compound(Box<[Self]>),
     Optional(Box<Self>),
     First(Box<[Self]>),
+    Conditional {
+        condition: Condition,
+        then: Box<Self>,
+        otherwise: Box<Self>,
+    },
+}
+
+pub(crate) enum Condition {
+    OffsetIsUtc,
+    OffsetMinuteIsZero,
+    OffsetSecondIsZero,
+    SecondIsZero,
+    SubsecondIsZero,
+}
+
+impl ToTokenStream for Condition {
+    fn append_to(self, ts: &mut TokenStream) {
+        let condition = match self {
+            Self::OffsetIsUtc => "OffsetIsUtc",
+            Self::OffsetMinuteIsZero => "OffsetMinuteIsZero",
+            Self::OffsetSecondIsZero => "OffsetSecondIsZero",
+            Self::SecondIsZero => "SecondIsZero",
+            Self::SubsecondIsZero => "SubsecondIsZero",
+        };
+        let condition = Ident::new(condition, Span::mixed_site());
+
+        quote_append! { ts
+            ::time::format_description::Condition::#(condition)
+        }
+    }
 }
 
 impl ToTokenStream for OwnedFormatItem {
@@ -48,6 +78,17 @@ impl ToTokenStream for OwnedFormatItem {
                     ::time::format_description::BorrowedFormatItem::First { 0: &[#S(items)] }
                 }
             }
+            Self::Conditional {
+                condition,
+                then,
+                otherwise,
+            } => quote_append! { ts
+                ::time::format_description::BorrowedFormatItem::Conditional {
+                    condition: #S(condition),
+                    then: &#S(*then),
+                    otherwise: &#S(*otherwise),
+                }
+            },
         }
     }
 }
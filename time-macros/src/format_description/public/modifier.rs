@@ -71,6 +71,7 @@ macro_rules! to_tokens {
 to_tokens! {
     pub(crate) struct Day {
         pub(crate) padding: Padding,
+        pub(crate) width: u8,
     }
 }
 
@@ -79,13 +80,24 @@ to_tokens! {
         Numerical,
         Long,
         Short,
+        Narrow,
+    }
+}
+
+to_tokens! {
+    pub(crate) enum TextCase {
+        Title,
+        Upper,
+        Lower,
     }
 }
 
 to_tokens! {
     pub(crate) struct Month {
         pub(crate) padding: Padding,
+        pub(crate) width: u8,
         pub(crate) repr: MonthRepr,
+        pub(crate) case: TextCase,
         pub(crate) case_sensitive: bool,
     }
 }
@@ -93,6 +105,7 @@ to_tokens! {
 to_tokens! {
     pub(crate) struct Ordinal {
         pub(crate) padding: Padding,
+        pub(crate) width: u8,
     }
 }
 
@@ -100,6 +113,7 @@ to_tokens! {
     pub(crate) enum WeekdayRepr {
         Short,
         Long,
+        Narrow,
         Sunday,
         Monday,
     }
@@ -109,6 +123,7 @@ to_tokens! {
     pub(crate) struct Weekday {
         pub(crate) repr: WeekdayRepr,
         pub(crate) one_indexed: bool,
+        pub(crate) case: TextCase,
         pub(crate) case_sensitive: bool,
     }
 }
@@ -124,10 +139,83 @@ to_tokens! {
 to_tokens! {
     pub(crate) struct WeekNumber {
         pub(crate) padding: Padding,
+        pub(crate) width: u8,
         pub(crate) repr: WeekNumberRepr,
     }
 }
 
+/// A day of the week, which is not a modifier but is used as the value of one.
+pub(crate) enum FirstWeekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl ToTokenStream for FirstWeekday {
+    fn append_to(self, ts: &mut TokenStream) {
+        quote_append! { ts
+            ::time::Weekday::
+        };
+        let name = match self {
+            Self::Monday => "Monday",
+            Self::Tuesday => "Tuesday",
+            Self::Wednesday => "Wednesday",
+            Self::Thursday => "Thursday",
+            Self::Friday => "Friday",
+            Self::Saturday => "Saturday",
+            Self::Sunday => "Sunday",
+        };
+        ts.extend([TokenTree::Ident(Ident::new(name, Span::mixed_site()))]);
+    }
+}
+
+to_tokens! {
+    pub(crate) struct WeekOfMonth {
+        pub(crate) first_weekday: FirstWeekday,
+        pub(crate) minimal_days: u8,
+    }
+}
+
+to_tokens! {
+    pub(crate) struct WeekdayInMonth {
+        pub(crate) from_end: bool,
+    }
+}
+
+to_tokens! {
+    pub(crate) enum QuarterRepr {
+        Numerical,
+        Short,
+    }
+}
+
+to_tokens! {
+    pub(crate) struct Quarter {
+        pub(crate) repr: QuarterRepr,
+        pub(crate) case_sensitive: bool,
+    }
+}
+
+to_tokens! {
+    pub(crate) enum EraRepr {
+        Short,
+        Common,
+        Long,
+        Narrow,
+    }
+}
+
+to_tokens! {
+    pub(crate) struct Era {
+        pub(crate) repr: EraRepr,
+        pub(crate) case_sensitive: bool,
+    }
+}
+
 to_tokens! {
     pub(crate) enum YearRepr {
         Full,
@@ -143,19 +231,44 @@ to_tokens! {
     }
 }
 
+pub(crate) enum YearPivot {
+    None,
+    Fixed(i32),
+    Posix,
+    Sliding,
+}
+
+impl ToTokenStream for YearPivot {
+    fn append_to(self, ts: &mut TokenStream) {
+        quote_append! { ts
+            ::time::format_description::modifier::YearPivot::
+        };
+        match self {
+            Self::None => quote_append!(ts NONE),
+            Self::Fixed(year) => quote_append!(ts __fixed(#(year))),
+            Self::Posix => quote_append!(ts POSIX),
+            Self::Sliding => quote_append!(ts SLIDING),
+        }
+    }
+}
+
 to_tokens! {
     pub(crate) struct Year {
         pub(crate) padding: Padding,
+        pub(crate) width: u8,
         pub(crate) repr: YearRepr,
         pub(crate) range: YearRange,
         pub(crate) iso_week_based: bool,
         pub(crate) sign_is_mandatory: bool,
+        pub(crate) pivot: YearPivot,
+        pub(crate) era_based: bool,
     }
 }
 
 to_tokens! {
     pub(crate) struct Hour {
         pub(crate) padding: Padding,
+        pub(crate) width: u8,
         pub(crate) is_12_hour_clock: bool,
     }
 }
@@ -163,12 +276,21 @@ to_tokens! {
 to_tokens! {
     pub(crate) struct Minute {
         pub(crate) padding: Padding,
+        pub(crate) width: u8,
+    }
+}
+
+to_tokens! {
+    pub(crate) enum PeriodRepr {
+        Short,
+        Narrow,
     }
 }
 
 to_tokens! {
     pub(crate) struct Period {
         pub(crate) is_uppercase: bool,
+        pub(crate) repr: PeriodRepr,
         pub(crate) case_sensitive: bool,
     }
 }
@@ -176,6 +298,7 @@ to_tokens! {
 to_tokens! {
     pub(crate) struct Second {
         pub(crate) padding: Padding,
+        pub(crate) width: u8,
     }
 }
 
@@ -255,6 +378,28 @@ to_tokens! {
     }
 }
 
+to_tokens! {
+    pub(crate) enum JulianDayRepr {
+        Julian,
+        Modified,
+    }
+}
+
+to_tokens! {
+    pub(crate) struct JulianDay {
+        pub(crate) repr: JulianDayRepr,
+        pub(crate) digits: u8,
+    }
+}
+
 to_tokens! {
     pub(crate) struct End {}
 }
+
+to_tokens! {
+    pub(crate) struct TimeZoneAnnotation {}
+}
+
+to_tokens! {
+    pub(crate) struct ExtensionAnnotations {}
+}
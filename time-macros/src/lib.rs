@@ -160,6 +160,44 @@ fn parse_format_description_version<const NO_EQUALS_IS_MOD_NAME: bool>(
     Ok(Some(version))
 }
 
+#[cfg(any(feature = "formatting", feature = "parsing"))]
+fn parse_round_trip_target(iter: &mut PeekableTokenStreamIter) -> Result<Option<TokenTree>, Error> {
+    const TARGETS: [&str; 6] = [
+        "Date",
+        "Time",
+        "UtcOffset",
+        "PrimitiveDateTime",
+        "OffsetDateTime",
+        "UtcDateTime",
+    ];
+
+    match iter.peek() {
+        Some(TokenTree::Ident(ident)) if ident.to_string() == "round_trip" => {
+            iter.next(); // consume `round_trip`
+        }
+        _ => return Ok(None),
+    }
+    helpers::consume_punct('=', iter)?;
+    let target = match iter.next() {
+        Some(TokenTree::Ident(ident)) if TARGETS.contains(&ident.to_string().as_str()) => {
+            TokenTree::Ident(ident)
+        }
+        Some(token) => {
+            return Err(Error::Custom {
+                message: "expected `Date`, `Time`, `UtcOffset`, `PrimitiveDateTime`, \
+                          `OffsetDateTime`, or `UtcDateTime`"
+                    .into(),
+                span_start: Some(token.span()),
+                span_end: Some(token.span()),
+            });
+        }
+        None => return Err(Error::UnexpectedEndOfInput),
+    };
+    helpers::consume_punct(',', iter)?;
+
+    Ok(Some(target))
+}
+
 #[cfg(all(feature = "serde", any(feature = "formatting", feature = "parsing")))]
 fn parse_visibility(iter: &mut PeekableTokenStreamIter) -> Result<TokenStream, Error> {
     let mut visibility = match iter.peek().ok_or(Error::UnexpectedEndOfInput)? {
@@ -188,18 +226,33 @@ pub fn format_description(input: TokenStream) -> TokenStream {
     (|| {
         let mut input = input.into_iter().peekable();
         let version = parse_format_description_version::<false>(&mut input)?;
+        let round_trip = parse_round_trip_target(&mut input)?;
         let (span, string) = helpers::get_string_literal(input)?;
         let items = format_description::parse_with_version(version, &string, span)?;
+        let items = items
+            .into_iter()
+            .map(|item| quote! { #S(item), })
+            .collect::<TokenStream>();
 
-        Ok(quote! {
-            const {
-                &[#S(
-                    items
-                        .into_iter()
-                        .map(|item| quote! { #S(item), })
-                        .collect::<TokenStream>()
-                )] as &[::time::format_description::BorrowedFormatItem]
-            }
+        Ok(match round_trip {
+            None => quote! {
+                const { &[#S(items)] as &[::time::format_description::BorrowedFormatItem] }
+            },
+            Some(target) => quote! {
+                const {
+                    const ITEMS: &[::time::format_description::BorrowedFormatItem<'_>] =
+                        &[#S(items)];
+                    if let ::core::result::Result::Err(issue) =
+                        ::time::format_description::analysis::analyze(
+                            ITEMS,
+                            ::time::format_description::analysis::Target::#(target),
+                        )
+                    {
+                        ::core::panic!("{}", issue.message());
+                    }
+                    ITEMS
+                }
+            },
         })
     })()
     .unwrap_or_else(|err: Error| err.to_compile_error())
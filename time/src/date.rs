@@ -16,8 +16,8 @@ use powerfmt::smart_display::{self, FormatterOptions, Metadata, SmartDisplay};
 
 use crate::convert::*;
 use crate::ext::DigitCount;
-#[cfg(feature = "formatting")]
-use crate::formatting::Formattable;
+#[cfg(feature = "formatting-core")]
+use crate::formatting::{self, FmtOutput, Formattable, SliceOutput};
 use crate::internal_macros::{
     const_try, const_try_opt, div_floor, ensure_ranged, expect_opt, impl_add_assign,
     impl_sub_assign,
@@ -25,7 +25,7 @@ use crate::internal_macros::{
 #[cfg(feature = "parsing")]
 use crate::parsing::Parsable;
 use crate::util::{days_in_year, is_leap_year, weeks_in_year};
-use crate::{error, Duration, Month, PrimitiveDateTime, Time, Weekday};
+use crate::{error, Duration, Month, PrimitiveDateTime, Quarter, SerialDateSystem, Time, Weekday};
 
 type Year = RangedI32<MIN_YEAR, MAX_YEAR>;
 
@@ -41,6 +41,14 @@ pub(crate) const MAX_YEAR: i32 = if cfg!(feature = "large-dates") {
 } else {
     9999
 };
+/// The Julian day of the modified Julian day zero (1858-11-17).
+pub(crate) const MODIFIED_JULIAN_DAY_OFFSET: i32 = 2_400_001;
+
+/// The Julian day of 1899-12-30, which is serial number 0 for OLE Automation dates.
+const SERIAL_DATE_OFFSET: i32 = 2_415_019;
+
+/// The number of days from 1899-12-30 to 1904-01-01, the epoch of the 1904 date system.
+const EXCEL_1904_OFFSET: i32 = 1_462;
 
 /// Date in the proleptic Gregorian calendar.
 ///
@@ -256,6 +264,59 @@ impl Date {
         })
     }
 
+    /// Attempt to create a `Date` from the year, quarter, and day of the quarter.
+    ///
+    /// ```rust
+    /// # use time::{Date, Quarter};
+    /// # use time_macros::date;
+    /// assert_eq!(
+    ///     Date::from_quarter_date(2024, Quarter::Third, 1),
+    ///     Ok(date!(2024-07-01))
+    /// );
+    /// assert_eq!(
+    ///     Date::from_quarter_date(2024, Quarter::First, 91),
+    ///     Ok(date!(2024-03-31))
+    /// );
+    /// ```
+    ///
+    /// ```rust
+    /// # use time::{Date, Quarter};
+    /// assert!(Date::from_quarter_date(2019, Quarter::First, 91).is_err()); // 2019 isn't a leap year.
+    /// ```
+    pub const fn from_quarter_date(
+        year: i32,
+        quarter: Quarter,
+        day: u8,
+    ) -> Result<Self, error::ComponentRange> {
+        ensure_ranged!(Year: year);
+        if day == 0 || day > quarter.length(year) {
+            return Err(error::ComponentRange {
+                name: "day",
+                minimum: 1,
+                maximum: quarter.length(year) as i64,
+                value: day as i64,
+                conditional_message: Some("for the given quarter and year"),
+            });
+        }
+
+        let days_in_preceding_quarters =
+            match quarter {
+                Quarter::First => 0,
+                Quarter::Second => 90,
+                Quarter::Third => 181,
+                Quarter::Fourth => 273,
+            } + if is_leap_year(year) && !matches!(quarter, Quarter::First) {
+                1
+            } else {
+                0
+            };
+
+        // Safety: `day` is not zero.
+        Ok(unsafe {
+            Self::__from_ordinal_date_unchecked(year, days_in_preceding_quarters + day as u16)
+        })
+    }
+
     /// Create a `Date` from the Julian day.
     ///
     /// The algorithm to perform this conversion is derived from one provided by Peter Baum; it is
@@ -277,6 +338,97 @@ impl Date {
         Ok(unsafe { Self::from_julian_day_unchecked(julian_day) })
     }
 
+    /// Create a `Date` from the modified Julian day, which is the number of days since 1858-11-17.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// # use time_macros::date;
+    /// assert_eq!(Date::from_modified_julian_day(0), Ok(date!(1858-11-17)));
+    /// assert_eq!(
+    ///     Date::from_modified_julian_day(51_544),
+    ///     Ok(date!(2000-01-01))
+    /// );
+    /// assert_eq!(Date::from_modified_julian_day(-1), Ok(date!(1858-11-16)));
+    /// ```
+    pub const fn from_modified_julian_day(
+        modified_julian_day: i32,
+    ) -> Result<Self, error::ComponentRange> {
+        type ModifiedJulianDay = RangedI32<
+            { Date::MIN.to_modified_julian_day() },
+            { Date::MAX.to_modified_julian_day() },
+        >;
+        ensure_ranged!(ModifiedJulianDay: modified_julian_day);
+        // Safety: The modified Julian day is in range, so the Julian day is as well.
+        Ok(unsafe {
+            Self::from_julian_day_unchecked(modified_julian_day + MODIFIED_JULIAN_DAY_OFFSET)
+        })
+    }
+
+    /// Create a `Date` from its serial number in the provided [`SerialDateSystem`], as used by
+    /// spreadsheet applications.
+    ///
+    /// In the 1900 date system, serial number 60 refers to 1900-02-29, which does not exist. An
+    /// error is returned for it.
+    ///
+    /// ```rust
+    /// # use time::{Date, SerialDateSystem};
+    /// # use time_macros::date;
+    /// assert_eq!(
+    ///     Date::from_serial_date(1, SerialDateSystem::Excel1900),
+    ///     Ok(date!(1900-01-01))
+    /// );
+    /// assert_eq!(
+    ///     Date::from_serial_date(61, SerialDateSystem::Excel1900),
+    ///     Ok(date!(1900-03-01))
+    /// );
+    /// assert!(Date::from_serial_date(60, SerialDateSystem::Excel1900).is_err());
+    /// assert_eq!(
+    ///     Date::from_serial_date(0, SerialDateSystem::Excel1904),
+    ///     Ok(date!(1904-01-01))
+    /// );
+    /// assert_eq!(
+    ///     Date::from_serial_date(-1, SerialDateSystem::OleAutomation),
+    ///     Ok(date!(1899-12-29))
+    /// );
+    /// ```
+    pub const fn from_serial_date(
+        serial: i32,
+        system: SerialDateSystem,
+    ) -> Result<Self, error::ComponentRange> {
+        let days = match system {
+            SerialDateSystem::Excel1900 if serial == 60 => {
+                return Err(error::ComponentRange {
+                    name: "day",
+                    minimum: 1,
+                    maximum: 28,
+                    value: 29,
+                    conditional_message: Some("for the given month and year"),
+                });
+            }
+            // Serial numbers before the nonexistent 1900-02-29 are offset by one day.
+            SerialDateSystem::Excel1900 if serial < 60 => serial as i64 + 1,
+            SerialDateSystem::Excel1900 | SerialDateSystem::OleAutomation => serial as i64,
+            SerialDateSystem::Excel1904 => serial as i64 + EXCEL_1904_OFFSET as i64,
+        };
+
+        let julian_day = SERIAL_DATE_OFFSET as i64 + days;
+        if julian_day < Self::MIN.to_julian_day() as i64
+            || julian_day > Self::MAX.to_julian_day() as i64
+        {
+            crate::hint::cold_path();
+            return Err(error::ComponentRange {
+                name: "serial",
+                minimum: Self::MIN.to_serial_date(system) as i64,
+                maximum: Self::MAX.to_serial_date(system) as i64,
+                value: serial as i64,
+                conditional_message: None,
+            });
+        }
+
+        // Safety: The Julian day was checked to be in range.
+        Ok(unsafe { Self::from_julian_day_unchecked(julian_day as i32) })
+    }
+
     /// Create a `Date` from the Julian day.
     ///
     /// # Safety
@@ -394,6 +546,19 @@ impl Date {
         (ordinal - days_in_preceding_months) as u8
     }
 
+    /// Get the quarter of the year.
+    ///
+    /// ```rust
+    /// # use time::Quarter;
+    /// # use time_macros::date;
+    /// assert_eq!(date!(2019-01-01).quarter(), Quarter::First);
+    /// assert_eq!(date!(2019-08-15).quarter(), Quarter::Third);
+    /// assert_eq!(date!(2019-12-31).quarter(), Quarter::Fourth);
+    /// ```
+    pub const fn quarter(self) -> Quarter {
+        self.month().quarter()
+    }
+
     /// Get the day of the year.
     ///
     /// The returned value will always be in the range `1..=366` (`1..=365` for common years).
@@ -464,6 +629,68 @@ impl Date {
         ((self.ordinal() as i16 - self.weekday().number_days_from_monday() as i16 + 6) / 7) as u8
     }
 
+    /// Get the week of the month, where each week begins on `first_weekday`. Week 1 is the first
+    /// week that contains at least `minimal_days` days of the month, and any days before it are in
+    /// week 0. A `minimal_days` of zero is treated as one, and values greater than seven are
+    /// treated as seven.
+    ///
+    /// The returned value will always be in the range `0..=6`.
+    ///
+    /// ```rust
+    /// # use time::Weekday;
+    /// # use time_macros::date;
+    /// // March 2024 begins on a Friday.
+    /// assert_eq!(date!(2024-03-01).week_of_month(Weekday::Monday, 1), 1);
+    /// assert_eq!(date!(2024-03-04).week_of_month(Weekday::Monday, 1), 2);
+    /// assert_eq!(date!(2024-03-01).week_of_month(Weekday::Monday, 4), 0);
+    /// assert_eq!(date!(2024-03-31).week_of_month(Weekday::Sunday, 1), 6);
+    /// ```
+    pub const fn week_of_month(self, first_weekday: Weekday, minimal_days: u8) -> u8 {
+        let day = self.day();
+        // The number of days from the start of the week to the first of the month.
+        let offset = (self.weekday().number_days_from_monday() + 14
+            - (day - 1) % 7
+            - first_weekday.number_days_from_monday())
+            % 7;
+        let minimal_days = if minimal_days > 7 { 7 } else { minimal_days };
+        let week = (day - 1 + offset) / 7;
+        if 7 - offset >= minimal_days {
+            week + 1
+        } else {
+            week
+        }
+    }
+
+    /// Get the occurrence of the day of the week within the month, such as 2 for the second
+    /// Tuesday of the month.
+    ///
+    /// The returned value will always be in the range `1..=5`.
+    ///
+    /// ```rust
+    /// # use time_macros::date;
+    /// assert_eq!(date!(2024-03-01).weekday_in_month(), 1);
+    /// assert_eq!(date!(2024-03-08).weekday_in_month(), 2);
+    /// assert_eq!(date!(2024-03-31).weekday_in_month(), 5);
+    /// ```
+    pub const fn weekday_in_month(self) -> u8 {
+        (self.day() - 1) / 7 + 1
+    }
+
+    /// Get the occurrence of the day of the week within the month, counted from the end of the
+    /// month. The last occurrence is 1, the second-to-last is 2, and so on.
+    ///
+    /// The returned value will always be in the range `1..=5`.
+    ///
+    /// ```rust
+    /// # use time_macros::date;
+    /// assert_eq!(date!(2024-03-31).weekday_in_month_from_end(), 1);
+    /// assert_eq!(date!(2024-03-24).weekday_in_month_from_end(), 2);
+    /// assert_eq!(date!(2024-03-01).weekday_in_month_from_end(), 5);
+    /// ```
+    pub const fn weekday_in_month_from_end(self) -> u8 {
+        (self.month().length(self.year()) - self.day()) / 7 + 1
+    }
+
     /// Get the year, month, and day.
     ///
     /// ```rust
@@ -514,6 +741,27 @@ impl Date {
         (self.year(), self.ordinal())
     }
 
+    /// Get the year, quarter, and day of the quarter.
+    ///
+    /// ```rust
+    /// # use time::Quarter;
+    /// # use time_macros::date;
+    /// assert_eq!(
+    ///     date!(2019-08-15).to_quarter_date(),
+    ///     (2019, Quarter::Third, 46)
+    /// );
+    /// ```
+    pub const fn to_quarter_date(self) -> (i32, Quarter, u8) {
+        let quarter = self.quarter();
+        let (year, month, day) = self.to_calendar_date();
+        let days_in_preceding_months = match month as u8 - quarter.first_month() as u8 {
+            0 => 0,
+            1 => quarter.first_month().length(year),
+            _ => quarter.first_month().length(year) + month.previous().length(year),
+        };
+        (year, quarter, days_in_preceding_months + day)
+    }
+
     /// Get the ISO 8601 year, week number, and weekday.
     ///
     /// ```rust
@@ -742,6 +990,51 @@ impl Date {
         days_before_year + ordinal as i32 - 363_521_075
     }
 
+    /// Get the modified Julian day for the date, which is the number of days since 1858-11-17.
+    ///
+    /// ```rust
+    /// # use time_macros::date;
+    /// assert_eq!(date!(1858-11-17).to_modified_julian_day(), 0);
+    /// assert_eq!(date!(2000-01-01).to_modified_julian_day(), 51_544);
+    /// assert_eq!(date!(1858-11-16).to_modified_julian_day(), -1);
+    /// ```
+    pub const fn to_modified_julian_day(self) -> i32 {
+        self.to_julian_day() - MODIFIED_JULIAN_DAY_OFFSET
+    }
+
+    /// Get the serial number of the date in the provided [`SerialDateSystem`], as used by
+    /// spreadsheet applications.
+    ///
+    /// ```rust
+    /// # use time::SerialDateSystem;
+    /// # use time_macros::date;
+    /// assert_eq!(
+    ///     date!(1900-01-01).to_serial_date(SerialDateSystem::Excel1900),
+    ///     1
+    /// );
+    /// assert_eq!(
+    ///     date!(1900-03-01).to_serial_date(SerialDateSystem::Excel1900),
+    ///     61
+    /// );
+    /// assert_eq!(
+    ///     date!(1900-03-01).to_serial_date(SerialDateSystem::OleAutomation),
+    ///     61
+    /// );
+    /// assert_eq!(
+    ///     date!(2024-01-01).to_serial_date(SerialDateSystem::Excel1904),
+    ///     43_830
+    /// );
+    /// ```
+    pub const fn to_serial_date(self, system: SerialDateSystem) -> i32 {
+        let days = self.to_julian_day() - SERIAL_DATE_OFFSET;
+        match system {
+            // Serial numbers before the nonexistent 1900-02-29 are offset by one day.
+            SerialDateSystem::Excel1900 if days < 61 => days - 1,
+            SerialDateSystem::Excel1900 | SerialDateSystem::OleAutomation => days,
+            SerialDateSystem::Excel1904 => days - EXCEL_1904_OFFSET,
+        }
+    }
+
     /// Computes `self + duration`, returning `None` if an overflow occurred.
     ///
     /// ```rust
@@ -1292,15 +1585,16 @@ impl Date {
     }
 }
 
-#[cfg(feature = "formatting")]
+#[cfg(feature = "formatting-core")]
 impl Date {
     /// Format the `Date` using the provided [format description](crate::format_description).
+    #[cfg(feature = "formatting")]
     pub fn format_into(
         self,
         output: &mut (impl io::Write + ?Sized),
         format: &(impl Formattable + ?Sized),
     ) -> Result<usize, error::Format> {
-        format.format_into(output, Some(self), None, None)
+        formatting::format_value_into_io(self, output, format)
     }
 
     /// Format the `Date` using the provided [format description](crate::format_description).
@@ -1312,8 +1606,54 @@ impl Date {
     /// assert_eq!(date!(2020-01-02).format(&format)?, "2020-01-02");
     /// # Ok::<_, time::Error>(())
     /// ```
+    #[cfg(feature = "formatting")]
     pub fn format(self, format: &(impl Formattable + ?Sized)) -> Result<String, error::Format> {
-        format.format(Some(self), None, None)
+        formatting::format_value_to_string(self, format)
+    }
+
+    /// Format the `Date` using the provided [format description](crate::format_description),
+    /// writing the output to a [`fmt::Write`]. This does not require `std` or `alloc`.
+    ///
+    /// As `fmt::Write` only accepts strings, an error is returned if the format description
+    /// contains a literal that is not valid UTF-8.
+    ///
+    /// ```rust
+    /// # use time_macros::{date, format_description};
+    /// let format = format_description!("[year]-[month]-[day]");
+    /// let mut output = String::new();
+    /// date!(2020-01-02).format_into_fmt(&mut output, format)?;
+    /// assert_eq!(output, "2020-01-02");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_fmt(
+        self,
+        output: &mut (impl fmt::Write + ?Sized),
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::FormatCore> {
+        formatting::format_value(self, &mut FmtOutput(output), format)
+    }
+
+    /// Format the `Date` using the provided [format description](crate::format_description),
+    /// writing the output to the start of the provided buffer. The number of bytes written is
+    /// returned. This does not require `std` or `alloc`.
+    ///
+    /// If the buffer is too small, [`error::FormatCore::InsufficientBufferSize`] is returned. The
+    /// contents of the buffer are unspecified in this situation.
+    ///
+    /// ```rust
+    /// # use time_macros::{date, format_description};
+    /// let format = format_description!("[year]-[month]-[day]");
+    /// let mut buf = [0; 64];
+    /// let len = date!(2020-01-02).format_to_slice(&mut buf, format)?;
+    /// assert_eq!(&buf[..len], b"2020-01-02");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_to_slice(
+        self,
+        output: &mut [u8],
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::FormatCore> {
+        formatting::format_value(self, &mut SliceOutput::new(output), format)
     }
 }
 
@@ -1335,6 +1675,53 @@ impl Date {
     ) -> Result<Self, error::Parse> {
         description.parse_date(input.as_bytes())
     }
+
+    /// Parse a `Date` from the start of the input using the provided [format
+    /// description](crate::format_description), returning it along with the remaining input.
+    ///
+    /// Unlike [`Date::parse`], it is not an error for input to remain after parsing. This is
+    /// useful when the value is followed by other data, such as in a log line.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// # use time_macros::{date, format_description};
+    /// let format = format_description!("[year]-[month]-[day]");
+    /// assert_eq!(
+    ///     Date::parse_prefix(b"2020-01-02,42", &format)?,
+    ///     (date!(2020-01-02), b",42".as_slice())
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_prefix<'a>(
+        input: &'a [u8],
+        description: &(impl Parsable + ?Sized),
+    ) -> Result<(Self, &'a [u8]), error::Parse> {
+        let (parsed, remaining) = description.parse_prefix(input)?;
+        Ok((parsed.try_into()?, remaining))
+    }
+
+    /// Parse a `Date` from the input using the provided [format
+    /// description](crate::format_description), returning a diagnostic that describes where and why
+    /// parsing failed if it does.
+    ///
+    /// See [`Parsed::parse_with_diagnostic`](crate::parsing::Parsed::parse_with_diagnostic) for
+    /// details.
+    ///
+    /// ```rust
+    /// # use time::Date;
+    /// # use time_macros::format_description;
+    /// let format = format_description!("[year]-[month]-[day]");
+    /// let err = Date::parse_with_diagnostic("2020-13-02", &format).unwrap_err();
+    /// assert_eq!(err.span(), Some(5..7));
+    /// ```
+    pub fn parse_with_diagnostic<'a>(
+        input: &str,
+        description: &'a (impl Parsable + ?Sized),
+    ) -> Result<Self, error::ParseDiagnostic<'a>> {
+        Ok(description
+            .parse_with_diagnostic(input.as_bytes())?
+            .try_into()?)
+    }
 }
 
 mod private {
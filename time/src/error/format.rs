@@ -21,6 +21,11 @@ pub enum Format {
     ComponentRange(Box<error::ComponentRange>),
     /// A value of `std::io::Error` was returned internally.
     StdIo(io::Error),
+    /// A value of `core::fmt::Error` was returned internally, or the output was not valid UTF-8
+    /// when formatting to a [`fmt::Write`].
+    Fmt(fmt::Error),
+    /// The provided buffer is too small to contain the formatted value.
+    InsufficientBufferSize,
 }
 
 impl fmt::Display for Format {
@@ -36,6 +41,10 @@ impl fmt::Display for Format {
             ),
             Self::ComponentRange(err) => err.fmt(f),
             Self::StdIo(err) => err.fmt(f),
+            Self::Fmt(err) => err.fmt(f),
+            Self::InsufficientBufferSize => {
+                f.write_str("The provided buffer is too small to contain the formatted value.")
+            }
         }
     }
 }
@@ -52,6 +61,24 @@ impl From<io::Error> for Format {
     }
 }
 
+impl From<fmt::Error> for Format {
+    fn from(err: fmt::Error) -> Self {
+        Self::Fmt(err)
+    }
+}
+
+impl From<error::FormatCore> for Format {
+    fn from(err: error::FormatCore) -> Self {
+        match err {
+            error::FormatCore::InsufficientTypeInformation => Self::InsufficientTypeInformation,
+            error::FormatCore::InvalidComponent(component) => Self::InvalidComponent(component),
+            error::FormatCore::ComponentRange(err) => Self::ComponentRange(Box::new(err)),
+            error::FormatCore::Fmt(err) => Self::Fmt(err),
+            error::FormatCore::InsufficientBufferSize => Self::InsufficientBufferSize,
+        }
+    }
+}
+
 impl TryFrom<Format> for error::ComponentRange {
     type Error = error::DifferentVariant;
 
@@ -77,9 +104,12 @@ impl TryFrom<Format> for io::Error {
 impl core::error::Error for Format {
     fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
         match self {
-            Self::InsufficientTypeInformation | Self::InvalidComponent(_) => None,
+            Self::InsufficientTypeInformation
+            | Self::InvalidComponent(_)
+            | Self::InsufficientBufferSize => None,
             Self::ComponentRange(err) => Some(&**err),
             Self::StdIo(err) => Some(err),
+            Self::Fmt(err) => Some(err),
         }
     }
 }
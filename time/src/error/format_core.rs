@@ -0,0 +1,105 @@
+//! Error formatting a struct without `std`
+
+use core::fmt;
+
+use crate::error;
+
+/// An error occurred when formatting to an output that does not require `std` or `alloc`.
+///
+/// This is returned by methods such as [`Date::format_into_fmt`](crate::Date::format_into_fmt)
+/// and [`Date::format_to_slice`](crate::Date::format_to_slice). When the `formatting` feature is
+/// enabled, it can be converted into [`Format`](error::Format).
+#[non_exhaustive]
+#[expect(
+    variant_size_differences,
+    reason = "the type is only constructed when an error occurs"
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormatCore {
+    /// The type being formatted does not contain sufficient information to format a component.
+    #[non_exhaustive]
+    InsufficientTypeInformation,
+    /// The component named has a value that cannot be formatted into the requested format.
+    ///
+    /// This variant is only returned when using well-known formats.
+    InvalidComponent(&'static str),
+    /// A component provided was out of range.
+    ComponentRange(error::ComponentRange),
+    /// A value of `core::fmt::Error` was returned internally, or the output was not valid UTF-8
+    /// when formatting to a [`fmt::Write`].
+    Fmt(fmt::Error),
+    /// The provided buffer is too small to contain the formatted value.
+    InsufficientBufferSize,
+}
+
+impl fmt::Display for FormatCore {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InsufficientTypeInformation => f.write_str(
+                "The type being formatted does not contain sufficient information to format a \
+                 component.",
+            ),
+            Self::InvalidComponent(component) => write!(
+                f,
+                "The {component} component cannot be formatted into the requested format."
+            ),
+            Self::ComponentRange(err) => err.fmt(f),
+            Self::Fmt(err) => err.fmt(f),
+            Self::InsufficientBufferSize => {
+                f.write_str("The provided buffer is too small to contain the formatted value.")
+            }
+        }
+    }
+}
+
+impl From<error::ComponentRange> for FormatCore {
+    fn from(err: error::ComponentRange) -> Self {
+        Self::ComponentRange(err)
+    }
+}
+
+impl From<fmt::Error> for FormatCore {
+    fn from(err: fmt::Error) -> Self {
+        Self::Fmt(err)
+    }
+}
+
+impl TryFrom<FormatCore> for error::ComponentRange {
+    type Error = error::DifferentVariant;
+
+    fn try_from(err: FormatCore) -> Result<Self, Self::Error> {
+        match err {
+            FormatCore::ComponentRange(err) => Ok(err),
+            _ => Err(error::DifferentVariant),
+        }
+    }
+}
+
+impl core::error::Error for FormatCore {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::InsufficientTypeInformation
+            | Self::InvalidComponent(_)
+            | Self::InsufficientBufferSize => None,
+            Self::ComponentRange(err) => Some(err),
+            Self::Fmt(err) => Some(err),
+        }
+    }
+}
+
+impl From<FormatCore> for crate::Error {
+    fn from(original: FormatCore) -> Self {
+        Self::FormatCore(original)
+    }
+}
+
+impl TryFrom<crate::Error> for FormatCore {
+    type Error = error::DifferentVariant;
+
+    fn try_from(err: crate::Error) -> Result<Self, Self::Error> {
+        match err {
+            crate::Error::FormatCore(err) => Ok(err),
+            _ => Err(error::DifferentVariant),
+        }
+    }
+}
@@ -5,13 +5,22 @@ mod conversion_range;
 mod different_variant;
 #[cfg(feature = "formatting")]
 mod format;
+#[cfg(feature = "formatting-core")]
+mod format_core;
 #[cfg(feature = "local-offset")]
 mod indeterminate_offset;
-#[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
+#[cfg(all(
+    any(feature = "formatting-core", feature = "parsing"),
+    feature = "alloc"
+))]
 mod invalid_format_description;
 mod invalid_variant;
 #[cfg(feature = "parsing")]
 mod parse;
+#[cfg(all(feature = "parsing", feature = "alloc"))]
+mod parse_any;
+#[cfg(feature = "parsing")]
+mod parse_diagnostic;
 #[cfg(feature = "parsing")]
 mod parse_from_description;
 #[cfg(feature = "parsing")]
@@ -26,13 +35,22 @@ pub use conversion_range::ConversionRange;
 pub use different_variant::DifferentVariant;
 #[cfg(feature = "formatting")]
 pub use format::Format;
+#[cfg(feature = "formatting-core")]
+pub use format_core::FormatCore;
 #[cfg(feature = "local-offset")]
 pub use indeterminate_offset::IndeterminateOffset;
-#[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
+#[cfg(all(
+    any(feature = "formatting-core", feature = "parsing"),
+    feature = "alloc"
+))]
 pub use invalid_format_description::InvalidFormatDescription;
 pub use invalid_variant::InvalidVariant;
 #[cfg(feature = "parsing")]
 pub use parse::Parse;
+#[cfg(all(feature = "parsing", feature = "alloc"))]
+pub use parse_any::ParseAny;
+#[cfg(feature = "parsing")]
+pub use parse_diagnostic::{Expected, ParseDiagnostic};
 #[cfg(feature = "parsing")]
 pub use parse_from_description::ParseFromDescription;
 #[cfg(feature = "parsing")]
@@ -55,6 +73,9 @@ pub enum Error {
     #[cfg(feature = "formatting")]
     #[expect(missing_docs)]
     Format(Format),
+    #[cfg(feature = "formatting-core")]
+    #[expect(missing_docs)]
+    FormatCore(FormatCore),
     #[cfg(feature = "parsing")]
     #[expect(missing_docs)]
     ParseFromDescription(ParseFromDescription),
@@ -72,7 +93,13 @@ pub enum Error {
     #[cfg(feature = "parsing")]
     #[expect(missing_docs)]
     TryFromParsed(TryFromParsed),
-    #[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
+    #[cfg(all(feature = "parsing", feature = "alloc"))]
+    #[expect(missing_docs)]
+    ParseAny(ParseAny),
+    #[cfg(all(
+        any(feature = "formatting-core", feature = "parsing"),
+        feature = "alloc"
+    ))]
     #[expect(missing_docs)]
     InvalidFormatDescription(InvalidFormatDescription),
     #[expect(missing_docs)]
@@ -90,6 +117,8 @@ impl fmt::Display for Error {
             Self::IndeterminateOffset(e) => e.fmt(f),
             #[cfg(feature = "formatting")]
             Self::Format(e) => e.fmt(f),
+            #[cfg(feature = "formatting-core")]
+            Self::FormatCore(e) => e.fmt(f),
             #[cfg(feature = "parsing")]
             Self::ParseFromDescription(e) => e.fmt(f),
             #[cfg(feature = "parsing")]
@@ -97,7 +126,12 @@ impl fmt::Display for Error {
             Self::UnexpectedTrailingCharacters { never } => match *never {},
             #[cfg(feature = "parsing")]
             Self::TryFromParsed(e) => e.fmt(f),
-            #[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
+            #[cfg(all(feature = "parsing", feature = "alloc"))]
+            Self::ParseAny(e) => e.fmt(f),
+            #[cfg(all(
+                any(feature = "formatting-core", feature = "parsing"),
+                feature = "alloc"
+            ))]
             Self::InvalidFormatDescription(e) => e.fmt(f),
             Self::DifferentVariant(e) => e.fmt(f),
             Self::InvalidVariant(e) => e.fmt(f),
@@ -114,6 +148,8 @@ impl core::error::Error for Error {
             Self::IndeterminateOffset(err) => Some(err),
             #[cfg(feature = "formatting")]
             Self::Format(err) => Some(err),
+            #[cfg(feature = "formatting-core")]
+            Self::FormatCore(err) => Some(err),
             #[cfg(feature = "parsing")]
             Self::ParseFromDescription(err) => Some(err),
             #[cfg(feature = "parsing")]
@@ -121,7 +157,12 @@ impl core::error::Error for Error {
             Self::UnexpectedTrailingCharacters { never } => match *never {},
             #[cfg(feature = "parsing")]
             Self::TryFromParsed(err) => Some(err),
-            #[cfg(all(any(feature = "formatting", feature = "parsing"), feature = "alloc"))]
+            #[cfg(all(feature = "parsing", feature = "alloc"))]
+            Self::ParseAny(err) => Some(err),
+            #[cfg(all(
+                any(feature = "formatting-core", feature = "parsing"),
+                feature = "alloc"
+            ))]
             Self::InvalidFormatDescription(err) => Some(err),
             Self::DifferentVariant(err) => Some(err),
             Self::InvalidVariant(err) => Some(err),
@@ -0,0 +1,61 @@
+//! Error parsing an input using any of several candidates
+
+use alloc::boxed::Box;
+use core::fmt;
+
+use crate::error;
+
+/// An error that occurred when none of the candidates passed to
+/// [`Parsed::parse_any`](crate::parsing::Parsed::parse_any) could parse the input.
+///
+/// The error returned by each candidate is retained, in the same order as the candidates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseAny {
+    /// The error returned by each candidate.
+    pub(crate) errors: Box<[error::Parse]>,
+}
+
+impl ParseAny {
+    /// Obtain the error returned by each candidate, in the same order as the candidates.
+    pub fn errors(&self) -> &[error::Parse] {
+        &self.errors
+    }
+
+    /// Obtain the error returned by each candidate, in the same order as the candidates.
+    pub fn into_errors(self) -> Box<[error::Parse]> {
+        self.errors
+    }
+}
+
+impl fmt::Display for ParseAny {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "none of the {} candidates could parse the input",
+            self.errors.len()
+        )?;
+        for (index, err) in self.errors.iter().enumerate() {
+            write!(f, "; candidate {index}: {err}")?;
+        }
+        Ok(())
+    }
+}
+
+impl core::error::Error for ParseAny {}
+
+impl From<ParseAny> for crate::Error {
+    fn from(err: ParseAny) -> Self {
+        Self::ParseAny(err)
+    }
+}
+
+impl TryFrom<crate::Error> for ParseAny {
+    type Error = error::DifferentVariant;
+
+    fn try_from(err: crate::Error) -> Result<Self, Self::Error> {
+        match err {
+            crate::Error::ParseAny(err) => Ok(err),
+            _ => Err(error::DifferentVariant),
+        }
+    }
+}
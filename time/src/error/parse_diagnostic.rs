@@ -0,0 +1,222 @@
+//! Parse error along with its location in the input
+
+use core::fmt;
+use core::ops::Range;
+
+use crate::error::{self, ParseFromDescription};
+use crate::format_description::Component;
+
+/// What was expected at the location of a [`ParseDiagnostic`].
+#[non_exhaustive]
+#[allow(
+    variant_size_differences,
+    reason = "the type is only constructed when an error occurs"
+)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Expected<'a> {
+    /// A literal byte sequence.
+    Literal(&'a [u8]),
+    /// A component of the format description.
+    Component(Component),
+    /// The end of the input.
+    End,
+}
+
+impl fmt::Display for Expected<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Literal(literal) => match core::str::from_utf8(literal) {
+                Ok(literal) => write!(f, "`{literal}`"),
+                Err(_) => write!(f, "`{}`", literal.escape_ascii()),
+            },
+            Self::Component(component) => write!(f, "[{}]", component.name()),
+            Self::End => f.write_str("the end of input"),
+        }
+    }
+}
+
+/// A parse error, along with where in the input it occurred and what was expected there.
+///
+/// This is returned by
+/// [`Parsed::parse_with_diagnostic`](crate::parsing::Parsed::parse_with_diagnostic)
+/// and the `parse_with_diagnostic` method of each type that can be parsed. The location is known
+/// when parsing with a format description or a well-known format, while what was expected there is
+/// only known for format descriptions. Errors from converting a
+/// [`Parsed`](crate::parsing::Parsed) struct to another type have no location.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseDiagnostic<'a> {
+    /// The underlying error.
+    error: error::Parse,
+    /// The byte offsets of the start and end of the input that could not be parsed, if known.
+    span: Option<(usize, usize)>,
+    /// What was expected at the start of the span, if known.
+    expected: Option<Expected<'a>>,
+}
+
+impl<'a> ParseDiagnostic<'a> {
+    /// Create a diagnostic for an error that occurred at the start of `at`, which must be a suffix
+    /// of `input`.
+    pub(crate) fn located(
+        input: &[u8],
+        at: &[u8],
+        error: ParseFromDescription,
+        expected: Expected<'a>,
+    ) -> Self {
+        let start = input.len() - at.len();
+        let len = match expected {
+            Expected::Literal(literal) => literal.len().min(at.len()),
+            Expected::Component(_) => at
+                .iter()
+                .take_while(|byte| byte.is_ascii_alphanumeric())
+                .count()
+                .max(usize::from(!at.is_empty())),
+            Expected::End => at.len(),
+        };
+        Self {
+            error: error.into(),
+            span: Some((start, start + len)),
+            expected: Some(expected),
+        }
+    }
+
+    /// Create a diagnostic for an error that occurred at the start of `at`, which must be a suffix
+    /// of `input`, when what was expected there is not known.
+    pub(crate) fn at(input: &[u8], at: &[u8], error: error::Parse) -> Self {
+        let start = input.len() - at.len();
+        let len = at
+            .iter()
+            .take_while(|byte| byte.is_ascii_alphanumeric())
+            .count()
+            .max(usize::from(!at.is_empty()));
+        Self {
+            error,
+            span: Some((start, start + len)),
+            expected: None,
+        }
+    }
+
+    /// Obtain the underlying error.
+    pub const fn error(&self) -> error::Parse {
+        self.error
+    }
+
+    /// Obtain the range of bytes in the input that could not be parsed, if known.
+    ///
+    /// The range starts where the item that failed began to be parsed. For a component, it extends
+    /// over any ASCII letters and digits that follow. The range is empty if the input ended early.
+    pub fn span(&self) -> Option<Range<usize>> {
+        self.span.map(|(start, end)| start..end)
+    }
+
+    /// Obtain what was expected at the start of the span, if known.
+    pub const fn expected(&self) -> Option<Expected<'a>> {
+        self.expected
+    }
+
+    /// Render the input with a caret under the location of the error, followed by the error
+    /// itself.
+    ///
+    /// The input must be the same as was parsed.
+    ///
+    /// ```rust
+    /// # use time::parsing::Parsed;
+    /// # use time_macros::format_description;
+    /// let format = format_description!("[year]-[month]-[day]");
+    /// let err = Parsed::parse_with_diagnostic(b"2024-13-01", format).unwrap_err();
+    /// assert_eq!(
+    ///     err.render("2024-13-01").to_string(),
+    ///     "2024-13-01\n     ^^ the 'month' component could not be parsed; expected [month]"
+    /// );
+    /// ```
+    pub fn render<'b>(&'b self, input: &'b str) -> impl fmt::Display + 'b {
+        Render {
+            diagnostic: self,
+            input,
+        }
+    }
+}
+
+impl fmt::Display for ParseDiagnostic<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.error.fmt(f)?;
+        if let Some((start, _)) = self.span {
+            write!(f, " at byte {start}")?;
+        }
+        if let Some(expected) = self.expected {
+            write!(f, "; expected {expected}")?;
+        }
+        Ok(())
+    }
+}
+
+impl core::error::Error for ParseDiagnostic<'_> {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl From<error::Parse> for ParseDiagnostic<'_> {
+    fn from(error: error::Parse) -> Self {
+        Self {
+            error,
+            span: None,
+            expected: None,
+        }
+    }
+}
+
+impl From<error::TryFromParsed> for ParseDiagnostic<'_> {
+    fn from(error: error::TryFromParsed) -> Self {
+        error::Parse::from(error).into()
+    }
+}
+
+impl From<ParseDiagnostic<'_>> for error::Parse {
+    fn from(diagnostic: ParseDiagnostic<'_>) -> Self {
+        diagnostic.error
+    }
+}
+
+impl From<ParseDiagnostic<'_>> for crate::Error {
+    fn from(diagnostic: ParseDiagnostic<'_>) -> Self {
+        diagnostic.error.into()
+    }
+}
+
+/// The input rendered with a caret under the location of the error.
+struct Render<'a> {
+    /// The diagnostic being rendered.
+    diagnostic: &'a ParseDiagnostic<'a>,
+    /// The input that was parsed.
+    input: &'a str,
+}
+
+impl fmt::Display for Render<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let diagnostic = self.diagnostic;
+        writeln!(f, "{}", self.input)?;
+
+        if let Some((start, end)) = diagnostic.span {
+            // Columns are counted in characters, not bytes.
+            let column = |offset: usize| {
+                self.input
+                    .get(..offset)
+                    .map_or(offset, |prefix| prefix.chars().count())
+            };
+            let (start, end) = (column(start), column(end));
+            for _ in 0..start {
+                f.write_str(" ")?;
+            }
+            for _ in start..end.max(start + 1) {
+                f.write_str("^")?;
+            }
+            f.write_str(" ")?;
+        }
+
+        diagnostic.error.fmt(f)?;
+        if let Some(expected) = diagnostic.expected {
+            write!(f, "; expected {expected}")?;
+        }
+        Ok(())
+    }
+}
@@ -13,6 +13,8 @@ pub enum ParseFromDescription {
     InvalidLiteral,
     /// A dynamic component was not valid.
     InvalidComponent(&'static str),
+    /// A dynamic component could be interpreted in more than one way.
+    AmbiguousComponent(&'static str),
     /// The input was expected to have ended, but there are characters that remain.
     #[non_exhaustive]
     UnexpectedTrailingCharacters,
@@ -25,6 +27,9 @@ impl fmt::Display for ParseFromDescription {
             Self::InvalidComponent(name) => {
                 write!(f, "the '{name}' component could not be parsed")
             }
+            Self::AmbiguousComponent(name) => {
+                write!(f, "the '{name}' component is ambiguous")
+            }
             Self::UnexpectedTrailingCharacters => {
                 f.write_str("unexpected trailing characters; the end of input was expected")
             }
@@ -0,0 +1,725 @@
+//! Static analysis of format descriptions.
+//!
+//! Formatting a value and parsing the result does not always produce the original value. A format
+//! description may omit information needed to construct the type, contain components whose
+//! boundaries cannot be determined when parsing, or contain components that cannot be formatted
+//! for the type at all. [`analyze`] detects these situations without needing a value.
+//!
+//! The analysis is also available at compile time by passing the `round_trip` argument to the
+//! [`format_description!`](crate::macros::format_description) macro.
+
+use core::fmt;
+
+#[cfg(feature = "alloc")]
+use crate::format_description::OwnedFormatItem;
+use crate::format_description::{modifier, BorrowedFormatItem, Component};
+
+/// The type that a format description is used with.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Target {
+    /// [`Date`](crate::Date)
+    Date,
+    /// [`Time`](crate::Time)
+    Time,
+    /// [`UtcOffset`](crate::UtcOffset)
+    UtcOffset,
+    /// [`PrimitiveDateTime`](crate::PrimitiveDateTime)
+    PrimitiveDateTime,
+    /// [`OffsetDateTime`](crate::OffsetDateTime)
+    OffsetDateTime,
+    /// [`UtcDateTime`](crate::UtcDateTime)
+    UtcDateTime,
+}
+
+impl Target {
+    /// Whether the target contains a date.
+    const fn has_date(self) -> bool {
+        matches!(
+            self,
+            Self::Date | Self::PrimitiveDateTime | Self::OffsetDateTime | Self::UtcDateTime
+        )
+    }
+
+    /// Whether the target contains a time.
+    const fn has_time(self) -> bool {
+        matches!(
+            self,
+            Self::Time | Self::PrimitiveDateTime | Self::OffsetDateTime | Self::UtcDateTime
+        )
+    }
+
+    /// Whether the target contains a UTC offset, either explicitly or implicitly.
+    const fn has_offset(self) -> bool {
+        matches!(
+            self,
+            Self::UtcOffset | Self::OffsetDateTime | Self::UtcDateTime
+        )
+    }
+}
+
+/// A reason that parsing the formatted value may fail or produce a different value.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Issue {
+    /// The component cannot be formatted, as the target type does not contain the necessary
+    /// information.
+    UnavailableComponent(Component),
+    /// The value cannot be constructed when parsing, as the format description does not contain
+    /// the named information.
+    MissingComponent(&'static str),
+    /// The component has a variable width and is followed by an item that may begin with a digit.
+    /// When parsing, the component may consume digits belonging to the following item.
+    AmbiguousBoundary(Component),
+    /// The component provides information that an earlier component already provided. Formatted
+    /// values are consistent, but when parsing other input, only one of the components is used to
+    /// construct the value.
+    ConflictingComponents(Component),
+    /// An `ignore` component is present. Nothing is formatted for it, but it consumes input when
+    /// parsing.
+    Ignore(modifier::Ignore),
+    /// An item that produces output follows an `end` component, which requires the end of input
+    /// when parsing.
+    ItemAfterEnd,
+}
+
+impl Issue {
+    /// A brief description of the issue, without any details of the components involved.
+    ///
+    /// This is available in `const` contexts, unlike the [`Display`](fmt::Display)
+    /// implementation.
+    pub const fn message(self) -> &'static str {
+        match self {
+            Self::UnavailableComponent(_) => "a component cannot be formatted for the target type",
+            Self::MissingComponent(_) => {
+                "a component necessary to construct the target type is missing"
+            }
+            Self::AmbiguousBoundary(_) => {
+                "a component with a variable width is followed by an item that may begin with a \
+                 digit"
+            }
+            Self::ConflictingComponents(_) => "multiple components provide the same information",
+            Self::Ignore(_) => "an `ignore` component consumes input that is never formatted",
+            Self::ItemAfterEnd => "an item follows an `end` component",
+        }
+    }
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnavailableComponent(component) => {
+                write!(
+                    f,
+                    "the component {component} cannot be formatted for the target type"
+                )
+            }
+            Self::MissingComponent(name) => write!(
+                f,
+                "the {name} is necessary to construct the target type, but is not present"
+            ),
+            Self::AmbiguousBoundary(component) => write!(
+                f,
+                "the component {component} has a variable width and is followed by an item that \
+                 may begin with a digit"
+            ),
+            Self::ConflictingComponents(component) => write!(
+                f,
+                "the component {component} provides information that an earlier component already \
+                 provided"
+            ),
+            Self::Ignore(_) | Self::ItemAfterEnd => f.write_str(self.message()),
+        }
+    }
+}
+
+impl core::error::Error for Issue {}
+
+/// Determine whether formatting a value of the target type and parsing the result will succeed
+/// and produce an equivalent value.
+///
+/// The first issue found is returned. Optional items are analyzed as if they were present, and only
+/// the first of the items in a [`BorrowedFormatItem::First`] is analyzed, as these are what is
+/// used when formatting. Conditional items are analyzed using the item used when the condition does
+/// not hold, as the values implied by the condition are set when parsing the other item. Modifiers
+/// that truncate the value, such as `[subsecond digits:3]` or `[year repr:last_two pivot:1950]`,
+/// are not considered to be issues. Likewise, years with more than four digits are not considered.
+///
+/// ```rust
+/// # use time::format_description::analysis::{analyze, Issue, Target};
+/// # use time::format_description::Component;
+/// # use time_macros::format_description;
+/// assert_eq!(
+///     analyze(format_description!("[year]-[month]-[day]"), Target::Date),
+///     Ok(())
+/// );
+/// assert_eq!(
+///     analyze(format_description!("[year]-[month]"), Target::Date),
+///     Err(Issue::MissingComponent("day"))
+/// );
+/// assert!(matches!(
+///     analyze(
+///         format_description!("[hour padding:none][minute]"),
+///         Target::Time
+///     ),
+///     Err(Issue::AmbiguousBoundary(Component::Hour(_)))
+/// ));
+/// ```
+pub const fn analyze(items: &[BorrowedFormatItem<'_>], target: Target) -> Result<(), Issue> {
+    let mut state = State::new(target);
+    let mut idx = 0;
+    while idx < items.len() {
+        state = visit_borrowed(state, &items[idx]);
+        idx += 1;
+    }
+    state.finish()
+}
+
+/// Determine whether formatting a value of the target type and parsing the result will succeed
+/// and produce an equivalent value.
+///
+/// This is identical to [`analyze`], but accepts an [`OwnedFormatItem`].
+#[cfg(feature = "alloc")]
+pub fn analyze_owned(item: &OwnedFormatItem, target: Target) -> Result<(), Issue> {
+    visit_owned(State::new(target), item).finish()
+}
+
+/// Analyze a single borrowed item.
+pub(super) const fn visit_borrowed(mut state: State, item: &BorrowedFormatItem<'_>) -> State {
+    match item {
+        BorrowedFormatItem::Literal(literal) => state.literal(literal),
+        BorrowedFormatItem::Component(component) => state.component(*component),
+        BorrowedFormatItem::Compound(items) => {
+            let mut idx = 0;
+            while idx < items.len() {
+                state = visit_borrowed(state, &items[idx]);
+                idx += 1;
+            }
+            state
+        }
+        BorrowedFormatItem::Optional(item) => visit_borrowed(state, item),
+        BorrowedFormatItem::First([item, ..]) => visit_borrowed(state, item),
+        BorrowedFormatItem::First([]) => state,
+        BorrowedFormatItem::Conditional {
+            condition: _,
+            then: _,
+            otherwise,
+        } => visit_borrowed(state, otherwise),
+    }
+}
+
+/// Analyze a single owned item.
+#[cfg(feature = "alloc")]
+fn visit_owned(state: State, item: &OwnedFormatItem) -> State {
+    match item {
+        OwnedFormatItem::Literal(literal) => state.literal(literal),
+        OwnedFormatItem::Component(component) => state.component(*component),
+        OwnedFormatItem::Compound(items) => items.iter().fold(state, visit_owned),
+        OwnedFormatItem::Optional(item) => visit_owned(state, item),
+        OwnedFormatItem::First(items) => match items.first() {
+            Some(item) => visit_owned(state, item),
+            None => state,
+        },
+        OwnedFormatItem::Conditional {
+            condition: _,
+            then: _,
+            otherwise,
+        } => visit_owned(state, otherwise),
+    }
+}
+
+/// The information that is present in a format description, as a bitset.
+#[derive(Clone, Copy)]
+struct Fields(u32);
+
+impl Fields {
+    const YEAR: Self = Self(1 << 0);
+    const YEAR_CENTURY: Self = Self(1 << 1);
+    const YEAR_LAST_TWO: Self = Self(1 << 2);
+    const ISO_YEAR: Self = Self(1 << 3);
+    const ISO_YEAR_CENTURY: Self = Self(1 << 4);
+    const ISO_YEAR_LAST_TWO: Self = Self(1 << 5);
+    const MONTH: Self = Self(1 << 6);
+    const DAY: Self = Self(1 << 7);
+    const ORDINAL: Self = Self(1 << 8);
+    const ISO_WEEK_NUMBER: Self = Self(1 << 9);
+    const SUNDAY_WEEK_NUMBER: Self = Self(1 << 10);
+    const MONDAY_WEEK_NUMBER: Self = Self(1 << 11);
+    const WEEKDAY: Self = Self(1 << 12);
+    const HOUR_24: Self = Self(1 << 13);
+    const HOUR_12: Self = Self(1 << 14);
+    const PERIOD: Self = Self(1 << 15);
+    const MINUTE: Self = Self(1 << 16);
+    const SECOND: Self = Self(1 << 17);
+    const SUBSECOND: Self = Self(1 << 18);
+    const OFFSET_HOUR: Self = Self(1 << 19);
+    const OFFSET_MINUTE: Self = Self(1 << 20);
+    const OFFSET_SECOND: Self = Self(1 << 21);
+    const UNIX_TIMESTAMP: Self = Self(1 << 22);
+    const WEEK_OF_MONTH: Self = Self(1 << 23);
+    const WEEKDAY_IN_MONTH: Self = Self(1 << 24);
+    const QUARTER: Self = Self(1 << 25);
+    const YEAR_OF_ERA: Self = Self(1 << 26);
+    const ERA: Self = Self(1 << 27);
+    const JULIAN_DAY: Self = Self(1 << 28);
+
+    /// Any week number.
+    const WEEK_NUMBER: Self =
+        Self(Self::ISO_WEEK_NUMBER.0 | Self::SUNDAY_WEEK_NUMBER.0 | Self::MONDAY_WEEK_NUMBER.0);
+    /// Any information that is superseded by a Unix timestamp.
+    const SUPERSEDED_BY_TIMESTAMP: Self =
+        Self(!(Self::UNIX_TIMESTAMP.0 | Self::SUBSECOND.0 | Self::WEEKDAY.0 | Self::PERIOD.0));
+
+    /// Whether any of the fields are present.
+    const fn any(self, fields: Self) -> bool {
+        self.0 & fields.0 != 0
+    }
+
+    /// Whether all of the fields are present.
+    const fn all(self, fields: Self) -> bool {
+        self.0 & fields.0 == fields.0
+    }
+
+    /// Add the fields to the set.
+    const fn with(self, fields: Self) -> Self {
+        Self(self.0 | fields.0)
+    }
+}
+
+/// The state of the analysis.
+#[derive(Clone, Copy)]
+pub(super) struct State {
+    /// The type that the format description is used with.
+    target: Target,
+    /// The information that is present.
+    fields: Fields,
+    /// A preceding component with a variable width that has not yet been followed by a literal.
+    variable_width: Option<Component>,
+    /// Whether an `end` component has been encountered.
+    ended: bool,
+    /// The first issue found, if any.
+    issue: Option<Issue>,
+}
+
+impl State {
+    /// Begin analysis for the given target.
+    pub(super) const fn new(target: Target) -> Self {
+        Self {
+            target,
+            fields: Fields(0),
+            variable_width: None,
+            ended: false,
+            issue: None,
+        }
+    }
+
+    /// Record the issue if no other issue has been found.
+    const fn issue(mut self, issue: Issue) -> Self {
+        if self.issue.is_none() {
+            self.issue = Some(issue);
+        }
+        self
+    }
+
+    /// Analyze a literal.
+    const fn literal(mut self, literal: &[u8]) -> Self {
+        let [first, ..] = literal else {
+            return self;
+        };
+        if self.ended {
+            self = self.issue(Issue::ItemAfterEnd);
+        }
+        if let Some(component) = self.variable_width {
+            if first.is_ascii_digit() {
+                self = self.issue(Issue::AmbiguousBoundary(component));
+            }
+        }
+        self.variable_width = None;
+        self
+    }
+
+    /// Analyze a component.
+    const fn component(mut self, component: Component) -> Self {
+        let (fields, conflicts) = fields(component);
+
+        if !is_available(component, self.target) {
+            self = self.issue(Issue::UnavailableComponent(component));
+        }
+        if self.fields.any(conflicts) {
+            self = self.issue(Issue::ConflictingComponents(component));
+        }
+        if let Component::Ignore(ignore) = component {
+            self = self.issue(Issue::Ignore(ignore));
+        }
+
+        match component {
+            // These components never produce output, so they do not affect boundaries.
+            Component::End(_) => {
+                self.ended = true;
+                return self;
+            }
+            Component::Ignore(_) | Component::ExtensionAnnotations(_) => return self,
+            _ => {}
+        }
+
+        if self.ended {
+            self = self.issue(Issue::ItemAfterEnd);
+        }
+        if let Some(preceding) = self.variable_width {
+            if may_begin_with_digit(component) {
+                self = self.issue(Issue::AmbiguousBoundary(preceding));
+            }
+        }
+        self.variable_width = if has_variable_width(component) {
+            Some(component)
+        } else {
+            None
+        };
+        self.fields = self.fields.with(fields);
+        self
+    }
+
+    /// Complete the analysis, checking that the target can be constructed.
+    pub(super) const fn finish(self) -> Result<(), Issue> {
+        if let Some(issue) = self.issue {
+            return Err(issue);
+        }
+
+        let fields = self.fields;
+        let target = self.target;
+
+        if fields.any(Fields::UNIX_TIMESTAMP)
+            && matches!(target, Target::OffsetDateTime | Target::UtcDateTime)
+        {
+            return Ok(());
+        }
+
+        if target.has_date() {
+            if let Some(missing) = missing_date(fields) {
+                return Err(Issue::MissingComponent(missing));
+            }
+        }
+        if target.has_time() {
+            if let Some(missing) = missing_time(fields) {
+                return Err(Issue::MissingComponent(missing));
+            }
+        }
+        if matches!(target, Target::UtcOffset | Target::OffsetDateTime)
+            && !fields.any(Fields::OFFSET_HOUR)
+        {
+            return Err(Issue::MissingComponent("offset hour"));
+        }
+        if fields.any(Fields::OFFSET_SECOND) && !fields.any(Fields::OFFSET_MINUTE) {
+            return Err(Issue::MissingComponent("offset minute"));
+        }
+
+        Ok(())
+    }
+}
+
+/// The information that the component provides and the information that it conflicts with.
+const fn fields(component: Component) -> (Fields, Fields) {
+    /// The fields provided by a year with the given representation.
+    const fn year(repr: modifier::YearRepr, pivot: modifier::YearPivot, iso: bool) -> Fields {
+        match (repr, iso) {
+            (modifier::YearRepr::Full, false) => Fields::YEAR,
+            (modifier::YearRepr::Full, true) => Fields::ISO_YEAR,
+            (modifier::YearRepr::Century, false) => Fields::YEAR_CENTURY,
+            (modifier::YearRepr::Century, true) => Fields::ISO_YEAR_CENTURY,
+            // With a pivot, the last two digits are sufficient to determine the year.
+            (modifier::YearRepr::LastTwo, false) => match pivot {
+                modifier::YearPivot::NONE => Fields::YEAR_LAST_TWO,
+                _ => Fields::YEAR,
+            },
+            (modifier::YearRepr::LastTwo, true) => match pivot {
+                modifier::YearPivot::NONE => Fields::ISO_YEAR_LAST_TWO,
+                _ => Fields::ISO_YEAR,
+            },
+        }
+    }
+
+    let none = Fields(0);
+    let timestamp = Fields::UNIX_TIMESTAMP;
+    // Information that determines the date on its own.
+    let date = timestamp.with(Fields::JULIAN_DAY);
+    match component {
+        // Narrow names do not uniquely identify the value, so they are not used when parsing.
+        Component::Month(modifier::Month {
+            repr: modifier::MonthRepr::Narrow,
+            ..
+        })
+        | Component::Weekday(modifier::Weekday {
+            repr: modifier::WeekdayRepr::Narrow,
+            ..
+        }) => (none, none),
+        Component::Day(_) | Component::Month(_) => (
+            match component {
+                Component::Day(_) => Fields::DAY,
+                _ => Fields::MONTH,
+            },
+            Fields::ORDINAL.with(Fields::WEEK_NUMBER).with(date),
+        ),
+        Component::Ordinal(_) => (
+            Fields::ORDINAL,
+            Fields::MONTH
+                .with(Fields::DAY)
+                .with(Fields::WEEK_NUMBER)
+                .with(date),
+        ),
+        Component::WeekNumber(modifier::WeekNumber { repr, .. }) => (
+            match repr {
+                modifier::WeekNumberRepr::Iso => Fields::ISO_WEEK_NUMBER,
+                modifier::WeekNumberRepr::Sunday => Fields::SUNDAY_WEEK_NUMBER,
+                modifier::WeekNumberRepr::Monday => Fields::MONDAY_WEEK_NUMBER,
+            },
+            Fields::MONTH
+                .with(Fields::DAY)
+                .with(Fields::ORDINAL)
+                .with(date),
+        ),
+        Component::Weekday(_) => (Fields::WEEKDAY, none),
+        Component::WeekOfMonth(_) => (Fields::WEEK_OF_MONTH, none),
+        Component::WeekdayInMonth(_) => (Fields::WEEKDAY_IN_MONTH, none),
+        Component::Quarter(_) => (Fields::QUARTER, none),
+        Component::Era(_) => (Fields::ERA, date),
+        Component::Year(modifier::Year {
+            repr: modifier::YearRepr::Full,
+            iso_week_based: false,
+            era_based: true,
+            ..
+        }) => (Fields::YEAR_OF_ERA, date),
+        Component::Year(modifier::Year {
+            repr,
+            iso_week_based,
+            pivot,
+            ..
+        }) => (year(repr, pivot, iso_week_based), date),
+        Component::Hour(modifier::Hour {
+            is_12_hour_clock: false,
+            ..
+        }) => (Fields::HOUR_24, Fields::HOUR_12.with(timestamp)),
+        Component::Hour(modifier::Hour {
+            is_12_hour_clock: true,
+            ..
+        }) => (Fields::HOUR_12, Fields::HOUR_24.with(timestamp)),
+        Component::Period(_) => (Fields::PERIOD, none),
+        Component::Minute(_) => (Fields::MINUTE, timestamp),
+        Component::Second(_) => (Fields::SECOND, timestamp),
+        Component::Subsecond(_) => (Fields::SUBSECOND, none),
+        Component::OffsetHour(_) => (Fields::OFFSET_HOUR, timestamp),
+        Component::OffsetMinute(_) => (Fields::OFFSET_MINUTE, timestamp),
+        Component::OffsetSecond(_) => (Fields::OFFSET_SECOND, timestamp),
+        Component::UnixTimestamp(_) => (Fields::UNIX_TIMESTAMP, Fields::SUPERSEDED_BY_TIMESTAMP),
+        Component::JulianDay(modifier::JulianDay { digits: 0, .. }) => (
+            Fields::JULIAN_DAY,
+            Fields::YEAR
+                .with(Fields::YEAR_CENTURY)
+                .with(Fields::YEAR_LAST_TWO)
+                .with(Fields::ISO_YEAR)
+                .with(Fields::ISO_YEAR_CENTURY)
+                .with(Fields::ISO_YEAR_LAST_TWO)
+                .with(Fields::YEAR_OF_ERA)
+                .with(Fields::ERA)
+                .with(Fields::MONTH)
+                .with(Fields::DAY)
+                .with(Fields::ORDINAL)
+                .with(Fields::WEEK_NUMBER)
+                .with(date),
+        ),
+        // With a fraction of the day, the value identifies a moment just as a Unix timestamp does.
+        Component::JulianDay(_) => (Fields::UNIX_TIMESTAMP, Fields::SUPERSEDED_BY_TIMESTAMP),
+        Component::Ignore(_)
+        | Component::End(_)
+        | Component::TimeZoneAnnotation(_)
+        | Component::ExtensionAnnotations(_) => (none, none),
+    }
+}
+
+/// Whether the component can be formatted for the target.
+const fn is_available(component: Component, target: Target) -> bool {
+    match component {
+        Component::Day(_)
+        | Component::Month(_)
+        | Component::Ordinal(_)
+        | Component::Weekday(_)
+        | Component::WeekNumber(_)
+        | Component::WeekOfMonth(_)
+        | Component::WeekdayInMonth(_)
+        | Component::Quarter(_)
+        | Component::Era(_)
+        | Component::Year(_) => target.has_date(),
+        Component::Hour(_)
+        | Component::Minute(_)
+        | Component::Period(_)
+        | Component::Second(_)
+        | Component::Subsecond(_) => target.has_time(),
+        Component::OffsetHour(_)
+        | Component::OffsetMinute(_)
+        | Component::OffsetSecond(_)
+        | Component::TimeZoneAnnotation(_) => target.has_offset(),
+        Component::JulianDay(modifier::JulianDay { digits: 0, .. }) => target.has_date(),
+        Component::UnixTimestamp(_) | Component::JulianDay(_) => {
+            target.has_date() && target.has_time() && target.has_offset()
+        }
+        Component::Ignore(_) | Component::End(_) | Component::ExtensionAnnotations(_) => true,
+    }
+}
+
+/// Whether the formatted component may begin with an ASCII digit.
+const fn may_begin_with_digit(component: Component) -> bool {
+    match component {
+        Component::Month(modifier::Month { repr, .. }) => {
+            matches!(repr, modifier::MonthRepr::Numerical)
+        }
+        Component::Weekday(modifier::Weekday { repr, .. }) => matches!(
+            repr,
+            modifier::WeekdayRepr::Sunday | modifier::WeekdayRepr::Monday
+        ),
+        Component::Year(modifier::Year {
+            sign_is_mandatory, ..
+        })
+        | Component::OffsetHour(modifier::OffsetHour {
+            sign_is_mandatory, ..
+        })
+        | Component::UnixTimestamp(modifier::UnixTimestamp {
+            sign_is_mandatory, ..
+        }) => !sign_is_mandatory,
+        Component::Day(_)
+        | Component::Ordinal(_)
+        | Component::WeekNumber(_)
+        | Component::WeekOfMonth(_)
+        | Component::WeekdayInMonth(_)
+        | Component::Quarter(modifier::Quarter {
+            repr: modifier::QuarterRepr::Numerical,
+            ..
+        })
+        | Component::Hour(_)
+        | Component::Minute(_)
+        | Component::Second(_)
+        | Component::Subsecond(_)
+        | Component::OffsetMinute(_)
+        | Component::OffsetSecond(_)
+        | Component::JulianDay(_) => true,
+        Component::Quarter(_)
+        | Component::Era(_)
+        | Component::Period(_)
+        | Component::Ignore(_)
+        | Component::End(_)
+        | Component::TimeZoneAnnotation(_)
+        | Component::ExtensionAnnotations(_) => false,
+    }
+}
+
+/// Whether the component may consume more digits when parsing than were formatted.
+const fn has_variable_width(component: Component) -> bool {
+    match component {
+        Component::Day(modifier::Day { padding, .. })
+        | Component::Ordinal(modifier::Ordinal { padding, .. })
+        | Component::WeekNumber(modifier::WeekNumber { padding, .. })
+        | Component::Hour(modifier::Hour { padding, .. })
+        | Component::Minute(modifier::Minute { padding, .. })
+        | Component::Second(modifier::Second { padding, .. })
+        | Component::OffsetHour(modifier::OffsetHour { padding, .. })
+        | Component::OffsetMinute(modifier::OffsetMinute { padding })
+        | Component::OffsetSecond(modifier::OffsetSecond { padding }) => {
+            matches!(padding, modifier::Padding::None)
+        }
+        Component::Month(modifier::Month { padding, repr, .. }) => {
+            matches!(repr, modifier::MonthRepr::Numerical)
+                && matches!(padding, modifier::Padding::None)
+        }
+        Component::Year(modifier::Year { padding, .. }) => {
+            matches!(padding, modifier::Padding::None)
+        }
+        Component::Subsecond(modifier::Subsecond { digits }) => {
+            matches!(digits, modifier::SubsecondDigits::OneOrMore)
+        }
+        Component::UnixTimestamp(_) | Component::JulianDay(_) => true,
+        Component::Weekday(_)
+        | Component::WeekOfMonth(_)
+        | Component::WeekdayInMonth(_)
+        | Component::Quarter(_)
+        | Component::Era(_)
+        | Component::Period(_)
+        | Component::Ignore(_)
+        | Component::End(_)
+        | Component::TimeZoneAnnotation(_)
+        | Component::ExtensionAnnotations(_) => false,
+    }
+}
+
+/// The information needed to construct a date that is missing, if any.
+const fn missing_date(fields: Fields) -> Option<&'static str> {
+    if fields.any(Fields::JULIAN_DAY) {
+        return None;
+    }
+
+    let has_year = fields.any(Fields::YEAR)
+        || fields.all(Fields::YEAR_CENTURY.with(Fields::YEAR_LAST_TWO))
+        || fields.all(Fields::YEAR_OF_ERA.with(Fields::ERA));
+    let has_iso_year = fields.any(Fields::ISO_YEAR)
+        || fields.all(Fields::ISO_YEAR_CENTURY.with(Fields::ISO_YEAR_LAST_TWO));
+    let has_weekday = fields.any(Fields::WEEKDAY);
+    let has_week_of_month = fields.any(Fields::WEEK_OF_MONTH.with(Fields::WEEKDAY_IN_MONTH));
+
+    if has_year
+        && (fields.any(Fields::ORDINAL)
+            || fields.all(Fields::MONTH.with(Fields::DAY))
+            || (has_weekday
+                && fields.any(Fields::SUNDAY_WEEK_NUMBER.with(Fields::MONDAY_WEEK_NUMBER)))
+            || (has_weekday && has_week_of_month && fields.any(Fields::MONTH))
+            || fields.any(Fields::QUARTER))
+    {
+        return None;
+    }
+    if has_iso_year && has_weekday && fields.any(Fields::ISO_WEEK_NUMBER) {
+        return None;
+    }
+
+    Some(if fields.any(Fields::ISO_WEEK_NUMBER) {
+        if !has_iso_year {
+            "ISO year"
+        } else {
+            "weekday"
+        }
+    } else if !has_year {
+        if fields.any(Fields::YEAR_OF_ERA) {
+            "era"
+        } else if fields.any(Fields::YEAR_LAST_TWO) {
+            "century"
+        } else {
+            "year"
+        }
+    } else if fields.any(Fields::SUNDAY_WEEK_NUMBER.with(Fields::MONDAY_WEEK_NUMBER)) {
+        "weekday"
+    } else if has_week_of_month && !fields.any(Fields::DAY) {
+        if fields.any(Fields::MONTH) {
+            "weekday"
+        } else {
+            "month"
+        }
+    } else if fields.any(Fields::DAY) {
+        "month"
+    } else {
+        "day"
+    })
+}
+
+/// The information needed to construct a time that is missing, if any.
+const fn missing_time(fields: Fields) -> Option<&'static str> {
+    if !fields.any(Fields::HOUR_24) {
+        if !fields.any(Fields::HOUR_12) {
+            return Some("hour");
+        }
+        if !fields.any(Fields::PERIOD) {
+            return Some("period");
+        }
+    }
+    if fields.any(Fields::SECOND.with(Fields::SUBSECOND)) && !fields.any(Fields::MINUTE) {
+        return Some("minute");
+    }
+    if fields.any(Fields::SUBSECOND) && !fields.any(Fields::SECOND) {
+        return Some("second");
+    }
+    None
+}
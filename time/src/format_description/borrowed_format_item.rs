@@ -2,13 +2,15 @@
 
 #[cfg(feature = "alloc")]
 use alloc::string::String;
-#[cfg(feature = "alloc")]
-use core::fmt;
+use core::fmt::{self, Write as _};
 
 use crate::error;
-use crate::format_description::Component;
+use crate::format_description::{Component, Condition};
 
 /// A complete description of how to format and parse a type.
+///
+/// The [`Display`](fmt::Display) implementation writes the equivalent version 2 format
+/// description. To display a slice of items, wrap it in [`BorrowedFormatItem::Compound`].
 #[non_exhaustive]
 #[cfg_attr(not(feature = "alloc"), derive(Debug))]
 #[derive(Clone, PartialEq, Eq)]
@@ -32,6 +34,39 @@ pub enum BorrowedFormatItem<'a> {
     /// formatting, the first element of the slice is used.  An empty slice is a no-op when
     /// formatting or parsing.
     First(&'a [Self]),
+    /// One of two items, depending on whether a condition holds for the value being formatted.
+    ///
+    /// When parsing, `otherwise` is attempted first, followed by `then`. If `then` is used, the
+    /// values implied by the condition are set.
+    ///
+    /// ```rust
+    /// # use time::OffsetDateTime;
+    /// # use time_macros::{datetime, format_description};
+    /// let format = format_description!(
+    ///     version = 2,
+    ///     "[year]-[month]-[day] [hour]:[minute][if second_is_zero [] [:[second]]] \
+    ///      [if offset_is_utc [Z] [[offset_hour sign:mandatory]:[offset_minute]]]"
+    /// );
+    ///
+    /// assert_eq!(datetime!(2024-01-02 03:04 UTC).format(format)?, "2024-01-02 03:04 Z");
+    /// assert_eq!(
+    ///     datetime!(2024-01-02 03:04:05 +01:00).format(format)?,
+    ///     "2024-01-02 03:04:05 +01:00"
+    /// );
+    /// assert_eq!(
+    ///     OffsetDateTime::parse("2024-01-02 03:04 Z", format)?,
+    ///     datetime!(2024-01-02 03:04 UTC)
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    Conditional {
+        /// The condition that determines which item is used.
+        condition: Condition,
+        /// The item used when the condition holds.
+        then: &'a Self,
+        /// The item used when the condition does not hold.
+        otherwise: &'a Self,
+    },
 }
 
 #[cfg(feature = "alloc")]
@@ -43,10 +78,94 @@ impl fmt::Debug for BorrowedFormatItem<'_> {
             Self::Compound(compound) => compound.fmt(f),
             Self::Optional(item) => f.debug_tuple("Optional").field(item).finish(),
             Self::First(items) => f.debug_tuple("First").field(items).finish(),
+            Self::Conditional {
+                condition,
+                then,
+                otherwise,
+            } => f
+                .debug_struct("Conditional")
+                .field("condition", condition)
+                .field("then", then)
+                .field("otherwise", otherwise)
+                .finish(),
         }
     }
 }
 
+impl fmt::Display for BorrowedFormatItem<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Literal(literal) => fmt_literal(f, literal),
+            Self::Component(component) => fmt::Display::fmt(component, f),
+            Self::Compound(items) => items.iter().try_for_each(|item| fmt::Display::fmt(item, f)),
+            Self::Optional(item) => write!(f, "[optional [{item}]]"),
+            Self::First(items) => fmt_first(f, items),
+            Self::Conditional {
+                condition,
+                then,
+                otherwise,
+            } => write!(f, "[if {condition} [{then}] [{otherwise}]]"),
+        }
+    }
+}
+
+/// Write a literal as it would appear in a version 2 format description. Characters with special
+/// meaning are escaped, and invalid UTF-8 is replaced with U+FFFD REPLACEMENT CHARACTER.
+pub(super) fn fmt_literal(f: &mut fmt::Formatter<'_>, literal: &[u8]) -> fmt::Result {
+    for chunk in literal.utf8_chunks() {
+        for c in chunk.valid().chars() {
+            if matches!(c, '\\' | '[' | ']') {
+                f.write_char('\\')?;
+            }
+            f.write_char(c)?;
+        }
+        if !chunk.invalid().is_empty() {
+            f.write_char(char::REPLACEMENT_CHARACTER)?;
+        }
+    }
+    Ok(())
+}
+
+/// Write a `first` item as it would appear in a version 2 format description. As an empty item has
+/// no effect, nothing is written in that case.
+pub(super) fn fmt_first<T: fmt::Display>(f: &mut fmt::Formatter<'_>, items: &[T]) -> fmt::Result {
+    if items.is_empty() {
+        return Ok(());
+    }
+    f.write_str("[first")?;
+    for item in items {
+        write!(f, " [{item}]")?;
+    }
+    f.write_str("]")
+}
+
+#[cfg(feature = "alloc")]
+impl BorrowedFormatItem<'_> {
+    /// Convert the item to an equivalent `strftime` format description, if possible.
+    ///
+    /// This is a best-effort conversion. Modifiers that only affect parsing, such as whether a
+    /// component is case-sensitive, are not represented. Optional items are converted as if they
+    /// were always present, and only the first of the items in a [`BorrowedFormatItem::First`] is
+    /// converted. In all of these situations, formatting with the returned description produces
+    /// the same output as formatting with the original. `None` is returned if any item has no
+    /// `strftime` equivalent.
+    ///
+    /// ```rust
+    /// # use time::format_description::BorrowedFormatItem;
+    /// # use time_macros::format_description;
+    /// let format = format_description!("[year]-[month]-[day] [hour padding:none]:[minute]");
+    /// assert_eq!(
+    ///     BorrowedFormatItem::Compound(format)
+    ///         .to_strftime()
+    ///         .as_deref(),
+    ///     Some("%Y-%m-%d %-H:%M")
+    /// );
+    /// ```
+    pub fn to_strftime(&self) -> Option<String> {
+        super::parse::format_strftime(self)
+    }
+}
+
 impl From<Component> for BorrowedFormatItem<'_> {
     fn from(component: Component) -> Self {
         Self::Component(component)
@@ -0,0 +1,279 @@
+//! A builder for format descriptions.
+
+use core::fmt;
+
+use crate::format_description::analysis::{self, Issue, Target};
+#[cfg(feature = "alloc")]
+use crate::format_description::OwnedFormatItem;
+use crate::format_description::{modifier, BorrowedFormatItem, Component, Condition};
+
+/// A builder for format descriptions, as an alternative to parsing the description syntax.
+///
+/// The builder holds up to `N` items. Each method appends an item, panicking if the capacity is
+/// exceeded. When the builder is created with [`for_target`](Self::for_target), items are
+/// [analyzed](analysis::analyze) for the target type as they are appended, and building fails if
+/// formatting a value and parsing the result may not succeed.
+///
+/// All methods other than [`build_owned`](Self::build_owned) are available in `const` contexts,
+/// such that a format description can be built without any runtime cost.
+///
+/// ```rust
+/// # use time::format_description::{modifier, BorrowedFormatItem, FormatBuilder};
+/// # use time::format_description::analysis::Target;
+/// # use time_macros::{date, format_description};
+/// const FORMAT: &[BorrowedFormatItem<'_>] = &FormatBuilder::<5>::for_target(Target::Date)
+///     .year(modifier::Year::full())
+///     .literal("-")
+///     .month(modifier::Month::numerical().with_padding(modifier::Padding::None))
+///     .literal("-")
+///     .day(modifier::Day::default())
+///     .build();
+///
+/// assert_eq!(
+///     FORMAT,
+///     format_description!("[year]-[month padding:none]-[day]")
+/// );
+/// assert_eq!(date!(2024-01-02).format(FORMAT)?, "2024-1-02");
+/// # Ok::<_, time::Error>(())
+/// ```
+///
+/// When the number of items is not known in advance, provide an upper bound and use
+/// [`as_slice`](Self::as_slice) or [`build_owned`](Self::build_owned).
+///
+/// ```rust
+/// # use time::format_description::{modifier, FormatBuilder};
+/// # use time_macros::date;
+/// let use_month_name = true;
+/// let builder = FormatBuilder::<16>::new()
+///     .day(modifier::Day::default())
+///     .literal(" ");
+/// let builder = if use_month_name {
+///     builder.month(modifier::Month::short())
+/// } else {
+///     builder.month(modifier::Month::numerical())
+/// };
+/// let builder = builder.literal(" ").year(modifier::Year::full());
+///
+/// assert_eq!(builder.len(), 5);
+/// assert_eq!(date!(2024-01-02).format(builder.as_slice())?, "02 Jan 2024");
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Clone)]
+pub struct FormatBuilder<'a, const N: usize> {
+    /// The items that have been appended, followed by placeholders.
+    items: [BorrowedFormatItem<'a>; N],
+    /// The number of items that have been appended.
+    len: usize,
+    /// The analysis of the appended items, if a target was provided.
+    analysis: Option<analysis::State>,
+}
+
+impl<const N: usize> fmt::Debug for FormatBuilder<'_, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FormatBuilder")
+            .field("items", &self.as_slice())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<const N: usize> Default for FormatBuilder<'_, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Generate a method that appends a component with the provided modifier.
+macro_rules! component_methods {
+    ($($method:ident: $variant:ident),* $(,)?) => {$(
+        #[doc = concat!(
+            "Append a [`", stringify!($variant), "`](Component::", stringify!($variant), ") ",
+            "component."
+        )]
+        #[must_use = "this does not modify the original value"]
+        pub const fn $method(self, modifier: modifier::$variant) -> Self {
+            self.component(Component::$variant(modifier))
+        }
+    )*};
+}
+
+impl<'a, const N: usize> FormatBuilder<'a, N> {
+    /// A placeholder for items that have not yet been appended.
+    const PLACEHOLDER: BorrowedFormatItem<'a> = BorrowedFormatItem::Literal(b"");
+
+    /// Create a builder with no items.
+    pub const fn new() -> Self {
+        Self {
+            items: [Self::PLACEHOLDER; N],
+            len: 0,
+            analysis: None,
+        }
+    }
+
+    /// Create a builder with no items that analyzes items for the provided target as they are
+    /// appended.
+    pub const fn for_target(target: Target) -> Self {
+        Self {
+            analysis: Some(analysis::State::new(target)),
+            ..Self::new()
+        }
+    }
+
+    /// Append an item.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if the builder already contains `N` items.
+    #[must_use = "this does not modify the original value"]
+    pub const fn item(mut self, item: BorrowedFormatItem<'a>) -> Self {
+        if self.len == N {
+            panic!("the capacity of the format builder was exceeded");
+        }
+        if let Some(state) = self.analysis {
+            self.analysis = Some(analysis::visit_borrowed(state, &item));
+        }
+        self.items[self.len] = item;
+        self.len += 1;
+        self
+    }
+
+    /// Append a literal.
+    #[must_use = "this does not modify the original value"]
+    pub const fn literal(self, literal: &'a str) -> Self {
+        self.item(BorrowedFormatItem::Literal(literal.as_bytes()))
+    }
+
+    /// Append a component.
+    #[must_use = "this does not modify the original value"]
+    pub const fn component(self, component: Component) -> Self {
+        self.item(BorrowedFormatItem::Component(component))
+    }
+
+    component_methods! {
+        day: Day,
+        month: Month,
+        ordinal: Ordinal,
+        weekday: Weekday,
+        week_number: WeekNumber,
+        week_of_month: WeekOfMonth,
+        weekday_in_month: WeekdayInMonth,
+        quarter: Quarter,
+        era: Era,
+        year: Year,
+        hour: Hour,
+        minute: Minute,
+        period: Period,
+        second: Second,
+        subsecond: Subsecond,
+        offset_hour: OffsetHour,
+        offset_minute: OffsetMinute,
+        offset_second: OffsetSecond,
+        ignore: Ignore,
+        unix_timestamp: UnixTimestamp,
+        julian_day: JulianDay,
+    }
+
+    /// Append an [`End`](Component::End) component.
+    #[must_use = "this does not modify the original value"]
+    pub const fn end(self) -> Self {
+        self.component(Component::End(modifier::End))
+    }
+
+    /// Append a [`TimeZoneAnnotation`](Component::TimeZoneAnnotation) component.
+    #[must_use = "this does not modify the original value"]
+    pub const fn time_zone_annotation(self) -> Self {
+        self.component(Component::TimeZoneAnnotation(modifier::TimeZoneAnnotation))
+    }
+
+    /// Append an [`ExtensionAnnotations`](Component::ExtensionAnnotations) component.
+    #[must_use = "this does not modify the original value"]
+    pub const fn extension_annotations(self) -> Self {
+        self.component(Component::ExtensionAnnotations(
+            modifier::ExtensionAnnotations,
+        ))
+    }
+
+    /// Append a [`Compound`](BorrowedFormatItem::Compound) item, such as one that was built
+    /// separately.
+    #[must_use = "this does not modify the original value"]
+    pub const fn compound(self, items: &'a [BorrowedFormatItem<'a>]) -> Self {
+        self.item(BorrowedFormatItem::Compound(items))
+    }
+
+    /// Append an [`Optional`](BorrowedFormatItem::Optional) item.
+    #[must_use = "this does not modify the original value"]
+    pub const fn optional(self, item: &'a BorrowedFormatItem<'a>) -> Self {
+        self.item(BorrowedFormatItem::Optional(item))
+    }
+
+    /// Append a [`First`](BorrowedFormatItem::First) item.
+    #[must_use = "this does not modify the original value"]
+    pub const fn first(self, items: &'a [BorrowedFormatItem<'a>]) -> Self {
+        self.item(BorrowedFormatItem::First(items))
+    }
+
+    /// Append a [`Conditional`](BorrowedFormatItem::Conditional) item.
+    #[must_use = "this does not modify the original value"]
+    pub const fn conditional(
+        self,
+        condition: Condition,
+        then: &'a BorrowedFormatItem<'a>,
+        otherwise: &'a BorrowedFormatItem<'a>,
+    ) -> Self {
+        self.item(BorrowedFormatItem::Conditional {
+            condition,
+            then,
+            otherwise,
+        })
+    }
+
+    /// The number of items that have been appended.
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether no items have been appended.
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The items that have been appended.
+    pub const fn as_slice(&self) -> &[BorrowedFormatItem<'a>] {
+        self.items.split_at(self.len).0
+    }
+
+    /// Determine whether formatting a value of the target type and parsing the result will
+    /// succeed and produce an equivalent value, returning the first issue found if not. This
+    /// always succeeds if the builder was not created with [`for_target`](Self::for_target).
+    pub const fn validate(&self) -> Result<(), Issue> {
+        match self.analysis {
+            Some(state) => state.finish(),
+            None => Ok(()),
+        }
+    }
+
+    /// Obtain the items, which must be exactly `N` in number.
+    ///
+    /// # Panics
+    ///
+    /// This method panics if fewer than `N` items have been appended, or if the builder was
+    /// created with [`for_target`](Self::for_target) and [`validate`](Self::validate) would fail.
+    pub const fn build(self) -> [BorrowedFormatItem<'a>; N] {
+        if self.len != N {
+            panic!("the format builder contains fewer items than its capacity");
+        }
+        if let Err(issue) = self.validate() {
+            panic!("{}", issue.message());
+        }
+        self.items
+    }
+
+    /// Obtain the items as an [`OwnedFormatItem`], regardless of the capacity.
+    ///
+    /// If the builder was created with [`for_target`](Self::for_target), an error is returned if
+    /// formatting a value and parsing the result may not succeed.
+    #[cfg(feature = "alloc")]
+    pub fn build_owned(self) -> Result<OwnedFormatItem, Issue> {
+        self.validate()?;
+        Ok(self.as_slice().into())
+    }
+}
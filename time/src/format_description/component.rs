@@ -1,5 +1,7 @@
 //! Part of a format description.
 
+use core::fmt;
+
 use crate::format_description::modifier;
 
 /// A component of a larger format description.
@@ -16,6 +18,14 @@ pub enum Component {
     Weekday(modifier::Weekday),
     /// Week within the year.
     WeekNumber(modifier::WeekNumber),
+    /// Week within the month.
+    WeekOfMonth(modifier::WeekOfMonth),
+    /// Occurrence of the day of the week within the month.
+    WeekdayInMonth(modifier::WeekdayInMonth),
+    /// Quarter of the year.
+    Quarter(modifier::Quarter),
+    /// Era of the date.
+    Era(modifier::Era),
     /// Year of the date.
     Year(modifier::Year),
     /// Hour of the day.
@@ -38,7 +48,367 @@ pub enum Component {
     Ignore(modifier::Ignore),
     /// A Unix timestamp.
     UnixTimestamp(modifier::UnixTimestamp),
+    /// A Julian day or modified Julian day, optionally with a fraction of the day.
+    JulianDay(modifier::JulianDay),
     /// The end of input. Parsing this component will fail if there is any input remaining. This
     /// component neither affects formatting nor consumes any input when parsing.
     End(modifier::End),
+    /// A time zone annotation, as defined in RFC 9557. When formatting, the UTC offset is written
+    /// as a numeric annotation. When parsing, the annotation is validated but not stored; use
+    /// [`Parsed::parse_annotations`](crate::parsing::Parsed::parse_annotations) to obtain it.
+    TimeZoneAnnotation(modifier::TimeZoneAnnotation),
+    /// Any number of extension annotations, as defined in RFC 9557. This component has no effect
+    /// on formatting. When parsing, the annotations are validated but not stored; use
+    /// [`Parsed::parse_annotations`](crate::parsing::Parsed::parse_annotations) to obtain them.
+    ExtensionAnnotations(modifier::ExtensionAnnotations),
+}
+
+impl Component {
+    /// The name of the component, as used in format descriptions.
+    pub(crate) const fn name(self) -> &'static str {
+        match self {
+            Self::Day(_) => "day",
+            Self::Month(_) => "month",
+            Self::Ordinal(_) => "ordinal",
+            Self::Weekday(_) => "weekday",
+            Self::WeekNumber(_) => "week_number",
+            Self::WeekOfMonth(_) => "week_of_month",
+            Self::WeekdayInMonth(_) => "weekday_in_month",
+            Self::Quarter(_) => "quarter",
+            Self::Era(_) => "era",
+            Self::Year(_) => "year",
+            Self::Hour(_) => "hour",
+            Self::Minute(_) => "minute",
+            Self::Period(_) => "period",
+            Self::Second(_) => "second",
+            Self::Subsecond(_) => "subsecond",
+            Self::OffsetHour(_) => "offset_hour",
+            Self::OffsetMinute(_) => "offset_minute",
+            Self::OffsetSecond(_) => "offset_second",
+            Self::Ignore(_) => "ignore",
+            Self::UnixTimestamp(_) => "unix_timestamp",
+            Self::JulianDay(_) => "julian_day",
+            Self::End(_) => "end",
+            Self::TimeZoneAnnotation(_) => "time_zone_annotation",
+            Self::ExtensionAnnotations(_) => "extension_annotations",
+        }
+    }
+}
+
+/// Write a modifier of a component, if it is present.
+fn write_modifier(
+    f: &mut fmt::Formatter<'_>,
+    key: &str,
+    value: Option<impl fmt::Display>,
+) -> fmt::Result {
+    match value {
+        Some(value) => write!(f, " {key}:{value}"),
+        None => Ok(()),
+    }
+}
+
+/// The value of a `padding` modifier, if it is not the default.
+const fn padding(padding: modifier::Padding) -> Option<&'static str> {
+    match padding {
+        modifier::Padding::Space => Some("space"),
+        modifier::Padding::Zero => None,
+        modifier::Padding::None => Some("none"),
+    }
+}
+
+/// The value of a `case` modifier on a textual component, if it is not the default.
+const fn text_case(case: modifier::TextCase) -> Option<&'static str> {
+    match case {
+        modifier::TextCase::Title => None,
+        modifier::TextCase::Upper => Some("upper"),
+        modifier::TextCase::Lower => Some("lower"),
+    }
+}
+
+/// The value of a `case_sensitive` modifier, if it is not the default.
+const fn case_sensitive(case_sensitive: bool) -> Option<&'static str> {
+    if case_sensitive {
+        None
+    } else {
+        Some("false")
+    }
+}
+
+/// The value of a `sign` modifier, if it is not the default.
+const fn sign(sign_is_mandatory: bool) -> Option<&'static str> {
+    if sign_is_mandatory {
+        Some("mandatory")
+    } else {
+        None
+    }
+}
+
+impl fmt::Display for Component {
+    /// Write the component as it would appear in a version 2 format description. Modifiers are
+    /// only present when they differ from their default value.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "[{}", self.name())?;
+
+        match *self {
+            Self::Day(modifier::Day {
+                padding: pad,
+                width,
+            })
+            | Self::Minute(modifier::Minute {
+                padding: pad,
+                width,
+            })
+            | Self::Ordinal(modifier::Ordinal {
+                padding: pad,
+                width,
+            })
+            | Self::Second(modifier::Second {
+                padding: pad,
+                width,
+            }) => {
+                write_modifier(f, "padding", padding(pad))?;
+                write_modifier(f, "width", (width != 0).then_some(width))?;
+            }
+            Self::OffsetMinute(modifier::OffsetMinute { padding: pad })
+            | Self::OffsetSecond(modifier::OffsetSecond { padding: pad }) => {
+                write_modifier(f, "padding", padding(pad))?;
+            }
+            Self::Month(modifier::Month {
+                padding: pad,
+                width,
+                repr,
+                case,
+                case_sensitive: is_case_sensitive,
+            }) => {
+                write_modifier(f, "padding", padding(pad))?;
+                write_modifier(f, "width", (width != 0).then_some(width))?;
+                write_modifier(
+                    f,
+                    "repr",
+                    match repr {
+                        modifier::MonthRepr::Numerical => None,
+                        modifier::MonthRepr::Long => Some("long"),
+                        modifier::MonthRepr::Short => Some("short"),
+                        modifier::MonthRepr::Narrow => Some("narrow"),
+                    },
+                )?;
+                write_modifier(f, "case", text_case(case))?;
+                write_modifier(f, "case_sensitive", case_sensitive(is_case_sensitive))?;
+            }
+            Self::Weekday(modifier::Weekday {
+                repr,
+                one_indexed,
+                case,
+                case_sensitive: is_case_sensitive,
+            }) => {
+                write_modifier(
+                    f,
+                    "repr",
+                    match repr {
+                        modifier::WeekdayRepr::Short => Some("short"),
+                        modifier::WeekdayRepr::Long => None,
+                        modifier::WeekdayRepr::Narrow => Some("narrow"),
+                        modifier::WeekdayRepr::Sunday => Some("sunday"),
+                        modifier::WeekdayRepr::Monday => Some("monday"),
+                    },
+                )?;
+                write_modifier(f, "one_indexed", (!one_indexed).then_some("false"))?;
+                write_modifier(f, "case", text_case(case))?;
+                write_modifier(f, "case_sensitive", case_sensitive(is_case_sensitive))?;
+            }
+            Self::WeekNumber(modifier::WeekNumber {
+                padding: pad,
+                width,
+                repr,
+            }) => {
+                write_modifier(f, "padding", padding(pad))?;
+                write_modifier(f, "width", (width != 0).then_some(width))?;
+                write_modifier(
+                    f,
+                    "repr",
+                    match repr {
+                        modifier::WeekNumberRepr::Iso => None,
+                        modifier::WeekNumberRepr::Sunday => Some("sunday"),
+                        modifier::WeekNumberRepr::Monday => Some("monday"),
+                    },
+                )?;
+            }
+            Self::WeekOfMonth(modifier::WeekOfMonth {
+                first_weekday,
+                minimal_days,
+            }) => {
+                write_modifier(
+                    f,
+                    "first_weekday",
+                    match first_weekday {
+                        crate::Weekday::Monday => None,
+                        crate::Weekday::Tuesday => Some("tuesday"),
+                        crate::Weekday::Wednesday => Some("wednesday"),
+                        crate::Weekday::Thursday => Some("thursday"),
+                        crate::Weekday::Friday => Some("friday"),
+                        crate::Weekday::Saturday => Some("saturday"),
+                        crate::Weekday::Sunday => Some("sunday"),
+                    },
+                )?;
+                write_modifier(
+                    f,
+                    "minimal_days",
+                    (minimal_days > 1).then_some(minimal_days.min(7)),
+                )?;
+            }
+            Self::WeekdayInMonth(modifier::WeekdayInMonth { from_end }) => {
+                write_modifier(f, "from_end", from_end.then_some("true"))?;
+            }
+            Self::Quarter(modifier::Quarter {
+                repr,
+                case_sensitive: is_case_sensitive,
+            }) => {
+                write_modifier(
+                    f,
+                    "repr",
+                    match repr {
+                        modifier::QuarterRepr::Numerical => None,
+                        modifier::QuarterRepr::Short => Some("short"),
+                    },
+                )?;
+                write_modifier(f, "case_sensitive", case_sensitive(is_case_sensitive))?;
+            }
+            Self::Era(modifier::Era {
+                repr,
+                case_sensitive: is_case_sensitive,
+            }) => {
+                write_modifier(
+                    f,
+                    "repr",
+                    match repr {
+                        modifier::EraRepr::Short => None,
+                        modifier::EraRepr::Common => Some("common"),
+                        modifier::EraRepr::Long => Some("long"),
+                        modifier::EraRepr::Narrow => Some("narrow"),
+                    },
+                )?;
+                write_modifier(f, "case_sensitive", case_sensitive(is_case_sensitive))?;
+            }
+            Self::Year(modifier::Year {
+                padding: pad,
+                width,
+                repr,
+                range,
+                iso_week_based,
+                sign_is_mandatory,
+                pivot,
+                era_based,
+            }) => {
+                write_modifier(f, "padding", padding(pad))?;
+                write_modifier(f, "width", (width != 0).then_some(width))?;
+                write_modifier(
+                    f,
+                    "repr",
+                    match repr {
+                        modifier::YearRepr::Full => None,
+                        modifier::YearRepr::Century => Some("century"),
+                        modifier::YearRepr::LastTwo => Some("last_two"),
+                    },
+                )?;
+                write_modifier(
+                    f,
+                    "range",
+                    match range {
+                        modifier::YearRange::Standard => Some("standard"),
+                        modifier::YearRange::Extended => None,
+                    },
+                )?;
+                write_modifier(f, "base", iso_week_based.then_some("iso_week"))?;
+                write_modifier(f, "sign", sign(sign_is_mandatory))?;
+                match (pivot, pivot.fixed_start()) {
+                    (_, Some(year)) => write!(f, " pivot:{year}")?,
+                    (modifier::YearPivot::POSIX, None) => f.write_str(" pivot:posix")?,
+                    (modifier::YearPivot::SLIDING, None) => f.write_str(" pivot:sliding")?,
+                    (_, None) => {}
+                }
+                write_modifier(f, "era_based", era_based.then_some("true"))?;
+            }
+            Self::Hour(modifier::Hour {
+                padding: pad,
+                width,
+                is_12_hour_clock,
+            }) => {
+                write_modifier(f, "padding", padding(pad))?;
+                write_modifier(f, "width", (width != 0).then_some(width))?;
+                write_modifier(f, "repr", is_12_hour_clock.then_some("12"))?;
+            }
+            Self::Period(modifier::Period {
+                is_uppercase,
+                repr,
+                case_sensitive: is_case_sensitive,
+            }) => {
+                write_modifier(f, "case", (!is_uppercase).then_some("lower"))?;
+                write_modifier(
+                    f,
+                    "repr",
+                    match repr {
+                        modifier::PeriodRepr::Short => None,
+                        modifier::PeriodRepr::Narrow => Some("narrow"),
+                    },
+                )?;
+                write_modifier(f, "case_sensitive", case_sensitive(is_case_sensitive))?;
+            }
+            Self::Subsecond(modifier::Subsecond { digits }) => write_modifier(
+                f,
+                "digits",
+                match digits {
+                    modifier::SubsecondDigits::One => Some("1"),
+                    modifier::SubsecondDigits::Two => Some("2"),
+                    modifier::SubsecondDigits::Three => Some("3"),
+                    modifier::SubsecondDigits::Four => Some("4"),
+                    modifier::SubsecondDigits::Five => Some("5"),
+                    modifier::SubsecondDigits::Six => Some("6"),
+                    modifier::SubsecondDigits::Seven => Some("7"),
+                    modifier::SubsecondDigits::Eight => Some("8"),
+                    modifier::SubsecondDigits::Nine => Some("9"),
+                    modifier::SubsecondDigits::OneOrMore => None,
+                },
+            )?,
+            Self::OffsetHour(modifier::OffsetHour {
+                sign_is_mandatory,
+                padding: pad,
+            }) => {
+                write_modifier(f, "sign", sign(sign_is_mandatory))?;
+                write_modifier(f, "padding", padding(pad))?;
+            }
+            Self::Ignore(modifier::Ignore { count }) => write_modifier(f, "count", Some(count))?,
+            Self::UnixTimestamp(modifier::UnixTimestamp {
+                precision,
+                sign_is_mandatory,
+            }) => {
+                write_modifier(
+                    f,
+                    "precision",
+                    match precision {
+                        modifier::UnixTimestampPrecision::Second => None,
+                        modifier::UnixTimestampPrecision::Millisecond => Some("millisecond"),
+                        modifier::UnixTimestampPrecision::Microsecond => Some("microsecond"),
+                        modifier::UnixTimestampPrecision::Nanosecond => Some("nanosecond"),
+                    },
+                )?;
+                write_modifier(f, "sign", sign(sign_is_mandatory))?;
+            }
+            Self::JulianDay(modifier::JulianDay { repr, digits }) => {
+                write_modifier(
+                    f,
+                    "repr",
+                    match repr {
+                        modifier::JulianDayRepr::Julian => None,
+                        modifier::JulianDayRepr::Modified => Some("modified"),
+                    },
+                )?;
+                write_modifier(f, "digits", (digits != 0).then(|| digits.min(14)))?;
+            }
+            Self::End(modifier::End)
+            | Self::TimeZoneAnnotation(modifier::TimeZoneAnnotation)
+            | Self::ExtensionAnnotations(modifier::ExtensionAnnotations) => {}
+        }
+
+        f.write_str("]")
+    }
 }
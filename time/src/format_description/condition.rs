@@ -0,0 +1,63 @@
+//! Conditions that select between format items.
+
+use core::fmt;
+
+/// A condition on the value being formatted, used by [`BorrowedFormatItem::Conditional`] and
+/// [`OwnedFormatItem::Conditional`] to select which item is used.
+///
+/// When parsing, a successful parse of the item used when the condition holds implies that the
+/// condition holds. The relevant values are set accordingly if they were not parsed otherwise.
+///
+/// [`BorrowedFormatItem::Conditional`]: crate::format_description::BorrowedFormatItem::Conditional
+/// [`OwnedFormatItem::Conditional`]: crate::format_description::OwnedFormatItem::Conditional
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Condition {
+    /// The UTC offset is zero. When parsing, this implies an offset of zero hours, minutes, and
+    /// seconds.
+    OffsetIsUtc,
+    /// The minute of the UTC offset is zero. When parsing, this implies that the minute of the UTC
+    /// offset is zero.
+    OffsetMinuteIsZero,
+    /// The second of the UTC offset is zero. When parsing, this implies that the second of the UTC
+    /// offset is zero.
+    OffsetSecondIsZero,
+    /// The second within the minute is zero. The subsecond is not considered. When parsing, this
+    /// implies a second of zero.
+    SecondIsZero,
+    /// The subsecond within the second is zero. When parsing, this implies a subsecond of zero.
+    SubsecondIsZero,
+}
+
+impl Condition {
+    /// The name of the condition, as used in format descriptions.
+    pub(crate) const fn name(self) -> &'static str {
+        match self {
+            Self::OffsetIsUtc => "offset_is_utc",
+            Self::OffsetMinuteIsZero => "offset_minute_is_zero",
+            Self::OffsetSecondIsZero => "offset_second_is_zero",
+            Self::SecondIsZero => "second_is_zero",
+            Self::SubsecondIsZero => "subsecond_is_zero",
+        }
+    }
+
+    /// Obtain the condition with the provided name, as used in format descriptions.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn from_name(name: &[u8]) -> Option<Self> {
+        match name {
+            b"offset_is_utc" => Some(Self::OffsetIsUtc),
+            b"offset_minute_is_zero" => Some(Self::OffsetMinuteIsZero),
+            b"offset_second_is_zero" => Some(Self::OffsetSecondIsZero),
+            b"second_is_zero" => Some(Self::SecondIsZero),
+            b"subsecond_is_zero" => Some(Self::SubsecondIsZero),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Condition {
+    /// Write the condition as it would appear in a version 2 format description.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
@@ -7,8 +7,11 @@
 //! For examples, see the implementors of [Formattable](crate::formatting::Formattable),
 //! e.g. [`well_known::Rfc3339`].
 
+pub mod analysis;
 mod borrowed_format_item;
+mod builder;
 mod component;
+mod condition;
 pub mod modifier;
 #[cfg(feature = "alloc")]
 mod owned_format_item;
@@ -22,20 +25,27 @@ pub use borrowed_format_item::BorrowedFormatItem as FormatItem;
 #[cfg(feature = "alloc")]
 pub use owned_format_item::OwnedFormatItem;
 
+pub use self::builder::FormatBuilder;
 pub use self::component::Component;
+pub use self::condition::Condition;
 #[cfg(feature = "alloc")]
 pub use self::parse::{
-    parse, parse_borrowed, parse_owned, parse_strftime_borrowed, parse_strftime_owned,
+    parse, parse_borrowed, parse_icu_owned, parse_owned, parse_strftime_borrowed,
+    parse_strftime_owned,
 };
 
 /// Well-known formats, typically standards.
 pub mod well_known {
+    mod http_date;
     pub mod iso8601;
     mod rfc2822;
     mod rfc3339;
+    mod rfc9557;
 
+    pub use http_date::HttpDate;
     #[doc(inline)]
     pub use iso8601::Iso8601;
     pub use rfc2822::Rfc2822;
     pub use rfc3339::Rfc3339;
+    pub use rfc9557::Rfc9557;
 }
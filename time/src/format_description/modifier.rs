@@ -8,6 +8,9 @@ use core::num::NonZero;
 pub struct Day {
     /// The padding to obtain the minimum width.
     pub padding: Padding,
+    /// The minimum width, which is obtained with the padding. This has no effect unless it exceeds
+    /// the natural width of the value.
+    pub width: u8,
 }
 
 /// The representation of a month.
@@ -20,6 +23,23 @@ pub enum MonthRepr {
     Long,
     /// The short form of the month name (e.g. "Jan").
     Short,
+    /// The narrow form of the month name, which is its first letter (e.g. "J").
+    ///
+    /// As the narrow form does not uniquely identify a month, it is not used to determine the
+    /// month when parsing.
+    Narrow,
+}
+
+/// The letter case used for a textual value.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextCase {
+    /// Only the first letter is uppercase (e.g. "January").
+    Title,
+    /// All letters are uppercase (e.g. "JANUARY").
+    Upper,
+    /// All letters are lowercase (e.g. "january").
+    Lower,
 }
 
 /// Month of the year.
@@ -28,8 +48,14 @@ pub enum MonthRepr {
 pub struct Month {
     /// The padding to obtain the minimum width.
     pub padding: Padding,
+    /// The minimum width, which is obtained with the padding. This has no effect unless it exceeds
+    /// the natural width of the value.
+    pub width: u8,
     /// What form of representation should be used?
     pub repr: MonthRepr,
+    /// The letter case of the textual representations. This has no effect on the numerical
+    /// representation.
+    pub case: TextCase,
     /// Is the value case sensitive when parsing?
     pub case_sensitive: bool,
 }
@@ -40,6 +66,9 @@ pub struct Month {
 pub struct Ordinal {
     /// The padding to obtain the minimum width.
     pub padding: Padding,
+    /// The minimum width, which is obtained with the padding. This has no effect unless it exceeds
+    /// the natural width of the value.
+    pub width: u8,
 }
 
 /// The representation used for the day of the week.
@@ -50,6 +79,11 @@ pub enum WeekdayRepr {
     Short,
     /// The long form of the weekday (e.g. "Monday").
     Long,
+    /// The narrow form of the weekday, which is its first letter (e.g. "M").
+    ///
+    /// As the narrow form does not uniquely identify a weekday, it is not used to determine the
+    /// weekday when parsing.
+    Narrow,
     /// A numerical representation using Sunday as the first day of the week.
     ///
     /// Sunday is either 0 or 1, depending on the other modifier's value.
@@ -68,6 +102,9 @@ pub struct Weekday {
     pub repr: WeekdayRepr,
     /// When using a numerical representation, should it be zero or one-indexed?
     pub one_indexed: bool,
+    /// The letter case of the textual representations. This has no effect on the numerical
+    /// representations.
+    pub case: TextCase,
     /// Is the value case sensitive when parsing?
     pub case_sensitive: bool,
 }
@@ -90,10 +127,78 @@ pub enum WeekNumberRepr {
 pub struct WeekNumber {
     /// The padding to obtain the minimum width.
     pub padding: Padding,
+    /// The minimum width, which is obtained with the padding. This has no effect unless it exceeds
+    /// the natural width of the value.
+    pub width: u8,
     /// What kind of representation should be used?
     pub repr: WeekNumberRepr,
 }
 
+/// Week within the month.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeekOfMonth {
+    /// The day of the week that each week begins on.
+    pub first_weekday: crate::Weekday,
+    /// The minimum number of days of the month that must be in the first week. Any days before
+    /// the first week are in week zero. A value of zero is treated as one, and values greater than
+    /// seven are treated as seven.
+    pub minimal_days: u8,
+}
+
+/// Occurrence of the day of the week within the month, such as the 2 in "the 2nd Tuesday".
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WeekdayInMonth {
+    /// Is the occurrence counted from the end of the month? If so, the last occurrence is 1.
+    pub from_end: bool,
+}
+
+/// The representation of a quarter.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuarterRepr {
+    /// The number of the quarter (e.g. "3").
+    Numerical,
+    /// The number of the quarter preceded by the letter "Q" (e.g. "Q3").
+    Short,
+}
+
+/// Quarter of the year.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Quarter {
+    /// What form of representation should be used?
+    pub repr: QuarterRepr,
+    /// Is the value case sensitive when parsing? This has no effect on the numerical
+    /// representation.
+    pub case_sensitive: bool,
+}
+
+/// The representation of an era.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EraRepr {
+    /// The abbreviation of the Christian era (e.g. "BC" or "AD").
+    Short,
+    /// The abbreviation of the common era (e.g. "BCE" or "CE").
+    Common,
+    /// The full name of the Christian era (e.g. "Before Christ" or "Anno Domini").
+    Long,
+    /// The first letter of the Christian era (e.g. "B" or "A").
+    Narrow,
+}
+
+/// Era of the date, which is either before or after the year 1 of the Gregorian calendar.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Era {
+    /// What form of representation should be used?
+    pub repr: EraRepr,
+    /// Is the value case sensitive when parsing?
+    pub case_sensitive: bool,
+}
+
 /// The representation used for a year value.
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -121,12 +226,186 @@ pub enum YearRange {
     Extended,
 }
 
+/// How the last two digits of a year are resolved to a full year when the century is not known.
+///
+/// This modifier only has an effect when parsing a year with the [`LastTwo`](YearRepr::LastTwo)
+/// repr. If the century is also parsed, it takes precedence over the pivot.
+///
+/// A fixed pivot is stored as an offset from 1900 to keep the modifier small, so the window it
+/// describes must begin between 1900 and 2152 inclusive.
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct YearPivot(u8);
+
+impl core::fmt::Debug for YearPivot {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match *self {
+            Self::NONE => f.write_str("None"),
+            Self::POSIX => f.write_str("Posix"),
+            Self::SLIDING => f.write_str("Sliding"),
+            Self(offset) => f
+                .debug_tuple("Fixed")
+                .field(&(Self::FIXED_MIN + offset as i32))
+                .finish(),
+        }
+    }
+}
+
+impl YearPivot {
+    /// The first year a fixed window can begin with.
+    const FIXED_MIN: i32 = 1900;
+    /// The last year a fixed window can begin with.
+    const FIXED_MAX: i32 = Self::FIXED_MIN + Self::POSIX.0 as i32 - 1;
+
+    /// The year is not resolved; the century must be provided separately.
+    pub const NONE: Self = Self(u8::MAX);
+    /// The year is resolved according to POSIX: 69 through 99 are resolved to 1969 through 1999,
+    /// while 00 through 68 are resolved to 2000 through 2068.
+    pub const POSIX: Self = Self(u8::MAX - 2);
+    /// The year is resolved to the window beginning 80 years before a reference year and ending 19
+    /// years after it, as is done by ICU and Java.
+    ///
+    /// The reference year is the year of the [`Defaults`](crate::parsing::Defaults) used to
+    /// resolve the value, if any. Otherwise, it is the current year if the `std` feature is
+    /// enabled. Without a reference year, the year is not resolved.
+    pub const SLIDING: Self = Self(u8::MAX - 1);
+
+    /// Create a pivot that resolves years to the 100-year window beginning with the provided
+    /// year. With a value of 1950, the years 50 through 99 are resolved to 1950 through 1999,
+    /// while 00 through 49 are resolved to 2000 through 2049.
+    ///
+    /// Returns `None` if the window does not begin between 1900 and 2152 inclusive.
+    ///
+    /// ```rust
+    /// # use time::format_description::modifier::YearPivot;
+    /// assert!(YearPivot::fixed(1950).is_some());
+    /// assert_eq!(YearPivot::fixed(1850), None);
+    /// ```
+    pub const fn fixed(start: i32) -> Option<Self> {
+        if start < Self::FIXED_MIN || start > Self::FIXED_MAX {
+            return None;
+        }
+        Some(Self((start - Self::FIXED_MIN) as u8))
+    }
+
+    /// Create a fixed pivot from a year that is already known to be in range, such as one validated
+    /// by the `format_description!` macro.
+    #[doc(hidden)]
+    pub const fn __fixed(start: i32) -> Self {
+        match Self::fixed(start) {
+            Some(pivot) => pivot,
+            None => panic!("year pivot out of range"),
+        }
+    }
+
+    /// Create a fixed pivot that resolves years to a window relative to the reference year. A year
+    /// is placed no more than `years_in_future` years after the reference year, and otherwise in
+    /// the past. Values of `years_in_future` greater than 99 are treated as 99.
+    ///
+    /// Returns `None` if the resulting window cannot be represented by [`YearPivot::fixed`].
+    ///
+    /// ```rust
+    /// # use time::format_description::modifier::YearPivot;
+    /// // RFC 9110: a year more than 50 years in the future is in the past.
+    /// let pivot = YearPivot::relative_to(2024, 50);
+    /// assert_eq!(pivot, YearPivot::fixed(1975));
+    /// ```
+    pub const fn relative_to(reference_year: i32, years_in_future: u8) -> Option<Self> {
+        let years_in_future = if years_in_future > 99 {
+            99
+        } else {
+            years_in_future as i32
+        };
+        Self::fixed(
+            reference_year
+                .saturating_add(years_in_future)
+                .saturating_sub(99),
+        )
+    }
+
+    /// Obtain the first year of the window, if the pivot is fixed.
+    ///
+    /// ```rust
+    /// # use time::format_description::modifier::YearPivot;
+    /// assert_eq!(
+    ///     YearPivot::fixed(1950).and_then(YearPivot::fixed_start),
+    ///     Some(1950)
+    /// );
+    /// assert_eq!(YearPivot::POSIX.fixed_start(), None);
+    /// ```
+    pub const fn fixed_start(self) -> Option<i32> {
+        match self {
+            Self::NONE | Self::POSIX | Self::SLIDING => None,
+            Self(offset) => Some(Self::FIXED_MIN + offset as i32),
+        }
+    }
+
+    /// Resolve the last two digits of a year to the full year. Returns `None` if the pivot is
+    /// [`YearPivot::NONE`] or [`YearPivot::SLIDING`], or if `last_two` is greater than 99.
+    ///
+    /// ```rust
+    /// # use time::format_description::modifier::YearPivot;
+    /// assert_eq!(YearPivot::POSIX.resolve(68), Some(2068));
+    /// assert_eq!(YearPivot::POSIX.resolve(69), Some(1969));
+    /// assert_eq!(
+    ///     YearPivot::fixed(1950).and_then(|pivot| pivot.resolve(49)),
+    ///     Some(2049)
+    /// );
+    /// assert_eq!(YearPivot::NONE.resolve(49), None);
+    /// assert_eq!(YearPivot::SLIDING.resolve(49), None);
+    /// ```
+    pub const fn resolve(self, last_two: u8) -> Option<i32> {
+        let start = match self {
+            Self::NONE | Self::SLIDING => return None,
+            Self::POSIX => 1969,
+            Self(offset) => Self::FIXED_MIN + offset as i32,
+        };
+        Self::resolve_in_window(start, last_two)
+    }
+
+    /// Resolve the last two digits of a year to the full year, using the provided reference year
+    /// for [`YearPivot::SLIDING`]. Other pivots ignore the reference year.
+    ///
+    /// ```rust
+    /// # use time::format_description::modifier::YearPivot;
+    /// assert_eq!(
+    ///     YearPivot::SLIDING.resolve_with_reference(43, 2024),
+    ///     Some(2043)
+    /// );
+    /// assert_eq!(
+    ///     YearPivot::SLIDING.resolve_with_reference(44, 2024),
+    ///     Some(1944)
+    /// );
+    /// assert_eq!(
+    ///     YearPivot::POSIX.resolve_with_reference(45, 2024),
+    ///     Some(2045)
+    /// );
+    /// ```
+    pub const fn resolve_with_reference(self, last_two: u8, reference_year: i32) -> Option<i32> {
+        match self {
+            Self::SLIDING => Self::resolve_in_window(reference_year.saturating_sub(80), last_two),
+            _ => self.resolve(last_two),
+        }
+    }
+
+    /// Resolve the last two digits of a year to the 100-year window beginning with `start`.
+    const fn resolve_in_window(start: i32, last_two: u8) -> Option<i32> {
+        if last_two > 99 {
+            return None;
+        }
+        start.checked_add((last_two as i32 - start.rem_euclid(100)).rem_euclid(100))
+    }
+}
+
 /// Year of the date.
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Year {
     /// The padding to obtain the minimum width.
     pub padding: Padding,
+    /// The minimum width, including any sign, which is obtained with the padding. This has no
+    /// effect unless it exceeds the natural width of the value. Zeroes are placed after the sign,
+    /// while spaces are placed before it.
+    pub width: u8,
     /// What kind of representation should be used?
     pub repr: YearRepr,
     /// What range of years is supported?
@@ -135,6 +414,18 @@ pub struct Year {
     pub iso_week_based: bool,
     /// Whether the `+` sign is present when a positive year contains fewer than five digits.
     pub sign_is_mandatory: bool,
+    /// How the last two digits are resolved to a full year when parsing.
+    pub pivot: YearPivot,
+    /// Whether the value is the year of the [era](Era) rather than the astronomical year. The
+    /// year of the era is never negative: 1 BCE is the astronomical year 0.
+    ///
+    /// As the year alone does not indicate its era, formatting a year before the common era fails
+    /// unless the format description also contains the era. When parsing, a year that is not
+    /// era-based must agree with the era if both are present.
+    ///
+    /// This modifier only has an effect when the year repr is [`Full`](YearRepr::Full) and the
+    /// year is not based on the ISO week number.
+    pub era_based: bool,
 }
 
 /// Hour of the day.
@@ -143,6 +434,9 @@ pub struct Year {
 pub struct Hour {
     /// The padding to obtain the minimum width.
     pub padding: Padding,
+    /// The minimum width, which is obtained with the padding. This has no effect unless it exceeds
+    /// the natural width of the value.
+    pub width: u8,
     /// Is the hour displayed using a 12 or 24-hour clock?
     pub is_12_hour_clock: bool,
 }
@@ -153,6 +447,19 @@ pub struct Hour {
 pub struct Minute {
     /// The padding to obtain the minimum width.
     pub padding: Padding,
+    /// The minimum width, which is obtained with the padding. This has no effect unless it exceeds
+    /// the natural width of the value.
+    pub width: u8,
+}
+
+/// The representation used for the AM/PM part of the time.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PeriodRepr {
+    /// The short form of the period (e.g. "AM").
+    Short,
+    /// The narrow form of the period, which is its first letter (e.g. "A").
+    Narrow,
 }
 
 /// AM/PM part of the time.
@@ -161,6 +468,8 @@ pub struct Minute {
 pub struct Period {
     /// Is the period uppercase or lowercase?
     pub is_uppercase: bool,
+    /// What form of representation should be used?
+    pub repr: PeriodRepr,
     /// Is the value case sensitive when parsing?
     ///
     /// Note that when `false`, the `is_uppercase` field has no effect on parsing behavior.
@@ -173,6 +482,9 @@ pub struct Period {
 pub struct Second {
     /// The padding to obtain the minimum width.
     pub padding: Padding,
+    /// The minimum width, which is obtained with the padding. This has no effect unless it exceeds
+    /// the natural width of the value.
+    pub width: u8,
 }
 
 /// The number of digits present in a subsecond representation.
@@ -292,6 +604,31 @@ pub struct UnixTimestamp {
     pub sign_is_mandatory: bool,
 }
 
+/// The day from which a Julian day is counted.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JulianDayRepr {
+    /// Days since noon UTC on -4713-11-24, such that 2000-01-01T12:00 UTC is 2451545.0.
+    Julian,
+    /// Days since midnight UTC on 1858-11-17, such that 2000-01-01T00:00 UTC is 51544.0.
+    Modified,
+}
+
+/// A day counted continuously from an epoch, as used in astronomy.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct JulianDay {
+    /// The day from which the value is counted.
+    pub repr: JulianDayRepr,
+    /// The number of digits in the fraction of the day, up to 14. Values greater than 14 are
+    /// treated as 14.
+    ///
+    /// With no digits, the value is the day number of the date. Otherwise, the value is the
+    /// moment in UTC, truncated to the number of digits. Fourteen digits are sufficient to
+    /// represent every nanosecond.
+    pub digits: u8,
+}
+
 /// The end of input.
 ///
 /// There is currently not customization for this modifier.
@@ -299,6 +636,287 @@ pub struct UnixTimestamp {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct End;
 
+/// A time zone annotation, such as `[Europe/Paris]` or `[+01:00]`, as defined in RFC 9557.
+///
+/// There is currently no customization for this modifier.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeZoneAnnotation;
+
+/// Any number of extension annotations, such as `[u-ca=hebrew]`, as defined in RFC 9557.
+///
+/// There is currently no customization for this modifier.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExtensionAnnotations;
+
+/// Implement methods that replace a single field of a modifier. As modifiers are
+/// `#[non_exhaustive]`, this permits constructing them with non-default values, including in
+/// `const` contexts.
+macro_rules! impl_with {
+    ($($type:ident { $($method:ident: $field:ident: $field_ty:ty),* $(,)? })*) => {$(
+        impl $type {$(
+            #[doc = concat!(
+                "Replace the [`", stringify!($field), "`](Self::", stringify!($field), ") of the ",
+                "modifier."
+            )]
+            #[must_use = "this does not modify the original value"]
+            pub const fn $method(self, $field: $field_ty) -> Self {
+                Self { $field, ..self }
+            }
+        )*}
+    )*};
+}
+
+impl_with! {
+    Day {
+        with_padding: padding: Padding,
+        with_width: width: u8,
+    }
+    Month {
+        with_padding: padding: Padding,
+        with_width: width: u8,
+        with_repr: repr: MonthRepr,
+        with_case: case: TextCase,
+        with_case_sensitive: case_sensitive: bool,
+    }
+    Ordinal {
+        with_padding: padding: Padding,
+        with_width: width: u8,
+    }
+    Weekday {
+        with_repr: repr: WeekdayRepr,
+        with_one_indexed: one_indexed: bool,
+        with_case: case: TextCase,
+        with_case_sensitive: case_sensitive: bool,
+    }
+    WeekNumber {
+        with_padding: padding: Padding,
+        with_width: width: u8,
+        with_repr: repr: WeekNumberRepr,
+    }
+    WeekOfMonth {
+        with_first_weekday: first_weekday: crate::Weekday,
+        with_minimal_days: minimal_days: u8,
+    }
+    WeekdayInMonth { with_from_end: from_end: bool }
+    Quarter {
+        with_repr: repr: QuarterRepr,
+        with_case_sensitive: case_sensitive: bool,
+    }
+    Era {
+        with_repr: repr: EraRepr,
+        with_case_sensitive: case_sensitive: bool,
+    }
+    Year {
+        with_padding: padding: Padding,
+        with_width: width: u8,
+        with_repr: repr: YearRepr,
+        with_range: range: YearRange,
+        with_iso_week_based: iso_week_based: bool,
+        with_sign_is_mandatory: sign_is_mandatory: bool,
+        with_pivot: pivot: YearPivot,
+        with_era_based: era_based: bool,
+    }
+    Hour {
+        with_padding: padding: Padding,
+        with_width: width: u8,
+        with_is_12_hour_clock: is_12_hour_clock: bool,
+    }
+    Minute {
+        with_padding: padding: Padding,
+        with_width: width: u8,
+    }
+    Period {
+        with_is_uppercase: is_uppercase: bool,
+        with_repr: repr: PeriodRepr,
+        with_case_sensitive: case_sensitive: bool,
+    }
+    Second {
+        with_padding: padding: Padding,
+        with_width: width: u8,
+    }
+    Subsecond { with_digits: digits: SubsecondDigits }
+    OffsetHour {
+        with_sign_is_mandatory: sign_is_mandatory: bool,
+        with_padding: padding: Padding,
+    }
+    OffsetMinute { with_padding: padding: Padding }
+    OffsetSecond { with_padding: padding: Padding }
+    UnixTimestamp {
+        with_precision: precision: UnixTimestampPrecision,
+        with_sign_is_mandatory: sign_is_mandatory: bool,
+    }
+    JulianDay {
+        with_repr: repr: JulianDayRepr,
+        with_digits: digits: u8,
+    }
+}
+
+impl Month {
+    /// Creates a modifier for the [number](MonthRepr::Numerical) of the month, which is otherwise
+    /// the [default](Self::default).
+    pub const fn numerical() -> Self {
+        Self::default()
+    }
+
+    /// Creates a modifier for the [short name](MonthRepr::Short) of the month, such as "Jan".
+    pub const fn short() -> Self {
+        Self::default().with_repr(MonthRepr::Short)
+    }
+
+    /// Creates a modifier for the [long name](MonthRepr::Long) of the month, such as "January".
+    pub const fn long() -> Self {
+        Self::default().with_repr(MonthRepr::Long)
+    }
+
+    /// Creates a modifier for the [narrow name](MonthRepr::Narrow) of the month, such as "J".
+    pub const fn narrow() -> Self {
+        Self::default().with_repr(MonthRepr::Narrow)
+    }
+}
+
+impl Weekday {
+    /// Creates a modifier for the [short name](WeekdayRepr::Short) of the weekday, such as "Mon".
+    pub const fn short() -> Self {
+        Self::default().with_repr(WeekdayRepr::Short)
+    }
+
+    /// Creates a modifier for the [long name](WeekdayRepr::Long) of the weekday, such as
+    /// "Monday", which is otherwise the [default](Self::default).
+    pub const fn long() -> Self {
+        Self::default()
+    }
+
+    /// Creates a modifier for the [narrow name](WeekdayRepr::Narrow) of the weekday, such as "M".
+    pub const fn narrow() -> Self {
+        Self::default().with_repr(WeekdayRepr::Narrow)
+    }
+
+    /// Creates a modifier for the one-indexed [number](WeekdayRepr::Sunday) of the weekday, with
+    /// Sunday as the first day of the week.
+    pub const fn sunday_based() -> Self {
+        Self::default().with_repr(WeekdayRepr::Sunday)
+    }
+
+    /// Creates a modifier for the one-indexed [number](WeekdayRepr::Monday) of the weekday, with
+    /// Monday as the first day of the week.
+    pub const fn monday_based() -> Self {
+        Self::default().with_repr(WeekdayRepr::Monday)
+    }
+}
+
+impl WeekNumber {
+    /// Creates a modifier for the [ISO](WeekNumberRepr::Iso) week number, which is otherwise the
+    /// [default](Self::default).
+    pub const fn iso() -> Self {
+        Self::default()
+    }
+
+    /// Creates a modifier for the week number where week 1 begins on the first
+    /// [Sunday](WeekNumberRepr::Sunday) of the year.
+    pub const fn sunday_based() -> Self {
+        Self::default().with_repr(WeekNumberRepr::Sunday)
+    }
+
+    /// Creates a modifier for the week number where week 1 begins on the first
+    /// [Monday](WeekNumberRepr::Monday) of the year.
+    pub const fn monday_based() -> Self {
+        Self::default().with_repr(WeekNumberRepr::Monday)
+    }
+}
+
+impl Quarter {
+    /// Creates a modifier for the [number](QuarterRepr::Numerical) of the quarter, which is
+    /// otherwise the [default](Self::default).
+    pub const fn numerical() -> Self {
+        Self::default()
+    }
+
+    /// Creates a modifier for the [short form](QuarterRepr::Short) of the quarter, such as "Q3".
+    pub const fn short() -> Self {
+        Self::default().with_repr(QuarterRepr::Short)
+    }
+}
+
+impl Era {
+    /// Creates a modifier for the [abbreviation of the Christian era](EraRepr::Short), such as
+    /// "BC", which is otherwise the [default](Self::default).
+    pub const fn short() -> Self {
+        Self::default()
+    }
+
+    /// Creates a modifier for the [abbreviation of the common era](EraRepr::Common), such as
+    /// "BCE".
+    pub const fn common() -> Self {
+        Self::default().with_repr(EraRepr::Common)
+    }
+
+    /// Creates a modifier for the [full name of the Christian era](EraRepr::Long), such as
+    /// "Before Christ".
+    pub const fn long() -> Self {
+        Self::default().with_repr(EraRepr::Long)
+    }
+
+    /// Creates a modifier for the [first letter of the Christian era](EraRepr::Narrow), such as
+    /// "B".
+    pub const fn narrow() -> Self {
+        Self::default().with_repr(EraRepr::Narrow)
+    }
+}
+
+impl Year {
+    /// Creates a modifier for the [full](YearRepr::Full) year, which is otherwise the
+    /// [default](Self::default).
+    pub const fn full() -> Self {
+        Self::default()
+    }
+
+    /// Creates a modifier for the full year of the [era](Self::era_based), which is never
+    /// negative. An [`Era`] is necessary to parse years before the common era.
+    pub const fn year_of_era() -> Self {
+        Self::default().with_era_based(true)
+    }
+
+    /// Creates a modifier for the [century](YearRepr::Century) of the year.
+    pub const fn century() -> Self {
+        Self::default().with_repr(YearRepr::Century)
+    }
+
+    /// Creates a modifier for the [last two digits](YearRepr::LastTwo) of the year. Unless a
+    /// [pivot](Self::with_pivot) is provided, the century must be parsed separately.
+    pub const fn last_two() -> Self {
+        Self::default().with_repr(YearRepr::LastTwo)
+    }
+}
+
+impl Hour {
+    /// Creates a modifier for the hour on a 24-hour clock, which is otherwise the
+    /// [default](Self::default).
+    pub const fn twenty_four_hour() -> Self {
+        Self::default()
+    }
+
+    /// Creates a modifier for the hour on a 12-hour clock. A [`Period`] is necessary to parse the
+    /// value.
+    pub const fn twelve_hour() -> Self {
+        Self::default().with_is_12_hour_clock(true)
+    }
+}
+
+impl JulianDay {
+    /// Creates a modifier for the [Julian day](JulianDayRepr::Julian), which is otherwise the
+    /// [default](Self::default).
+    pub const fn julian() -> Self {
+        Self::default()
+    }
+
+    /// Creates a modifier for the [modified Julian day](JulianDayRepr::Modified).
+    pub const fn modified() -> Self {
+        Self::default().with_repr(JulianDayRepr::Modified)
+    }
+}
+
 /// Generate the provided code if and only if `pub` is present.
 macro_rules! if_pub {
     (pub $(#[$attr:meta])*; $($x:tt)*) => {
@@ -340,28 +958,40 @@ macro_rules! impl_const_default {
 
 impl_const_default! {
     /// Creates a modifier that indicates the value is [padded with zeroes](Padding::Zero).
-    @pub Day => Self { padding: Padding::Zero };
+    @pub Day => Self {
+        padding: Padding::Zero,
+        width: 0,
+    };
     /// Creates a modifier that indicates the value uses the
     /// [`Numerical`](Self::Numerical) representation.
     MonthRepr => Self::Numerical;
+    /// Creates a modifier that indicates the value uses [title case](Self::Title).
+    TextCase => Self::Title;
     /// Creates an instance of this type that indicates the value uses the
     /// [`Numerical`](MonthRepr::Numerical) representation, is [padded with zeroes](Padding::Zero),
-    /// and is case-sensitive when parsing.
+    /// and is case-sensitive when parsing. Textual representations use [title
+    /// case](TextCase::Title).
     @pub Month => Self {
         padding: Padding::Zero,
+        width: 0,
         repr: MonthRepr::Numerical,
+        case: TextCase::Title,
         case_sensitive: true,
     };
     /// Creates a modifier that indicates the value is [padded with zeroes](Padding::Zero).
-    @pub Ordinal => Self { padding: Padding::Zero };
+    @pub Ordinal => Self {
+        padding: Padding::Zero,
+        width: 0,
+    };
     /// Creates a modifier that indicates the value uses the [`Long`](Self::Long) representation.
     WeekdayRepr => Self::Long;
     /// Creates a modifier that indicates the value uses the [`Long`](WeekdayRepr::Long)
-    /// representation and is case-sensitive when parsing. If the representation is changed to a
-    /// numerical one, the instance defaults to one-based indexing.
+    /// representation in [title case](TextCase::Title) and is case-sensitive when parsing. If the
+    /// representation is changed to a numerical one, the instance defaults to one-based indexing.
     @pub Weekday => Self {
         repr: WeekdayRepr::Long,
         one_indexed: true,
+        case: TextCase::Title,
         case_sensitive: true,
     };
     /// Creates a modifier that indicates that the value uses the [`Iso`](Self::Iso) representation.
@@ -370,38 +1000,80 @@ impl_const_default! {
             /// and uses the [`Iso`](WeekNumberRepr::Iso) representation.
     @pub WeekNumber => Self {
         padding: Padding::Zero,
+        width: 0,
         repr: WeekNumberRepr::Iso,
     };
+    /// Creates a modifier that indicates that weeks begin on Monday and that the first week
+    /// contains the first day of the month.
+    @pub WeekOfMonth => Self {
+        first_weekday: crate::Weekday::Monday,
+        minimal_days: 1,
+    };
+    /// Creates a modifier that indicates that the occurrence is counted from the start of the
+    /// month.
+    @pub WeekdayInMonth => Self { from_end: false };
+    /// Creates a modifier that indicates the value uses the [`Numerical`](Self::Numerical)
+    /// representation.
+    QuarterRepr => Self::Numerical;
+    /// Creates a modifier that indicates the value uses the [`Numerical`](QuarterRepr::Numerical)
+    /// representation and is case-sensitive when parsing.
+    @pub Quarter => Self {
+        repr: QuarterRepr::Numerical,
+        case_sensitive: true,
+    };
+    /// Creates a modifier that indicates the value uses the [`Short`](Self::Short) representation.
+    EraRepr => Self::Short;
+    /// Creates a modifier that indicates the value uses the [`Short`](EraRepr::Short)
+    /// representation and is case-sensitive when parsing.
+    @pub Era => Self {
+        repr: EraRepr::Short,
+        case_sensitive: true,
+    };
     /// Creates a modifier that indicates the value uses the [`Full`](Self::Full) representation.
     YearRepr => Self::Full;
     /// Creates a modifier that indicates the value uses the [`Extended`](Self::Extended) range.
     YearRange => Self::Extended;
+    /// Creates a modifier that indicates the value is [not resolved](Self::NONE) without a century.
+    YearPivot => Self::NONE;
     /// Creates a modifier that indicates the value uses the [`Full`](YearRepr::Full)
     /// representation, is [padded with zeroes](Padding::Zero), uses the Gregorian calendar as its
-    /// base, and only includes the year's sign if necessary.
+    /// base, is not relative to the era, and only includes the year's sign if necessary.
     @pub Year => Self {
         padding: Padding::Zero,
+        width: 0,
         repr: YearRepr::Full,
         range: YearRange::Extended,
         iso_week_based: false,
         sign_is_mandatory: false,
+        pivot: YearPivot::NONE,
+        era_based: false,
     };
     /// Creates a modifier that indicates the value is [padded with zeroes](Padding::Zero) and
     /// has the 24-hour representation.
     @pub Hour => Self {
         padding: Padding::Zero,
+        width: 0,
         is_12_hour_clock: false,
     };
     /// Creates a modifier that indicates the value is [padded with zeroes](Padding::Zero).
-    @pub Minute => Self { padding: Padding::Zero };
-    /// Creates a modifier that indicates the value uses the upper-case representation and is
-    /// case-sensitive when parsing.
+    @pub Minute => Self {
+        padding: Padding::Zero,
+        width: 0,
+    };
+    /// Creates a modifier that indicates the value uses the [`Short`](Self::Short) representation.
+    PeriodRepr => Self::Short;
+    /// Creates a modifier that indicates the value uses the upper-case
+    /// [`Short`](PeriodRepr::Short) representation and is case-sensitive when parsing.
     @pub Period => Self {
         is_uppercase: true,
+        repr: PeriodRepr::Short,
         case_sensitive: true,
     };
     /// Creates a modifier that indicates the value is [padded with zeroes](Padding::Zero).
-    @pub Second => Self { padding: Padding::Zero };
+    @pub Second => Self {
+        padding: Padding::Zero,
+        width: 0,
+    };
     /// Creates a modifier that indicates the stringified value contains [one or more
     /// digits](Self::OneOrMore).
     SubsecondDigits => Self::OneOrMore;
@@ -429,6 +1101,18 @@ impl_const_default! {
         precision: UnixTimestampPrecision::Second,
         sign_is_mandatory: false,
     };
+    /// Creates a modifier that indicates the value uses the [`Julian`](Self::Julian) epoch.
+    JulianDayRepr => Self::Julian;
+    /// Creates a modifier that indicates the value is the [Julian day](JulianDayRepr::Julian) of
+    /// the date, without a fraction of the day.
+    @pub JulianDay => Self {
+        repr: JulianDayRepr::Julian,
+        digits: 0,
+    };
     /// Creates a modifier used to represent the end of input.
     @pub End => End;
+    /// Creates a modifier used to represent a time zone annotation.
+    @pub TimeZoneAnnotation => TimeZoneAnnotation;
+    /// Creates a modifier used to represent any number of extension annotations.
+    @pub ExtensionAnnotations => ExtensionAnnotations;
 }
@@ -6,9 +6,14 @@ use alloc::vec::Vec;
 use core::fmt;
 
 use crate::error;
-use crate::format_description::{BorrowedFormatItem, Component};
+use crate::format_description::borrowed_format_item::{fmt_first, fmt_literal};
+use crate::format_description::{BorrowedFormatItem, Component, Condition};
 
 /// A complete description of how to format and parse a type.
+///
+/// The [`Display`](fmt::Display) implementation writes the equivalent version 2 format
+/// description. Parsing the result with [`parse_owned::<2>`](super::parse_owned) produces an
+/// equivalent item.
 #[non_exhaustive]
 #[derive(Clone, PartialEq, Eq)]
 pub enum OwnedFormatItem {
@@ -31,6 +36,18 @@ pub enum OwnedFormatItem {
     /// formatting, the first element of the [`Vec`] is used. An empty [`Vec`] is a no-op when
     /// formatting or parsing.
     First(Box<[Self]>),
+    /// One of two items, depending on whether a condition holds for the value being formatted.
+    ///
+    /// When parsing, `otherwise` is attempted first, followed by `then`. If `then` is used, the
+    /// values implied by the condition are set.
+    Conditional {
+        /// The condition that determines which item is used.
+        condition: Condition,
+        /// The item used when the condition holds.
+        then: Box<Self>,
+        /// The item used when the condition does not hold.
+        otherwise: Box<Self>,
+    },
 }
 
 impl fmt::Debug for OwnedFormatItem {
@@ -41,10 +58,69 @@ impl fmt::Debug for OwnedFormatItem {
             Self::Compound(compound) => compound.fmt(f),
             Self::Optional(item) => f.debug_tuple("Optional").field(item).finish(),
             Self::First(items) => f.debug_tuple("First").field(items).finish(),
+            Self::Conditional {
+                condition,
+                then,
+                otherwise,
+            } => f
+                .debug_struct("Conditional")
+                .field("condition", condition)
+                .field("then", then)
+                .field("otherwise", otherwise)
+                .finish(),
+        }
+    }
+}
+
+impl fmt::Display for OwnedFormatItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Literal(literal) => fmt_literal(f, literal),
+            Self::Component(component) => fmt::Display::fmt(component, f),
+            Self::Compound(items) => items.iter().try_for_each(|item| fmt::Display::fmt(item, f)),
+            Self::Optional(item) => write!(f, "[optional [{item}]]"),
+            Self::First(items) => fmt_first(f, items),
+            Self::Conditional {
+                condition,
+                then,
+                otherwise,
+            } => write!(f, "[if {condition} [{then}] [{otherwise}]]"),
         }
     }
 }
 
+impl OwnedFormatItem {
+    /// Convert the item to an equivalent `strftime` format description, if possible.
+    ///
+    /// This is a best-effort conversion. Modifiers that only affect parsing, such as whether a
+    /// component is case-sensitive, are not represented. Optional items are converted as if they
+    /// were always present, and only the first of the items in a [`OwnedFormatItem::First`] is
+    /// converted. In all of these situations, formatting with the returned description produces
+    /// the same output as formatting with the original. `None` is returned if any item has no
+    /// `strftime` equivalent.
+    ///
+    /// Combined with [`parse_strftime_owned`](super::parse_strftime_owned) and the
+    /// [`Display`](fmt::Display) implementation, this permits converting between the two syntaxes.
+    ///
+    /// ```rust
+    /// # use time::format_description;
+    /// let format = format_description::parse_strftime_owned("%a, %-d %b %Y %T %z")?;
+    /// assert_eq!(
+    ///     format.to_string(),
+    ///     "[weekday repr:short], [day padding:none] [month repr:short] [year] \
+    ///      [hour]:[minute]:[second] [first [[offset_hour sign:mandatory][offset_minute]] \
+    ///      [[offset_hour sign:mandatory]:[offset_minute]] [[offset_hour sign:mandatory]]]"
+    /// );
+    ///
+    /// let format = format_description::parse_owned::<2>("[day].[month].[year repr:last_two]")?;
+    /// assert_eq!(format.to_strftime().as_deref(), Some("%d.%m.%y"));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn to_strftime(&self) -> Option<String> {
+        super::parse::format_strftime(self)
+    }
+}
+
 impl From<BorrowedFormatItem<'_>> for OwnedFormatItem {
     fn from(item: BorrowedFormatItem<'_>) -> Self {
         (&item).into()
@@ -75,6 +151,15 @@ impl From<&BorrowedFormatItem<'_>> for OwnedFormatItem {
                     .collect::<Vec<_>>()
                     .into_boxed_slice(),
             ),
+            BorrowedFormatItem::Conditional {
+                condition,
+                then,
+                otherwise,
+            } => Self::Conditional {
+                condition: *condition,
+                then: Box::new((*then).into()),
+                otherwise: Box::new((*otherwise).into()),
+            },
         }
     }
 }
@@ -68,6 +68,27 @@ pub(super) enum Item<'a> {
         /// Where the closing bracket was in the format string.
         closing_bracket: Location,
     },
+    /// One of two sequences of items, depending on a condition.
+    Conditional {
+        /// Where the opening bracket was in the format string.
+        opening_bracket: Location,
+        /// Whitespace between the opening bracket and "if".
+        _leading_whitespace: Unused<Option<Spanned<&'a [u8]>>>,
+        /// The "if" keyword.
+        _if_kw: Unused<Spanned<&'a [u8]>>,
+        /// Whitespace between the "if" keyword and the condition.
+        _whitespace: Unused<Spanned<&'a [u8]>>,
+        /// The name of the condition.
+        condition: Spanned<&'a [u8]>,
+        /// Whitespace between the condition and the opening bracket.
+        _condition_whitespace: Unused<Spanned<&'a [u8]>>,
+        /// The items used when the condition holds.
+        then: NestedFormatDescription<'a>,
+        /// The items used when the condition does not hold.
+        otherwise: NestedFormatDescription<'a>,
+        /// Where the closing bracket was in the format string.
+        closing_bracket: Location,
+    },
 }
 
 /// A format description that is nested within another format description.
@@ -265,6 +286,62 @@ fn parse_component<
         });
     }
 
+    if *name == b"if" {
+        let Some(whitespace) = tokens.next_if_whitespace() else {
+            return Err(Error {
+                _inner: unused(name.span.error("expected whitespace after `if`")),
+                public: crate::error::InvalidFormatDescription::Expected {
+                    what: "whitespace after `if`",
+                    index: name.span.end.byte as usize,
+                },
+            });
+        };
+
+        let Some(condition) = tokens.next_if_not_whitespace() else {
+            return Err(Error {
+                _inner: unused(whitespace.span.error("expected condition")),
+                public: crate::error::InvalidFormatDescription::Expected {
+                    what: "condition",
+                    index: whitespace.span.end.byte as usize,
+                },
+            });
+        };
+
+        let Some(condition_whitespace) = tokens.next_if_whitespace() else {
+            return Err(Error {
+                _inner: unused(condition.span.error("expected whitespace after condition")),
+                public: crate::error::InvalidFormatDescription::Expected {
+                    what: "whitespace after condition",
+                    index: condition.span.end.byte as usize,
+                },
+            });
+        };
+
+        let then = parse_nested::<_, VERSION>(condition_whitespace.span.end, tokens)?;
+        let otherwise = parse_nested::<_, VERSION>(condition_whitespace.span.end, tokens)?;
+
+        let Some(closing_bracket) = tokens.next_if_closing_bracket() else {
+            return Err(Error {
+                _inner: unused(opening_bracket.error("unclosed bracket")),
+                public: crate::error::InvalidFormatDescription::UnclosedOpeningBracket {
+                    index: opening_bracket.byte as usize,
+                },
+            });
+        };
+
+        return Ok(Item::Conditional {
+            opening_bracket,
+            _leading_whitespace: unused(leading_whitespace),
+            _if_kw: unused(name),
+            _whitespace: unused(whitespace),
+            condition,
+            _condition_whitespace: unused(condition_whitespace),
+            then,
+            otherwise,
+            closing_bracket,
+        });
+    }
+
     let mut modifiers = Vec::new();
     let trailing_whitespace = loop {
         let Some(whitespace) = tokens.next_if_whitespace() else {
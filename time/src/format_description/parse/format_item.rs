@@ -6,6 +6,7 @@ use core::num::NonZero;
 use core::str::{self, FromStr};
 
 use super::{ast, unused, Error, Span, Spanned};
+use crate::format_description::Condition;
 use crate::internal_macros::bug;
 
 /// Parse an AST iterator into a sequence of format items.
@@ -35,6 +36,17 @@ pub(super) enum Item<'a> {
         /// The span of the full sequence.
         span: Span,
     },
+    /// One of two sequences of items, depending on a condition.
+    Conditional {
+        /// The condition that determines which sequence is used.
+        condition: Condition,
+        /// The items used when the condition holds.
+        then: Box<[Self]>,
+        /// The items used when the condition does not hold.
+        otherwise: Box<[Self]>,
+        /// The span of the full item.
+        span: Span,
+    },
 }
 
 impl Item<'_> {
@@ -98,6 +110,43 @@ impl Item<'_> {
                     span: opening_bracket.to(closing_bracket),
                 }
             }
+            ast::Item::Conditional {
+                opening_bracket,
+                _leading_whitespace: _,
+                _if_kw: _,
+                _whitespace: _,
+                condition,
+                _condition_whitespace: _,
+                then,
+                otherwise,
+                closing_bracket,
+            } => {
+                let Some(condition_value) = Condition::from_name(&condition) else {
+                    return Err(Error {
+                        _inner: unused(condition.span.error("invalid condition")),
+                        public: crate::error::InvalidFormatDescription::Expected {
+                            what: "valid condition",
+                            index: condition.span.start.byte as usize,
+                        },
+                    });
+                };
+                Item::Conditional {
+                    condition: condition_value,
+                    then: then
+                        .items
+                        .into_vec()
+                        .into_iter()
+                        .map(Item::from_ast)
+                        .collect::<Result<_, _>>()?,
+                    otherwise: otherwise
+                        .items
+                        .into_vec()
+                        .into_iter()
+                        .map(Item::from_ast)
+                        .collect::<Result<_, _>>()?,
+                    span: opening_bracket.to(closing_bracket),
+                }
+            }
         })
     }
 }
@@ -129,6 +178,21 @@ impl<'a> TryFrom<Item<'a>> for crate::format_description::BorrowedFormatItem<'a>
                     index: span.start.byte as usize,
                 },
             }),
+            Item::Conditional {
+                condition: _,
+                then: _,
+                otherwise: _,
+                span,
+            } => Err(Error {
+                _inner: unused(span.error(
+                    "conditional items are not supported in runtime-parsed format descriptions",
+                )),
+                public: crate::error::InvalidFormatDescription::NotSupported {
+                    what: "conditional item",
+                    context: "runtime-parsed format descriptions",
+                    index: span.start.byte as usize,
+                },
+            }),
         }
     }
 }
@@ -142,6 +206,16 @@ impl From<Item<'_>> for crate::format_description::OwnedFormatItem {
             Item::First { value, span: _ } => {
                 Self::First(value.into_vec().into_iter().map(Into::into).collect())
             }
+            Item::Conditional {
+                condition,
+                then,
+                otherwise,
+                span: _,
+            } => Self::Conditional {
+                condition,
+                then: Box::new(then.into()),
+                otherwise: Box::new(otherwise.into()),
+            },
         }
     }
 }
@@ -277,22 +351,36 @@ component_definition! {
     pub(super) enum Component {
         Day = "day" {
             padding = "padding": Option<Padding> => padding,
+            width = "width": Option<#[from_str] u8> => width,
         },
         End = "end" {},
+        Era = "era" {
+            repr = "repr": Option<EraRepr> => repr,
+            case_sensitive = "case_sensitive": Option<EraCaseSensitive> => case_sensitive,
+        },
+        ExtensionAnnotations = "extension_annotations" {},
         Hour = "hour" {
             padding = "padding": Option<Padding> => padding,
+            width = "width": Option<#[from_str] u8> => width,
             base = "repr": Option<HourBase> => is_12_hour_clock,
         },
         Ignore = "ignore" {
             #[required]
             count = "count": Option<#[from_str] NonZero<u16>> => count,
         },
+        JulianDay = "julian_day" {
+            repr = "repr": Option<JulianDayRepr> => repr,
+            digits = "digits": Option<JulianDayDigits> => digits,
+        },
         Minute = "minute" {
             padding = "padding": Option<Padding> => padding,
+            width = "width": Option<#[from_str] u8> => width,
         },
         Month = "month" {
             padding = "padding": Option<Padding> => padding,
+            width = "width": Option<#[from_str] u8> => width,
             repr = "repr": Option<MonthRepr> => repr,
+            case = "case": Option<TextCase> => case,
             case_sensitive = "case_sensitive": Option<MonthCaseSensitive> => case_sensitive,
         },
         OffsetHour = "offset_hour" {
@@ -307,17 +395,25 @@ component_definition! {
         },
         Ordinal = "ordinal" {
             padding = "padding": Option<Padding> => padding,
+            width = "width": Option<#[from_str] u8> => width,
         },
         Period = "period" {
             case = "case": Option<PeriodCase> => is_uppercase,
+            repr = "repr": Option<PeriodRepr> => repr,
             case_sensitive = "case_sensitive": Option<PeriodCaseSensitive> => case_sensitive,
         },
+        Quarter = "quarter" {
+            repr = "repr": Option<QuarterRepr> => repr,
+            case_sensitive = "case_sensitive": Option<QuarterCaseSensitive> => case_sensitive,
+        },
         Second = "second" {
             padding = "padding": Option<Padding> => padding,
+            width = "width": Option<#[from_str] u8> => width,
         },
         Subsecond = "subsecond" {
             digits = "digits": Option<SubsecondDigits> => digits,
         },
+        TimeZoneAnnotation = "time_zone_annotation" {},
         UnixTimestamp = "unix_timestamp" {
             precision = "precision": Option<UnixTimestampPrecision> => precision,
             sign_behavior = "sign": Option<SignBehavior> => sign_is_mandatory,
@@ -325,18 +421,30 @@ component_definition! {
         Weekday = "weekday" {
             repr = "repr": Option<WeekdayRepr> => repr,
             one_indexed = "one_indexed": Option<WeekdayOneIndexed> => one_indexed,
+            case = "case": Option<TextCase> => case,
             case_sensitive = "case_sensitive": Option<WeekdayCaseSensitive> => case_sensitive,
         },
         WeekNumber = "week_number" {
             padding = "padding": Option<Padding> => padding,
+            width = "width": Option<#[from_str] u8> => width,
             repr = "repr": Option<WeekNumberRepr> => repr,
         },
+        WeekOfMonth = "week_of_month" {
+            first_weekday = "first_weekday": Option<FirstWeekday> => first_weekday,
+            minimal_days = "minimal_days": Option<MinimalDays> => minimal_days,
+        },
+        WeekdayInMonth = "weekday_in_month" {
+            from_end = "from_end": Option<WeekdayInMonthFromEnd> => from_end,
+        },
         Year = "year" {
             padding = "padding": Option<Padding> => padding,
+            width = "width": Option<#[from_str] u8> => width,
             repr = "repr": Option<YearRepr> => repr,
             range = "range": Option<YearRange> => range,
             base = "base": Option<YearBase> => iso_week_based,
             sign_behavior = "sign": Option<SignBehavior> => sign_is_mandatory,
+            pivot = "pivot": Option<YearPivot> => pivot,
+            era_based = "era_based": Option<YearEraBased> => era_based,
         },
     }
 }
@@ -420,12 +528,73 @@ macro_rules! modifier {
 
 // Keep in alphabetical order.
 modifier! {
+    enum EraCaseSensitive(bool) {
+        False(false) = b"false",
+        #[default]
+        True(true) = b"true",
+    }
+
+    enum EraRepr {
+        #[default]
+        Short = b"short",
+        Common = b"common",
+        Long = b"long",
+        Narrow = b"narrow",
+    }
+
+    enum FirstWeekday(crate::Weekday) {
+        #[default]
+        Monday(crate::Weekday::Monday) = b"monday",
+        Tuesday(crate::Weekday::Tuesday) = b"tuesday",
+        Wednesday(crate::Weekday::Wednesday) = b"wednesday",
+        Thursday(crate::Weekday::Thursday) = b"thursday",
+        Friday(crate::Weekday::Friday) = b"friday",
+        Saturday(crate::Weekday::Saturday) = b"saturday",
+        Sunday(crate::Weekday::Sunday) = b"sunday",
+    }
+
     enum HourBase(bool) {
         Twelve(true) = b"12",
         #[default]
         TwentyFour(false) = b"24",
     }
 
+    enum JulianDayDigits(u8) {
+        #[default]
+        Zero(0) = b"0",
+        One(1) = b"1",
+        Two(2) = b"2",
+        Three(3) = b"3",
+        Four(4) = b"4",
+        Five(5) = b"5",
+        Six(6) = b"6",
+        Seven(7) = b"7",
+        Eight(8) = b"8",
+        Nine(9) = b"9",
+        Ten(10) = b"10",
+        Eleven(11) = b"11",
+        Twelve(12) = b"12",
+        Thirteen(13) = b"13",
+        Fourteen(14) = b"14",
+    }
+
+    enum JulianDayRepr {
+        #[default]
+        Julian = b"julian",
+        Modified = b"modified",
+    }
+
+    enum MinimalDays(u8) {
+        #[default]
+        One(1) = b"1",
+        Two(2) = b"2",
+        Three(3) = b"3",
+        Four(4) = b"4",
+        Five(5) = b"5",
+        Six(6) = b"6",
+        Seven(7) = b"7",
+    }
+
     enum MonthCaseSensitive(bool) {
         False(false) = b"false",
         #[default]
@@ -437,6 +606,7 @@ modifier! {
         Numerical = b"numerical",
         Long = b"long",
         Short = b"short",
+        Narrow = b"narrow",
     }
 
     enum Padding {
@@ -458,6 +628,24 @@ modifier! {
         True(true) = b"true",
     }
 
+    enum PeriodRepr {
+        #[default]
+        Short = b"short",
+        Narrow = b"narrow",
+    }
+
+    enum QuarterCaseSensitive(bool) {
+        False(false) = b"false",
+        #[default]
+        True(true) = b"true",
+    }
+
+    enum QuarterRepr {
+        #[default]
+        Numerical = b"numerical",
+        Short = b"short",
+    }
+
     enum SignBehavior(bool) {
         #[default]
         Automatic(false) = b"automatic",
@@ -478,6 +666,13 @@ modifier! {
         OneOrMore = b"1+",
     }
 
+    enum TextCase {
+        #[default]
+        Title = b"title",
+        Upper = b"upper",
+        Lower = b"lower",
+    }
+
     enum UnixTimestampPrecision {
         #[default]
         Second = b"second",
@@ -499,6 +694,12 @@ modifier! {
         True(true) = b"true",
     }
 
+    enum WeekdayInMonthFromEnd(bool) {
+        #[default]
+        False(false) = b"false",
+        True(true) = b"true",
+    }
+
     enum WeekdayOneIndexed(bool) {
         False(false) = b"false",
         #[default]
@@ -509,6 +710,7 @@ modifier! {
         Short = b"short",
         #[default]
         Long = b"long",
+        Narrow = b"narrow",
         Sunday = b"sunday",
         Monday = b"monday",
     }
@@ -519,6 +721,12 @@ modifier! {
         IsoWeek(true) = b"iso_week",
     }
 
+    enum YearEraBased(bool) {
+        #[default]
+        False(false) = b"false",
+        True(true) = b"true",
+    }
+
     enum YearRepr {
         #[default]
         Full = b"full",
@@ -533,17 +741,65 @@ modifier! {
     }
 }
 
+/// The pivot used to resolve two-digit years. This is either `none`, `posix`, `sliding`, or the
+/// first year of the 100-year window.
+#[derive(Default)]
+enum YearPivot {
+    #[default]
+    None,
+    Fixed(crate::format_description::modifier::YearPivot),
+    Posix,
+    Sliding,
+}
+
+impl YearPivot {
+    /// Parse the modifier from its string representation.
+    fn from_modifier_value(value: &Spanned<&[u8]>) -> Result<Option<Self>, Error> {
+        if value.eq_ignore_ascii_case(b"none") {
+            return Ok(Some(Self::None));
+        }
+        if value.eq_ignore_ascii_case(b"posix") {
+            return Ok(Some(Self::Posix));
+        }
+        if value.eq_ignore_ascii_case(b"sliding") {
+            return Ok(Some(Self::Sliding));
+        }
+        match parse_from_modifier_value::<i32>(value)? {
+            Some(year) => crate::format_description::modifier::YearPivot::fixed(year)
+                .map(|pivot| Some(Self::Fixed(pivot)))
+                .ok_or_else(|| invalid_modifier_value(value)),
+            None => Ok(None),
+        }
+    }
+}
+
+impl From<YearPivot> for crate::format_description::modifier::YearPivot {
+    fn from(modifier: YearPivot) -> Self {
+        match modifier {
+            YearPivot::None => Self::NONE,
+            YearPivot::Fixed(pivot) => pivot,
+            YearPivot::Posix => Self::POSIX,
+            YearPivot::Sliding => Self::SLIDING,
+        }
+    }
+}
+
 /// Parse a modifier value using `FromStr`. Requires the modifier value to be valid UTF-8.
 fn parse_from_modifier_value<T: FromStr>(value: &Spanned<&[u8]>) -> Result<Option<T>, Error> {
     str::from_utf8(value)
         .ok()
         .and_then(|val| val.parse::<T>().ok())
         .map(|val| Some(val))
-        .ok_or_else(|| Error {
-            _inner: unused(value.span.error("invalid modifier value")),
-            public: crate::error::InvalidFormatDescription::InvalidModifier {
-                value: String::from_utf8_lossy(value).into_owned(),
-                index: value.span.start.byte as usize,
-            },
-        })
+        .ok_or_else(|| invalid_modifier_value(value))
+}
+
+/// The error returned when a modifier value is not valid.
+fn invalid_modifier_value(value: &Spanned<&[u8]>) -> Error {
+    Error {
+        _inner: unused(value.span.error("invalid modifier value")),
+        public: crate::error::InvalidFormatDescription::InvalidModifier {
+            value: String::from_utf8_lossy(value).into_owned(),
+            index: value.span.start.byte as usize,
+        },
+    }
 }
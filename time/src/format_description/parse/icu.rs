@@ -0,0 +1,459 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+use super::strftime::{
+    OFFSET_HOUR, OFFSET_HOUR_COLON_MINUTE, OFFSET_HOUR_COLON_MINUTE_COLON_SECOND,
+    OFFSET_HOUR_MINUTE,
+};
+use crate::error::InvalidFormatDescription;
+use crate::format_description::{
+    modifier, BorrowedFormatItem, Component, Condition, OwnedFormatItem,
+};
+
+/// Parse an [ICU] or Java [`DateTimeFormatter`] pattern, such as `yyyy-MM-dd'T'HH:mm:ss.SSSXXX`.
+///
+/// ASCII letters are pattern letters, where a run of the same letter is a single field. The number
+/// of letters selects the representation: `M` is the month without padding, `MM` is the month
+/// with zero padding, `MMM` is the abbreviated name, `MMMM` is the full name, and `MMMMM` is the
+/// narrow name (its first letter). Text enclosed in single quotes is literal, and two consecutive
+/// single quotes are a literal single quote, both inside and outside of quoted text. All other
+/// characters are literal. Text enclosed in square brackets is optional, as with Java's
+/// `DateTimeFormatter`.
+///
+/// The following pattern letters are supported:
+///
+/// | Letter | Field | Supported counts |
+/// |--------|-------|------------------|
+/// | `G` | era | 1 through 5 |
+/// | `y`, `u` | year | `y`, `yy`, `yyyy` |
+/// | `Y` | ISO week-based year | `Y`, `YY`, `YYYY` |
+/// | `M`, `L` | month | 1 through 5 |
+/// | `Q`, `q` | quarter | `Q`, `QQQ`, `QQQQQ` |
+/// | `w` | ISO week number | `w`, `ww` |
+/// | `W` | week of the month | `W` |
+/// | `d` | day of the month | `d`, `dd` |
+/// | `D` | day of the year | `D`, `DDD` |
+/// | `F` | occurrence of the day of the week in the month | `F` |
+/// | `E` | day of the week | 1 through 5 |
+/// | `e`, `c` | day of the week | 3 through 5 |
+/// | `a` | AM/PM | 1 through 5 |
+/// | `h` | hour (1–12) | `h`, `hh` |
+/// | `H` | hour (0–23) | `H`, `HH` |
+/// | `m` | minute | `m`, `mm` |
+/// | `s` | second | `s`, `ss` |
+/// | `S` | fraction of the second | 1 through 9, selecting the number of digits |
+/// | `X` | UTC offset, with `Z` for zero | 1 through 5 |
+/// | `x` | UTC offset | 1 through 5 |
+/// | `Z` | UTC offset | 1 through 3, and 5 |
+///
+/// Week-based years and week numbers always follow ISO 8601, rather than the rules of a locale.
+/// Likewise, weeks of the month begin on Monday, and the first week of the month is the first
+/// week with at least four days in the month. A two-digit year is resolved using
+/// [`YearPivot::SLIDING`](modifier::YearPivot::SLIDING) when parsing, as in ICU and Java: the
+/// year is within 80 years before and 19 years after the reference year. When the pattern has an
+/// era, `y` and `yyyy` are the year of the era, which is never negative. Otherwise, they are the
+/// same as `u`. Other pattern letters, including those for time zone names, are not supported.
+///
+/// UTC offsets follow the widths of the pattern letter: `xx` is `+hhmm`, `xxx` is `+hh:mm`, and
+/// so on. As in Java, the minutes of `x` and the seconds of `xxxx` and `xxxxx` are omitted when
+/// they are zero, so `x` is `+05` or `+0530` and `xxxxx` is `+05:30` or `+05:30:15`. A zero
+/// offset is formatted as `Z` for `X` and `ZZZZZ`, and `Z` is accepted in place of a zero offset
+/// for them when parsing.
+///
+/// ```rust
+/// # use time::format_description;
+/// # use time_macros::datetime;
+/// let format = format_description::parse_icu_owned("yyyy-MM-dd'T'HH:mm:ss.SSSxxx")?;
+/// assert_eq!(
+///     datetime!(2024-01-02 03:04:05.678 +01:00).format(&format)?,
+///     "2024-01-02T03:04:05.678+01:00"
+/// );
+///
+/// let format = format_description::parse_icu_owned("EEE, d MMM yyyy")?;
+/// assert_eq!(
+///     datetime!(2024-01-02 03:04:05 UTC).format(&format)?,
+///     "Tue, 2 Jan 2024"
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+///
+/// [ICU]: https://unicode-org.github.io/icu/userguide/format_parse/datetime/#datetimeformatter
+/// [`DateTimeFormatter`]: https://docs.oracle.com/en/java/javase/21/docs/api/java.base/java/time/format/DateTimeFormatter.html
+#[doc(alias = "DateTimeFormatter")]
+#[doc(alias = "SimpleDateFormat")]
+pub fn parse_icu_owned(s: &str) -> Result<OwnedFormatItem, InvalidFormatDescription> {
+    let input = s.as_bytes();
+    // The items of each optional section that has not yet been closed, along with the index of its
+    // opening bracket. The first entry is the top level.
+    let mut sections = alloc::vec![(0, Vec::new())];
+    let mut literal = Vec::new();
+    let mut idx = 0;
+    let has_era = has_era(input);
+
+    /// Move any pending literal to the innermost section.
+    fn flush(sections: &mut [(usize, Vec<OwnedFormatItem>)], literal: &mut Vec<u8>) {
+        if literal.is_empty() {
+            return;
+        }
+        if let Some((_, items)) = sections.last_mut() {
+            items.push(OwnedFormatItem::Literal(
+                core::mem::take(literal).into_boxed_slice(),
+            ));
+        }
+    }
+
+    while let Some(&byte) = input.get(idx) {
+        match byte {
+            b'\'' => {
+                let start = idx;
+                idx += 1;
+                loop {
+                    match input.get(idx) {
+                        Some(b'\'') if input.get(idx + 1) == Some(&b'\'') => {
+                            literal.push(b'\'');
+                            idx += 2;
+                        }
+                        // Two consecutive single quotes outside of quoted text are an empty quote.
+                        Some(b'\'') if idx == start + 1 => {
+                            literal.push(b'\'');
+                            idx += 1;
+                            break;
+                        }
+                        Some(b'\'') => {
+                            idx += 1;
+                            break;
+                        }
+                        Some(&byte) => {
+                            literal.push(byte);
+                            idx += 1;
+                        }
+                        None => {
+                            return Err(InvalidFormatDescription::Expected {
+                                what: "closing quote",
+                                index: start,
+                            });
+                        }
+                    }
+                }
+            }
+            b'[' => {
+                flush(&mut sections, &mut literal);
+                sections.push((idx, Vec::new()));
+                idx += 1;
+            }
+            b']' => {
+                flush(&mut sections, &mut literal);
+                let (Some((_, items)), [_, ..]) = (sections.pop(), sections.as_slice()) else {
+                    return Err(InvalidFormatDescription::Expected {
+                        what: "opening bracket before closing bracket",
+                        index: idx,
+                    });
+                };
+                let optional = OwnedFormatItem::Optional(alloc::boxed::Box::new(items.into()));
+                if let Some((_, items)) = sections.last_mut() {
+                    items.push(optional);
+                }
+                idx += 1;
+            }
+            b'A'..=b'Z' | b'a'..=b'z' => {
+                flush(&mut sections, &mut literal);
+                let start = idx;
+                while input.get(idx) == Some(&byte) {
+                    idx += 1;
+                }
+                let item = parse_field(&input[start..idx], start, has_era)?;
+                if let Some((_, items)) = sections.last_mut() {
+                    items.push(item.into());
+                }
+            }
+            _ => {
+                literal.push(byte);
+                idx += 1;
+            }
+        }
+    }
+    flush(&mut sections, &mut literal);
+
+    match sections.pop() {
+        Some((_, items)) if sections.is_empty() => Ok(items.into()),
+        Some((index, _)) => Err(InvalidFormatDescription::UnclosedOpeningBracket { index }),
+        None => unreachable!("the top level is never removed"),
+    }
+}
+
+/// The UTC offset including seconds without a separator (`+hhmmss`).
+const OFFSET_HOUR_MINUTE_SECOND: BorrowedFormatItem<'static> = BorrowedFormatItem::Compound(&[
+    OFFSET_HOUR,
+    component!(OffsetMinute {
+        padding: modifier::Padding::Zero,
+    }),
+    component!(OffsetSecond {
+        padding: modifier::Padding::Zero,
+    }),
+]);
+/// The UTC offset as `+hh`, including the minutes (`+hhmm`) if they are not zero.
+const OFFSET_HOUR_OPTIONAL_MINUTE: BorrowedFormatItem<'static> = BorrowedFormatItem::Conditional {
+    condition: Condition::OffsetMinuteIsZero,
+    then: &OFFSET_HOUR,
+    otherwise: &OFFSET_HOUR_MINUTE,
+};
+/// The UTC offset as `+hhmm`, including the seconds (`+hhmmss`) if they are not zero.
+const OFFSET_HOUR_MINUTE_OPTIONAL_SECOND: BorrowedFormatItem<'static> =
+    BorrowedFormatItem::Conditional {
+        condition: Condition::OffsetSecondIsZero,
+        then: &OFFSET_HOUR_MINUTE,
+        otherwise: &OFFSET_HOUR_MINUTE_SECOND,
+    };
+/// The UTC offset as `+hh:mm`, including the seconds (`+hh:mm:ss`) if they are not zero.
+const OFFSET_HOUR_COLON_MINUTE_OPTIONAL_SECOND: BorrowedFormatItem<'static> =
+    BorrowedFormatItem::Conditional {
+        condition: Condition::OffsetSecondIsZero,
+        then: &OFFSET_HOUR_COLON_MINUTE,
+        otherwise: &OFFSET_HOUR_COLON_MINUTE_COLON_SECOND,
+    };
+/// The designator used in place of a zero UTC offset.
+const UTC_DESIGNATOR: BorrowedFormatItem<'static> = BorrowedFormatItem::Literal(b"Z");
+
+/// The UTC designator if the UTC offset is zero, and the provided item otherwise.
+macro_rules! utc_designator_or {
+    ($item:expr) => {
+        BorrowedFormatItem::Conditional {
+            condition: Condition::OffsetIsUtc,
+            then: &UTC_DESIGNATOR,
+            otherwise: &$item,
+        }
+    };
+}
+
+/// Whether the pattern contains an era (`G`) outside of quoted text.
+fn has_era(input: &[u8]) -> bool {
+    let mut is_quoted = false;
+    for &byte in input {
+        match byte {
+            b'\'' => is_quoted = !is_quoted,
+            b'G' if !is_quoted => return true,
+            _ => {}
+        }
+    }
+    false
+}
+
+/// Parse a run of a single pattern letter that begins at the provided index. If the pattern has an
+/// era, `y` is the year of the era.
+fn parse_field(
+    run: &[u8],
+    index: usize,
+    has_era: bool,
+) -> Result<BorrowedFormatItem<'static>, InvalidFormatDescription> {
+    let not_supported = |what| InvalidFormatDescription::NotSupported {
+        what,
+        context: "",
+        index,
+    };
+    let invalid_count = || not_supported("number of pattern letters");
+
+    let [letter, ..] = *run else {
+        unreachable!("a run contains at least one letter");
+    };
+    let count = run.len();
+
+    // The padding of a numeric field with a natural width of two digits.
+    let padding = match count {
+        1 => Ok(modifier::Padding::None),
+        2 => Ok(modifier::Padding::Zero),
+        _ => Err(invalid_count()),
+    };
+    let year = |iso_week_based, era_based| {
+        let (padding, repr, pivot) = match count {
+            1 => (
+                modifier::Padding::None,
+                modifier::YearRepr::Full,
+                modifier::YearPivot::NONE,
+            ),
+            2 => (
+                modifier::Padding::Zero,
+                modifier::YearRepr::LastTwo,
+                modifier::YearPivot::SLIDING,
+            ),
+            4 => (
+                modifier::Padding::Zero,
+                modifier::YearRepr::Full,
+                modifier::YearPivot::NONE,
+            ),
+            _ => return Err(invalid_count()),
+        };
+        Ok(component!(Year {
+            padding,
+            width: 0,
+            repr,
+            range: modifier::YearRange::Extended,
+            iso_week_based,
+            sign_is_mandatory: false,
+            pivot,
+            era_based,
+        }))
+    };
+    let weekday = |repr| {
+        Ok(component!(Weekday {
+            repr,
+            one_indexed: true,
+            case: modifier::TextCase::Title,
+            case_sensitive: true,
+        }))
+    };
+
+    match letter {
+        b'y' => year(false, has_era),
+        b'u' => year(false, false),
+        b'Y' => year(true, false),
+        b'M' | b'L' => {
+            let (padding, repr) = match count {
+                1 => (modifier::Padding::None, modifier::MonthRepr::Numerical),
+                2 => (modifier::Padding::Zero, modifier::MonthRepr::Numerical),
+                3 => (modifier::Padding::Zero, modifier::MonthRepr::Short),
+                4 => (modifier::Padding::Zero, modifier::MonthRepr::Long),
+                5 => (modifier::Padding::Zero, modifier::MonthRepr::Narrow),
+                _ => return Err(invalid_count()),
+            };
+            Ok(component!(Month {
+                padding,
+                width: 0,
+                repr,
+                case: modifier::TextCase::Title,
+                case_sensitive: true,
+            }))
+        }
+        b'Q' | b'q' => {
+            let repr = match count {
+                1 | 5 => modifier::QuarterRepr::Numerical,
+                3 => modifier::QuarterRepr::Short,
+                _ => return Err(invalid_count()),
+            };
+            Ok(component!(Quarter {
+                repr,
+                case_sensitive: true,
+            }))
+        }
+        b'w' => Ok(component!(WeekNumber {
+            padding: padding?,
+            width: 0,
+            repr: modifier::WeekNumberRepr::Iso,
+        })),
+        b'W' => match count {
+            1 => Ok(component!(WeekOfMonth {
+                first_weekday: crate::Weekday::Monday,
+                minimal_days: 4,
+            })),
+            _ => Err(invalid_count()),
+        },
+        b'd' => Ok(component!(Day {
+            padding: padding?,
+            width: 0,
+        })),
+        b'D' => Ok(component!(Ordinal {
+            padding: match count {
+                1 => modifier::Padding::None,
+                3 => modifier::Padding::Zero,
+                _ => return Err(invalid_count()),
+            },
+            width: 0,
+        })),
+        b'F' => match count {
+            1 => Ok(component!(WeekdayInMonth { from_end: false })),
+            _ => Err(invalid_count()),
+        },
+        b'E' | b'e' | b'c' => match count {
+            1 | 2 if letter != b'E' => Err(not_supported("numeric localized day of the week")),
+            1..=3 => weekday(modifier::WeekdayRepr::Short),
+            4 => weekday(modifier::WeekdayRepr::Long),
+            5 => weekday(modifier::WeekdayRepr::Narrow),
+            6 => Err(not_supported("two-letter day of the week name")),
+            _ => Err(invalid_count()),
+        },
+        b'a' => match count {
+            1..=4 => Ok(component!(Period {
+                is_uppercase: true,
+                repr: modifier::PeriodRepr::Short,
+                case_sensitive: true,
+            })),
+            5 => Ok(component!(Period {
+                is_uppercase: false,
+                repr: modifier::PeriodRepr::Narrow,
+                case_sensitive: true,
+            })),
+            _ => Err(invalid_count()),
+        },
+        b'h' => Ok(component!(Hour {
+            padding: padding?,
+            width: 0,
+            is_12_hour_clock: true,
+        })),
+        b'H' => Ok(component!(Hour {
+            padding: padding?,
+            width: 0,
+            is_12_hour_clock: false,
+        })),
+        b'm' => Ok(component!(Minute {
+            padding: padding?,
+            width: 0,
+        })),
+        b's' => Ok(component!(Second {
+            padding: padding?,
+            width: 0,
+        })),
+        b'S' => Ok(component!(Subsecond {
+            digits: match count {
+                1 => modifier::SubsecondDigits::One,
+                2 => modifier::SubsecondDigits::Two,
+                3 => modifier::SubsecondDigits::Three,
+                4 => modifier::SubsecondDigits::Four,
+                5 => modifier::SubsecondDigits::Five,
+                6 => modifier::SubsecondDigits::Six,
+                7 => modifier::SubsecondDigits::Seven,
+                8 => modifier::SubsecondDigits::Eight,
+                9 => modifier::SubsecondDigits::Nine,
+                _ => return Err(invalid_count()),
+            },
+        })),
+        b'x' => Ok(match count {
+            1 => OFFSET_HOUR_OPTIONAL_MINUTE,
+            2 => OFFSET_HOUR_MINUTE,
+            3 => OFFSET_HOUR_COLON_MINUTE,
+            4 => OFFSET_HOUR_MINUTE_OPTIONAL_SECOND,
+            5 => OFFSET_HOUR_COLON_MINUTE_OPTIONAL_SECOND,
+            _ => return Err(invalid_count()),
+        }),
+        b'X' => Ok(match count {
+            1 => utc_designator_or!(OFFSET_HOUR_OPTIONAL_MINUTE),
+            2 => utc_designator_or!(OFFSET_HOUR_MINUTE),
+            3 => utc_designator_or!(OFFSET_HOUR_COLON_MINUTE),
+            4 => utc_designator_or!(OFFSET_HOUR_MINUTE_OPTIONAL_SECOND),
+            5 => utc_designator_or!(OFFSET_HOUR_COLON_MINUTE_OPTIONAL_SECOND),
+            _ => return Err(invalid_count()),
+        }),
+        b'Z' => Ok(match count {
+            1..=3 => OFFSET_HOUR_MINUTE,
+            4 => return Err(not_supported("localized UTC offset")),
+            5 => utc_designator_or!(OFFSET_HOUR_COLON_MINUTE_OPTIONAL_SECOND),
+            _ => return Err(invalid_count()),
+        }),
+        b'G' => Ok(component!(Era {
+            repr: match count {
+                1..=3 => modifier::EraRepr::Short,
+                4 => modifier::EraRepr::Long,
+                5 => modifier::EraRepr::Narrow,
+                _ => return Err(invalid_count()),
+            },
+            case_sensitive: true,
+        })),
+        b'k' | b'K' => Err(not_supported("alternative hour numbering")),
+        b'B' | b'b' => Err(not_supported("day period")),
+        b'n' => Err(not_supported("unpadded nanosecond")),
+        b'A' | b'N' => Err(not_supported("time of day as a count")),
+        b'O' | b'v' | b'V' | b'z' => Err(not_supported("time zone name")),
+        _ => Err(InvalidFormatDescription::InvalidComponentName {
+            name: String::from_utf8_lossy(run).into_owned(),
+            index,
+        }),
+    }
+}
@@ -3,6 +3,8 @@
 use alloc::boxed::Box;
 use alloc::vec::Vec;
 
+pub use self::icu::parse_icu_owned;
+pub(super) use self::strftime::format_strftime;
 pub use self::strftime::{parse_strftime_borrowed, parse_strftime_owned};
 use crate::{error, format_description};
 
@@ -22,8 +24,18 @@ macro_rules! validate_version {
     };
 }
 
+/// Helper macro to create a component.
+macro_rules! component {
+    ($name:ident { $($inner:tt)* }) => {
+        BorrowedFormatItem::Component(Component::$name(modifier::$name {
+            $($inner)*
+        }))
+    }
+}
+
 mod ast;
 mod format_item;
+mod icu;
 mod lexer;
 mod strftime;
 
@@ -1,18 +1,41 @@
-use alloc::string::String;
+use alloc::string::{String, ToString};
 use alloc::vec::Vec;
+use core::cell::Cell;
 use core::iter;
 
 use crate::error::InvalidFormatDescription;
 use crate::format_description::parse::{
-    attach_location, unused, Error, ErrorInner, Location, Spanned, SpannedValue, Unused,
+    attach_location, unused, Error, ErrorInner, Location, Span, Spanned, SpannedValue, Unused,
+};
+use crate::format_description::{
+    modifier, BorrowedFormatItem, Component, Condition, OwnedFormatItem,
 };
-use crate::format_description::{self, modifier, BorrowedFormatItem, Component};
 
 /// Parse a sequence of items from the [`strftime` format description][strftime docs].
 ///
 /// The only heap allocation required is for the `Vec` itself. All components are bound to the
 /// lifetime of the input.
 ///
+/// In addition to the conversions listed in the linked documentation, the following GNU and
+/// `chrono` extensions are supported:
+///
+/// - The `_`, `-`, and `0` flags set the padding of numeric values. The `^` and `#` flags convert
+///   textual values to uppercase, other than `%p` where `#` converts to lowercase.
+/// - A field width may be provided for numeric values, such as `%1d` or `%10d`, setting the minimum
+///   width of the value. Widths between one and the natural width of the value (exclusive) are not
+///   supported. The width of the year and century includes any sign, which precedes any zeroes.
+/// - `%N` and `%f` are the subsecond with nine digits by default. A width sets the number of
+///   digits, such as `%3N`. `%.f` is a period followed by the subsecond with as many digits as
+///   needed, both of which are omitted when the subsecond is zero and optional when parsing. `%.3f`
+///   is a period followed by the subsecond with the given number of digits.
+/// - `%:z` is the UTC offset with a colon (`+hh:mm`), `%::z` includes seconds (`+hh:mm:ss`), and
+///   `%:::z` only includes the hour (`+hh`).
+/// - `%+` is an RFC 3339 timestamp, matching `chrono`.
+///
+/// When parsing, `%z` and `%:z` accept an offset with or without a colon and with or without
+/// minutes. `%Z` is formatted as a numeric offset, as time zone names are not available; when
+/// parsing, it additionally accepts `UTC`, `GMT`, and `Z`, each of which is a UTC offset of zero.
+///
 /// [strftime docs]: https://man7.org/linux/man-pages/man3/strftime.3.html
 #[doc(alias = "parse_strptime_borrowed")]
 pub fn parse_strftime_borrowed(
@@ -25,16 +48,333 @@ pub fn parse_strftime_borrowed(
 
 /// Parse a sequence of items from the [`strftime` format description][strftime docs].
 ///
-/// This requires heap allocation for some owned items.
+/// This requires heap allocation for some owned items. The supported syntax is the same as
+/// [`parse_strftime_borrowed`].
 ///
 /// [strftime docs]: https://man7.org/linux/man-pages/man3/strftime.3.html
 #[doc(alias = "parse_strptime_owned")]
-pub fn parse_strftime_owned(
-    s: &str,
-) -> Result<format_description::OwnedFormatItem, InvalidFormatDescription> {
+pub fn parse_strftime_owned(s: &str) -> Result<OwnedFormatItem, InvalidFormatDescription> {
     parse_strftime_borrowed(s).map(Into::into)
 }
 
+/// A format item that has been flattened for conversion to `strftime`.
+pub(in crate::format_description) enum Atom<'a> {
+    /// Bytes that are formatted as-is.
+    Literal(&'a [u8]),
+    /// A single component.
+    Component(Component),
+    /// A period followed by the subsecond, omitted when the subsecond is zero, as produced by
+    /// `%.f`.
+    PeriodSubsecond,
+    /// An item with no `strftime` equivalent.
+    Unsupported,
+}
+
+/// A format item that can be converted to a `strftime` format description.
+pub(in crate::format_description) trait Flatten {
+    /// Append the atoms of the item to the provided `Vec`.
+    fn flatten<'a>(&'a self, atoms: &mut Vec<Atom<'a>>);
+}
+
+/// Whether the items are a period followed by the subsecond with any number of digits.
+fn is_period_subsecond(literal: &[u8], component: &Component) -> bool {
+    literal == b"."
+        && matches!(
+            component,
+            Component::Subsecond(modifier::Subsecond {
+                digits: modifier::SubsecondDigits::OneOrMore
+            })
+        )
+}
+
+impl Flatten for BorrowedFormatItem<'_> {
+    fn flatten<'a>(&'a self, atoms: &mut Vec<Atom<'a>>) {
+        match self {
+            Self::Literal(literal) => atoms.push(Atom::Literal(literal)),
+            Self::Component(component) => atoms.push(Atom::Component(*component)),
+            Self::Compound(items) => items.iter().for_each(|item| item.flatten(atoms)),
+            Self::Optional(item) => item.flatten(atoms),
+            Self::First([item, ..]) => item.flatten(atoms),
+            Self::First([]) => {}
+            Self::Conditional {
+                condition: Condition::SubsecondIsZero,
+                then: Self::Compound([]),
+                otherwise: Self::Compound([Self::Literal(literal), Self::Component(component)]),
+            } if is_period_subsecond(literal, component) => atoms.push(Atom::PeriodSubsecond),
+            Self::Conditional { .. } => atoms.push(Atom::Unsupported),
+        }
+    }
+}
+
+impl Flatten for OwnedFormatItem {
+    fn flatten<'a>(&'a self, atoms: &mut Vec<Atom<'a>>) {
+        match self {
+            Self::Literal(literal) => atoms.push(Atom::Literal(literal)),
+            Self::Component(component) => atoms.push(Atom::Component(*component)),
+            Self::Compound(items) => items.iter().for_each(|item| item.flatten(atoms)),
+            Self::Optional(item) => item.flatten(atoms),
+            Self::First(items) => {
+                if let Some(item) = items.first() {
+                    item.flatten(atoms);
+                }
+            }
+            Self::Conditional {
+                condition: Condition::SubsecondIsZero,
+                then,
+                otherwise,
+            } if matches!(&**then, Self::Compound(items) if items.is_empty())
+                && matches!(
+                    &**otherwise,
+                    Self::Compound(items) if matches!(
+                        &**items,
+                        [Self::Literal(literal), Self::Component(component)]
+                            if is_period_subsecond(literal, component)
+                    )
+                ) =>
+            {
+                atoms.push(Atom::PeriodSubsecond);
+            }
+            Self::Conditional { .. } => atoms.push(Atom::Unsupported),
+        }
+    }
+}
+
+/// Convert a format item to an equivalent `strftime` format description, if possible.
+pub(in crate::format_description) fn format_strftime(item: &impl Flatten) -> Option<String> {
+    let mut atoms = Vec::new();
+    item.flatten(&mut atoms);
+
+    /// Whether the atom is the minute of the UTC offset with zero padding.
+    const fn is_offset_minute(atom: &Atom<'_>) -> bool {
+        matches!(
+            atom,
+            Atom::Component(Component::OffsetMinute(modifier::OffsetMinute {
+                padding: modifier::Padding::Zero
+            }))
+        )
+    }
+
+    /// Whether the atom is the second of the UTC offset with zero padding.
+    const fn is_offset_second(atom: &Atom<'_>) -> bool {
+        matches!(
+            atom,
+            Atom::Component(Component::OffsetSecond(modifier::OffsetSecond {
+                padding: modifier::Padding::Zero
+            }))
+        )
+    }
+
+    let mut output = String::new();
+    let mut remaining = atoms.as_slice();
+    while let [atom, rest @ ..] = remaining {
+        remaining = rest;
+        match atom {
+            Atom::Literal(literal) => {
+                output.push_str(&String::from_utf8_lossy(literal).replace('%', "%%"));
+            }
+            Atom::PeriodSubsecond => output.push_str("%.f"),
+            Atom::Unsupported => return None,
+            // The UTC offset is only representable as a whole, so look ahead for the remaining
+            // parts.
+            Atom::Component(Component::OffsetHour(modifier::OffsetHour {
+                sign_is_mandatory: true,
+                padding: modifier::Padding::Zero,
+            })) => match remaining {
+                [minute, rest @ ..] if is_offset_minute(minute) => {
+                    output.push_str("%z");
+                    remaining = rest;
+                }
+                [Atom::Literal(b":"), minute, Atom::Literal(b":"), second, rest @ ..]
+                    if is_offset_minute(minute) && is_offset_second(second) =>
+                {
+                    output.push_str("%::z");
+                    remaining = rest;
+                }
+                [Atom::Literal(b":"), minute, rest @ ..] if is_offset_minute(minute) => {
+                    output.push_str("%:z");
+                    remaining = rest;
+                }
+                _ => output.push_str("%:::z"),
+            },
+            Atom::Component(component) => push_component(&mut output, *component)?,
+        }
+    }
+
+    Some(output)
+}
+
+/// Append the `strftime` equivalent of the component to the output, if there is one.
+fn push_component(output: &mut String, component: Component) -> Option<()> {
+    /// Append a numeric conversion, including a flag if the padding is not the default and a
+    /// field width if it exceeds the natural width.
+    fn numeric(
+        output: &mut String,
+        (padding, width): (modifier::Padding, u8),
+        (default, natural_width): (modifier::Padding, u8),
+        conversion: char,
+    ) {
+        output.push('%');
+        if padding != default {
+            output.push(match padding {
+                modifier::Padding::Space => '_',
+                modifier::Padding::Zero => '0',
+                modifier::Padding::None => '-',
+            });
+        }
+        if width > natural_width && padding != modifier::Padding::None {
+            output.push_str(&width.to_string());
+        }
+        output.push(conversion);
+    }
+
+    /// The flag for a textual component in the given case.
+    const fn text_flag(case: modifier::TextCase) -> Option<&'static str> {
+        match case {
+            modifier::TextCase::Title => Some("%"),
+            modifier::TextCase::Upper => Some("%^"),
+            modifier::TextCase::Lower => None,
+        }
+    }
+
+    match component {
+        Component::Day(modifier::Day {
+            padding: modifier::Padding::Space,
+            width: 0..=2,
+        }) => output.push_str("%e"),
+        Component::Day(modifier::Day { padding, width }) => {
+            numeric(output, (padding, width), (modifier::Padding::Zero, 2), 'd');
+        }
+        Component::Month(modifier::Month {
+            padding,
+            width,
+            repr: modifier::MonthRepr::Numerical,
+            ..
+        }) => numeric(output, (padding, width), (modifier::Padding::Zero, 2), 'm'),
+        Component::Month(modifier::Month { repr, case, .. }) => {
+            let conversion = match repr {
+                modifier::MonthRepr::Long => 'B',
+                modifier::MonthRepr::Short => 'b',
+                _ => return None,
+            };
+            output.push_str(text_flag(case)?);
+            output.push(conversion);
+        }
+        Component::Ordinal(modifier::Ordinal { padding, width }) => {
+            numeric(output, (padding, width), (modifier::Padding::Zero, 3), 'j');
+        }
+        Component::Weekday(modifier::Weekday {
+            repr: modifier::WeekdayRepr::Short,
+            case,
+            ..
+        }) => {
+            output.push_str(text_flag(case)?);
+            output.push('a');
+        }
+        Component::Weekday(modifier::Weekday {
+            repr: modifier::WeekdayRepr::Long,
+            case,
+            ..
+        }) => {
+            output.push_str(text_flag(case)?);
+            output.push('A');
+        }
+        Component::Weekday(modifier::Weekday {
+            repr: modifier::WeekdayRepr::Monday,
+            one_indexed: true,
+            ..
+        }) => output.push_str("%u"),
+        Component::Weekday(modifier::Weekday {
+            repr: modifier::WeekdayRepr::Sunday,
+            one_indexed: true,
+            ..
+        }) => output.push_str("%w"),
+        Component::WeekNumber(modifier::WeekNumber {
+            padding,
+            width,
+            repr,
+        }) => numeric(
+            output,
+            (padding, width),
+            (modifier::Padding::Zero, 2),
+            match repr {
+                modifier::WeekNumberRepr::Iso => 'V',
+                modifier::WeekNumberRepr::Sunday => 'U',
+                modifier::WeekNumberRepr::Monday => 'W',
+            },
+        ),
+        Component::Year(modifier::Year {
+            padding,
+            width,
+            repr,
+            iso_week_based,
+            sign_is_mandatory: false,
+            ..
+        }) => {
+            let (natural_width, conversion) = match (repr, iso_week_based) {
+                (modifier::YearRepr::Full, false) => (4, 'Y'),
+                (modifier::YearRepr::Full, true) => (4, 'G'),
+                (modifier::YearRepr::Century, false) => (2, 'C'),
+                (modifier::YearRepr::LastTwo, false) => (2, 'y'),
+                (modifier::YearRepr::LastTwo, true) => (2, 'g'),
+                _ => return None,
+            };
+            numeric(
+                output,
+                (padding, width),
+                (modifier::Padding::Zero, natural_width),
+                conversion,
+            );
+        }
+        Component::Hour(modifier::Hour {
+            padding: modifier::Padding::Space,
+            width: 0..=2,
+            is_12_hour_clock,
+        }) => output.push_str(if is_12_hour_clock { "%l" } else { "%k" }),
+        Component::Hour(modifier::Hour {
+            padding,
+            width,
+            is_12_hour_clock,
+        }) => numeric(
+            output,
+            (padding, width),
+            (modifier::Padding::Zero, 2),
+            if is_12_hour_clock { 'I' } else { 'H' },
+        ),
+        Component::Minute(modifier::Minute { padding, width }) => {
+            numeric(output, (padding, width), (modifier::Padding::Zero, 2), 'M');
+        }
+        Component::Period(modifier::Period {
+            is_uppercase,
+            repr: modifier::PeriodRepr::Short,
+            ..
+        }) => {
+            output.push_str(if is_uppercase { "%p" } else { "%P" });
+        }
+        Component::Second(modifier::Second { padding, width }) => {
+            numeric(output, (padding, width), (modifier::Padding::Zero, 2), 'S');
+        }
+        Component::Subsecond(modifier::Subsecond { digits }) => output.push_str(match digits {
+            modifier::SubsecondDigits::One => "%1N",
+            modifier::SubsecondDigits::Two => "%2N",
+            modifier::SubsecondDigits::Three => "%3N",
+            modifier::SubsecondDigits::Four => "%4N",
+            modifier::SubsecondDigits::Five => "%5N",
+            modifier::SubsecondDigits::Six => "%6N",
+            modifier::SubsecondDigits::Seven => "%7N",
+            modifier::SubsecondDigits::Eight => "%8N",
+            modifier::SubsecondDigits::Nine => "%N",
+            modifier::SubsecondDigits::OneOrMore => return None,
+        }),
+        Component::UnixTimestamp(modifier::UnixTimestamp {
+            precision: modifier::UnixTimestampPrecision::Second,
+            sign_is_mandatory: false,
+        }) => output.push_str("%s"),
+        _ => return None,
+    }
+
+    Some(())
+}
+
 #[derive(Debug, Clone, Copy, PartialEq)]
 enum Padding {
     /// The default padding for a numeric component. Indicated by no character.
@@ -47,46 +387,82 @@ enum Padding {
     Zeroes,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Case {
+    /// The default case for a textual component. Indicated by no character.
+    Default,
+    /// Convert a textual component to uppercase. Indicated by a caret.
+    Upper,
+    /// Swap the case of a textual component. Indicated by a number sign.
+    Swap,
+}
+
 enum Token<'a> {
     Literal(Spanned<&'a [u8]>),
     Component {
         _percent: Unused<Location>,
         padding: Spanned<Padding>,
+        case: Spanned<Case>,
+        period: Option<Location>,
+        width: Option<Spanned<u32>>,
+        colons: Option<Spanned<u8>>,
         component: Spanned<u8>,
     },
 }
 
-fn lex(mut input: &[u8]) -> iter::Peekable<impl Iterator<Item = Result<Token<'_>, Error>>> {
+fn lex(input: &[u8]) -> iter::Peekable<impl Iterator<Item = Result<Token<'_>, Error>>> {
     let mut iter = attach_location(input.iter()).peekable();
 
     iter::from_fn(move || {
         Some(Ok(match iter.next()? {
-            (b'%', percent_loc) => match iter.next() {
-                Some((padding @ (b'_' | b'-' | b'0'), padding_loc)) => {
-                    let padding = match padding {
-                        b'_' => Padding::Spaces,
-                        b'-' => Padding::None,
-                        b'0' => Padding::Zeroes,
+            (b'%', percent_loc) => {
+                let mut padding = Padding::Default.spanned(percent_loc.to_self());
+                let mut case = Case::Default.spanned(percent_loc.to_self());
+                while let Some((&flag, flag_loc)) =
+                    iter.next_if(|&(&byte, _)| matches!(byte, b'_' | b'-' | b'0' | b'^' | b'#'))
+                {
+                    let span = flag_loc.to_self();
+                    match flag {
+                        b'_' => padding = Padding::Spaces.spanned(span),
+                        b'-' => padding = Padding::None.spanned(span),
+                        b'0' => padding = Padding::Zeroes.spanned(span),
+                        b'^' => case = Case::Upper.spanned(span),
+                        b'#' => case = Case::Swap.spanned(span),
                         _ => unreachable!(),
-                    };
-                    let (&component, component_loc) = iter.next()?;
-                    input = &input[3..];
-                    Token::Component {
-                        _percent: unused(percent_loc),
-                        padding: padding.spanned(padding_loc.to_self()),
-                        component: component.spanned(component_loc.to_self()),
                     }
                 }
-                Some((&component, component_loc)) => {
-                    input = &input[2..];
-                    let span = component_loc.to_self();
-                    Token::Component {
-                        _percent: unused(percent_loc),
-                        padding: Padding::Default.spanned(span),
-                        component: component.spanned(span),
-                    }
+
+                let period = iter
+                    .next_if(|&(&byte, _)| byte == b'.')
+                    .map(|(_, location)| location);
+
+                let width = iter.next_if(|&(&byte, _)| matches!(byte, b'1'..=b'9')).map(
+                    |(&digit, start_loc)| {
+                        let mut width = u32::from(digit - b'0');
+                        let mut end_loc = start_loc;
+                        while let Some((&digit, location)) =
+                            iter.next_if(|&(&byte, _)| byte.is_ascii_digit())
+                        {
+                            width = width
+                                .saturating_mul(10)
+                                .saturating_add(u32::from(digit - b'0'));
+                            end_loc = location;
+                        }
+                        width.spanned(start_loc.to(end_loc))
+                    },
+                );
+
+                let mut colons = None::<Spanned<u8>>;
+                while let Some((_, location)) = iter.next_if(|&(&byte, _)| byte == b':') {
+                    colons = Some(match colons {
+                        Some(colons) => colons
+                            .saturating_add(1)
+                            .spanned(colons.span.start.to(location)),
+                        None => 1.spanned(location.to_self()),
+                    });
                 }
-                None => {
+
+                let Some((&component, component_loc)) = iter.next() else {
                     return Some(Err(Error {
                         _inner: unused(percent_loc.error("unexpected end of input")),
                         public: InvalidFormatDescription::Expected {
@@ -94,19 +470,26 @@ fn lex(mut input: &[u8]) -> iter::Peekable<impl Iterator<Item = Result<Token<'_>
                             index: percent_loc.byte as usize,
                         },
                     }));
+                };
+
+                Token::Component {
+                    _percent: unused(percent_loc),
+                    padding,
+                    case,
+                    period,
+                    width,
+                    colons,
+                    component: component.spanned(component_loc.to_self()),
                 }
-            },
+            }
             (_, start_location) => {
-                let mut bytes = 1;
                 let mut end_location = start_location;
 
                 while let Some((_, location)) = iter.next_if(|&(&byte, _)| byte != b'%') {
                     end_location = location;
-                    bytes += 1;
                 }
 
-                let value = &input[..bytes];
-                input = &input[bytes..];
+                let value = &input[start_location.byte as usize..=end_location.byte as usize];
 
                 Token::Literal(value.spanned(start_location.to(end_location)))
             }
@@ -118,7 +501,14 @@ fn lex(mut input: &[u8]) -> iter::Peekable<impl Iterator<Item = Result<Token<'_>
 fn into_items<'iter, 'token: 'iter>(
     mut tokens: iter::Peekable<impl Iterator<Item = Result<Token<'token>, Error>> + 'iter>,
 ) -> impl Iterator<Item = Result<BorrowedFormatItem<'token>, Error>> + 'iter {
+    // A component that follows the padding emitted for a field width on the numeric weekday.
+    let mut pending = None;
+
     iter::from_fn(move || {
+        if let Some(item) = pending.take() {
+            return Some(Ok(item));
+        }
+
         let next = match tokens.next()? {
             Ok(token) => token,
             Err(err) => return Some(Err(err)),
@@ -129,185 +519,449 @@ fn into_items<'iter, 'token: 'iter>(
             Token::Component {
                 _percent,
                 padding,
+                case,
+                period,
+                width,
+                colons,
                 component,
-            } => parse_component(padding, component),
+            } => match parse_component(padding, case, period, width, colons, component) {
+                Ok((Some(width_padding), item)) => {
+                    pending = Some(item);
+                    Ok(BorrowedFormatItem::Literal(width_padding))
+                }
+                Ok((None, item)) => Ok(item),
+                Err(err) => Err(err),
+            },
         })
     })
 }
 
+/// The hour of the UTC offset, which always includes the sign.
+pub(super) const OFFSET_HOUR: BorrowedFormatItem<'static> = component!(OffsetHour {
+    sign_is_mandatory: true,
+    padding: modifier::Padding::Zero,
+});
+/// The UTC offset without a separator (`+hhmm`).
+pub(super) const OFFSET_HOUR_MINUTE: BorrowedFormatItem<'static> = BorrowedFormatItem::Compound(&[
+    OFFSET_HOUR,
+    component!(OffsetMinute {
+        padding: modifier::Padding::Zero,
+    }),
+]);
+/// The UTC offset with a colon as the separator (`+hh:mm`).
+pub(super) const OFFSET_HOUR_COLON_MINUTE: BorrowedFormatItem<'static> =
+    BorrowedFormatItem::Compound(&[
+        OFFSET_HOUR,
+        BorrowedFormatItem::Literal(b":"),
+        component!(OffsetMinute {
+            padding: modifier::Padding::Zero,
+        }),
+    ]);
+/// The UTC offset including seconds with colons as the separators (`+hh:mm:ss`).
+pub(super) const OFFSET_HOUR_COLON_MINUTE_COLON_SECOND: BorrowedFormatItem<'static> =
+    BorrowedFormatItem::Compound(&[
+        OFFSET_HOUR,
+        BorrowedFormatItem::Literal(b":"),
+        component!(OffsetMinute {
+            padding: modifier::Padding::Zero,
+        }),
+        BorrowedFormatItem::Literal(b":"),
+        component!(OffsetSecond {
+            padding: modifier::Padding::Zero,
+        }),
+    ]);
+/// The UTC offset as `%:z`. This is formatted as `+hh:mm` but parsed permissively.
+const OFFSET_COLON_Z: BorrowedFormatItem<'static> =
+    BorrowedFormatItem::First(&[OFFSET_HOUR_COLON_MINUTE, OFFSET_HOUR_MINUTE, OFFSET_HOUR]);
+/// The time zone as `%Z`. This is formatted as `+hh:mm`, while `UTC`, `GMT`, and `Z` are
+/// additionally accepted when parsing and imply a UTC offset of zero.
+const ZONE: BorrowedFormatItem<'static> = BorrowedFormatItem::First(&[
+    OFFSET_HOUR_COLON_MINUTE,
+    BorrowedFormatItem::Conditional {
+        condition: Condition::OffsetIsUtc,
+        then: &BorrowedFormatItem::First(&[
+            BorrowedFormatItem::Literal(b"UTC"),
+            BorrowedFormatItem::Literal(b"GMT"),
+            BorrowedFormatItem::Literal(b"Z"),
+        ]),
+        otherwise: &OFFSET_HOUR_COLON_MINUTE,
+    },
+]);
+/// A period followed by the subsecond with as many digits as needed, as `%.f`. Both are omitted
+/// when the subsecond is zero.
+const PERIOD_SUBSECOND_UNLESS_ZERO: BorrowedFormatItem<'static> = BorrowedFormatItem::Conditional {
+    condition: Condition::SubsecondIsZero,
+    then: &BorrowedFormatItem::Compound(&[]),
+    otherwise: &BorrowedFormatItem::Compound(&[
+        BorrowedFormatItem::Literal(b"."),
+        component!(Subsecond {
+            digits: modifier::SubsecondDigits::OneOrMore,
+        }),
+    ]),
+};
+
+/// The maximum number of characters a field width may add to the numeric weekday.
+const MAX_EXCESS_WIDTH: usize = u8::MAX as usize - 1;
+/// Zeroes used to pad the numeric weekday to a field width.
+const ZEROES: &[u8] = &[b'0'; MAX_EXCESS_WIDTH];
+/// Spaces used to pad the numeric weekday to a field width.
+const SPACES: &[u8] = &[b' '; MAX_EXCESS_WIDTH];
+
+/// A period followed by the subsecond, indexed by the number of digits minus one.
+static PERIOD_SUBSECOND: [[BorrowedFormatItem<'static>; 2]; 9] = {
+    /// A period followed by the subsecond with the given number of digits.
+    macro_rules! period_subsecond {
+        ($($digits:ident),*) => {[$([
+            BorrowedFormatItem::Literal(b"."),
+            component!(Subsecond {
+                digits: modifier::SubsecondDigits::$digits,
+            }),
+        ]),*]};
+    }
+    period_subsecond!(One, Two, Three, Four, Five, Six, Seven, Eight, Nine)
+};
+
 fn parse_component(
     padding: Spanned<Padding>,
+    case: Spanned<Case>,
+    period: Option<Location>,
+    width: Option<Spanned<u32>>,
+    colons: Option<Spanned<u8>>,
     component: Spanned<u8>,
-) -> Result<BorrowedFormatItem<'static>, Error> {
-    let padding_or_default = |padding: Padding, default| match padding {
+) -> Result<(Option<&'static [u8]>, BorrowedFormatItem<'static>), Error> {
+    let not_supported = |what, span: Span| Error {
+        _inner: unused(span.error("unsupported modifier")),
+        public: InvalidFormatDescription::NotSupported {
+            what,
+            context: "",
+            index: span.start.byte as usize,
+        },
+    };
+
+    if let (Some(period), false) = (period, *component == b'f') {
+        return Err(not_supported("period flag", period.to_self()));
+    }
+    if let Some(colons) = colons {
+        if *component != b'z' || *colons > 3 {
+            return Err(not_supported("colon flag", colons.span));
+        }
+    }
+
+    let flag_padding = |default| match *padding {
         Padding::Default => default,
         Padding::Spaces => modifier::Padding::Space,
         Padding::None => modifier::Padding::None,
         Padding::Zeroes => modifier::Padding::Zero,
     };
-
-    /// Helper macro to create a component.
-    macro_rules! component {
-        ($name:ident { $($inner:tt)* }) => {
-            BorrowedFormatItem::Component(Component::$name(modifier::$name {
-                $($inner)*
-            }))
+    // Obtain the padding and minimum width of a numeric component, accounting for the flag and
+    // width. A width of one removes the padding, while a width of the natural width is the
+    // default.
+    let numeric_padding = |natural_width: u32, default| {
+        let padding = flag_padding(default);
+        let Some(width) = width else {
+            return Ok((padding, 0));
+        };
+        if *width == natural_width || padding == modifier::Padding::None {
+            return Ok((padding, 0));
         }
-    }
+        if *width == 1 {
+            return Ok((modifier::Padding::None, 0));
+        }
+        match u8::try_from(*width) {
+            Ok(width) if u32::from(width) > natural_width => Ok((padding, width)),
+            _ => Err(not_supported("field width", width.span)),
+        }
+    };
+    // Obtain the padding of a numeric weekday. As the value is always a single digit, a larger
+    // width is emitted as a literal preceding it, which is exact.
+    let width_padding = Cell::new(None);
+    let weekday_padding = || {
+        let (padding, width) = numeric_padding(1, modifier::Padding::Zero)?;
+        if width != 0 {
+            let source = if padding == modifier::Padding::Space {
+                SPACES
+            } else {
+                ZEROES
+            };
+            width_padding.set(Some(&source[..usize::from(width) - 1]));
+        }
+        Ok(())
+    };
+    // Ensure that no width is present, as it cannot be represented.
+    let no_width = || match width {
+        None => Ok(()),
+        Some(width) => Err(not_supported("field width", width.span)),
+    };
+    let text_case = match *case {
+        Case::Default => modifier::TextCase::Title,
+        Case::Upper | Case::Swap => modifier::TextCase::Upper,
+    };
 
-    Ok(match *component {
-        b'%' => BorrowedFormatItem::Literal(b"%"),
-        b'a' => component!(Weekday {
-            repr: modifier::WeekdayRepr::Short,
-            one_indexed: true,
-            case_sensitive: true,
-        }),
-        b'A' => component!(Weekday {
-            repr: modifier::WeekdayRepr::Long,
-            one_indexed: true,
-            case_sensitive: true,
-        }),
-        b'b' | b'h' => component!(Month {
-            repr: modifier::MonthRepr::Short,
-            padding: modifier::Padding::Zero,
-            case_sensitive: true,
-        }),
-        b'B' => component!(Month {
-            repr: modifier::MonthRepr::Long,
-            padding: modifier::Padding::Zero,
-            case_sensitive: true,
-        }),
-        b'c' => BorrowedFormatItem::Compound(&[
+    let item = match *component {
+        b'%' => {
+            no_width()?;
+            BorrowedFormatItem::Literal(b"%")
+        }
+        b'a' => {
+            no_width()?;
             component!(Weekday {
                 repr: modifier::WeekdayRepr::Short,
                 one_indexed: true,
+                case: text_case,
                 case_sensitive: true,
-            }),
-            BorrowedFormatItem::Literal(b" "),
+            })
+        }
+        b'A' => {
+            no_width()?;
+            component!(Weekday {
+                repr: modifier::WeekdayRepr::Long,
+                one_indexed: true,
+                case: text_case,
+                case_sensitive: true,
+            })
+        }
+        b'b' | b'h' => {
+            no_width()?;
             component!(Month {
                 repr: modifier::MonthRepr::Short,
                 padding: modifier::Padding::Zero,
+                width: 0,
+                case: text_case,
                 case_sensitive: true,
-            }),
-            BorrowedFormatItem::Literal(b" "),
-            component!(Day {
-                padding: modifier::Padding::Space
-            }),
-            BorrowedFormatItem::Literal(b" "),
-            component!(Hour {
-                padding: modifier::Padding::Zero,
-                is_12_hour_clock: false,
-            }),
-            BorrowedFormatItem::Literal(b":"),
-            component!(Minute {
-                padding: modifier::Padding::Zero,
-            }),
-            BorrowedFormatItem::Literal(b":"),
-            component!(Second {
+            })
+        }
+        b'B' => {
+            no_width()?;
+            component!(Month {
+                repr: modifier::MonthRepr::Long,
                 padding: modifier::Padding::Zero,
-            }),
-            BorrowedFormatItem::Literal(b" "),
+                width: 0,
+                case: text_case,
+                case_sensitive: true,
+            })
+        }
+        b'c' => {
+            no_width()?;
+            BorrowedFormatItem::Compound(&[
+                component!(Weekday {
+                    repr: modifier::WeekdayRepr::Short,
+                    one_indexed: true,
+                    case: modifier::TextCase::Title,
+                    case_sensitive: true,
+                }),
+                BorrowedFormatItem::Literal(b" "),
+                component!(Month {
+                    repr: modifier::MonthRepr::Short,
+                    padding: modifier::Padding::Zero,
+                    width: 0,
+                    case: modifier::TextCase::Title,
+                    case_sensitive: true,
+                }),
+                BorrowedFormatItem::Literal(b" "),
+                component!(Day {
+                    padding: modifier::Padding::Space,
+                    width: 0
+                }),
+                BorrowedFormatItem::Literal(b" "),
+                component!(Hour {
+                    padding: modifier::Padding::Zero,
+                    width: 0,
+                    is_12_hour_clock: false,
+                }),
+                BorrowedFormatItem::Literal(b":"),
+                component!(Minute {
+                    padding: modifier::Padding::Zero,
+                    width: 0,
+                }),
+                BorrowedFormatItem::Literal(b":"),
+                component!(Second {
+                    padding: modifier::Padding::Zero,
+                    width: 0,
+                }),
+                BorrowedFormatItem::Literal(b" "),
+                component!(Year {
+                    padding: modifier::Padding::Zero,
+                    width: 0,
+                    repr: modifier::YearRepr::Full,
+                    range: modifier::YearRange::Extended,
+                    iso_week_based: false,
+                    sign_is_mandatory: false,
+                    pivot: modifier::YearPivot::NONE,
+                    era_based: false,
+                }),
+            ])
+        }
+        b'C' => {
+            let (padding, width) = numeric_padding(2, modifier::Padding::Zero)?;
             component!(Year {
-                padding: modifier::Padding::Zero,
-                repr: modifier::YearRepr::Full,
+                padding,
+                width,
+                repr: modifier::YearRepr::Century,
                 range: modifier::YearRange::Extended,
                 iso_week_based: false,
                 sign_is_mandatory: false,
-            }),
-        ]),
-        b'C' => component!(Year {
-            padding: padding_or_default(*padding, modifier::Padding::Zero),
-            repr: modifier::YearRepr::Century,
-            range: modifier::YearRange::Extended,
-            iso_week_based: false,
-            sign_is_mandatory: false,
-        }),
-        b'd' => component!(Day {
-            padding: padding_or_default(*padding, modifier::Padding::Zero),
-        }),
-        b'D' => BorrowedFormatItem::Compound(&[
-            component!(Month {
-                repr: modifier::MonthRepr::Numerical,
-                padding: modifier::Padding::Zero,
-                case_sensitive: true,
-            }),
-            BorrowedFormatItem::Literal(b"/"),
-            component!(Day {
-                padding: modifier::Padding::Zero,
-            }),
-            BorrowedFormatItem::Literal(b"/"),
+                pivot: modifier::YearPivot::NONE,
+                era_based: false,
+            })
+        }
+        b'd' => {
+            let (padding, width) = numeric_padding(2, modifier::Padding::Zero)?;
+            component!(Day { padding, width })
+        }
+        b'D' => {
+            no_width()?;
+            BorrowedFormatItem::Compound(&[
+                component!(Month {
+                    repr: modifier::MonthRepr::Numerical,
+                    padding: modifier::Padding::Zero,
+                    width: 0,
+                    case: modifier::TextCase::Title,
+                    case_sensitive: true,
+                }),
+                BorrowedFormatItem::Literal(b"/"),
+                component!(Day {
+                    padding: modifier::Padding::Zero,
+                    width: 0,
+                }),
+                BorrowedFormatItem::Literal(b"/"),
+                component!(Year {
+                    padding: modifier::Padding::Zero,
+                    width: 0,
+                    repr: modifier::YearRepr::LastTwo,
+                    range: modifier::YearRange::Extended,
+                    iso_week_based: false,
+                    sign_is_mandatory: false,
+                    pivot: modifier::YearPivot::NONE,
+                    era_based: false,
+                }),
+            ])
+        }
+        b'e' => {
+            let (padding, width) = numeric_padding(2, modifier::Padding::Space)?;
+            component!(Day { padding, width })
+        }
+        b'f' | b'N' => {
+            let digits = match width.map(|width| *width) {
+                None => 9,
+                Some(digits @ 1..=9) => digits,
+                Some(_) => return Err(no_width().expect_err("width is present")),
+            };
+            if period.is_some() && width.is_none() {
+                PERIOD_SUBSECOND_UNLESS_ZERO
+            } else if period.is_some() {
+                BorrowedFormatItem::Compound(&PERIOD_SUBSECOND[digits as usize - 1])
+            } else {
+                PERIOD_SUBSECOND[digits as usize - 1][1].clone()
+            }
+        }
+        b'F' => {
+            no_width()?;
+            BorrowedFormatItem::Compound(&[
+                component!(Year {
+                    padding: modifier::Padding::Zero,
+                    width: 0,
+                    repr: modifier::YearRepr::Full,
+                    range: modifier::YearRange::Extended,
+                    iso_week_based: false,
+                    sign_is_mandatory: false,
+                    pivot: modifier::YearPivot::NONE,
+                    era_based: false,
+                }),
+                BorrowedFormatItem::Literal(b"-"),
+                component!(Month {
+                    padding: modifier::Padding::Zero,
+                    width: 0,
+                    repr: modifier::MonthRepr::Numerical,
+                    case: modifier::TextCase::Title,
+                    case_sensitive: true,
+                }),
+                BorrowedFormatItem::Literal(b"-"),
+                component!(Day {
+                    padding: modifier::Padding::Zero,
+                    width: 0,
+                }),
+            ])
+        }
+        b'g' => {
+            let (padding, width) = numeric_padding(2, modifier::Padding::Zero)?;
             component!(Year {
-                padding: modifier::Padding::Zero,
+                padding,
+                width,
                 repr: modifier::YearRepr::LastTwo,
                 range: modifier::YearRange::Extended,
-                iso_week_based: false,
+                iso_week_based: true,
                 sign_is_mandatory: false,
-            }),
-        ]),
-        b'e' => component!(Day {
-            padding: padding_or_default(*padding, modifier::Padding::Space),
-        }),
-        b'F' => BorrowedFormatItem::Compound(&[
+                pivot: modifier::YearPivot::NONE,
+                era_based: false,
+            })
+        }
+        b'G' => {
+            let (padding, width) = numeric_padding(4, modifier::Padding::Zero)?;
             component!(Year {
-                padding: modifier::Padding::Zero,
+                padding,
+                width,
                 repr: modifier::YearRepr::Full,
                 range: modifier::YearRange::Extended,
-                iso_week_based: false,
+                iso_week_based: true,
                 sign_is_mandatory: false,
-            }),
-            BorrowedFormatItem::Literal(b"-"),
+                pivot: modifier::YearPivot::NONE,
+                era_based: false,
+            })
+        }
+        b'H' => {
+            let (padding, width) = numeric_padding(2, modifier::Padding::Zero)?;
+            component!(Hour {
+                padding,
+                width,
+                is_12_hour_clock: false,
+            })
+        }
+        b'I' => {
+            let (padding, width) = numeric_padding(2, modifier::Padding::Zero)?;
+            component!(Hour {
+                padding,
+                width,
+                is_12_hour_clock: true,
+            })
+        }
+        b'j' => {
+            let (padding, width) = numeric_padding(3, modifier::Padding::Zero)?;
+            component!(Ordinal { padding, width })
+        }
+        b'k' => {
+            let (padding, width) = numeric_padding(2, modifier::Padding::Space)?;
+            component!(Hour {
+                padding,
+                width,
+                is_12_hour_clock: false,
+            })
+        }
+        b'l' => {
+            let (padding, width) = numeric_padding(2, modifier::Padding::Space)?;
+            component!(Hour {
+                padding,
+                width,
+                is_12_hour_clock: true,
+            })
+        }
+        b'm' => {
+            let (padding, width) = numeric_padding(2, modifier::Padding::Zero)?;
             component!(Month {
-                padding: modifier::Padding::Zero,
+                padding,
+                width,
                 repr: modifier::MonthRepr::Numerical,
+                case: modifier::TextCase::Title,
                 case_sensitive: true,
-            }),
-            BorrowedFormatItem::Literal(b"-"),
-            component!(Day {
-                padding: modifier::Padding::Zero,
-            }),
-        ]),
-        b'g' => component!(Year {
-            padding: padding_or_default(*padding, modifier::Padding::Zero),
-            repr: modifier::YearRepr::LastTwo,
-            range: modifier::YearRange::Extended,
-            iso_week_based: true,
-            sign_is_mandatory: false,
-        }),
-        b'G' => component!(Year {
-            padding: modifier::Padding::Zero,
-            repr: modifier::YearRepr::Full,
-            range: modifier::YearRange::Extended,
-            iso_week_based: true,
-            sign_is_mandatory: false,
-        }),
-        b'H' => component!(Hour {
-            padding: padding_or_default(*padding, modifier::Padding::Zero),
-            is_12_hour_clock: false,
-        }),
-        b'I' => component!(Hour {
-            padding: padding_or_default(*padding, modifier::Padding::Zero),
-            is_12_hour_clock: true,
-        }),
-        b'j' => component!(Ordinal {
-            padding: padding_or_default(*padding, modifier::Padding::Zero),
-        }),
-        b'k' => component!(Hour {
-            padding: padding_or_default(*padding, modifier::Padding::Space),
-            is_12_hour_clock: false,
-        }),
-        b'l' => component!(Hour {
-            padding: padding_or_default(*padding, modifier::Padding::Space),
-            is_12_hour_clock: true,
-        }),
-        b'm' => component!(Month {
-            padding: padding_or_default(*padding, modifier::Padding::Zero),
-            repr: modifier::MonthRepr::Numerical,
-            case_sensitive: true,
-        }),
-        b'M' => component!(Minute {
-            padding: padding_or_default(*padding, modifier::Padding::Zero),
-        }),
-        b'n' => BorrowedFormatItem::Literal(b"\n"),
+            })
+        }
+        b'M' => {
+            let (padding, width) = numeric_padding(2, modifier::Padding::Zero)?;
+            component!(Minute { padding, width })
+        }
+        b'n' => {
+            no_width()?;
+            BorrowedFormatItem::Literal(b"\n")
+        }
         b'O' => {
             return Err(Error {
                 _inner: unused(ErrorInner {
@@ -321,155 +975,280 @@ fn parse_component(
                 },
             })
         }
-        b'p' => component!(Period {
-            is_uppercase: true,
-            case_sensitive: true
-        }),
-        b'P' => component!(Period {
-            is_uppercase: false,
-            case_sensitive: true
-        }),
-        b'r' => BorrowedFormatItem::Compound(&[
-            component!(Hour {
-                padding: modifier::Padding::Zero,
-                is_12_hour_clock: true,
-            }),
-            BorrowedFormatItem::Literal(b":"),
-            component!(Minute {
-                padding: modifier::Padding::Zero,
-            }),
-            BorrowedFormatItem::Literal(b":"),
-            component!(Second {
-                padding: modifier::Padding::Zero,
-            }),
-            BorrowedFormatItem::Literal(b" "),
+        b'p' => {
+            no_width()?;
             component!(Period {
-                is_uppercase: true,
+                is_uppercase: *case != Case::Swap,
+                repr: modifier::PeriodRepr::Short,
+                case_sensitive: true
+            })
+        }
+        // As with glibc, this is always lowercase, regardless of any flag.
+        b'P' => {
+            no_width()?;
+            component!(Period {
+                is_uppercase: false,
+                repr: modifier::PeriodRepr::Short,
+                case_sensitive: true
+            })
+        }
+        b'r' => {
+            no_width()?;
+            BorrowedFormatItem::Compound(&[
+                component!(Hour {
+                    padding: modifier::Padding::Zero,
+                    width: 0,
+                    is_12_hour_clock: true,
+                }),
+                BorrowedFormatItem::Literal(b":"),
+                component!(Minute {
+                    padding: modifier::Padding::Zero,
+                    width: 0,
+                }),
+                BorrowedFormatItem::Literal(b":"),
+                component!(Second {
+                    padding: modifier::Padding::Zero,
+                    width: 0,
+                }),
+                BorrowedFormatItem::Literal(b" "),
+                component!(Period {
+                    is_uppercase: true,
+                    repr: modifier::PeriodRepr::Short,
+                    case_sensitive: true,
+                }),
+            ])
+        }
+        b'R' => {
+            no_width()?;
+            BorrowedFormatItem::Compound(&[
+                component!(Hour {
+                    padding: modifier::Padding::Zero,
+                    width: 0,
+                    is_12_hour_clock: false,
+                }),
+                BorrowedFormatItem::Literal(b":"),
+                component!(Minute {
+                    padding: modifier::Padding::Zero,
+                    width: 0,
+                }),
+            ])
+        }
+        b's' => {
+            no_width()?;
+            component!(UnixTimestamp {
+                precision: modifier::UnixTimestampPrecision::Second,
+                sign_is_mandatory: false,
+            })
+        }
+        b'S' => {
+            let (padding, width) = numeric_padding(2, modifier::Padding::Zero)?;
+            component!(Second { padding, width })
+        }
+        b't' => {
+            no_width()?;
+            BorrowedFormatItem::Literal(b"\t")
+        }
+        b'T' => {
+            no_width()?;
+            BorrowedFormatItem::Compound(&[
+                component!(Hour {
+                    padding: modifier::Padding::Zero,
+                    width: 0,
+                    is_12_hour_clock: false,
+                }),
+                BorrowedFormatItem::Literal(b":"),
+                component!(Minute {
+                    padding: modifier::Padding::Zero,
+                    width: 0,
+                }),
+                BorrowedFormatItem::Literal(b":"),
+                component!(Second {
+                    padding: modifier::Padding::Zero,
+                    width: 0,
+                }),
+            ])
+        }
+        b'u' => {
+            weekday_padding()?;
+            component!(Weekday {
+                repr: modifier::WeekdayRepr::Monday,
+                one_indexed: true,
+                case: modifier::TextCase::Title,
                 case_sensitive: true,
-            }),
-        ]),
-        b'R' => BorrowedFormatItem::Compound(&[
-            component!(Hour {
-                padding: modifier::Padding::Zero,
-                is_12_hour_clock: false,
-            }),
-            BorrowedFormatItem::Literal(b":"),
-            component!(Minute {
-                padding: modifier::Padding::Zero,
-            }),
-        ]),
-        b's' => component!(UnixTimestamp {
-            precision: modifier::UnixTimestampPrecision::Second,
-            sign_is_mandatory: false,
-        }),
-        b'S' => component!(Second {
-            padding: padding_or_default(*padding, modifier::Padding::Zero),
-        }),
-        b't' => BorrowedFormatItem::Literal(b"\t"),
-        b'T' => BorrowedFormatItem::Compound(&[
-            component!(Hour {
-                padding: modifier::Padding::Zero,
-                is_12_hour_clock: false,
-            }),
-            BorrowedFormatItem::Literal(b":"),
-            component!(Minute {
-                padding: modifier::Padding::Zero,
-            }),
-            BorrowedFormatItem::Literal(b":"),
-            component!(Second {
-                padding: modifier::Padding::Zero,
-            }),
-        ]),
-        b'u' => component!(Weekday {
-            repr: modifier::WeekdayRepr::Monday,
-            one_indexed: true,
-            case_sensitive: true,
-        }),
-        b'U' => component!(WeekNumber {
-            padding: padding_or_default(*padding, modifier::Padding::Zero),
-            repr: modifier::WeekNumberRepr::Sunday,
-        }),
-        b'V' => component!(WeekNumber {
-            padding: padding_or_default(*padding, modifier::Padding::Zero),
-            repr: modifier::WeekNumberRepr::Iso,
-        }),
-        b'w' => component!(Weekday {
-            repr: modifier::WeekdayRepr::Sunday,
-            one_indexed: true,
-            case_sensitive: true,
-        }),
-        b'W' => component!(WeekNumber {
-            padding: padding_or_default(*padding, modifier::Padding::Zero),
-            repr: modifier::WeekNumberRepr::Monday,
-        }),
-        b'x' => BorrowedFormatItem::Compound(&[
-            component!(Month {
-                repr: modifier::MonthRepr::Numerical,
-                padding: modifier::Padding::Zero,
+            })
+        }
+        b'U' => {
+            let (padding, width) = numeric_padding(2, modifier::Padding::Zero)?;
+            component!(WeekNumber {
+                padding,
+                width,
+                repr: modifier::WeekNumberRepr::Sunday,
+            })
+        }
+        b'V' => {
+            let (padding, width) = numeric_padding(2, modifier::Padding::Zero)?;
+            component!(WeekNumber {
+                padding,
+                width,
+                repr: modifier::WeekNumberRepr::Iso,
+            })
+        }
+        b'w' => {
+            weekday_padding()?;
+            component!(Weekday {
+                repr: modifier::WeekdayRepr::Sunday,
+                one_indexed: true,
+                case: modifier::TextCase::Title,
                 case_sensitive: true,
-            }),
-            BorrowedFormatItem::Literal(b"/"),
-            component!(Day {
-                padding: modifier::Padding::Zero
-            }),
-            BorrowedFormatItem::Literal(b"/"),
+            })
+        }
+        b'W' => {
+            let (padding, width) = numeric_padding(2, modifier::Padding::Zero)?;
+            component!(WeekNumber {
+                padding,
+                width,
+                repr: modifier::WeekNumberRepr::Monday,
+            })
+        }
+        b'x' => {
+            no_width()?;
+            BorrowedFormatItem::Compound(&[
+                component!(Month {
+                    repr: modifier::MonthRepr::Numerical,
+                    padding: modifier::Padding::Zero,
+                    width: 0,
+                    case: modifier::TextCase::Title,
+                    case_sensitive: true,
+                }),
+                BorrowedFormatItem::Literal(b"/"),
+                component!(Day {
+                    padding: modifier::Padding::Zero,
+                    width: 0
+                }),
+                BorrowedFormatItem::Literal(b"/"),
+                component!(Year {
+                    padding: modifier::Padding::Zero,
+                    width: 0,
+                    repr: modifier::YearRepr::LastTwo,
+                    range: modifier::YearRange::Extended,
+                    iso_week_based: false,
+                    sign_is_mandatory: false,
+                    pivot: modifier::YearPivot::NONE,
+                    era_based: false,
+                }),
+            ])
+        }
+        b'X' => {
+            no_width()?;
+            BorrowedFormatItem::Compound(&[
+                component!(Hour {
+                    padding: modifier::Padding::Zero,
+                    width: 0,
+                    is_12_hour_clock: false,
+                }),
+                BorrowedFormatItem::Literal(b":"),
+                component!(Minute {
+                    padding: modifier::Padding::Zero,
+                    width: 0,
+                }),
+                BorrowedFormatItem::Literal(b":"),
+                component!(Second {
+                    padding: modifier::Padding::Zero,
+                    width: 0,
+                }),
+            ])
+        }
+        b'y' => {
+            let (padding, width) = numeric_padding(2, modifier::Padding::Zero)?;
             component!(Year {
-                padding: modifier::Padding::Zero,
+                padding,
+                width,
                 repr: modifier::YearRepr::LastTwo,
                 range: modifier::YearRange::Extended,
                 iso_week_based: false,
                 sign_is_mandatory: false,
-            }),
-        ]),
-        b'X' => BorrowedFormatItem::Compound(&[
-            component!(Hour {
-                padding: modifier::Padding::Zero,
-                is_12_hour_clock: false,
-            }),
-            BorrowedFormatItem::Literal(b":"),
-            component!(Minute {
-                padding: modifier::Padding::Zero,
-            }),
-            BorrowedFormatItem::Literal(b":"),
-            component!(Second {
-                padding: modifier::Padding::Zero,
-            }),
-        ]),
-        b'y' => component!(Year {
-            padding: padding_or_default(*padding, modifier::Padding::Zero),
-            repr: modifier::YearRepr::LastTwo,
-            range: modifier::YearRange::Extended,
-            iso_week_based: false,
-            sign_is_mandatory: false,
-        }),
-        b'Y' => component!(Year {
-            padding: modifier::Padding::Zero,
-            repr: modifier::YearRepr::Full,
-            range: modifier::YearRange::Extended,
-            iso_week_based: false,
-            sign_is_mandatory: false,
-        }),
-        b'z' => BorrowedFormatItem::Compound(&[
-            component!(OffsetHour {
-                sign_is_mandatory: true,
-                padding: modifier::Padding::Zero,
-            }),
-            component!(OffsetMinute {
-                padding: modifier::Padding::Zero,
-            }),
-        ]),
+                pivot: modifier::YearPivot::NONE,
+                era_based: false,
+            })
+        }
+        b'Y' => {
+            let (padding, width) = numeric_padding(4, modifier::Padding::Zero)?;
+            component!(Year {
+                padding,
+                width,
+                repr: modifier::YearRepr::Full,
+                range: modifier::YearRange::Extended,
+                iso_week_based: false,
+                sign_is_mandatory: false,
+                pivot: modifier::YearPivot::NONE,
+                era_based: false,
+            })
+        }
+        b'z' => {
+            no_width()?;
+            match colons.map_or(0, |colons| *colons) {
+                0 => BorrowedFormatItem::First(&[
+                    OFFSET_HOUR_MINUTE,
+                    OFFSET_HOUR_COLON_MINUTE,
+                    OFFSET_HOUR,
+                ]),
+                1 => OFFSET_COLON_Z,
+                2 => BorrowedFormatItem::First(&[
+                    OFFSET_HOUR_COLON_MINUTE_COLON_SECOND,
+                    OFFSET_HOUR_COLON_MINUTE,
+                ]),
+                _ => OFFSET_HOUR,
+            }
+        }
         b'Z' => {
-            return Err(Error {
-                _inner: unused(ErrorInner {
-                    _message: "unsupported component",
-                    _span: component.span,
+            no_width()?;
+            ZONE
+        }
+        b'+' => {
+            no_width()?;
+            BorrowedFormatItem::Compound(&[
+                component!(Year {
+                    padding: modifier::Padding::Zero,
+                    width: 0,
+                    repr: modifier::YearRepr::Full,
+                    range: modifier::YearRange::Extended,
+                    iso_week_based: false,
+                    sign_is_mandatory: false,
+                    pivot: modifier::YearPivot::NONE,
+                    era_based: false,
                 }),
-                public: InvalidFormatDescription::NotSupported {
-                    what: "component",
-                    context: "",
-                    index: component.span.start.byte as usize,
-                },
-            })
+                BorrowedFormatItem::Literal(b"-"),
+                component!(Month {
+                    padding: modifier::Padding::Zero,
+                    width: 0,
+                    repr: modifier::MonthRepr::Numerical,
+                    case: modifier::TextCase::Title,
+                    case_sensitive: true,
+                }),
+                BorrowedFormatItem::Literal(b"-"),
+                component!(Day {
+                    padding: modifier::Padding::Zero,
+                    width: 0,
+                }),
+                BorrowedFormatItem::Literal(b"T"),
+                component!(Hour {
+                    padding: modifier::Padding::Zero,
+                    width: 0,
+                    is_12_hour_clock: false,
+                }),
+                BorrowedFormatItem::Literal(b":"),
+                component!(Minute {
+                    padding: modifier::Padding::Zero,
+                    width: 0,
+                }),
+                BorrowedFormatItem::Literal(b":"),
+                component!(Second {
+                    padding: modifier::Padding::Zero,
+                    width: 0,
+                }),
+                PERIOD_SUBSECOND_UNLESS_ZERO,
+                OFFSET_COLON_Z,
+            ])
         }
         _ => {
             return Err(Error {
@@ -483,5 +1262,7 @@ fn parse_component(
                 },
             })
         }
-    })
+    };
+
+    Ok((width_padding.get(), item))
 }
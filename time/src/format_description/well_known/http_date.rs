@@ -0,0 +1,49 @@
+//! The format described in RFC 9110.
+
+/// The `HTTP-date` format described in [RFC 9110](https://datatracker.ietf.org/doc/html/rfc9110#section-5.6.7).
+///
+/// Formatting always produces the preferred IMF-fixdate form in GMT, converting the value to UTC
+/// if necessary. Parsing accepts IMF-fixdate as well as the obsolete RFC 850 and asctime forms.
+/// Unlike [`Rfc2822`](super::Rfc2822), no whitespace, comments, or time zones other than GMT are
+/// permitted, and names are case-sensitive.
+///
+/// The RFC 850 form has a two-digit year. As required by the RFC, a year that appears to be more
+/// than 50 years in the future is resolved to the most recent year in the past with the same last
+/// two digits. This is relative to the current year when the `std` feature is enabled. Without it,
+/// the current year is not known, and the year is resolved to a year between 1970 and 2069
+/// (inclusive). The weekday must agree with the date in every form.
+///
+/// Format example: `Sun, 06 Nov 1994 08:49:37 GMT`
+///
+/// # Examples
+#[cfg_attr(feature = "parsing", doc = "```rust")]
+#[cfg_attr(not(feature = "parsing"), doc = "```rust,ignore")]
+/// # use time::{format_description::well_known::HttpDate, OffsetDateTime};
+/// # use time_macros::datetime;
+/// assert_eq!(
+///     OffsetDateTime::parse("Sun, 06 Nov 1994 08:49:37 GMT", &HttpDate)?,
+///     datetime!(1994-11-06 08:49:37 UTC)
+/// );
+/// assert_eq!(
+///     OffsetDateTime::parse("Sunday, 06-Nov-94 08:49:37 GMT", &HttpDate)?,
+///     datetime!(1994-11-06 08:49:37 UTC)
+/// );
+/// assert_eq!(
+///     OffsetDateTime::parse("Sun Nov  6 08:49:37 1994", &HttpDate)?,
+///     datetime!(1994-11-06 08:49:37 UTC)
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+///
+#[cfg_attr(feature = "formatting", doc = "```rust")]
+#[cfg_attr(not(feature = "formatting"), doc = "```rust,ignore")]
+/// # use time::format_description::well_known::HttpDate;
+/// # use time_macros::datetime;
+/// assert_eq!(
+///     datetime!(1994-11-06 09:49:37 +01:00).format(&HttpDate)?,
+///     "Sun, 06 Nov 1994 08:49:37 GMT"
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HttpDate;
@@ -2,7 +2,7 @@
 
 use core::num::NonZero;
 
-#[cfg(feature = "formatting")]
+#[cfg(feature = "formatting-core")]
 use super::Iso8601;
 use super::{Config, DateKind, FormattedComponents as FC, OffsetPrecision, TimePrecision};
 
@@ -17,7 +17,7 @@ pub type DoNotRelyOnWhatThisIs = u128;
 /// notice.
 pub type EncodedConfig = DoNotRelyOnWhatThisIs;
 
-#[cfg(feature = "formatting")]
+#[cfg(feature = "formatting-core")]
 impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
     /// The user-provided configuration for the ISO 8601 format.
     const CONFIG: Config = Config::decode(CONFIG);
@@ -46,6 +46,68 @@ impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
     pub(crate) const TIME_PRECISION: TimePrecision = Self::CONFIG.time_precision;
     /// The precision for the UTC offset.
     pub(crate) const OFFSET_PRECISION: OffsetPrecision = Self::CONFIG.offset_precision;
+    /// The maximum number of bytes produced when formatting with this configuration.
+    pub(crate) const MAX_LEN: usize = Self::CONFIG.max_formatted_len();
+}
+
+#[cfg(feature = "formatting-core")]
+impl Config {
+    /// The configuration that produces the longest output of any configuration.
+    const LONGEST: Self = Self::DEFAULT
+        .set_year_is_six_digits(true)
+        .set_date_kind(DateKind::Week)
+        .set_time_precision(TimePrecision::Second {
+            decimal_digits: NonZero::new(u8::MAX),
+        });
+
+    /// The maximum number of bytes produced when formatting with any configuration.
+    pub(crate) const MAX_FORMATTED_LEN: usize = Self::LONGEST.max_formatted_len();
+
+    /// The maximum number of bytes produced when formatting with this configuration.
+    const fn max_formatted_len(&self) -> usize {
+        let format_date = matches!(
+            self.formatted_components,
+            FC::Date | FC::DateTime | FC::DateTimeOffset
+        );
+        let format_time = matches!(
+            self.formatted_components,
+            FC::Time | FC::DateTime | FC::DateTimeOffset | FC::TimeOffset
+        );
+        let format_offset = matches!(
+            self.formatted_components,
+            FC::Offset | FC::DateTimeOffset | FC::TimeOffset
+        );
+        let separator = self.use_separators as usize;
+
+        let mut len = 0;
+        if format_date {
+            len += if self.year_is_six_digits { 7 } else { 4 };
+            len += match self.date_kind {
+                DateKind::Calendar => 4 + 2 * separator,
+                DateKind::Week => 4 + 2 * separator,
+                DateKind::Ordinal => 3 + separator,
+            };
+        }
+        if format_time {
+            len += (self.use_separators || format_date) as usize;
+            let (components, decimal_digits) = match self.time_precision {
+                TimePrecision::Hour { decimal_digits } => (1, decimal_digits),
+                TimePrecision::Minute { decimal_digits } => (2, decimal_digits),
+                TimePrecision::Second { decimal_digits } => (3, decimal_digits),
+            };
+            len += 2 * components + (components - 1) * separator;
+            if let Some(decimal_digits) = decimal_digits {
+                len += 1 + decimal_digits.get() as usize;
+            }
+        }
+        if format_offset {
+            len += 3;
+            if matches!(self.offset_precision, OffsetPrecision::Minute) {
+                len += 2 + separator;
+            }
+        }
+        len
+    }
 }
 
 impl Config {
@@ -225,6 +287,27 @@ mod tests {
         assert_roundtrip!(Config::DEFAULT.set_offset_precision(OffsetPrecision::Minute));
     }
 
+    #[cfg(feature = "formatting-core")]
+    #[test]
+    fn max_formatted_len() {
+        assert_eq!(Iso8601::<{ Config::DEFAULT.encode() }>::MAX_LEN, 35);
+        assert_eq!(
+            Iso8601::<{ Config::DEFAULT.set_formatted_components(FC::Date).encode() }>::MAX_LEN,
+            10
+        );
+        assert_eq!(
+            Iso8601::<
+                {
+                    Config::DEFAULT
+                        .set_formatted_components(FC::TimeOffset)
+                        .encode()
+                },
+            >::MAX_LEN,
+            25
+        );
+        assert_eq!(Config::MAX_FORMATTED_LEN, 284);
+    }
+
     macro_rules! assert_decode_fail {
         ($encoding:expr) => {
             assert!(std::panic::catch_unwind(|| {
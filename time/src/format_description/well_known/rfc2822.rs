@@ -4,6 +4,22 @@
 ///
 /// Example: Fri, 21 Nov 1997 09:55:06 -0600
 ///
+/// When parsing, the obsolete syntax of [RFC 5322 §4] is accepted in addition to the current
+/// syntax. This includes comments and folding whitespace between any two tokens, two- and
+/// three-digit years, and the obsolete named time zones. A two-digit year less than 50 has 2000
+/// added to it, while any other two- or three-digit year has 1900 added to it. The `-0000` offset
+/// and the military time zones (single letters other than `J`) indicate that the local offset is
+/// unknown; as no further information is available, they are parsed as UTC.
+#[cfg_attr(
+    feature = "parsing",
+    doc = "This can be distinguished from an explicit UTC offset with \
+           [`Parsed::offset_is_unknown`](crate::parsing::Parsed::offset_is_unknown)."
+)]
+///
+/// When formatting, the year must be between 0 and 9999 (inclusive).
+///
+/// [RFC 5322 §4]: https://tools.ietf.org/html/rfc5322#section-4
+///
 /// # Examples
 #[cfg_attr(feature = "parsing", doc = "```rust")]
 #[cfg_attr(not(feature = "parsing"), doc = "```rust,ignore")]
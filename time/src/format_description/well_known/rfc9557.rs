@@ -0,0 +1,39 @@
+//! The format described in RFC 9557.
+
+/// The format described in [RFC 9557](https://datatracker.ietf.org/doc/html/rfc9557#section-4.1).
+///
+/// This is the format of [RFC 3339](super::Rfc3339), optionally followed by a time zone annotation
+/// and any number of extension annotations. Critical annotations that cannot be acted on cause
+/// parsing to fail. Only the ISO 8601 calendar is supported, so a critical `u-ca` annotation is
+/// accepted only if its value is `iso8601` or `gregory`. The annotations themselves can be
+/// obtained with [`Parsed::parse_rfc9557`](crate::parsing::Parsed::parse_rfc9557).
+///
+/// When formatting, the UTC offset is always written numerically and repeated as a time zone
+/// annotation.
+///
+/// Format example: `2022-07-08T00:14:07+01:00[Europe/Paris][u-ca=hebrew]`
+///
+/// # Examples
+#[cfg_attr(feature = "parsing", doc = "```rust")]
+#[cfg_attr(not(feature = "parsing"), doc = "```rust,ignore")]
+/// # use time::{format_description::well_known::Rfc9557, OffsetDateTime};
+/// # use time_macros::datetime;
+/// assert_eq!(
+///     OffsetDateTime::parse("2022-07-08T00:14:07+01:00[Europe/Paris]", &Rfc9557)?,
+///     datetime!(2022-07-08 00:14:07 +01:00)
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+///
+#[cfg_attr(feature = "formatting", doc = "```rust")]
+#[cfg_attr(not(feature = "formatting"), doc = "```rust,ignore")]
+/// # use time::format_description::well_known::Rfc9557;
+/// # use time_macros::datetime;
+/// assert_eq!(
+///     datetime!(2022-07-08 00:14:07 +01:00).format(&Rfc9557)?,
+///     "2022-07-08T00:14:07+01:00[+01:00]"
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rfc9557;
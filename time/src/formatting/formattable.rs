@@ -1,19 +1,23 @@
 //! A trait that can be used to format an item from its components.
 
+#[cfg(feature = "formatting")]
 use alloc::string::String;
+#[cfg(feature = "formatting")]
 use alloc::vec::Vec;
 use core::ops::Deref;
-use std::io;
 
 use num_conv::prelude::*;
 
 use crate::format_description::well_known::iso8601::EncodedConfig;
-use crate::format_description::well_known::{Iso8601, Rfc2822, Rfc3339};
-use crate::format_description::{BorrowedFormatItem, OwnedFormatItem};
+use crate::format_description::well_known::{HttpDate, Iso8601, Rfc2822, Rfc3339, Rfc9557};
+#[cfg(feature = "alloc")]
+use crate::format_description::OwnedFormatItem;
+use crate::format_description::{modifier, BorrowedFormatItem, Component};
 use crate::formatting::{
-    format_component, format_number_pad_zero, iso8601, write, MONTH_NAMES, WEEKDAY_NAMES,
+    condition_holds, format_component, format_number_pad_zero, iso8601, write, Output, MONTH_NAMES,
+    WEEKDAY_NAMES,
 };
-use crate::{error, Date, Time, UtcOffset};
+use crate::{error, Date, OffsetDateTime, Time, UtcOffset};
 
 /// A type that describes a format.
 ///
@@ -25,10 +29,14 @@ use crate::{error, Date, Time, UtcOffset};
 pub trait Formattable: sealed::Sealed {}
 impl Formattable for BorrowedFormatItem<'_> {}
 impl Formattable for [BorrowedFormatItem<'_>] {}
+#[cfg(feature = "alloc")]
 impl Formattable for OwnedFormatItem {}
+#[cfg(feature = "alloc")]
 impl Formattable for [OwnedFormatItem] {}
 impl Formattable for Rfc3339 {}
 impl Formattable for Rfc2822 {}
+impl Formattable for HttpDate {}
+impl Formattable for Rfc9557 {}
 impl<const CONFIG: EncodedConfig> Formattable for Iso8601<CONFIG> {}
 impl<T: Deref> Formattable for T where T::Target: Formattable {}
 
@@ -41,13 +49,20 @@ mod sealed {
         /// Format the item into the provided output, returning the number of bytes written.
         fn format_into(
             &self,
-            output: &mut (impl io::Write + ?Sized),
+            output: &mut (impl Output + ?Sized),
             date: Option<Date>,
             time: Option<Time>,
             offset: Option<UtcOffset>,
-        ) -> Result<usize, error::Format>;
+        ) -> Result<usize, error::FormatCore>;
+
+        /// Whether the item contains a year relative to its era that is not accompanied by the
+        /// era, and whether the era is always formatted, in that order.
+        fn era_usage(&self) -> (bool, bool) {
+            (false, false)
+        }
 
         /// Format the item directly to a `String`.
+        #[cfg(feature = "formatting")]
         fn format(
             &self,
             date: Option<Date>,
@@ -64,11 +79,11 @@ mod sealed {
 impl sealed::Sealed for BorrowedFormatItem<'_> {
     fn format_into(
         &self,
-        output: &mut (impl io::Write + ?Sized),
+        output: &mut (impl Output + ?Sized),
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
-    ) -> Result<usize, error::Format> {
+    ) -> Result<usize, error::FormatCore> {
         Ok(match *self {
             Self::Literal(literal) => write(output, literal)?,
             Self::Component(component) => format_component(output, component, date, time, offset)?,
@@ -78,36 +93,68 @@ impl sealed::Sealed for BorrowedFormatItem<'_> {
                 [] => 0,
                 [item, ..] => item.format_into(output, date, time, offset)?,
             },
+            Self::Conditional {
+                condition,
+                then,
+                otherwise,
+            } => {
+                if condition_holds(condition, time, offset)? {
+                    then.format_into(output, date, time, offset)?
+                } else {
+                    otherwise.format_into(output, date, time, offset)?
+                }
+            }
         })
     }
+
+    fn era_usage(&self) -> (bool, bool) {
+        match *self {
+            Self::Literal(_) | Self::First([]) => (false, false),
+            Self::Component(component) => component_era_usage(component),
+            Self::Compound(items) => items.era_usage(),
+            Self::Optional(item) | Self::First([item, ..]) => item.era_usage(),
+            Self::Conditional {
+                condition: _,
+                then,
+                otherwise,
+            } => conditional_era_usage(then.era_usage(), otherwise.era_usage()),
+        }
+    }
 }
 
 impl sealed::Sealed for [BorrowedFormatItem<'_>] {
     fn format_into(
         &self,
-        output: &mut (impl io::Write + ?Sized),
+        output: &mut (impl Output + ?Sized),
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
-    ) -> Result<usize, error::Format> {
+    ) -> Result<usize, error::FormatCore> {
         let mut bytes = 0;
         for item in self.iter() {
             bytes += item.format_into(output, date, time, offset)?;
         }
         Ok(bytes)
     }
+
+    fn era_usage(&self) -> (bool, bool) {
+        self.iter()
+            .map(sealed::Sealed::era_usage)
+            .fold((false, false), |(a, b), (c, d)| (a || c, b || d))
+    }
 }
 
+#[cfg(feature = "alloc")]
 impl sealed::Sealed for OwnedFormatItem {
     fn format_into(
         &self,
-        output: &mut (impl io::Write + ?Sized),
+        output: &mut (impl Output + ?Sized),
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
-    ) -> Result<usize, error::Format> {
+    ) -> Result<usize, error::FormatCore> {
         match self {
-            Self::Literal(literal) => Ok(write(output, literal)?),
+            Self::Literal(literal) => write(output, literal),
             Self::Component(component) => format_component(output, *component, date, time, offset),
             Self::Compound(items) => items.format_into(output, date, time, offset),
             Self::Optional(item) => item.format_into(output, date, time, offset),
@@ -115,24 +162,59 @@ impl sealed::Sealed for OwnedFormatItem {
                 [] => Ok(0),
                 [item, ..] => item.format_into(output, date, time, offset),
             },
+            Self::Conditional {
+                condition,
+                then,
+                otherwise,
+            } => {
+                if condition_holds(*condition, time, offset)? {
+                    then.format_into(output, date, time, offset)
+                } else {
+                    otherwise.format_into(output, date, time, offset)
+                }
+            }
+        }
+    }
+
+    fn era_usage(&self) -> (bool, bool) {
+        match self {
+            Self::Literal(_) => (false, false),
+            Self::Component(component) => component_era_usage(*component),
+            Self::Compound(items) => items.era_usage(),
+            Self::Optional(item) => item.era_usage(),
+            Self::First(items) => items
+                .first()
+                .map_or((false, false), sealed::Sealed::era_usage),
+            Self::Conditional {
+                condition: _,
+                then,
+                otherwise,
+            } => conditional_era_usage(then.era_usage(), otherwise.era_usage()),
         }
     }
 }
 
+#[cfg(feature = "alloc")]
 impl sealed::Sealed for [OwnedFormatItem] {
     fn format_into(
         &self,
-        output: &mut (impl io::Write + ?Sized),
+        output: &mut (impl Output + ?Sized),
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
-    ) -> Result<usize, error::Format> {
+    ) -> Result<usize, error::FormatCore> {
         let mut bytes = 0;
         for item in self.iter() {
             bytes += item.format_into(output, date, time, offset)?;
         }
         Ok(bytes)
     }
+
+    fn era_usage(&self) -> (bool, bool) {
+        self.iter()
+            .map(sealed::Sealed::era_usage)
+            .fold((false, false), |(a, b), (c, d)| (a || c, b || d))
+    }
 }
 
 impl<T> sealed::Sealed for T
@@ -141,36 +223,66 @@ where
 {
     fn format_into(
         &self,
-        output: &mut (impl io::Write + ?Sized),
+        output: &mut (impl Output + ?Sized),
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
-    ) -> Result<usize, error::Format> {
+    ) -> Result<usize, error::FormatCore> {
         self.deref().format_into(output, date, time, offset)
     }
+
+    fn era_usage(&self) -> (bool, bool) {
+        self.deref().era_usage()
+    }
+}
+
+/// Whether the component is a year relative to its era, and whether it is the era.
+const fn component_era_usage(component: Component) -> (bool, bool) {
+    match component {
+        Component::Year(modifier::Year {
+            repr: modifier::YearRepr::Full,
+            iso_week_based: false,
+            era_based: true,
+            ..
+        }) => (true, false),
+        Component::Era(_) => (false, true),
+        _ => (false, false),
+    }
+}
+
+/// Combine the usage of the era in the branches of a conditional. Either branch may be formatted,
+/// so a branch must provide the era for its own era-based year, and the era is only always
+/// formatted if both branches format it.
+const fn conditional_era_usage(then: (bool, bool), otherwise: (bool, bool)) -> (bool, bool) {
+    (
+        (then.0 && !then.1) || (otherwise.0 && !otherwise.1),
+        then.1 && otherwise.1,
+    )
 }
 
 impl sealed::Sealed for Rfc2822 {
     fn format_into(
         &self,
-        output: &mut (impl io::Write + ?Sized),
+        output: &mut (impl Output + ?Sized),
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
-    ) -> Result<usize, error::Format> {
-        let date = date.ok_or(error::Format::InsufficientTypeInformation)?;
-        let time = time.ok_or(error::Format::InsufficientTypeInformation)?;
-        let offset = offset.ok_or(error::Format::InsufficientTypeInformation)?;
+    ) -> Result<usize, error::FormatCore> {
+        let date = date.ok_or(error::FormatCore::InsufficientTypeInformation)?;
+        let time = time.ok_or(error::FormatCore::InsufficientTypeInformation)?;
+        let offset = offset.ok_or(error::FormatCore::InsufficientTypeInformation)?;
 
         let mut bytes = 0;
 
         let (year, month, day) = date.to_calendar_date();
 
-        if year < 1900 {
-            return Err(error::Format::InvalidComponent("year"));
+        // Years before 1900 are not permitted to be generated by RFC 5322, but they are still
+        // syntactically valid and are accepted when parsing.
+        if !(0..10_000).contains(&year) {
+            return Err(error::FormatCore::InvalidComponent("year"));
         }
         if offset.seconds_past_minute() != 0 {
-            return Err(error::Format::InvalidComponent("offset_second"));
+            return Err(error::FormatCore::InvalidComponent("offset_second"));
         }
 
         bytes += write(
@@ -201,102 +313,200 @@ impl sealed::Sealed for Rfc2822 {
     }
 }
 
-impl sealed::Sealed for Rfc3339 {
+impl sealed::Sealed for HttpDate {
     fn format_into(
         &self,
-        output: &mut (impl io::Write + ?Sized),
+        output: &mut (impl Output + ?Sized),
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
-    ) -> Result<usize, error::Format> {
-        let date = date.ok_or(error::Format::InsufficientTypeInformation)?;
-        let time = time.ok_or(error::Format::InsufficientTypeInformation)?;
-        let offset = offset.ok_or(error::Format::InsufficientTypeInformation)?;
+    ) -> Result<usize, error::FormatCore> {
+        let date = date.ok_or(error::FormatCore::InsufficientTypeInformation)?;
+        let time = time.ok_or(error::FormatCore::InsufficientTypeInformation)?;
+        let offset = offset.ok_or(error::FormatCore::InsufficientTypeInformation)?;
+
+        // HTTP dates are always in GMT.
+        let date_time = OffsetDateTime::new_in_offset(date, time, offset)
+            .checked_to_offset(UtcOffset::UTC)
+            .ok_or(error::FormatCore::InvalidComponent("year"))?;
+        let (date, time) = (date_time.date(), date_time.time());
 
         let mut bytes = 0;
 
-        let year = date.year();
+        let (year, month, day) = date.to_calendar_date();
 
         if !(0..10_000).contains(&year) {
-            return Err(error::Format::InvalidComponent("year"));
-        }
-        if offset.whole_hours().unsigned_abs() > 23 {
-            return Err(error::Format::InvalidComponent("offset_hour"));
-        }
-        if offset.seconds_past_minute() != 0 {
-            return Err(error::Format::InvalidComponent("offset_second"));
+            return Err(error::FormatCore::InvalidComponent("year"));
         }
 
+        bytes += write(
+            output,
+            &WEEKDAY_NAMES[date.weekday().number_days_from_monday().extend::<usize>()][..3],
+        )?;
+        bytes += write(output, b", ")?;
+        bytes += format_number_pad_zero::<2>(output, day)?;
+        bytes += write(output, b" ")?;
+        bytes += write(
+            output,
+            &MONTH_NAMES[u8::from(month).extend::<usize>() - 1][..3],
+        )?;
+        bytes += write(output, b" ")?;
         bytes += format_number_pad_zero::<4>(output, year.cast_unsigned())?;
-        bytes += write(output, b"-")?;
-        bytes += format_number_pad_zero::<2>(output, u8::from(date.month()))?;
-        bytes += write(output, b"-")?;
-        bytes += format_number_pad_zero::<2>(output, date.day())?;
-        bytes += write(output, b"T")?;
+        bytes += write(output, b" ")?;
         bytes += format_number_pad_zero::<2>(output, time.hour())?;
         bytes += write(output, b":")?;
         bytes += format_number_pad_zero::<2>(output, time.minute())?;
         bytes += write(output, b":")?;
         bytes += format_number_pad_zero::<2>(output, time.second())?;
+        bytes += write(output, b" GMT")?;
 
-        if time.nanosecond() != 0 {
-            let nanos = time.nanosecond();
-            bytes += write(output, b".")?;
-            bytes += if nanos % 10 != 0 {
-                format_number_pad_zero::<9>(output, nanos)
-            } else if (nanos / 10) % 10 != 0 {
-                format_number_pad_zero::<8>(output, nanos / 10)
-            } else if (nanos / 100) % 10 != 0 {
-                format_number_pad_zero::<7>(output, nanos / 100)
-            } else if (nanos / 1_000) % 10 != 0 {
-                format_number_pad_zero::<6>(output, nanos / 1_000)
-            } else if (nanos / 10_000) % 10 != 0 {
-                format_number_pad_zero::<5>(output, nanos / 10_000)
-            } else if (nanos / 100_000) % 10 != 0 {
-                format_number_pad_zero::<4>(output, nanos / 100_000)
-            } else if (nanos / 1_000_000) % 10 != 0 {
-                format_number_pad_zero::<3>(output, nanos / 1_000_000)
-            } else if (nanos / 10_000_000) % 10 != 0 {
-                format_number_pad_zero::<2>(output, nanos / 10_000_000)
-            } else {
-                format_number_pad_zero::<1>(output, nanos / 100_000_000)
-            }?;
-        }
+        Ok(bytes)
+    }
+}
+
+impl sealed::Sealed for Rfc3339 {
+    fn format_into(
+        &self,
+        output: &mut (impl Output + ?Sized),
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<usize, error::FormatCore> {
+        let date = date.ok_or(error::FormatCore::InsufficientTypeInformation)?;
+        let time = time.ok_or(error::FormatCore::InsufficientTypeInformation)?;
+        let offset = offset.ok_or(error::FormatCore::InsufficientTypeInformation)?;
+
+        let mut bytes = fmt_rfc3339_date_time(output, date, time, offset)?;
 
         if offset == UtcOffset::UTC {
             bytes += write(output, b"Z")?;
             return Ok(bytes);
         }
 
-        bytes += write(output, if offset.is_negative() { b"-" } else { b"+" })?;
-        bytes += format_number_pad_zero::<2>(output, offset.whole_hours().unsigned_abs())?;
-        bytes += write(output, b":")?;
-        bytes += format_number_pad_zero::<2>(output, offset.minutes_past_hour().unsigned_abs())?;
+        bytes += fmt_rfc3339_offset(output, offset)?;
+
+        Ok(bytes)
+    }
+}
+
+impl sealed::Sealed for Rfc9557 {
+    fn format_into(
+        &self,
+        output: &mut (impl Output + ?Sized),
+        date: Option<Date>,
+        time: Option<Time>,
+        offset: Option<UtcOffset>,
+    ) -> Result<usize, error::FormatCore> {
+        let date = date.ok_or(error::FormatCore::InsufficientTypeInformation)?;
+        let time = time.ok_or(error::FormatCore::InsufficientTypeInformation)?;
+        let offset = offset.ok_or(error::FormatCore::InsufficientTypeInformation)?;
+
+        let mut bytes = fmt_rfc3339_date_time(output, date, time, offset)?;
+
+        // `Z` would indicate that the local offset is unknown, so the numeric offset is used.
+        bytes += fmt_rfc3339_offset(output, offset)?;
+        bytes += write(output, b"[")?;
+        bytes += fmt_rfc3339_offset(output, offset)?;
+        bytes += write(output, b"]")?;
 
         Ok(bytes)
     }
 }
 
+/// Format the date and time as defined in RFC 3339, excluding the UTC offset. The offset is
+/// validated so that it can be written afterwards.
+fn fmt_rfc3339_date_time(
+    output: &mut (impl Output + ?Sized),
+    date: Date,
+    time: Time,
+    offset: UtcOffset,
+) -> Result<usize, error::FormatCore> {
+    let mut bytes = 0;
+
+    let year = date.year();
+
+    if !(0..10_000).contains(&year) {
+        return Err(error::FormatCore::InvalidComponent("year"));
+    }
+    if offset.whole_hours().unsigned_abs() > 23 {
+        return Err(error::FormatCore::InvalidComponent("offset_hour"));
+    }
+    if offset.seconds_past_minute() != 0 {
+        return Err(error::FormatCore::InvalidComponent("offset_second"));
+    }
+
+    bytes += format_number_pad_zero::<4>(output, year.cast_unsigned())?;
+    bytes += write(output, b"-")?;
+    bytes += format_number_pad_zero::<2>(output, u8::from(date.month()))?;
+    bytes += write(output, b"-")?;
+    bytes += format_number_pad_zero::<2>(output, date.day())?;
+    bytes += write(output, b"T")?;
+    bytes += format_number_pad_zero::<2>(output, time.hour())?;
+    bytes += write(output, b":")?;
+    bytes += format_number_pad_zero::<2>(output, time.minute())?;
+    bytes += write(output, b":")?;
+    bytes += format_number_pad_zero::<2>(output, time.second())?;
+
+    if time.nanosecond() != 0 {
+        let nanos = time.nanosecond();
+        bytes += write(output, b".")?;
+        bytes += if nanos % 10 != 0 {
+            format_number_pad_zero::<9>(output, nanos)
+        } else if (nanos / 10) % 10 != 0 {
+            format_number_pad_zero::<8>(output, nanos / 10)
+        } else if (nanos / 100) % 10 != 0 {
+            format_number_pad_zero::<7>(output, nanos / 100)
+        } else if (nanos / 1_000) % 10 != 0 {
+            format_number_pad_zero::<6>(output, nanos / 1_000)
+        } else if (nanos / 10_000) % 10 != 0 {
+            format_number_pad_zero::<5>(output, nanos / 10_000)
+        } else if (nanos / 100_000) % 10 != 0 {
+            format_number_pad_zero::<4>(output, nanos / 100_000)
+        } else if (nanos / 1_000_000) % 10 != 0 {
+            format_number_pad_zero::<3>(output, nanos / 1_000_000)
+        } else if (nanos / 10_000_000) % 10 != 0 {
+            format_number_pad_zero::<2>(output, nanos / 10_000_000)
+        } else {
+            format_number_pad_zero::<1>(output, nanos / 100_000_000)
+        }?;
+    }
+
+    Ok(bytes)
+}
+
+/// Format the UTC offset as defined in RFC 3339, always using the numeric form.
+fn fmt_rfc3339_offset(
+    output: &mut (impl Output + ?Sized),
+    offset: UtcOffset,
+) -> Result<usize, error::FormatCore> {
+    let mut bytes = 0;
+    bytes += write(output, if offset.is_negative() { b"-" } else { b"+" })?;
+    bytes += format_number_pad_zero::<2>(output, offset.whole_hours().unsigned_abs())?;
+    bytes += write(output, b":")?;
+    bytes += format_number_pad_zero::<2>(output, offset.minutes_past_hour().unsigned_abs())?;
+    Ok(bytes)
+}
+
 impl<const CONFIG: EncodedConfig> sealed::Sealed for Iso8601<CONFIG> {
     fn format_into(
         &self,
-        output: &mut (impl io::Write + ?Sized),
+        output: &mut (impl Output + ?Sized),
         date: Option<Date>,
         time: Option<Time>,
         offset: Option<UtcOffset>,
-    ) -> Result<usize, error::Format> {
+    ) -> Result<usize, error::FormatCore> {
         let mut bytes = 0;
 
         if Self::FORMAT_DATE {
-            let date = date.ok_or(error::Format::InsufficientTypeInformation)?;
+            let date = date.ok_or(error::FormatCore::InsufficientTypeInformation)?;
             bytes += iso8601::format_date::<CONFIG>(output, date)?;
         }
         if Self::FORMAT_TIME {
-            let time = time.ok_or(error::Format::InsufficientTypeInformation)?;
+            let time = time.ok_or(error::FormatCore::InsufficientTypeInformation)?;
             bytes += iso8601::format_time::<CONFIG>(output, time)?;
         }
         if Self::FORMAT_OFFSET {
-            let offset = offset.ok_or(error::Format::InsufficientTypeInformation)?;
+            let offset = offset.ok_or(error::FormatCore::InsufficientTypeInformation)?;
             bytes += iso8601::format_offset::<CONFIG>(output, offset)?;
         }
 
@@ -0,0 +1,126 @@
+//! A fixed-capacity string used to format values without allocating.
+
+use core::borrow::Borrow;
+use core::hash::{Hash, Hasher};
+use core::ops::Deref;
+use core::{fmt, str};
+
+use crate::error;
+use crate::formatting::{format_value, Components, Formattable, SliceOutput};
+
+/// A string with a fixed capacity of `N` bytes, stored inline rather than on the heap.
+///
+/// This is returned by methods such as [`OffsetDateTime::format_rfc3339_inline`]. The capacity is
+/// chosen such that any value that can be formatted will fit. As a result, formatting a value in
+/// this manner never allocates.
+///
+/// The string can be used through its [`Display`](fmt::Display) implementation or by
+/// dereferencing it to a `str`.
+///
+/// ```rust
+/// # use time_macros::datetime;
+/// let formatted = datetime!(2020-01-02 03:04:05.6 +06:07).format_rfc3339_inline()?;
+/// assert_eq!(&*formatted, "2020-01-02T03:04:05.6+06:07");
+/// assert_eq!(formatted.to_string(), "2020-01-02T03:04:05.6+06:07");
+/// assert_eq!(formatted.as_bytes(), b"2020-01-02T03:04:05.6+06:07");
+/// # Ok::<_, time::Error>(())
+/// ```
+///
+/// [`OffsetDateTime::format_rfc3339_inline`]: crate::OffsetDateTime::format_rfc3339_inline
+#[derive(Clone, Copy)]
+pub struct InlineString<const N: usize> {
+    /// The buffer containing the string. Only the first `len` bytes are meaningful.
+    buf: [u8; N],
+    /// The length of the string in bytes.
+    len: usize,
+}
+
+impl<const N: usize> InlineString<N> {
+    /// Format the provided value into a new `InlineString`.
+    ///
+    /// The format description must only ever produce ASCII, which is the case for all well-known
+    /// formats. If the output does not fit in `N` bytes, an error is returned.
+    pub(crate) fn format(
+        value: impl Components,
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<Self, error::FormatCore> {
+        let mut buf = [0; N];
+        let len = format_value(value, &mut SliceOutput::new(&mut buf), format)?;
+        debug_assert!(buf[..len].is_ascii());
+        Ok(Self { buf, len })
+    }
+
+    /// Obtain the string as a `str`.
+    pub fn as_str(&self) -> &str {
+        str::from_utf8(self.as_bytes()).expect("only ASCII is ever written to the buffer")
+    }
+
+    /// Obtain the string as a byte slice.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+}
+
+impl<const N: usize> Deref for InlineString<N> {
+    type Target = str;
+
+    fn deref(&self) -> &Self::Target {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<str> for InlineString<N> {
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> AsRef<[u8]> for InlineString<N> {
+    fn as_ref(&self) -> &[u8] {
+        self.as_bytes()
+    }
+}
+
+impl<const N: usize> Borrow<str> for InlineString<N> {
+    fn borrow(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl<const N: usize> fmt::Display for InlineString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> fmt::Debug for InlineString<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self.as_str(), f)
+    }
+}
+
+impl<const N: usize> PartialEq for InlineString<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl<const N: usize> Eq for InlineString<N> {}
+
+impl<const N: usize> PartialEq<str> for InlineString<N> {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl<const N: usize> PartialEq<&str> for InlineString<N> {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+impl<const N: usize> Hash for InlineString<N> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
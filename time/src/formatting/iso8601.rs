@@ -1,23 +1,26 @@
 //! Helpers for implementing formatting for ISO 8601.
 
-use std::io;
-
 #[allow(unused_imports, reason = "MSRV of 1.87")]
 use num_conv::prelude::*;
 
 use crate::convert::*;
+use crate::ext::DigitCount;
 use crate::format_description::well_known::iso8601::{
     DateKind, EncodedConfig, OffsetPrecision, TimePrecision,
 };
 use crate::format_description::well_known::Iso8601;
-use crate::formatting::{format_float, format_number_pad_zero, write, write_if, write_if_else};
+use crate::formatting::{
+    format_float, format_number_pad_none, format_number_pad_zero, write, write_if, write_if_else,
+    Output,
+};
+use crate::interval::{Endpoint, Interval, NominalDuration, RepeatingInterval};
 use crate::{error, Date, Time, UtcOffset};
 
 /// Format the date portion of ISO 8601.
 pub(super) fn format_date<const CONFIG: EncodedConfig>(
-    output: &mut (impl io::Write + ?Sized),
+    output: &mut (impl Output + ?Sized),
     date: Date,
-) -> Result<usize, error::Format> {
+) -> Result<usize, error::FormatCore> {
     let mut bytes = 0;
 
     match Iso8601::<CONFIG>::DATE_KIND {
@@ -27,7 +30,7 @@ pub(super) fn format_date<const CONFIG: EncodedConfig>(
                 bytes += write_if_else(output, year < 0, b"-", b"+")?;
                 bytes += format_number_pad_zero::<6>(output, year.unsigned_abs())?;
             } else if !(0..=9999).contains(&year) {
-                return Err(error::Format::InvalidComponent("year"));
+                return Err(error::FormatCore::InvalidComponent("year"));
             } else {
                 bytes += format_number_pad_zero::<4>(output, year.cast_unsigned())?;
             }
@@ -42,7 +45,7 @@ pub(super) fn format_date<const CONFIG: EncodedConfig>(
                 bytes += write_if_else(output, year < 0, b"-", b"+")?;
                 bytes += format_number_pad_zero::<6>(output, year.unsigned_abs())?;
             } else if !(0..=9999).contains(&year) {
-                return Err(error::Format::InvalidComponent("year"));
+                return Err(error::FormatCore::InvalidComponent("year"));
             } else {
                 bytes += format_number_pad_zero::<4>(output, year.cast_unsigned())?;
             }
@@ -57,7 +60,7 @@ pub(super) fn format_date<const CONFIG: EncodedConfig>(
                 bytes += write_if_else(output, year < 0, b"-", b"+")?;
                 bytes += format_number_pad_zero::<6>(output, year.unsigned_abs())?;
             } else if !(0..=9999).contains(&year) {
-                return Err(error::Format::InvalidComponent("year"));
+                return Err(error::FormatCore::InvalidComponent("year"));
             } else {
                 bytes += format_number_pad_zero::<4>(output, year.cast_unsigned())?;
             }
@@ -71,9 +74,9 @@ pub(super) fn format_date<const CONFIG: EncodedConfig>(
 
 /// Format the time portion of ISO 8601.
 pub(super) fn format_time<const CONFIG: EncodedConfig>(
-    output: &mut (impl io::Write + ?Sized),
+    output: &mut (impl Output + ?Sized),
     time: Time,
-) -> Result<usize, error::Format> {
+) -> Result<usize, error::FormatCore> {
     let mut bytes = 0;
 
     // The "T" can only be omitted in extended format where there is no date being formatted.
@@ -117,24 +120,24 @@ pub(super) fn format_time<const CONFIG: EncodedConfig>(
 
 /// Format the UTC offset portion of ISO 8601.
 pub(super) fn format_offset<const CONFIG: EncodedConfig>(
-    output: &mut (impl io::Write + ?Sized),
+    output: &mut (impl Output + ?Sized),
     offset: UtcOffset,
-) -> Result<usize, error::Format> {
+) -> Result<usize, error::FormatCore> {
     if Iso8601::<CONFIG>::FORMAT_TIME && offset.is_utc() {
-        return Ok(write(output, b"Z")?);
+        return write(output, b"Z");
     }
 
     let mut bytes = 0;
 
     let (hours, minutes, seconds) = offset.as_hms();
     if seconds != 0 {
-        return Err(error::Format::InvalidComponent("offset_second"));
+        return Err(error::FormatCore::InvalidComponent("offset_second"));
     }
     bytes += write_if_else(output, offset.is_negative(), b"-", b"+")?;
     bytes += format_number_pad_zero::<2>(output, hours.unsigned_abs())?;
 
     if Iso8601::<CONFIG>::OFFSET_PRECISION == OffsetPrecision::Hour && minutes != 0 {
-        return Err(error::Format::InvalidComponent("offset_minute"));
+        return Err(error::FormatCore::InvalidComponent("offset_minute"));
     } else if Iso8601::<CONFIG>::OFFSET_PRECISION == OffsetPrecision::Minute {
         bytes += write_if(output, Iso8601::<CONFIG>::USE_SEPARATORS, b":")?;
         bytes += format_number_pad_zero::<2>(output, minutes.unsigned_abs())?;
@@ -142,3 +145,124 @@ pub(super) fn format_offset<const CONFIG: EncodedConfig>(
 
     Ok(bytes)
 }
+
+/// Format an endpoint of an ISO 8601 interval. Only the components present in the endpoint are
+/// written.
+fn format_endpoint<const CONFIG: EncodedConfig>(
+    output: &mut (impl Output + ?Sized),
+    endpoint: Endpoint,
+) -> Result<usize, error::FormatCore> {
+    let mut bytes = format_date::<CONFIG>(output, endpoint.date())?;
+
+    let (time, offset) = match endpoint {
+        Endpoint::Date(_) => return Ok(bytes),
+        Endpoint::PrimitiveDateTime(datetime) => (datetime.time(), None),
+        Endpoint::OffsetDateTime(datetime) => (datetime.time(), Some(datetime.offset())),
+    };
+
+    // The "T" is always required when a date is present, which `format_time` does not know.
+    bytes += write_if(
+        output,
+        !(Iso8601::<CONFIG>::USE_SEPARATORS || Iso8601::<CONFIG>::FORMAT_DATE),
+        b"T",
+    )?;
+    bytes += format_time::<CONFIG>(output, time)?;
+    if let Some(offset) = offset {
+        bytes += format_offset::<CONFIG>(output, offset)?;
+    }
+
+    Ok(bytes)
+}
+
+/// Format a nominal duration, such as `P1Y2M10DT2H30M`.
+pub(crate) fn format_nominal_duration(
+    output: &mut (impl Output + ?Sized),
+    duration: NominalDuration,
+) -> Result<usize, error::FormatCore> {
+    let mut bytes = write(output, b"P")?;
+
+    for (value, designator) in [
+        (duration.years(), b"Y"),
+        (duration.months(), b"M"),
+        (duration.weeks(), b"W"),
+        (duration.days(), b"D"),
+    ] {
+        if value != 0 {
+            bytes += format_number_pad_none(output, value)?;
+            bytes += write(output, designator)?;
+        }
+    }
+
+    let has_seconds = duration.seconds() != 0 || duration.nanoseconds() != 0;
+    if duration.hours() != 0 || duration.minutes() != 0 || has_seconds {
+        bytes += write(output, b"T")?;
+        for (value, designator) in [(duration.hours(), b"H"), (duration.minutes(), b"M")] {
+            if value != 0 {
+                bytes += format_number_pad_none(output, value)?;
+                bytes += write(output, designator)?;
+            }
+        }
+    } else if bytes == 1 {
+        // A duration must have at least one component, even if it is zero.
+        return Ok(bytes + write(output, b"T0S")?);
+    }
+
+    if has_seconds {
+        bytes += format_number_pad_none(output, duration.seconds())?;
+        if duration.nanoseconds() != 0 {
+            let mut nanoseconds = duration.nanoseconds();
+            let mut digits = 9;
+            while nanoseconds % 10 == 0 {
+                nanoseconds /= 10;
+                digits -= 1;
+            }
+            bytes += write(output, b".")?;
+            for _ in 0..(digits - nanoseconds.num_digits()) {
+                bytes += write(output, b"0")?;
+            }
+            bytes += format_number_pad_none(output, nanoseconds)?;
+        }
+        bytes += write(output, b"S")?;
+    }
+
+    Ok(bytes)
+}
+
+/// Format an ISO 8601 interval, such as `2024-02-15/2024-02-18`.
+pub(crate) fn format_interval<const CONFIG: EncodedConfig>(
+    output: &mut (impl Output + ?Sized),
+    interval: Interval,
+) -> Result<usize, error::FormatCore> {
+    Ok(match interval {
+        Interval::StartEnd { start, end } => {
+            format_endpoint::<CONFIG>(output, start)?
+                + write(output, b"/")?
+                + format_endpoint::<CONFIG>(output, end)?
+        }
+        Interval::StartDuration { start, duration } => {
+            format_endpoint::<CONFIG>(output, start)?
+                + write(output, b"/")?
+                + format_nominal_duration(output, duration)?
+        }
+        Interval::DurationEnd { duration, end } => {
+            format_nominal_duration(output, duration)?
+                + write(output, b"/")?
+                + format_endpoint::<CONFIG>(output, end)?
+        }
+        Interval::Duration(duration) => format_nominal_duration(output, duration)?,
+    })
+}
+
+/// Format an ISO 8601 repeating interval, such as `R5/2024-01-01T00:00Z/P1D`.
+pub(crate) fn format_repeating_interval<const CONFIG: EncodedConfig>(
+    output: &mut (impl Output + ?Sized),
+    interval: RepeatingInterval,
+) -> Result<usize, error::FormatCore> {
+    let mut bytes = write(output, b"R")?;
+    if let Some(repetitions) = interval.repetitions() {
+        bytes += format_number_pad_none(output, repetitions)?;
+    }
+    bytes += write(output, b"/")?;
+    bytes += format_interval::<CONFIG>(output, interval.interval())?;
+    Ok(bytes)
+}
@@ -1,19 +1,29 @@
 //! Formatting for various types.
 
 pub(crate) mod formattable;
-mod iso8601;
+mod inline_string;
+pub(crate) mod iso8601;
+mod output;
 
+#[cfg(feature = "formatting")]
+use alloc::string::String;
+use core::fmt;
 use core::num::NonZero;
+#[cfg(feature = "formatting")]
 use std::io;
-use std::string::ToString;
 
 use num_conv::prelude::*;
 
 pub use self::formattable::Formattable;
+pub use self::inline_string::InlineString;
+#[cfg(feature = "formatting")]
+pub(crate) use self::output::IoOutput;
+pub(crate) use self::output::{FmtOutput, Output, SliceOutput};
 use crate::convert::*;
+use crate::date::MAX_YEAR;
 use crate::ext::DigitCount;
-use crate::format_description::{modifier, Component};
-use crate::{error, Date, OffsetDateTime, Time, UtcOffset};
+use crate::format_description::{modifier, Component, Condition};
+use crate::{error, Date, OffsetDateTime, PrimitiveDateTime, Time, UtcDateTime, UtcOffset};
 
 const MONTH_NAMES: [&[u8]; 12] = [
     b"January",
@@ -40,18 +50,161 @@ const WEEKDAY_NAMES: [&[u8]; 7] = [
     b"Sunday",
 ];
 
+/// A value that can be formatted, providing the components used by a format description.
+pub(crate) trait Components: Copy {
+    /// Obtain the date, time, and offset of the value, if they are present.
+    fn components(self) -> (Option<Date>, Option<Time>, Option<UtcOffset>);
+}
+
+impl Components for Date {
+    fn components(self) -> (Option<Date>, Option<Time>, Option<UtcOffset>) {
+        (Some(self), None, None)
+    }
+}
+
+impl Components for Time {
+    fn components(self) -> (Option<Date>, Option<Time>, Option<UtcOffset>) {
+        (None, Some(self), None)
+    }
+}
+
+impl Components for UtcOffset {
+    fn components(self) -> (Option<Date>, Option<Time>, Option<UtcOffset>) {
+        (None, None, Some(self))
+    }
+}
+
+impl Components for PrimitiveDateTime {
+    fn components(self) -> (Option<Date>, Option<Time>, Option<UtcOffset>) {
+        (Some(self.date()), Some(self.time()), None)
+    }
+}
+
+impl Components for OffsetDateTime {
+    fn components(self) -> (Option<Date>, Option<Time>, Option<UtcOffset>) {
+        (Some(self.date()), Some(self.time()), Some(self.offset()))
+    }
+}
+
+impl Components for UtcDateTime {
+    fn components(self) -> (Option<Date>, Option<Time>, Option<UtcOffset>) {
+        (Some(self.date()), Some(self.time()), Some(UtcOffset::UTC))
+    }
+}
+
+/// Format the value into the provided output, returning the number of bytes written. This is shared
+/// by all types that can be formatted, regardless of where the output is written.
+pub(crate) fn format_value(
+    value: impl Components,
+    output: &mut (impl Output + ?Sized),
+    format: &(impl Formattable + ?Sized),
+) -> Result<usize, error::FormatCore> {
+    let (date, time, offset) = value.components();
+    check_era(format, date)?;
+    format.format_into(output, date, time, offset)
+}
+
+/// Format the value into the provided writer, returning the number of bytes written.
+#[cfg(feature = "formatting")]
+pub(crate) fn format_value_into_io(
+    value: impl Components,
+    output: &mut (impl io::Write + ?Sized),
+    format: &(impl Formattable + ?Sized),
+) -> Result<usize, error::Format> {
+    let mut output = IoOutput::new(output);
+    format_value(value, &mut output, format).map_err(|err| output.into_error(err))
+}
+
+/// Format the value directly to a `String`.
+#[cfg(feature = "formatting")]
+pub(crate) fn format_value_to_string(
+    value: impl Components,
+    format: &(impl Formattable + ?Sized),
+) -> Result<String, error::Format> {
+    let (date, time, offset) = value.components();
+    check_era(format, date)?;
+    format.format(date, time, offset)
+}
+
+/// Ensure that a year before the common era is not formatted relative to its era unless the era is
+/// also formatted. Otherwise the output would be indistinguishable from a year of the common era.
+fn check_era(
+    format: &(impl Formattable + ?Sized),
+    date: Option<Date>,
+) -> Result<(), error::FormatCore> {
+    let Some(date) = date else {
+        return Ok(());
+    };
+    if date.year() > 0 {
+        return Ok(());
+    }
+    match format.era_usage() {
+        (true, false) => Err(error::ComponentRange {
+            name: "year",
+            minimum: 1,
+            maximum: MAX_YEAR.extend(),
+            value: date.year().extend(),
+            conditional_message: Some("when `era_based:true` is used without an era"),
+        }
+        .into()),
+        _ => Ok(()),
+    }
+}
+
 /// Write all bytes to the output, returning the number of bytes written.
-pub(crate) fn write(output: &mut (impl io::Write + ?Sized), bytes: &[u8]) -> io::Result<usize> {
+pub(crate) fn write(
+    output: &mut (impl Output + ?Sized),
+    bytes: &[u8],
+) -> Result<usize, error::FormatCore> {
     output.write_all(bytes)?;
     Ok(bytes.len())
 }
 
+/// Write the formatted arguments to the output, returning the number of bytes written.
+pub(crate) fn write_fmt(
+    output: &mut (impl Output + ?Sized),
+    args: fmt::Arguments<'_>,
+) -> Result<usize, error::FormatCore> {
+    /// Permits `fmt::Write` to be used on any `Output`. As `fmt::Error` does not contain any
+    /// information, the error returned by the output is retained.
+    struct Adapter<'a, O: ?Sized> {
+        output: &'a mut O,
+        bytes: usize,
+        error: Option<error::FormatCore>,
+    }
+
+    impl<O: Output + ?Sized> fmt::Write for Adapter<'_, O> {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            match self.output.write_all(s.as_bytes()) {
+                Ok(()) => {
+                    self.bytes += s.len();
+                    Ok(())
+                }
+                Err(err) => {
+                    self.error = Some(err);
+                    Err(fmt::Error)
+                }
+            }
+        }
+    }
+
+    let mut adapter = Adapter {
+        output,
+        bytes: 0,
+        error: None,
+    };
+    match fmt::write(&mut adapter, args) {
+        Ok(()) => Ok(adapter.bytes),
+        Err(fmt::Error) => Err(adapter.error.unwrap_or(error::FormatCore::Fmt(fmt::Error))),
+    }
+}
+
 /// If `pred` is true, write all bytes to the output, returning the number of bytes written.
 pub(crate) fn write_if(
-    output: &mut (impl io::Write + ?Sized),
+    output: &mut (impl Output + ?Sized),
     pred: bool,
     bytes: &[u8],
-) -> io::Result<usize> {
+) -> Result<usize, error::FormatCore> {
     if pred {
         write(output, bytes)
     } else {
@@ -61,18 +214,38 @@ pub(crate) fn write_if(
 
 /// If `pred` is true, write `true_bytes` to the output. Otherwise, write `false_bytes`.
 pub(crate) fn write_if_else(
-    output: &mut (impl io::Write + ?Sized),
+    output: &mut (impl Output + ?Sized),
     pred: bool,
     true_bytes: &[u8],
     false_bytes: &[u8],
-) -> io::Result<usize> {
+) -> Result<usize, error::FormatCore> {
     write(output, if pred { true_bytes } else { false_bytes })
 }
 
+/// Write the title-case text to the output in the requested case, returning the number of bytes
+/// written.
+fn write_in_case(
+    output: &mut (impl Output + ?Sized),
+    text: &[u8],
+    case: modifier::TextCase,
+) -> Result<usize, error::FormatCore> {
+    // The longest text is the name of a month or weekday, which is at most nine bytes.
+    let mut buf = [0; 9];
+    let buf = &mut buf[..text.len()];
+    buf.copy_from_slice(text);
+    match case {
+        modifier::TextCase::Title => {}
+        modifier::TextCase::Upper => buf.make_ascii_uppercase(),
+        modifier::TextCase::Lower => buf.make_ascii_lowercase(),
+    }
+    write(output, buf)
+}
+
 /// Helper function to obtain 10^x, guaranteeing determinism for x ≤ 9. For these cases, the
-/// function optimizes to a lookup table. For x ≥ 10, it falls back to `10_f64.powi(x)`. The only
-/// situation where this would occur is if the user explicitly requests such precision when
-/// configuring the ISO 8601 well known format. All other possibilities max out at nine digits.
+/// function optimizes to a lookup table. For x ≥ 10, it falls back to repeated multiplication, as
+/// `f64::powi` is not available without `std`. The only situation where this would occur is if the
+/// user explicitly requests such precision when configuring the ISO 8601 well known format. All
+/// other possibilities max out at nine digits.
 fn f64_10_pow_x(x: NonZero<u8>) -> f64 {
     match x.get() {
         1 => 10.,
@@ -84,7 +257,24 @@ fn f64_10_pow_x(x: NonZero<u8>) -> f64 {
         7 => 10_000_000.,
         8 => 100_000_000.,
         9 => 1_000_000_000.,
-        x => 10_f64.powi(x.cast_signed().extend()),
+        x => {
+            let mut value = 1_000_000_000.;
+            for _ in 9..x {
+                value *= 10.;
+            }
+            value
+        }
+    }
+}
+
+/// Truncate the floating point number towards zero. This is equivalent to `f64::trunc`, which is
+/// not available without `std`.
+fn f64_trunc(value: f64) -> f64 {
+    // Every value with a magnitude of at least 2^52 is an integer, as is every non-finite value.
+    if value > -4_503_599_627_370_496. && value < 4_503_599_627_370_496. {
+        (value as i64) as f64
+    } else {
+        value
     }
 }
 
@@ -93,26 +283,29 @@ fn f64_10_pow_x(x: NonZero<u8>) -> f64 {
 /// This method accepts the number of digits before and after the decimal. The value will be padded
 /// with zeroes to the left if necessary.
 pub(crate) fn format_float(
-    output: &mut (impl io::Write + ?Sized),
+    output: &mut (impl Output + ?Sized),
     value: f64,
     digits_before_decimal: u8,
     digits_after_decimal: Option<NonZero<u8>>,
-) -> io::Result<usize> {
+) -> Result<usize, error::FormatCore> {
     match digits_after_decimal {
         Some(digits_after_decimal) => {
             // Truncate the decimal points up to the precision
             let trunc_num = f64_10_pow_x(digits_after_decimal);
-            let value = f64::trunc(value * trunc_num) / trunc_num;
+            let value = f64_trunc(value * trunc_num) / trunc_num;
 
             let digits_after_decimal = digits_after_decimal.get().extend();
             let width = digits_before_decimal.extend::<usize>() + 1 + digits_after_decimal;
-            write!(output, "{value:0>width$.digits_after_decimal$}")?;
+            write_fmt(
+                output,
+                format_args!("{value:0>width$.digits_after_decimal$}"),
+            )?;
             Ok(width)
         }
         None => {
-            let value = value.trunc() as u64;
+            let value = value as u64;
             let width = digits_before_decimal.extend();
-            write!(output, "{value:0>width$}")?;
+            write_fmt(output, format_args!("{value:0>width$}"))?;
             Ok(width)
         }
     }
@@ -122,10 +315,10 @@ pub(crate) fn format_float(
 ///
 /// The sign must be written by the caller.
 pub(crate) fn format_number<const WIDTH: u8>(
-    output: &mut (impl io::Write + ?Sized),
-    value: impl ToString + DigitCount + Copy,
+    output: &mut (impl Output + ?Sized),
+    value: impl fmt::Display + DigitCount + Copy,
     padding: modifier::Padding,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::FormatCore> {
     match padding {
         modifier::Padding::Space => format_number_pad_space::<WIDTH>(output, value),
         modifier::Padding::Zero => format_number_pad_zero::<WIDTH>(output, value),
@@ -133,18 +326,52 @@ pub(crate) fn format_number<const WIDTH: u8>(
     }
 }
 
+/// Format a number with the provided padding, preceded by its sign. If the minimum width exceeds
+/// `WIDTH`, the sign and number together are padded to that width, with spaces placed before the
+/// sign and zeroes placed after it.
+pub(crate) fn format_number_to_width<const WIDTH: u8>(
+    output: &mut (impl Output + ?Sized),
+    sign: &[u8],
+    value: impl fmt::Display + DigitCount + Copy,
+    padding: modifier::Padding,
+    width: u8,
+) -> Result<usize, error::FormatCore> {
+    if width <= WIDTH || padding == modifier::Padding::None {
+        return Ok(write(output, sign)? + format_number::<WIDTH>(output, value, padding)?);
+    }
+
+    // The sign is at most one byte.
+    let excess = width
+        .saturating_sub(sign.len().truncate::<u8>())
+        .saturating_sub(value.num_digits());
+    let mut bytes = 0;
+    if padding == modifier::Padding::Space {
+        for _ in 0..excess {
+            bytes += write(output, b" ")?;
+        }
+        bytes += write(output, sign)?;
+    } else {
+        bytes += write(output, sign)?;
+        for _ in 0..excess.max(WIDTH.saturating_sub(value.num_digits())) {
+            bytes += write(output, b"0")?;
+        }
+    }
+    bytes += write_fmt(output, format_args!("{value}"))?;
+    Ok(bytes)
+}
+
 /// Format a number with the provided width and spaces as padding.
 ///
 /// The sign must be written by the caller.
 pub(crate) fn format_number_pad_space<const WIDTH: u8>(
-    output: &mut (impl io::Write + ?Sized),
-    value: impl ToString + DigitCount + Copy,
-) -> Result<usize, io::Error> {
+    output: &mut (impl Output + ?Sized),
+    value: impl fmt::Display + DigitCount + Copy,
+) -> Result<usize, error::FormatCore> {
     let mut bytes = 0;
     for _ in 0..(WIDTH.saturating_sub(value.num_digits())) {
         bytes += write(output, b" ")?;
     }
-    bytes += write(output, value.to_string().as_bytes())?;
+    bytes += write_fmt(output, format_args!("{value}"))?;
     Ok(bytes)
 }
 
@@ -152,14 +379,14 @@ pub(crate) fn format_number_pad_space<const WIDTH: u8>(
 ///
 /// The sign must be written by the caller.
 pub(crate) fn format_number_pad_zero<const WIDTH: u8>(
-    output: &mut (impl io::Write + ?Sized),
-    value: impl ToString + DigitCount + Copy,
-) -> Result<usize, io::Error> {
+    output: &mut (impl Output + ?Sized),
+    value: impl fmt::Display + DigitCount + Copy,
+) -> Result<usize, error::FormatCore> {
     let mut bytes = 0;
     for _ in 0..(WIDTH.saturating_sub(value.num_digits())) {
         bytes += write(output, b"0")?;
     }
-    bytes += write(output, value.to_string().as_bytes())?;
+    bytes += write_fmt(output, format_args!("{value}"))?;
     Ok(bytes)
 }
 
@@ -167,22 +394,22 @@ pub(crate) fn format_number_pad_zero<const WIDTH: u8>(
 ///
 /// If the sign is mandatory, the sign must be written by the caller.
 pub(crate) fn format_number_pad_none(
-    output: &mut (impl io::Write + ?Sized),
-    value: impl ToString + Copy,
-) -> Result<usize, io::Error> {
-    write(output, value.to_string().as_bytes())
+    output: &mut (impl Output + ?Sized),
+    value: impl fmt::Display + Copy,
+) -> Result<usize, error::FormatCore> {
+    write_fmt(output, format_args!("{value}"))
 }
 
 /// Format the provided component into the designated output. An `Err` will be returned if the
 /// component requires information that it does not provide or if the value cannot be output to the
 /// stream.
 pub(crate) fn format_component(
-    output: &mut (impl io::Write + ?Sized),
+    output: &mut (impl Output + ?Sized),
     component: Component,
     date: Option<Date>,
     time: Option<Time>,
     offset: Option<UtcOffset>,
-) -> Result<usize, error::Format> {
+) -> Result<usize, error::FormatCore> {
     use Component::*;
     Ok(match (component, date, time, offset) {
         (Day(modifier), Some(date), ..) => fmt_day(output, date, modifier)?,
@@ -190,6 +417,10 @@ pub(crate) fn format_component(
         (Ordinal(modifier), Some(date), ..) => fmt_ordinal(output, date, modifier)?,
         (Weekday(modifier), Some(date), ..) => fmt_weekday(output, date, modifier)?,
         (WeekNumber(modifier), Some(date), ..) => fmt_week_number(output, date, modifier)?,
+        (WeekOfMonth(modifier), Some(date), ..) => fmt_week_of_month(output, date, modifier)?,
+        (WeekdayInMonth(modifier), Some(date), ..) => fmt_weekday_in_month(output, date, modifier)?,
+        (Quarter(modifier), Some(date), ..) => fmt_quarter(output, date, modifier)?,
+        (Era(modifier), Some(date), ..) => fmt_era(output, date, modifier)?,
         (Year(modifier), Some(date), ..) => fmt_year(output, date, modifier)?,
         (Hour(modifier), _, Some(time), _) => fmt_hour(output, time, modifier)?,
         (Minute(modifier), _, Some(time), _) => fmt_minute(output, time, modifier)?,
@@ -203,7 +434,17 @@ pub(crate) fn format_component(
         (UnixTimestamp(modifier), Some(date), Some(time), Some(offset)) => {
             fmt_unix_timestamp(output, date, time, offset, modifier)?
         }
+        (JulianDay(modifier), Some(date), ..) if modifier.digits == 0 => {
+            fmt_julian_day(output, date, modifier)?
+        }
+        (JulianDay(modifier), Some(date), Some(time), Some(offset)) => {
+            fmt_julian_date(output, date, time, offset, modifier)?
+        }
         (End(modifier::End {}), ..) => 0,
+        (TimeZoneAnnotation(modifier), .., Some(offset)) => {
+            fmt_time_zone_annotation(output, offset, modifier)?
+        }
+        (ExtensionAnnotations(modifier::ExtensionAnnotations {}), ..) => 0,
 
         // This is functionally the same as a wildcard arm, but it will cause an error if a new
         // component is added. This is to avoid a bug where a new component, the code compiles, and
@@ -211,75 +452,137 @@ pub(crate) fn format_component(
         // Allow unreachable patterns because some branches may be fully matched above.
         #[allow(unreachable_patterns)]
         (
-            Day(_) | Month(_) | Ordinal(_) | Weekday(_) | WeekNumber(_) | Year(_) | Hour(_)
-            | Minute(_) | Period(_) | Second(_) | Subsecond(_) | OffsetHour(_) | OffsetMinute(_)
-            | OffsetSecond(_) | Ignore(_) | UnixTimestamp(_) | End(_),
+            Day(_)
+            | Month(_)
+            | Ordinal(_)
+            | Weekday(_)
+            | WeekNumber(_)
+            | WeekOfMonth(_)
+            | WeekdayInMonth(_)
+            | Quarter(_)
+            | Era(_)
+            | Year(_)
+            | Hour(_)
+            | Minute(_)
+            | Period(_)
+            | Second(_)
+            | Subsecond(_)
+            | OffsetHour(_)
+            | OffsetMinute(_)
+            | OffsetSecond(_)
+            | Ignore(_)
+            | UnixTimestamp(_)
+            | JulianDay(_)
+            | End(_)
+            | TimeZoneAnnotation(_)
+            | ExtensionAnnotations(_),
             ..,
-        ) => return Err(error::Format::InsufficientTypeInformation),
+        ) => return Err(error::FormatCore::InsufficientTypeInformation),
     })
 }
 
+/// Determine whether the provided condition holds for the value being formatted. An `Err` will be
+/// returned if the condition requires information that is not provided.
+pub(crate) const fn condition_holds(
+    condition: Condition,
+    time: Option<Time>,
+    offset: Option<UtcOffset>,
+) -> Result<bool, error::FormatCore> {
+    match (condition, time, offset) {
+        (Condition::OffsetIsUtc, _, Some(offset)) => Ok(offset.is_utc()),
+        (Condition::OffsetMinuteIsZero, _, Some(offset)) => Ok(offset.minutes_past_hour() == 0),
+        (Condition::OffsetSecondIsZero, _, Some(offset)) => Ok(offset.seconds_past_minute() == 0),
+        (Condition::SecondIsZero, Some(time), _) => Ok(time.second() == 0),
+        (Condition::SubsecondIsZero, Some(time), _) => Ok(time.nanosecond() == 0),
+        (
+            Condition::OffsetIsUtc
+            | Condition::OffsetMinuteIsZero
+            | Condition::OffsetSecondIsZero
+            | Condition::SecondIsZero
+            | Condition::SubsecondIsZero,
+            ..,
+        ) => Err(error::FormatCore::InsufficientTypeInformation),
+    }
+}
+
 /// Format the day into the designated output.
 fn fmt_day(
-    output: &mut (impl io::Write + ?Sized),
+    output: &mut (impl Output + ?Sized),
     date: Date,
-    modifier::Day { padding }: modifier::Day,
-) -> Result<usize, io::Error> {
-    format_number::<2>(output, date.day(), padding)
+    modifier::Day { padding, width }: modifier::Day,
+) -> Result<usize, error::FormatCore> {
+    format_number_to_width::<2>(output, b"", date.day(), padding, width)
 }
 
 /// Format the month into the designated output.
 fn fmt_month(
-    output: &mut (impl io::Write + ?Sized),
+    output: &mut (impl Output + ?Sized),
     date: Date,
     modifier::Month {
         padding,
+        width,
         repr,
+        case,
         case_sensitive: _, // no effect on formatting
     }: modifier::Month,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::FormatCore> {
     match repr {
         modifier::MonthRepr::Numerical => {
-            format_number::<2>(output, u8::from(date.month()), padding)
+            format_number_to_width::<2>(output, b"", u8::from(date.month()), padding, width)
         }
-        modifier::MonthRepr::Long => write(
+        modifier::MonthRepr::Long => write_in_case(
             output,
             MONTH_NAMES[u8::from(date.month()).extend::<usize>() - 1],
+            case,
         ),
-        modifier::MonthRepr::Short => write(
+        modifier::MonthRepr::Short => write_in_case(
             output,
             &MONTH_NAMES[u8::from(date.month()).extend::<usize>() - 1][..3],
+            case,
+        ),
+        modifier::MonthRepr::Narrow => write_in_case(
+            output,
+            &MONTH_NAMES[u8::from(date.month()).extend::<usize>() - 1][..1],
+            case,
         ),
     }
 }
 
 /// Format the ordinal into the designated output.
 fn fmt_ordinal(
-    output: &mut (impl io::Write + ?Sized),
+    output: &mut (impl Output + ?Sized),
     date: Date,
-    modifier::Ordinal { padding }: modifier::Ordinal,
-) -> Result<usize, io::Error> {
-    format_number::<3>(output, date.ordinal(), padding)
+    modifier::Ordinal { padding, width }: modifier::Ordinal,
+) -> Result<usize, error::FormatCore> {
+    format_number_to_width::<3>(output, b"", date.ordinal(), padding, width)
 }
 
 /// Format the weekday into the designated output.
 fn fmt_weekday(
-    output: &mut (impl io::Write + ?Sized),
+    output: &mut (impl Output + ?Sized),
     date: Date,
     modifier::Weekday {
         repr,
         one_indexed,
+        case,
         case_sensitive: _, // no effect on formatting
     }: modifier::Weekday,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::FormatCore> {
     match repr {
-        modifier::WeekdayRepr::Short => write(
+        modifier::WeekdayRepr::Short => write_in_case(
             output,
             &WEEKDAY_NAMES[date.weekday().number_days_from_monday().extend::<usize>()][..3],
+            case,
         ),
-        modifier::WeekdayRepr::Long => write(
+        modifier::WeekdayRepr::Long => write_in_case(
             output,
             WEEKDAY_NAMES[date.weekday().number_days_from_monday().extend::<usize>()],
+            case,
+        ),
+        modifier::WeekdayRepr::Narrow => write_in_case(
+            output,
+            &WEEKDAY_NAMES[date.weekday().number_days_from_monday().extend::<usize>()][..1],
+            case,
         ),
         modifier::WeekdayRepr::Sunday => format_number::<1>(
             output,
@@ -296,35 +599,125 @@ fn fmt_weekday(
 
 /// Format the week number into the designated output.
 fn fmt_week_number(
-    output: &mut (impl io::Write + ?Sized),
+    output: &mut (impl Output + ?Sized),
     date: Date,
-    modifier::WeekNumber { padding, repr }: modifier::WeekNumber,
-) -> Result<usize, io::Error> {
-    format_number::<2>(
+    modifier::WeekNumber {
+        padding,
+        width,
+        repr,
+    }: modifier::WeekNumber,
+) -> Result<usize, error::FormatCore> {
+    format_number_to_width::<2>(
         output,
+        b"",
         match repr {
             modifier::WeekNumberRepr::Iso => date.iso_week(),
             modifier::WeekNumberRepr::Sunday => date.sunday_based_week(),
             modifier::WeekNumberRepr::Monday => date.monday_based_week(),
         },
         padding,
+        width,
+    )
+}
+
+/// Format the week of the month into the designated output.
+fn fmt_week_of_month(
+    output: &mut (impl Output + ?Sized),
+    date: Date,
+    modifier::WeekOfMonth {
+        first_weekday,
+        minimal_days,
+    }: modifier::WeekOfMonth,
+) -> Result<usize, error::FormatCore> {
+    format_number::<1>(
+        output,
+        date.week_of_month(first_weekday, minimal_days),
+        modifier::Padding::None,
+    )
+}
+
+/// Format the occurrence of the weekday within the month into the designated output.
+fn fmt_weekday_in_month(
+    output: &mut (impl Output + ?Sized),
+    date: Date,
+    modifier::WeekdayInMonth { from_end }: modifier::WeekdayInMonth,
+) -> Result<usize, error::FormatCore> {
+    format_number::<1>(
+        output,
+        if from_end {
+            date.weekday_in_month_from_end()
+        } else {
+            date.weekday_in_month()
+        },
+        modifier::Padding::None,
+    )
+}
+
+/// Format the quarter into the designated output.
+fn fmt_quarter(
+    output: &mut (impl Output + ?Sized),
+    date: Date,
+    modifier::Quarter {
+        repr,
+        case_sensitive: _, // no effect on formatting
+    }: modifier::Quarter,
+) -> Result<usize, error::FormatCore> {
+    let quarter = u8::from(date.quarter());
+    match repr {
+        modifier::QuarterRepr::Numerical => {
+            format_number::<1>(output, quarter, modifier::Padding::None)
+        }
+        modifier::QuarterRepr::Short => Ok(
+            write(output, b"Q")? + format_number::<1>(output, quarter, modifier::Padding::None)?
+        ),
+    }
+}
+
+/// Format the era into the designated output.
+fn fmt_era(
+    output: &mut (impl Output + ?Sized),
+    date: Date,
+    modifier::Era {
+        repr,
+        case_sensitive: _, // no effect on formatting
+    }: modifier::Era,
+) -> Result<usize, error::FormatCore> {
+    let is_bce = date.year() <= 0;
+    write(
+        output,
+        match (repr, is_bce) {
+            (modifier::EraRepr::Short, false) => b"AD",
+            (modifier::EraRepr::Short, true) => b"BC",
+            (modifier::EraRepr::Common, false) => b"CE",
+            (modifier::EraRepr::Common, true) => b"BCE",
+            (modifier::EraRepr::Long, false) => b"Anno Domini",
+            (modifier::EraRepr::Long, true) => b"Before Christ",
+            (modifier::EraRepr::Narrow, false) => b"A",
+            (modifier::EraRepr::Narrow, true) => b"B",
+        },
     )
 }
 
 /// Format the year into the designated output.
 fn fmt_year(
-    output: &mut (impl io::Write + ?Sized),
+    output: &mut (impl Output + ?Sized),
     date: Date,
     modifier::Year {
         padding,
+        width,
         repr,
         range,
         iso_week_based,
         sign_is_mandatory,
+        pivot: _,
+        era_based,
     }: modifier::Year,
-) -> Result<usize, error::Format> {
+) -> Result<usize, error::FormatCore> {
+    let era_based = era_based && !iso_week_based && repr == modifier::YearRepr::Full;
     let full_year = if iso_week_based {
         date.iso_year_week().0
+    } else if era_based && date.year() <= 0 {
+        1 - date.year()
     } else {
         date.year()
     };
@@ -335,13 +728,13 @@ fn fmt_year(
     };
     let format_number = if cfg!(feature = "large-dates") && range == modifier::YearRange::Extended {
         match repr {
-            modifier::YearRepr::Full if value.abs() >= 100_000 => format_number::<6>,
-            modifier::YearRepr::Full if value.abs() >= 10_000 => format_number::<5>,
-            modifier::YearRepr::Full => format_number::<4>,
-            modifier::YearRepr::Century if value.abs() >= 1_000 => format_number::<4>,
-            modifier::YearRepr::Century if value.abs() >= 100 => format_number::<3>,
-            modifier::YearRepr::Century => format_number::<2>,
-            modifier::YearRepr::LastTwo => format_number::<2>,
+            modifier::YearRepr::Full if value.abs() >= 100_000 => format_number_to_width::<6>,
+            modifier::YearRepr::Full if value.abs() >= 10_000 => format_number_to_width::<5>,
+            modifier::YearRepr::Full => format_number_to_width::<4>,
+            modifier::YearRepr::Century if value.abs() >= 1_000 => format_number_to_width::<4>,
+            modifier::YearRepr::Century if value.abs() >= 100 => format_number_to_width::<3>,
+            modifier::YearRepr::Century => format_number_to_width::<2>,
+            modifier::YearRepr::LastTwo => format_number_to_width::<2>,
         }
     } else {
         match repr {
@@ -358,82 +751,88 @@ fn fmt_year(
             _ => {}
         }
         match repr {
-            modifier::YearRepr::Full => format_number::<4>,
-            modifier::YearRepr::Century => format_number::<2>,
-            modifier::YearRepr::LastTwo => format_number::<2>,
+            modifier::YearRepr::Full => format_number_to_width::<4>,
+            modifier::YearRepr::Century => format_number_to_width::<2>,
+            modifier::YearRepr::LastTwo => format_number_to_width::<2>,
         }
     };
-    let mut bytes = 0;
-    if repr != modifier::YearRepr::LastTwo {
-        if full_year < 0 {
-            bytes += write(output, b"-")?;
-        } else if sign_is_mandatory || cfg!(feature = "large-dates") && full_year >= 10_000 {
-            bytes += write(output, b"+")?;
-        }
-    }
-    bytes += format_number(output, value.unsigned_abs(), padding)?;
-    Ok(bytes)
+    let sign: &[u8] = if repr == modifier::YearRepr::LastTwo || era_based {
+        b""
+    } else if full_year < 0 {
+        b"-"
+    } else if sign_is_mandatory || cfg!(feature = "large-dates") && full_year >= 10_000 {
+        b"+"
+    } else {
+        b""
+    };
+    format_number(output, sign, value.unsigned_abs(), padding, width)
 }
 
 /// Format the hour into the designated output.
 fn fmt_hour(
-    output: &mut (impl io::Write + ?Sized),
+    output: &mut (impl Output + ?Sized),
     time: Time,
     modifier::Hour {
         padding,
+        width,
         is_12_hour_clock,
     }: modifier::Hour,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::FormatCore> {
     let value = match (time.hour(), is_12_hour_clock) {
         (hour, false) => hour,
         (0 | 12, true) => 12,
         (hour, true) if hour < 12 => hour,
         (hour, true) => hour - 12,
     };
-    format_number::<2>(output, value, padding)
+    format_number_to_width::<2>(output, b"", value, padding, width)
 }
 
 /// Format the minute into the designated output.
 fn fmt_minute(
-    output: &mut (impl io::Write + ?Sized),
+    output: &mut (impl Output + ?Sized),
     time: Time,
-    modifier::Minute { padding }: modifier::Minute,
-) -> Result<usize, io::Error> {
-    format_number::<2>(output, time.minute(), padding)
+    modifier::Minute { padding, width }: modifier::Minute,
+) -> Result<usize, error::FormatCore> {
+    format_number_to_width::<2>(output, b"", time.minute(), padding, width)
 }
 
 /// Format the period into the designated output.
 fn fmt_period(
-    output: &mut (impl io::Write + ?Sized),
+    output: &mut (impl Output + ?Sized),
     time: Time,
     modifier::Period {
         is_uppercase,
+        repr,
         case_sensitive: _, // no effect on formatting
     }: modifier::Period,
-) -> Result<usize, io::Error> {
-    match (time.hour() >= 12, is_uppercase) {
-        (false, false) => write(output, b"am"),
-        (false, true) => write(output, b"AM"),
-        (true, false) => write(output, b"pm"),
-        (true, true) => write(output, b"PM"),
+) -> Result<usize, error::FormatCore> {
+    let text = match (time.hour() >= 12, is_uppercase) {
+        (false, false) => b"am",
+        (false, true) => b"AM",
+        (true, false) => b"pm",
+        (true, true) => b"PM",
+    };
+    match repr {
+        modifier::PeriodRepr::Short => write(output, text),
+        modifier::PeriodRepr::Narrow => write(output, &text[..1]),
     }
 }
 
 /// Format the second into the designated output.
 fn fmt_second(
-    output: &mut (impl io::Write + ?Sized),
+    output: &mut (impl Output + ?Sized),
     time: Time,
-    modifier::Second { padding }: modifier::Second,
-) -> Result<usize, io::Error> {
-    format_number::<2>(output, time.second(), padding)
+    modifier::Second { padding, width }: modifier::Second,
+) -> Result<usize, error::FormatCore> {
+    format_number_to_width::<2>(output, b"", time.second(), padding, width)
 }
 
 /// Format the subsecond into the designated output.
 fn fmt_subsecond(
-    output: &mut (impl io::Write + ?Sized),
+    output: &mut (impl Output + ?Sized),
     time: Time,
     modifier::Subsecond { digits }: modifier::Subsecond,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::FormatCore> {
     use modifier::SubsecondDigits::*;
     let nanos = time.nanosecond();
 
@@ -460,13 +859,13 @@ fn fmt_subsecond(
 
 /// Format the offset hour into the designated output.
 fn fmt_offset_hour(
-    output: &mut (impl io::Write + ?Sized),
+    output: &mut (impl Output + ?Sized),
     offset: UtcOffset,
     modifier::OffsetHour {
         padding,
         sign_is_mandatory,
     }: modifier::OffsetHour,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::FormatCore> {
     let mut bytes = 0;
     if offset.is_negative() {
         bytes += write(output, b"-")?;
@@ -477,27 +876,105 @@ fn fmt_offset_hour(
     Ok(bytes)
 }
 
+/// Format the UTC offset as a numeric time zone annotation into the designated output.
+fn fmt_time_zone_annotation(
+    output: &mut (impl Output + ?Sized),
+    offset: UtcOffset,
+    modifier::TimeZoneAnnotation {}: modifier::TimeZoneAnnotation,
+) -> Result<usize, error::FormatCore> {
+    // RFC 9557 does not permit seconds in the offset.
+    if offset.seconds_past_minute() != 0 {
+        return Err(error::FormatCore::InvalidComponent("time_zone_annotation"));
+    }
+
+    let mut bytes = 0;
+    bytes += write(output, if offset.is_negative() { b"[-" } else { b"[+" })?;
+    bytes += format_number_pad_zero::<2>(output, offset.whole_hours().unsigned_abs())?;
+    bytes += write(output, b":")?;
+    bytes += format_number_pad_zero::<2>(output, offset.minutes_past_hour().unsigned_abs())?;
+    bytes += write(output, b"]")?;
+    Ok(bytes)
+}
+
 /// Format the offset minute into the designated output.
 fn fmt_offset_minute(
-    output: &mut (impl io::Write + ?Sized),
+    output: &mut (impl Output + ?Sized),
     offset: UtcOffset,
     modifier::OffsetMinute { padding }: modifier::OffsetMinute,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::FormatCore> {
     format_number::<2>(output, offset.minutes_past_hour().unsigned_abs(), padding)
 }
 
+/// Format the Julian day of the date into the designated output.
+fn fmt_julian_day(
+    output: &mut (impl Output + ?Sized),
+    date: Date,
+    modifier::JulianDay { repr, digits: _ }: modifier::JulianDay,
+) -> Result<usize, error::FormatCore> {
+    let julian_day = match repr {
+        modifier::JulianDayRepr::Julian => date.to_julian_day(),
+        modifier::JulianDayRepr::Modified => date.to_modified_julian_day(),
+    };
+
+    let mut bytes = 0;
+    if julian_day < 0 {
+        bytes += write(output, b"-")?;
+    }
+    bytes += format_number_pad_none(output, julian_day.unsigned_abs())?;
+    Ok(bytes)
+}
+
+/// Format the Julian date, which includes the fraction of the day in UTC, into the designated
+/// output.
+fn fmt_julian_date(
+    output: &mut (impl Output + ?Sized),
+    date: Date,
+    time: Time,
+    offset: UtcOffset,
+    modifier::JulianDay { repr, digits }: modifier::JulianDay,
+) -> Result<usize, error::FormatCore> {
+    let nanos_per_day = Nanosecond::per_t::<i128>(Day);
+    let epoch = match repr {
+        // The Julian day begins at noon.
+        modifier::JulianDayRepr::Julian => {
+            Date::UNIX_EPOCH.to_julian_day().extend::<i128>() * nanos_per_day - nanos_per_day / 2
+        }
+        modifier::JulianDayRepr::Modified => {
+            Date::UNIX_EPOCH.to_modified_julian_day().extend::<i128>() * nanos_per_day
+        }
+    };
+    let value = OffsetDateTime::new_in_offset(date, time, offset).unix_timestamp_nanos() + epoch;
+
+    let digits = digits.min(14);
+    let nanos_per_day = nanos_per_day.cast_unsigned();
+    let fraction =
+        value.unsigned_abs() % nanos_per_day * 10_u128.pow(digits.extend()) / nanos_per_day;
+
+    let mut bytes = 0;
+    if value < 0 {
+        bytes += write(output, b"-")?;
+    }
+    bytes += format_number_pad_none(output, value.unsigned_abs() / nanos_per_day)?;
+    bytes += write(output, b".")?;
+    bytes += write_fmt(
+        output,
+        format_args!("{fraction:0width$}", width = digits.extend::<usize>()),
+    )?;
+    Ok(bytes)
+}
+
 /// Format the offset second into the designated output.
 fn fmt_offset_second(
-    output: &mut (impl io::Write + ?Sized),
+    output: &mut (impl Output + ?Sized),
     offset: UtcOffset,
     modifier::OffsetSecond { padding }: modifier::OffsetSecond,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::FormatCore> {
     format_number::<2>(output, offset.seconds_past_minute().unsigned_abs(), padding)
 }
 
 /// Format the Unix timestamp into the designated output.
 fn fmt_unix_timestamp(
-    output: &mut (impl io::Write + ?Sized),
+    output: &mut (impl Output + ?Sized),
     date: Date,
     time: Time,
     offset: UtcOffset,
@@ -505,7 +982,7 @@ fn fmt_unix_timestamp(
         precision,
         sign_is_mandatory,
     }: modifier::UnixTimestamp,
-) -> Result<usize, io::Error> {
+) -> Result<usize, error::FormatCore> {
     let date_time = OffsetDateTime::new_in_offset(date, time, offset).to_offset(UtcOffset::UTC);
 
     if date_time < OffsetDateTime::UNIX_EPOCH {
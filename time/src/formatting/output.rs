@@ -0,0 +1,103 @@
+//! Destinations that formatted output can be written to.
+
+#[cfg(feature = "formatting")]
+use alloc::vec::Vec;
+use core::{fmt, str};
+#[cfg(feature = "formatting")]
+use std::io;
+
+use crate::error;
+
+/// A destination for formatted output.
+///
+/// This abstracts over the various ways output can be written, permitting formatting with or
+/// without `std` and `alloc`.
+pub trait Output {
+    /// Write all bytes to the output.
+    fn write_all(&mut self, bytes: &[u8]) -> Result<(), error::FormatCore>;
+}
+
+#[cfg(feature = "formatting")]
+impl Output for Vec<u8> {
+    fn write_all(&mut self, bytes: &[u8]) -> Result<(), error::FormatCore> {
+        self.extend_from_slice(bytes);
+        Ok(())
+    }
+}
+
+/// An adapter for any type implementing [`io::Write`]. As [`error::FormatCore`] cannot contain an
+/// [`io::Error`], the error is retained by the adapter.
+#[cfg(feature = "formatting")]
+pub(crate) struct IoOutput<'a, W: io::Write + ?Sized> {
+    /// The writer being written to.
+    writer: &'a mut W,
+    /// The error returned by the writer, if any.
+    error: Option<io::Error>,
+}
+
+#[cfg(feature = "formatting")]
+impl<'a, W: io::Write + ?Sized> IoOutput<'a, W> {
+    /// Create a new adapter that writes to the provided writer.
+    pub(crate) const fn new(writer: &'a mut W) -> Self {
+        Self {
+            writer,
+            error: None,
+        }
+    }
+
+    /// Obtain the error to return to the caller, preferring the error returned by the writer.
+    pub(crate) fn into_error(self, err: error::FormatCore) -> error::Format {
+        match self.error {
+            Some(err) => error::Format::StdIo(err),
+            None => err.into(),
+        }
+    }
+}
+
+#[cfg(feature = "formatting")]
+impl<W: io::Write + ?Sized> Output for IoOutput<'_, W> {
+    fn write_all(&mut self, bytes: &[u8]) -> Result<(), error::FormatCore> {
+        self.writer.write_all(bytes).map_err(|err| {
+            self.error = Some(err);
+            error::FormatCore::Fmt(fmt::Error)
+        })
+    }
+}
+
+/// An adapter for any type implementing [`fmt::Write`]. As `fmt::Write` only accepts `str`, output
+/// that is not valid UTF-8 results in an error.
+pub(crate) struct FmtOutput<'a, W: fmt::Write + ?Sized>(pub(crate) &'a mut W);
+
+impl<W: fmt::Write + ?Sized> Output for FmtOutput<'_, W> {
+    fn write_all(&mut self, bytes: &[u8]) -> Result<(), error::FormatCore> {
+        let s = str::from_utf8(bytes).map_err(|_| error::FormatCore::Fmt(fmt::Error))?;
+        Ok(self.0.write_str(s)?)
+    }
+}
+
+/// An adapter for a byte slice. Bytes are written sequentially from the start of the slice.
+pub(crate) struct SliceOutput<'a> {
+    /// The buffer being written to.
+    buf: &'a mut [u8],
+    /// The number of bytes that have been written.
+    len: usize,
+}
+
+impl<'a> SliceOutput<'a> {
+    /// Create a new adapter that writes to the start of the provided buffer.
+    pub(crate) const fn new(buf: &'a mut [u8]) -> Self {
+        Self { buf, len: 0 }
+    }
+}
+
+impl Output for SliceOutput<'_> {
+    fn write_all(&mut self, bytes: &[u8]) -> Result<(), error::FormatCore> {
+        let end = self.len + bytes.len();
+        self.buf
+            .get_mut(self.len..end)
+            .ok_or(error::FormatCore::InsufficientBufferSize)?
+            .copy_from_slice(bytes);
+        self.len = end;
+        Ok(())
+    }
+}
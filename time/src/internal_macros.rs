@@ -196,7 +196,10 @@ macro_rules! expect_opt {
 }
 
 /// `unreachable!()`, but better.
-#[cfg(any(feature = "formatting", feature = "parsing"))]
+#[cfg(any(
+    all(feature = "formatting-core", feature = "alloc"),
+    feature = "parsing"
+))]
 macro_rules! bug {
     () => { compile_error!("provide an error message to help fix a possible bug") };
     ($descr:literal $($rest:tt)?) => {
@@ -204,7 +207,10 @@ macro_rules! bug {
     }
 }
 
-#[cfg(any(feature = "formatting", feature = "parsing"))]
+#[cfg(any(
+    all(feature = "formatting-core", feature = "alloc"),
+    feature = "parsing"
+))]
 pub(crate) use bug;
 pub(crate) use {
     __impl_assign, cascade, const_try, const_try_opt, div_floor, ensure_ranged, expect_opt,
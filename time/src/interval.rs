@@ -0,0 +1,561 @@
+//! ISO 8601 time intervals and repeating intervals.
+
+#[cfg(feature = "formatting")]
+use alloc::string::String;
+#[cfg(feature = "formatting")]
+use alloc::vec::Vec;
+#[cfg(feature = "formatting-core")]
+use core::fmt;
+#[cfg(feature = "formatting")]
+use std::io;
+
+use deranged::RangedU32;
+
+use crate::convert::*;
+use crate::format_description::well_known::iso8601::EncodedConfig;
+use crate::format_description::well_known::Iso8601;
+#[cfg(feature = "formatting")]
+use crate::formatting::IoOutput;
+#[cfg(feature = "formatting-core")]
+use crate::formatting::{iso8601, FmtOutput};
+use crate::internal_macros::ensure_ranged;
+use crate::{error, Date, OffsetDateTime, PrimitiveDateTime};
+
+/// The type of the `nanoseconds` field of `NominalDuration`.
+type Nanoseconds = RangedU32<0, { Nanosecond::per_t::<u32>(Second) - 1 }>;
+
+/// One end of an [`Interval`].
+///
+/// ISO 8601 permits the ends of an interval to be given at any precision. The variant indicates
+/// which components were present.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Endpoint {
+    /// A calendar date with no time, such as `2024-02-15`.
+    Date(Date),
+    /// A date and time with no UTC offset, such as `2024-02-15T09:00`.
+    PrimitiveDateTime(PrimitiveDateTime),
+    /// A date and time with a UTC offset, such as `2024-02-15T09:00Z`.
+    OffsetDateTime(OffsetDateTime),
+}
+
+impl Endpoint {
+    /// Get the date of the endpoint.
+    ///
+    /// ```rust
+    /// # use time::interval::Endpoint;
+    /// # use time_macros::{date, datetime};
+    /// assert_eq!(Endpoint::Date(date!(2024-02-15)).date(), date!(2024-02-15));
+    /// assert_eq!(
+    ///     Endpoint::OffsetDateTime(datetime!(2024-02-15 9:00 UTC)).date(),
+    ///     date!(2024-02-15)
+    /// );
+    /// ```
+    pub const fn date(self) -> Date {
+        match self {
+            Self::Date(date) => date,
+            Self::PrimitiveDateTime(datetime) => datetime.date(),
+            Self::OffsetDateTime(datetime) => datetime.date(),
+        }
+    }
+}
+
+impl From<Date> for Endpoint {
+    fn from(date: Date) -> Self {
+        Self::Date(date)
+    }
+}
+
+impl From<PrimitiveDateTime> for Endpoint {
+    fn from(datetime: PrimitiveDateTime) -> Self {
+        Self::PrimitiveDateTime(datetime)
+    }
+}
+
+impl From<OffsetDateTime> for Endpoint {
+    fn from(datetime: OffsetDateTime) -> Self {
+        Self::OffsetDateTime(datetime)
+    }
+}
+
+/// A duration expressed in calendar and clock components, such as `P1Y2M10DT2H30M`.
+///
+/// Unlike [`Duration`](crate::Duration), the components are not normalized. The length of a year,
+/// month, or day depends on the point in time it is applied to, so `P1M` and `P30D` are distinct
+/// values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct NominalDuration {
+    /// The number of years.
+    years: u32,
+    /// The number of months.
+    months: u32,
+    /// The number of weeks.
+    weeks: u32,
+    /// The number of days.
+    days: u32,
+    /// The number of hours.
+    hours: u32,
+    /// The number of minutes.
+    minutes: u32,
+    /// The number of whole seconds.
+    seconds: u32,
+    /// The fractional part of the seconds, in nanoseconds.
+    nanoseconds: Nanoseconds,
+}
+
+impl NominalDuration {
+    /// A duration with every component set to zero.
+    ///
+    /// ```rust
+    /// # use time::interval::NominalDuration;
+    /// assert_eq!(NominalDuration::ZERO.years(), 0);
+    /// assert_eq!(NominalDuration::ZERO.nanoseconds(), 0);
+    /// ```
+    pub const ZERO: Self = Self {
+        years: 0,
+        months: 0,
+        weeks: 0,
+        days: 0,
+        hours: 0,
+        minutes: 0,
+        seconds: 0,
+        nanoseconds: Nanoseconds::MIN,
+    };
+
+    /// Get the number of years.
+    ///
+    /// ```rust
+    /// # use time::interval::NominalDuration;
+    /// assert_eq!(NominalDuration::ZERO.with_years(2).years(), 2);
+    /// ```
+    pub const fn years(self) -> u32 {
+        self.years
+    }
+
+    /// Get the number of months.
+    ///
+    /// ```rust
+    /// # use time::interval::NominalDuration;
+    /// assert_eq!(NominalDuration::ZERO.with_months(2).months(), 2);
+    /// ```
+    pub const fn months(self) -> u32 {
+        self.months
+    }
+
+    /// Get the number of weeks.
+    ///
+    /// ```rust
+    /// # use time::interval::NominalDuration;
+    /// assert_eq!(NominalDuration::ZERO.with_weeks(2).weeks(), 2);
+    /// ```
+    pub const fn weeks(self) -> u32 {
+        self.weeks
+    }
+
+    /// Get the number of days.
+    ///
+    /// ```rust
+    /// # use time::interval::NominalDuration;
+    /// assert_eq!(NominalDuration::ZERO.with_days(2).days(), 2);
+    /// ```
+    pub const fn days(self) -> u32 {
+        self.days
+    }
+
+    /// Get the number of hours.
+    ///
+    /// ```rust
+    /// # use time::interval::NominalDuration;
+    /// assert_eq!(NominalDuration::ZERO.with_hours(2).hours(), 2);
+    /// ```
+    pub const fn hours(self) -> u32 {
+        self.hours
+    }
+
+    /// Get the number of minutes.
+    ///
+    /// ```rust
+    /// # use time::interval::NominalDuration;
+    /// assert_eq!(NominalDuration::ZERO.with_minutes(2).minutes(), 2);
+    /// ```
+    pub const fn minutes(self) -> u32 {
+        self.minutes
+    }
+
+    /// Get the number of whole seconds.
+    ///
+    /// ```rust
+    /// # use time::interval::NominalDuration;
+    /// assert_eq!(NominalDuration::ZERO.with_seconds(2).seconds(), 2);
+    /// ```
+    pub const fn seconds(self) -> u32 {
+        self.seconds
+    }
+
+    /// Get the fractional part of the seconds, in nanoseconds.
+    ///
+    /// ```rust
+    /// # use time::interval::NominalDuration;
+    /// assert_eq!(
+    ///     NominalDuration::ZERO
+    ///         .with_nanoseconds(500_000_000)?
+    ///         .nanoseconds(),
+    ///     500_000_000
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub const fn nanoseconds(self) -> u32 {
+        self.nanoseconds.get()
+    }
+
+    /// Set the number of years.
+    #[must_use = "This method does not mutate the original `NominalDuration`."]
+    pub const fn with_years(mut self, years: u32) -> Self {
+        self.years = years;
+        self
+    }
+
+    /// Set the number of months.
+    #[must_use = "This method does not mutate the original `NominalDuration`."]
+    pub const fn with_months(mut self, months: u32) -> Self {
+        self.months = months;
+        self
+    }
+
+    /// Set the number of weeks.
+    #[must_use = "This method does not mutate the original `NominalDuration`."]
+    pub const fn with_weeks(mut self, weeks: u32) -> Self {
+        self.weeks = weeks;
+        self
+    }
+
+    /// Set the number of days.
+    #[must_use = "This method does not mutate the original `NominalDuration`."]
+    pub const fn with_days(mut self, days: u32) -> Self {
+        self.days = days;
+        self
+    }
+
+    /// Set the number of hours.
+    #[must_use = "This method does not mutate the original `NominalDuration`."]
+    pub const fn with_hours(mut self, hours: u32) -> Self {
+        self.hours = hours;
+        self
+    }
+
+    /// Set the number of minutes.
+    #[must_use = "This method does not mutate the original `NominalDuration`."]
+    pub const fn with_minutes(mut self, minutes: u32) -> Self {
+        self.minutes = minutes;
+        self
+    }
+
+    /// Set the number of whole seconds.
+    #[must_use = "This method does not mutate the original `NominalDuration`."]
+    pub const fn with_seconds(mut self, seconds: u32) -> Self {
+        self.seconds = seconds;
+        self
+    }
+
+    /// Set the fractional part of the seconds, in nanoseconds.
+    ///
+    /// ```rust
+    /// # use time::interval::NominalDuration;
+    /// assert!(NominalDuration::ZERO.with_nanoseconds(999_999_999).is_ok());
+    /// assert!(NominalDuration::ZERO
+    ///     .with_nanoseconds(1_000_000_000)
+    ///     .is_err());
+    /// ```
+    #[must_use = "This method does not mutate the original `NominalDuration`."]
+    pub const fn with_nanoseconds(
+        mut self,
+        nanoseconds: u32,
+    ) -> Result<Self, error::ComponentRange> {
+        self.nanoseconds = ensure_ranged!(Nanoseconds: nanoseconds);
+        Ok(self)
+    }
+}
+
+/// A time interval, as described by ISO 8601.
+///
+/// ISO 8601 permits an interval to be given as a start and end, a start and duration, a duration
+/// and end, or a duration alone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Interval {
+    /// An interval with a start and end, such as `2024-02-15/2024-02-18`.
+    StartEnd {
+        /// The start of the interval.
+        start: Endpoint,
+        /// The end of the interval.
+        end: Endpoint,
+    },
+    /// An interval with a start and duration, such as `2024-02-15/P3D`.
+    StartDuration {
+        /// The start of the interval.
+        start: Endpoint,
+        /// The duration of the interval.
+        duration: NominalDuration,
+    },
+    /// An interval with a duration and end, such as `P3D/2024-02-18`.
+    DurationEnd {
+        /// The duration of the interval.
+        duration: NominalDuration,
+        /// The end of the interval.
+        end: Endpoint,
+    },
+    /// An interval with only a duration, such as `P3D`.
+    Duration(NominalDuration),
+}
+
+/// A repeating time interval, as described by ISO 8601, such as `R5/2024-01-01T00:00Z/P1D`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RepeatingInterval {
+    /// The number of repetitions, or `None` if the interval repeats without bound.
+    repetitions: Option<u32>,
+    /// The interval that is repeated.
+    interval: Interval,
+}
+
+impl RepeatingInterval {
+    /// Create a `RepeatingInterval` from the number of repetitions and the interval being repeated.
+    /// A value of `None` indicates that the interval repeats without bound.
+    ///
+    /// ```rust
+    /// # use time::interval::{Interval, NominalDuration, RepeatingInterval};
+    /// let interval = Interval::Duration(NominalDuration::ZERO.with_days(1));
+    /// assert_eq!(
+    ///     RepeatingInterval::new(Some(5), interval).repetitions(),
+    ///     Some(5)
+    /// );
+    /// ```
+    pub const fn new(repetitions: Option<u32>, interval: Interval) -> Self {
+        Self {
+            repetitions,
+            interval,
+        }
+    }
+
+    /// Get the number of repetitions, or `None` if the interval repeats without bound.
+    ///
+    /// ```rust
+    /// # use time::interval::{Interval, NominalDuration, RepeatingInterval};
+    /// let interval = Interval::Duration(NominalDuration::ZERO.with_days(1));
+    /// assert_eq!(RepeatingInterval::new(None, interval).repetitions(), None);
+    /// ```
+    pub const fn repetitions(self) -> Option<u32> {
+        self.repetitions
+    }
+
+    /// Get the interval that is repeated.
+    ///
+    /// ```rust
+    /// # use time::interval::{Interval, NominalDuration, RepeatingInterval};
+    /// let interval = Interval::Duration(NominalDuration::ZERO.with_days(1));
+    /// assert_eq!(RepeatingInterval::new(None, interval).interval(), interval);
+    /// ```
+    pub const fn interval(self) -> Interval {
+        self.interval
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl NominalDuration {
+    /// Parse a `NominalDuration` from ISO 8601 text, such as `P1Y2M10DT2H30M`.
+    ///
+    /// A decimal fraction is permitted on the last component, provided it is hours, minutes, or
+    /// seconds.
+    ///
+    /// ```rust
+    /// # use time::interval::NominalDuration;
+    /// let duration = NominalDuration::parse("P1Y2M10DT2H30.5M")?;
+    /// assert_eq!(duration.years(), 1);
+    /// assert_eq!(duration.months(), 2);
+    /// assert_eq!(duration.days(), 10);
+    /// assert_eq!(duration.hours(), 2);
+    /// assert_eq!(duration.minutes(), 30);
+    /// assert_eq!(duration.seconds(), 30);
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse(input: &str) -> Result<Self, error::Parse> {
+        crate::parsing::iso8601::parse_nominal_duration(input.as_bytes())
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl Interval {
+    /// Parse an `Interval` from ISO 8601 text.
+    ///
+    /// Each endpoint is a date, optionally followed by a time and UTC offset. When both ends are
+    /// present, the end may omit any leading components that are the same as the start, as in
+    /// `2024-02-15/18` or `2024-02-15T09:00Z/17:00`. Omitted UTC offsets are also taken from the
+    /// start.
+    ///
+    /// An end that precedes the start is rejected, as is an end with a UTC offset when the start
+    /// has none.
+    ///
+    /// ```rust
+    /// # use time::format_description::well_known::Iso8601;
+    /// # use time::interval::{Endpoint, Interval};
+    /// # use time_macros::date;
+    /// assert_eq!(
+    ///     Interval::parse("2024-02-15/18", &Iso8601::DEFAULT)?,
+    ///     Interval::StartEnd {
+    ///         start: Endpoint::Date(date!(2024-02-15)),
+    ///         end: Endpoint::Date(date!(2024-02-18)),
+    ///     }
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse<const CONFIG: EncodedConfig>(
+        input: &str,
+        _description: &Iso8601<CONFIG>,
+    ) -> Result<Self, error::Parse> {
+        Iso8601::<CONFIG>::parse_interval(input.as_bytes())
+    }
+}
+
+#[cfg(feature = "parsing")]
+impl RepeatingInterval {
+    /// Parse a `RepeatingInterval` from ISO 8601 text, such as `R5/2024-01-01T00:00Z/P1D`. The
+    /// interval is parsed as [`Interval::parse`] does.
+    ///
+    /// ```rust
+    /// # use time::format_description::well_known::Iso8601;
+    /// # use time::interval::{Endpoint, Interval, NominalDuration, RepeatingInterval};
+    /// # use time_macros::datetime;
+    /// let interval = RepeatingInterval::parse("R5/2024-01-01T00:00Z/P1D", &Iso8601::DEFAULT)?;
+    /// assert_eq!(interval.repetitions(), Some(5));
+    /// assert_eq!(
+    ///     interval.interval(),
+    ///     Interval::StartDuration {
+    ///         start: Endpoint::OffsetDateTime(datetime!(2024-01-01 0:00 UTC)),
+    ///         duration: NominalDuration::ZERO.with_days(1),
+    ///     }
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse<const CONFIG: EncodedConfig>(
+        input: &str,
+        _description: &Iso8601<CONFIG>,
+    ) -> Result<Self, error::Parse> {
+        Iso8601::<CONFIG>::parse_repeating_interval(input.as_bytes())
+    }
+}
+
+#[cfg(feature = "formatting-core")]
+impl fmt::Display for NominalDuration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match iso8601::format_nominal_duration(&mut FmtOutput(f), *self) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(fmt::Error),
+        }
+    }
+}
+
+#[cfg(feature = "formatting-core")]
+impl Interval {
+    /// Format the `Interval` using the provided [`Iso8601`] configuration. The configuration
+    /// determines how each endpoint is written, but only the components present in the endpoint
+    /// are written.
+    #[cfg(feature = "formatting")]
+    pub fn format_into<const CONFIG: EncodedConfig>(
+        self,
+        output: &mut (impl io::Write + ?Sized),
+        _format: &Iso8601<CONFIG>,
+    ) -> Result<usize, error::Format> {
+        let mut output = IoOutput::new(output);
+        iso8601::format_interval::<CONFIG>(&mut output, self).map_err(|err| output.into_error(err))
+    }
+
+    /// Format the `Interval` using the provided [`Iso8601`] configuration. The configuration
+    /// determines how each endpoint is written, but only the components present in the endpoint
+    /// are written.
+    ///
+    /// ```rust
+    /// # use time::format_description::well_known::iso8601::{Config, TimePrecision};
+    /// # use time::format_description::well_known::Iso8601;
+    /// # use time::interval::Interval;
+    /// const FORMAT: Iso8601<
+    ///     {
+    ///         Config::DEFAULT
+    ///             .set_time_precision(TimePrecision::Minute {
+    ///                 decimal_digits: None,
+    ///             })
+    ///             .encode()
+    ///     },
+    /// > = Iso8601;
+    /// let interval = Interval::parse("2024-02-15T09:00Z/17:00", &FORMAT)?;
+    /// assert_eq!(
+    ///     interval.format(&FORMAT)?,
+    ///     "2024-02-15T09:00Z/2024-02-15T17:00Z"
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[cfg(feature = "formatting")]
+    pub fn format<const CONFIG: EncodedConfig>(
+        self,
+        _format: &Iso8601<CONFIG>,
+    ) -> Result<String, error::Format> {
+        let mut buf = Vec::new();
+        iso8601::format_interval::<CONFIG>(&mut buf, self)?;
+        Ok(String::from_utf8_lossy(&buf).into_owned())
+    }
+
+    /// Format the `Interval` using the provided [`Iso8601`] configuration, writing the output to
+    /// a [`fmt::Write`]. This does not require `std` or `alloc`.
+    pub fn format_into_fmt<const CONFIG: EncodedConfig>(
+        self,
+        output: &mut (impl fmt::Write + ?Sized),
+        _format: &Iso8601<CONFIG>,
+    ) -> Result<usize, error::FormatCore> {
+        iso8601::format_interval::<CONFIG>(&mut FmtOutput(output), self)
+    }
+}
+
+#[cfg(feature = "formatting-core")]
+impl RepeatingInterval {
+    /// Format the `RepeatingInterval` using the provided [`Iso8601`] configuration. The interval is
+    /// written as [`Interval::format`] does.
+    #[cfg(feature = "formatting")]
+    pub fn format_into<const CONFIG: EncodedConfig>(
+        self,
+        output: &mut (impl io::Write + ?Sized),
+        _format: &Iso8601<CONFIG>,
+    ) -> Result<usize, error::Format> {
+        let mut output = IoOutput::new(output);
+        iso8601::format_repeating_interval::<CONFIG>(&mut output, self)
+            .map_err(|err| output.into_error(err))
+    }
+
+    /// Format the `RepeatingInterval` using the provided [`Iso8601`] configuration. The interval is
+    /// written as [`Interval::format`] does.
+    ///
+    /// ```rust
+    /// # use time::format_description::well_known::Iso8601;
+    /// # use time::interval::{Interval, NominalDuration, RepeatingInterval};
+    /// let interval = RepeatingInterval::new(
+    ///     None,
+    ///     Interval::Duration(NominalDuration::ZERO.with_hours(12)),
+    /// );
+    /// assert_eq!(interval.format(&Iso8601::DEFAULT)?, "R/PT12H");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[cfg(feature = "formatting")]
+    pub fn format<const CONFIG: EncodedConfig>(
+        self,
+        _format: &Iso8601<CONFIG>,
+    ) -> Result<String, error::Format> {
+        let mut buf = Vec::new();
+        iso8601::format_repeating_interval::<CONFIG>(&mut buf, self)?;
+        Ok(String::from_utf8_lossy(&buf).into_owned())
+    }
+
+    /// Format the `RepeatingInterval` using the provided [`Iso8601`] configuration, writing the
+    /// output to a [`fmt::Write`]. This does not require `std` or `alloc`.
+    pub fn format_into_fmt<const CONFIG: EncodedConfig>(
+        self,
+        output: &mut (impl fmt::Write + ?Sized),
+        _format: &Iso8601<CONFIG>,
+    ) -> Result<usize, error::FormatCore> {
+        iso8601::format_repeating_interval::<CONFIG>(&mut FmtOutput(output), self)
+    }
+}
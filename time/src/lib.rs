@@ -18,10 +18,16 @@
 //!
 //!   Enables macros that provide compile-time verification of values and intuitive syntax.
 //!
-//! - `formatting` (_implicitly enables `std`_)
+//! - `formatting` (_implicitly enables `std` and `formatting-core`_)
 //!
 //!   Enables formatting of most structs.
 //!
+//! - `formatting-core`
+//!
+//!   Enables formatting of most structs to a `core::fmt::Write`, a byte slice, or an inline buffer.
+//!   This does not require `std` or `alloc`. Errors from these methods are reported as
+//!   `error::FormatCore`.
+//!
 //! - `parsing`
 //!
 //!   Enables parsing of most structs.
@@ -84,15 +90,17 @@ mod date;
 mod duration;
 pub mod error;
 pub mod ext;
-#[cfg(any(feature = "formatting", feature = "parsing"))]
+#[cfg(any(feature = "formatting-core", feature = "parsing"))]
 pub mod format_description;
-#[cfg(feature = "formatting")]
+#[cfg(feature = "formatting-core")]
 pub mod formatting;
 mod hint;
 #[cfg(feature = "std")]
 mod instant;
 mod internal_macros;
 mod interop;
+#[cfg(any(feature = "formatting-core", feature = "parsing"))]
+pub mod interval;
 #[cfg(feature = "macros")]
 pub mod macros;
 mod month;
@@ -100,12 +108,14 @@ mod offset_date_time;
 #[cfg(feature = "parsing")]
 pub mod parsing;
 mod primitive_date_time;
+mod quarter;
 #[cfg(feature = "quickcheck")]
 mod quickcheck;
 #[cfg(feature = "rand")]
 mod rand;
 #[cfg(feature = "serde")]
 pub mod serde;
+mod serial_date_system;
 mod sys;
 #[cfg(test)]
 mod tests;
@@ -127,6 +137,8 @@ pub use crate::instant::Instant;
 pub use crate::month::Month;
 pub use crate::offset_date_time::OffsetDateTime;
 pub use crate::primitive_date_time::PrimitiveDateTime;
+pub use crate::quarter::Quarter;
+pub use crate::serial_date_system::SerialDateSystem;
 pub use crate::time::Time;
 pub use crate::utc_date_time::UtcDateTime;
 pub use crate::utc_offset::UtcOffset;
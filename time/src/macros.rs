@@ -56,6 +56,22 @@ pub use time_macros::datetime;
 ///
 /// The resulting expression can be used in `const` or `static` declarations, and implements
 /// the sealed traits required for both formatting and parsing.
+///
+/// A `round_trip` argument may be provided after the optional version. The format description
+/// is then [analyzed](crate::format_description::analysis::analyze) for the given
+/// [`Target`](crate::format_description::analysis::Target), and compilation fails if
+/// formatting a value and parsing the result may not succeed.
+///
+/// ```rust
+/// # use time::macros::format_description;
+/// let _ = format_description!(round_trip = Date, "[year]-[month]-[day]");
+/// ```
+///
+/// ```rust,compile_fail
+/// # use time::macros::format_description;
+/// // The day is missing, so a `Date` cannot be parsed.
+/// let _ = format_description!(round_trip = Date, "[year]-[month]");
+/// ```
 #[cfg_attr(feature = "alloc", doc = "```rust")]
 #[cfg_attr(not(feature = "alloc"), doc = "```rust,ignore")]
 /// # use time::{format_description, macros::format_description};
@@ -70,7 +86,7 @@ pub use time_macros::datetime;
 /// be found in [the book](https://time-rs.github.io/book/api/format-description.html).
 ///
 /// [`format_description::parse()`]: crate::format_description::parse()
-#[cfg(any(feature = "formatting", feature = "parsing"))]
+#[cfg(any(feature = "formatting-core", feature = "parsing"))]
 pub use time_macros::format_description;
 /// Construct a [`UtcOffset`](crate::UtcOffset) with a statically known value.
 ///
@@ -7,7 +7,7 @@ use core::str::FromStr;
 use powerfmt::smart_display::{FormatterOptions, Metadata, SmartDisplay};
 
 use self::Month::*;
-use crate::{error, util};
+use crate::{error, util, Quarter};
 
 /// Months of the year.
 #[repr(u8)]
@@ -75,6 +75,22 @@ impl Month {
         util::days_in_month(self, year)
     }
 
+    /// Get the quarter that contains the month.
+    ///
+    /// ```rust
+    /// # use time::{Month, Quarter};
+    /// assert_eq!(Month::February.quarter(), Quarter::First);
+    /// assert_eq!(Month::October.quarter(), Quarter::Fourth);
+    /// ```
+    pub const fn quarter(self) -> Quarter {
+        match self {
+            January | February | March => Quarter::First,
+            April | May | June => Quarter::Second,
+            July | August | September => Quarter::Third,
+            October | November | December => Quarter::Fourth,
+        }
+    }
+
     /// Get the previous month.
     ///
     /// ```rust
@@ -17,8 +17,12 @@ use powerfmt::smart_display::{self, FormatterOptions, Metadata, SmartDisplay};
 use time_core::convert::*;
 
 use crate::date::{MAX_YEAR, MIN_YEAR};
-#[cfg(feature = "formatting")]
-use crate::formatting::Formattable;
+#[cfg(feature = "formatting-core")]
+use crate::format_description::well_known::iso8601::{Config, EncodedConfig};
+#[cfg(feature = "formatting-core")]
+use crate::format_description::well_known::{Iso8601, Rfc2822, Rfc3339};
+#[cfg(feature = "formatting-core")]
+use crate::formatting::{self, FmtOutput, Formattable, InlineString, SliceOutput};
 use crate::internal_macros::{
     cascade, const_try, const_try_opt, div_floor, ensure_ranged, expect_opt,
 };
@@ -1268,21 +1272,17 @@ impl OffsetDateTime {
     }
 }
 
-#[cfg(feature = "formatting")]
+#[cfg(feature = "formatting-core")]
 impl OffsetDateTime {
     /// Format the `OffsetDateTime` using the provided [format
     /// description](crate::format_description).
+    #[cfg(feature = "formatting")]
     pub fn format_into(
         self,
         output: &mut (impl io::Write + ?Sized),
         format: &(impl Formattable + ?Sized),
     ) -> Result<usize, error::Format> {
-        format.format_into(
-            output,
-            Some(self.date()),
-            Some(self.time()),
-            Some(self.offset()),
-        )
+        formatting::format_value_into_io(self, output, format)
     }
 
     /// Format the `OffsetDateTime` using the provided [format
@@ -1301,8 +1301,113 @@ impl OffsetDateTime {
     /// );
     /// # Ok::<_, time::Error>(())
     /// ```
+    #[cfg(feature = "formatting")]
     pub fn format(self, format: &(impl Formattable + ?Sized)) -> Result<String, error::Format> {
-        format.format(Some(self.date()), Some(self.time()), Some(self.offset()))
+        formatting::format_value_to_string(self, format)
+    }
+
+    /// Format the `OffsetDateTime` using the provided [format
+    /// description](crate::format_description), writing the output to a [`fmt::Write`]. This does
+    /// not require `std` or `alloc`.
+    ///
+    /// As `fmt::Write` only accepts strings, an error is returned if the format description
+    /// contains a literal that is not valid UTF-8.
+    ///
+    /// ```rust
+    /// # use time::format_description::well_known::Rfc3339;
+    /// # use time_macros::datetime;
+    /// let mut output = String::new();
+    /// datetime!(2020-01-02 03:04:05 +06:07).format_into_fmt(&mut output, &Rfc3339)?;
+    /// assert_eq!(output, "2020-01-02T03:04:05+06:07");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_fmt(
+        self,
+        output: &mut (impl fmt::Write + ?Sized),
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::FormatCore> {
+        formatting::format_value(self, &mut FmtOutput(output), format)
+    }
+
+    /// Format the `OffsetDateTime` using the provided [format
+    /// description](crate::format_description), writing the output to the start of the provided
+    /// buffer. The number of bytes written is returned. This does not require `std` or `alloc`.
+    ///
+    /// If the buffer is too small, [`error::FormatCore::InsufficientBufferSize`] is returned. The
+    /// contents of the buffer are unspecified in this situation.
+    ///
+    /// ```rust
+    /// # use time::format_description::well_known::Rfc3339;
+    /// # use time_macros::datetime;
+    /// let mut buf = [0; 64];
+    /// let len = datetime!(2020-01-02 03:04:05 +06:07).format_to_slice(&mut buf, &Rfc3339)?;
+    /// assert_eq!(&buf[..len], b"2020-01-02T03:04:05+06:07");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_to_slice(
+        self,
+        output: &mut [u8],
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::FormatCore> {
+        formatting::format_value(self, &mut SliceOutput::new(output), format)
+    }
+
+    /// Format the `OffsetDateTime` using the well-known [RFC3339 format](Rfc3339), returning a
+    /// string that is stored inline rather than on the heap. This does not require `std` or
+    /// `alloc`.
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(2020-01-02 03:04:05.6 +06:07).format_rfc3339_inline()?,
+    ///     "2020-01-02T03:04:05.6+06:07"
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_rfc3339_inline(self) -> Result<InlineString<35>, error::FormatCore> {
+        InlineString::format(self, &Rfc3339)
+    }
+
+    /// Format the `OffsetDateTime` using the well-known [RFC2822 format](Rfc2822), returning a
+    /// string that is stored inline rather than on the heap. This does not require `std` or
+    /// `alloc`.
+    ///
+    /// ```rust
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(2020-01-02 03:04:05 +06:07).format_rfc2822_inline()?,
+    ///     "Thu, 02 Jan 2020 03:04:05 +0607"
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_rfc2822_inline(self) -> Result<InlineString<31>, error::FormatCore> {
+        InlineString::format(self, &Rfc2822)
+    }
+
+    /// Format the `OffsetDateTime` using the well-known [ISO 8601 format](Iso8601) with the
+    /// provided configuration, returning a string that is stored inline rather than on the heap.
+    /// This does not require `std` or `alloc`.
+    ///
+    /// The capacity is sufficient for any configuration, including those with the maximum number
+    /// of decimal digits.
+    ///
+    /// ```rust
+    /// # use time::format_description::well_known::iso8601;
+    /// # use time_macros::datetime;
+    /// const CONFIG: iso8601::EncodedConfig = iso8601::Config::DEFAULT
+    ///     .set_year_is_six_digits(true)
+    ///     .encode();
+    /// assert_eq!(
+    ///     datetime!(2020-01-02 03:04:05 +06:07).format_iso8601_inline::<CONFIG>()?,
+    ///     "+002020-01-02T03:04:05.000000000+06:07"
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_iso8601_inline<const CONFIG: EncodedConfig>(
+        self,
+    ) -> Result<InlineString<{ Config::MAX_FORMATTED_LEN }>, error::FormatCore> {
+        const { assert!(Iso8601::<CONFIG>::MAX_LEN <= Config::MAX_FORMATTED_LEN) };
+        InlineString::format(self, &Iso8601::<CONFIG>)
     }
 }
 
@@ -1331,6 +1436,53 @@ impl OffsetDateTime {
         description.parse_offset_date_time(input.as_bytes())
     }
 
+    /// Parse an `OffsetDateTime` from the start of the input using the provided [format
+    /// description](crate::format_description), returning it along with the remaining input.
+    ///
+    /// Unlike [`OffsetDateTime::parse`], it is not an error for input to remain after parsing. This
+    /// is useful when the value is followed by other data, such as in a log line.
+    ///
+    /// ```rust
+    /// # use time::OffsetDateTime;
+    /// # use time::format_description::well_known::Rfc3339;
+    /// # use time_macros::datetime;
+    /// let line = b"2020-01-02T03:04:05Z INFO server started";
+    /// let (timestamp, rest) = OffsetDateTime::parse_prefix(line, &Rfc3339)?;
+    /// assert_eq!(timestamp, datetime!(2020-01-02 03:04:05 UTC));
+    /// assert_eq!(rest, b" INFO server started");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_prefix<'a>(
+        input: &'a [u8],
+        description: &(impl Parsable + ?Sized),
+    ) -> Result<(Self, &'a [u8]), error::Parse> {
+        let (parsed, remaining) = description.parse_prefix(input)?;
+        Ok((parsed.try_into()?, remaining))
+    }
+
+    /// Parse an `OffsetDateTime` from the input using the provided [format
+    /// description](crate::format_description), returning a diagnostic that describes where and why
+    /// parsing failed if it does.
+    ///
+    /// See [`Parsed::parse_with_diagnostic`](crate::parsing::Parsed::parse_with_diagnostic) for
+    /// details.
+    ///
+    /// ```rust
+    /// # use time::OffsetDateTime;
+    /// # use time::format_description::well_known::Rfc3339;
+    /// let err =
+    ///     OffsetDateTime::parse_with_diagnostic("2020-01-02T03:04:05+25:00", &Rfc3339).unwrap_err();
+    /// assert_eq!(err.span(), Some(20..22));
+    /// ```
+    pub fn parse_with_diagnostic<'a>(
+        input: &str,
+        description: &'a (impl Parsable + ?Sized),
+    ) -> Result<Self, error::ParseDiagnostic<'a>> {
+        Ok(description
+            .parse_with_diagnostic(input.as_bytes())?
+            .try_into()?)
+    }
+
     /// A helper method to check if the `OffsetDateTime` is a valid representation of a leap second.
     /// Leap seconds, when parsed, are represented as the preceding nanosecond. However, leap
     /// seconds can only occur as the last second of a month UTC.
@@ -0,0 +1,130 @@
+//! Annotations as defined in [RFC 9557], borrowed from the input.
+//!
+//! [RFC 9557]: https://datatracker.ietf.org/doc/html/rfc9557
+
+use core::iter::FusedIterator;
+
+use crate::parsing::combinator::rfc::rfc9557::suffix_tag;
+use crate::parsing::ParsedItem;
+
+/// An extension annotation, such as `[u-ca=hebrew]`, as defined in [RFC 9557].
+///
+/// [RFC 9557]: https://datatracker.ietf.org/doc/html/rfc9557
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Annotation<'a> {
+    /// The key of the annotation, such as `u-ca`.
+    key: &'a str,
+    /// The value of the annotation, such as `hebrew`.
+    value: &'a str,
+    /// Whether the critical flag (`!`) was present.
+    is_critical: bool,
+}
+
+impl<'a> Annotation<'a> {
+    /// The key of the annotation, such as `u-ca`.
+    pub const fn key(self) -> &'a str {
+        self.key
+    }
+
+    /// The value of the annotation, such as `hebrew`.
+    pub const fn value(self) -> &'a str {
+        self.value
+    }
+
+    /// Whether the critical flag (`!`) was present.
+    ///
+    /// Critical annotations that cannot be acted on cause parsing to fail. As only the ISO 8601
+    /// calendar is supported, any critical annotation obtained from a successful parse requests
+    /// that calendar.
+    pub const fn is_critical(self) -> bool {
+        self.is_critical
+    }
+}
+
+/// The time zone annotation and extension annotations following a timestamp, as defined in
+/// [RFC 9557].
+///
+/// This struct is created by
+/// [`Parsed::parse_annotations`](crate::parsing::Parsed::parse_annotations)
+/// and [`Parsed::parse_rfc9557`](crate::parsing::Parsed::parse_rfc9557). The annotations are
+/// borrowed from the input rather than stored in [`Parsed`](crate::parsing::Parsed), so there is no
+/// limit on their length.
+///
+/// [RFC 9557]: https://datatracker.ietf.org/doc/html/rfc9557
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AnnotationSuffix<'a> {
+    /// The time zone name or numeric offset, along with whether the critical flag was present.
+    time_zone: Option<(&'a str, bool)>,
+    /// All extension annotations, in their bracketed form.
+    extensions: &'a [u8],
+}
+
+impl<'a> AnnotationSuffix<'a> {
+    /// Create a suffix from its already-validated parts.
+    pub(crate) const fn new(time_zone: Option<(&'a str, bool)>, extensions: &'a [u8]) -> Self {
+        Self {
+            time_zone,
+            extensions,
+        }
+    }
+
+    /// Obtain the time zone annotation.
+    ///
+    /// This is either a time zone name (such as `Europe/Paris`) or a numeric offset (such as
+    /// `+01:00`).
+    pub const fn time_zone_annotation(self) -> Option<&'a str> {
+        match self.time_zone {
+            Some((time_zone, _)) => Some(time_zone),
+            None => None,
+        }
+    }
+
+    /// Obtain whether the time zone annotation had the critical flag (`!`).
+    pub const fn time_zone_annotation_is_critical(self) -> Option<bool> {
+        match self.time_zone {
+            Some((_, is_critical)) => Some(is_critical),
+            None => None,
+        }
+    }
+
+    /// Obtain an iterator over the extension annotations (such as `[u-ca=hebrew]`), in the order
+    /// they appear in the input.
+    pub const fn annotations(self) -> Annotations<'a> {
+        Annotations {
+            remaining: self.extensions,
+        }
+    }
+
+    /// Obtain the value of the first extension annotation with the provided key.
+    pub fn annotation(self, key: &str) -> Option<&'a str> {
+        self.annotations()
+            .find(|annotation| annotation.key() == key)
+            .map(Annotation::value)
+    }
+}
+
+/// An iterator over the extension [`Annotation`]s of an [`AnnotationSuffix`].
+///
+/// This struct is created by the [`AnnotationSuffix::annotations`] method.
+#[derive(Debug, Clone)]
+pub struct Annotations<'a> {
+    /// The remaining annotations, in their bracketed form.
+    remaining: &'a [u8],
+}
+
+impl<'a> Iterator for Annotations<'a> {
+    type Item = Annotation<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ParsedItem(remaining, (is_critical, key, value)) = suffix_tag(self.remaining)?;
+        self.remaining = remaining;
+        // Both the key and value are guaranteed to be ASCII by the grammar.
+        Some(Annotation {
+            key: core::str::from_utf8(key).ok()?,
+            value: core::str::from_utf8(value).ok()?,
+            is_critical,
+        })
+    }
+}
+
+impl FusedIterator for Annotations<'_> {}
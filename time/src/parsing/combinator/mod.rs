@@ -157,6 +157,64 @@ pub(crate) fn n_to_m_digits_padded<'a, const N: u8, const M: u8, T: Integer>(
     }
 }
 
+/// Consume a number with the provided padding, where the number and any sign are padded to at
+/// least `width` characters. The width must exceed the natural width of `n` digits. Without any
+/// padding, the number may have up to `m` digits.
+///
+/// If `signed`, a sign may follow any spaces and precede any zeroes. A signed number that is
+/// missing its sign is always exactly the width, as a number that cannot fit has a sign.
+pub(crate) fn n_to_m_digits_padded_to_width<T: Integer>(
+    mut input: &[u8],
+    (n, m): (u8, u8),
+    padding: Padding,
+    width: u8,
+    signed: bool,
+) -> Option<ParsedItem<'_, (Option<u8>, T)>> {
+    debug_assert!(m >= n);
+    debug_assert!(width > n);
+
+    let mut spaces = 0;
+    if padding == Padding::Space {
+        while spaces < width - 1 {
+            match ascii_char::<b' '>(input) {
+                Some(parsed) => input = parsed.into_inner(),
+                None => break,
+            }
+            spaces += 1;
+        }
+    }
+    let ParsedItem(input, sign) = if signed {
+        opt(sign)(input)
+    } else {
+        ParsedItem(input, None)
+    };
+
+    let remaining_width = width - spaces - u8::from(sign.is_some());
+    let min = match padding {
+        Padding::Zero => remaining_width.max(n),
+        Padding::Space | Padding::None => remaining_width.max(1),
+    };
+    let max = if spaces != 0 || (signed && sign.is_none()) {
+        min
+    } else {
+        min.max(m)
+    };
+
+    let mut remaining = input;
+    for _ in 0..min {
+        remaining = any_digit(remaining)?.0;
+    }
+    for _ in min..max {
+        match any_digit(remaining) {
+            Some(parsed) => remaining = parsed.0,
+            None => break,
+        }
+    }
+
+    let value = T::parse_bytes(&input[..(input.len() - remaining.len())])?;
+    Some(ParsedItem(remaining, (sign, value)))
+}
+
 /// Consume exactly one digit.
 pub(crate) const fn any_digit(input: &[u8]) -> Option<ParsedItem<'_, u8>> {
     match input {
@@ -173,6 +173,6 @@ pub(crate) fn float(input: &[u8]) -> Option<ParsedItem<'_, (u8, Option<f64>)>> {
 }
 
 /// Parse a "decimal sign", which is either a comma or a period.
-fn decimal_sign(input: &[u8]) -> Option<ParsedItem<'_, ()>> {
+pub(crate) fn decimal_sign(input: &[u8]) -> Option<ParsedItem<'_, ()>> {
     ascii_char::<b'.'>(input).or_else(|| ascii_char::<b','>(input))
 }
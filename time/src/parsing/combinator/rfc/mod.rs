@@ -8,3 +8,5 @@
 pub(crate) mod iso8601;
 pub(crate) mod rfc2234;
 pub(crate) mod rfc2822;
+pub(crate) mod rfc9110;
+pub(crate) mod rfc9557;
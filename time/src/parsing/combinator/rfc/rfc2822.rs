@@ -3,7 +3,7 @@
 //! [RFC 2822]: https://datatracker.ietf.org/doc/html/rfc2822
 
 use crate::parsing::combinator::rfc::rfc2234::wsp;
-use crate::parsing::combinator::{ascii_char, one_or_more, zero_or_more};
+use crate::parsing::combinator::{ascii_char, n_to_m_digits, one_or_more, zero_or_more};
 use crate::parsing::ParsedItem;
 
 /// Consume the `fws` rule.
@@ -20,6 +20,21 @@ pub(crate) fn fws(mut input: &[u8]) -> Option<ParsedItem<'_, ()>> {
     }
 }
 
+/// Consume the digits of the `year` rule, including those of the `obs-year` rule, returning the
+/// interpreted year.
+///
+/// As specified in RFC 5322 §4.3, a two-digit year less than 50 has 2000 added to it, while any
+/// other two- or three-digit year has 1900 added to it.
+pub(crate) fn year(input: &[u8]) -> Option<ParsedItem<'_, i32>> {
+    let ParsedItem(remaining, year) = n_to_m_digits::<2, 4, u32>(input)?;
+    let year = match input.len() - remaining.len() {
+        2 if year < 50 => year + 2000,
+        2 | 3 => year + 1900,
+        _ => year,
+    };
+    Some(ParsedItem(remaining, year.cast_signed()))
+}
+
 /// Consume the `cfws` rule.
 // The full rule is equivalent to any combination of `fws` and `comment` so long as it is not empty.
 pub(crate) fn cfws(input: &[u8]) -> Option<ParsedItem<'_, ()>> {
@@ -0,0 +1,99 @@
+//! Rules defined in [RFC 9110].
+//!
+//! [RFC 9110]: https://datatracker.ietf.org/doc/html/rfc9110#section-5.6.7
+
+use core::num::NonZero;
+
+use crate::parsing::combinator::{ascii_char, exactly_n_digits, first_match};
+use crate::parsing::ParsedItem;
+use crate::{Month, Weekday};
+
+/// Consume the `day-name` rule.
+pub(crate) fn day_name(input: &[u8]) -> Option<ParsedItem<'_, Weekday>> {
+    first_match(
+        [
+            (b"Mon".as_slice(), Weekday::Monday),
+            (b"Tue".as_slice(), Weekday::Tuesday),
+            (b"Wed".as_slice(), Weekday::Wednesday),
+            (b"Thu".as_slice(), Weekday::Thursday),
+            (b"Fri".as_slice(), Weekday::Friday),
+            (b"Sat".as_slice(), Weekday::Saturday),
+            (b"Sun".as_slice(), Weekday::Sunday),
+        ],
+        true,
+    )(input)
+}
+
+/// Consume the `day-name-l` rule.
+pub(crate) fn day_name_l(input: &[u8]) -> Option<ParsedItem<'_, Weekday>> {
+    first_match(
+        [
+            (b"Monday".as_slice(), Weekday::Monday),
+            (b"Tuesday".as_slice(), Weekday::Tuesday),
+            (b"Wednesday".as_slice(), Weekday::Wednesday),
+            (b"Thursday".as_slice(), Weekday::Thursday),
+            (b"Friday".as_slice(), Weekday::Friday),
+            (b"Saturday".as_slice(), Weekday::Saturday),
+            (b"Sunday".as_slice(), Weekday::Sunday),
+        ],
+        true,
+    )(input)
+}
+
+/// Consume the `month` rule.
+pub(crate) fn month(input: &[u8]) -> Option<ParsedItem<'_, Month>> {
+    first_match(
+        [
+            (b"Jan".as_slice(), Month::January),
+            (b"Feb".as_slice(), Month::February),
+            (b"Mar".as_slice(), Month::March),
+            (b"Apr".as_slice(), Month::April),
+            (b"May".as_slice(), Month::May),
+            (b"Jun".as_slice(), Month::June),
+            (b"Jul".as_slice(), Month::July),
+            (b"Aug".as_slice(), Month::August),
+            (b"Sep".as_slice(), Month::September),
+            (b"Oct".as_slice(), Month::October),
+            (b"Nov".as_slice(), Month::November),
+            (b"Dec".as_slice(), Month::December),
+        ],
+        true,
+    )(input)
+}
+
+/// Consume the `time-of-day` rule, returning the hour, minute, and second.
+pub(crate) fn time_of_day(input: &[u8]) -> Option<ParsedItem<'_, (u8, u8, u8)>> {
+    let ParsedItem(input, hour) = exactly_n_digits::<2, u8>(input)?;
+    let input = ascii_char::<b':'>(input)?.into_inner();
+    let ParsedItem(input, minute) = exactly_n_digits::<2, u8>(input)?;
+    let input = ascii_char::<b':'>(input)?.into_inner();
+    let ParsedItem(input, second) = exactly_n_digits::<2, u8>(input)?;
+    Some(ParsedItem(input, (hour, minute, second)))
+}
+
+/// Consume the day of the month in the `date3` rule, which is either two digits or a space
+/// followed by one digit.
+pub(crate) fn asctime_day(input: &[u8]) -> Option<ParsedItem<'_, NonZero<u8>>> {
+    match input {
+        [b' ', digit @ b'1'..=b'9', rest @ ..] => {
+            Some(ParsedItem(rest, NonZero::new(digit - b'0')?))
+        }
+        _ => exactly_n_digits::<2, _>(input),
+    }
+}
+
+/// Resolve the two-digit year of the `rfc850-date` rule to a full year.
+///
+/// RFC 9110 requires that a year that appears to be more than 50 years in the future be
+/// interpreted as the most recent year in the past with the same last two digits. The current year
+/// is only known if the `std` feature is enabled. Otherwise, 2019 is used in its place, which
+/// places the year between 1970 and 2069 (inclusive).
+pub(crate) fn resolve_rfc850_year(last_two: u8) -> i32 {
+    #[cfg(feature = "std")]
+    let current_year = crate::OffsetDateTime::now_utc().year();
+    #[cfg(not(feature = "std"))]
+    let current_year: i32 = 2019;
+
+    let start = current_year - 49;
+    start + (i32::from(last_two) - start.rem_euclid(100)).rem_euclid(100)
+}
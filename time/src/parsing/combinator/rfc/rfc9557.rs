@@ -0,0 +1,131 @@
+//! Rules defined in [RFC 9557].
+//!
+//! [RFC 9557]: https://datatracker.ietf.org/doc/html/rfc9557
+
+use crate::parsing::combinator::{
+    ascii_char, exactly_n_digits, n_to_m, one_or_more, opt, sign, zero_or_more,
+};
+use crate::parsing::ParsedItem;
+
+/// Whether the critical flag was present, the key, and the value of a suffix tag.
+type SuffixTag<'a> = (bool, &'a [u8], &'a [u8]);
+
+/// Consume the `critical-flag` rule, returning whether the flag was present.
+fn critical_flag(input: &[u8]) -> ParsedItem<'_, bool> {
+    opt(ascii_char::<b'!'>)(input).map(|flag| flag.is_some())
+}
+
+/// Consume the `time-zone-initial` rule.
+const fn time_zone_initial(input: &[u8]) -> Option<ParsedItem<'_, ()>> {
+    match input {
+        [b'a'..=b'z' | b'A'..=b'Z' | b'.' | b'_', rest @ ..] => Some(ParsedItem(rest, ())),
+        _ => None,
+    }
+}
+
+/// Consume the `time-zone-char` rule.
+const fn time_zone_char(input: &[u8]) -> Option<ParsedItem<'_, ()>> {
+    match input {
+        [b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9' | b'.' | b'_' | b'-' | b'+', rest @ ..] => {
+            Some(ParsedItem(rest, ()))
+        }
+        _ => None,
+    }
+}
+
+/// Consume the `time-zone-part` rule.
+fn time_zone_part(input: &[u8]) -> Option<ParsedItem<'_, ()>> {
+    let remaining = time_zone_initial(input)?.into_inner();
+    let ParsedItem(remaining, _) = n_to_m::<0, 13, _, _>(time_zone_char)(remaining)?;
+    // The rule explicitly excludes the relative path components.
+    match &input[..input.len() - remaining.len()] {
+        b"." | b".." => None,
+        _ => Some(ParsedItem(remaining, ())),
+    }
+}
+
+/// Consume the `time-zone-name` rule, returning the name.
+fn time_zone_name(input: &[u8]) -> Option<ParsedItem<'_, &[u8]>> {
+    let mut remaining = time_zone_part(input)?.into_inner();
+    while let Some(rest) = ascii_char::<b'/'>(remaining) {
+        remaining = time_zone_part(rest.into_inner())?.into_inner();
+    }
+    Some(ParsedItem(
+        remaining,
+        &input[..input.len() - remaining.len()],
+    ))
+}
+
+/// Consume the `time-numoffset` rule, returning the offset as written.
+fn time_numoffset(input: &[u8]) -> Option<ParsedItem<'_, &[u8]>> {
+    let remaining = sign(input)?.0;
+    let remaining = exactly_n_digits::<2, u8>(remaining)?
+        .filter(|&hour| hour <= 23)?
+        .0;
+    let remaining = ascii_char::<b':'>(remaining)?.into_inner();
+    let remaining = exactly_n_digits::<2, u8>(remaining)?
+        .filter(|&minute| minute <= 59)?
+        .0;
+    Some(ParsedItem(
+        remaining,
+        &input[..input.len() - remaining.len()],
+    ))
+}
+
+/// Consume the `time-zone` rule, returning whether the critical flag was present and the time zone
+/// name or numeric offset.
+pub(crate) fn time_zone(input: &[u8]) -> Option<ParsedItem<'_, (bool, &[u8])>> {
+    let input = ascii_char::<b'['>(input)?.into_inner();
+    let ParsedItem(input, is_critical) = critical_flag(input);
+    let ParsedItem(input, name) = time_numoffset(input).or_else(|| time_zone_name(input))?;
+    let input = ascii_char::<b']'>(input)?.into_inner();
+    Some(ParsedItem(input, (is_critical, name)))
+}
+
+/// Consume the `suffix-key` rule.
+fn suffix_key(input: &[u8]) -> Option<ParsedItem<'_, &[u8]>> {
+    let [b'a'..=b'z' | b'_', remaining @ ..] = input else {
+        return None;
+    };
+    let remaining = zero_or_more(|input| match input {
+        [b'a'..=b'z' | b'0'..=b'9' | b'_' | b'-', rest @ ..] => Some(ParsedItem(rest, ())),
+        _ => None,
+    })(remaining)
+    .into_inner();
+    Some(ParsedItem(
+        remaining,
+        &input[..input.len() - remaining.len()],
+    ))
+}
+
+/// Consume the `suffix-values` rule, returning the full value.
+fn suffix_values(input: &[u8]) -> Option<ParsedItem<'_, &[u8]>> {
+    let suffix_value = one_or_more(|input| match input {
+        [b'a'..=b'z' | b'A'..=b'Z' | b'0'..=b'9', rest @ ..] => Some(ParsedItem(rest, ())),
+        _ => None,
+    });
+
+    let mut remaining = suffix_value(input)?.into_inner();
+    while let [b'-', rest @ ..] = remaining {
+        match suffix_value(rest) {
+            Some(item) => remaining = item.into_inner(),
+            None => break,
+        }
+    }
+    Some(ParsedItem(
+        remaining,
+        &input[..input.len() - remaining.len()],
+    ))
+}
+
+/// Consume the `suffix-tag` rule, returning whether the critical flag was present, the key, and the
+/// value.
+pub(crate) fn suffix_tag(input: &[u8]) -> Option<ParsedItem<'_, SuffixTag<'_>>> {
+    let input = ascii_char::<b'['>(input)?.into_inner();
+    let ParsedItem(input, is_critical) = critical_flag(input);
+    let ParsedItem(input, key) = suffix_key(input)?;
+    let input = ascii_char::<b'='>(input)?.into_inner();
+    let ParsedItem(input, value) = suffix_values(input)?;
+    let input = ascii_char::<b']'>(input)?.into_inner();
+    Some(ParsedItem(input, (is_critical, key, value)))
+}
@@ -6,19 +6,36 @@ use num_conv::prelude::*;
 
 use crate::convert::*;
 use crate::format_description::modifier;
+use crate::parsing::combinator::rfc::rfc9557;
 use crate::parsing::combinator::{
-    any_digit, exactly_n_digits, exactly_n_digits_padded, first_match, n_to_m_digits,
-    n_to_m_digits_padded, opt, sign,
+    any_digit, ascii_char, exactly_n_digits, exactly_n_digits_padded, first_match, n_to_m_digits,
+    n_to_m_digits_padded, n_to_m_digits_padded_to_width, opt, sign,
 };
+use crate::parsing::shim::Integer;
 use crate::parsing::ParsedItem;
-use crate::{Month, Weekday};
+use crate::{Date, Month, Quarter, Weekday};
 
 /// Parse the "year" component of a `Date`.
 pub(crate) fn parse_year(
     input: &[u8],
     modifiers: modifier::Year,
 ) -> Option<ParsedItem<'_, (i32, bool)>> {
+    if let Some(parsed) = parse_year_to_width(input, modifiers) {
+        return parsed;
+    }
+
     match modifiers.repr {
+        // The year of the era is never negative, so there is no sign.
+        modifier::YearRepr::Full if modifiers.era_based && !modifiers.iso_week_based => {
+            let ParsedItem(input, year) = if cfg!(feature = "large-dates")
+                && modifiers.range == modifier::YearRange::Extended
+            {
+                n_to_m_digits_padded::<4, 7, u32>(modifiers.padding)(input)?
+            } else {
+                exactly_n_digits_padded::<4, u32>(modifiers.padding)(input)?
+            };
+            Some(ParsedItem(input, (year.cast_signed(), false)))
+        }
         modifier::YearRepr::Full => {
             let ParsedItem(input, sign) = opt(sign)(input);
 
@@ -76,17 +93,81 @@ pub(crate) fn parse_year(
     }
 }
 
-/// Parse the "month" component of a `Date`.
+/// Parse the "year" component of a `Date` when it is padded to a width exceeding its natural width.
+/// The outer `None` indicates that the year is not padded to such a width.
+fn parse_year_to_width(
+    input: &[u8],
+    modifiers: modifier::Year,
+) -> Option<Option<ParsedItem<'_, (i32, bool)>>> {
+    let extended =
+        cfg!(feature = "large-dates") && modifiers.range == modifier::YearRange::Extended;
+    let (digits, signed) = match modifiers.repr {
+        modifier::YearRepr::Full if modifiers.era_based && !modifiers.iso_week_based => {
+            ((4, if extended { 7 } else { 4 }), false)
+        }
+        modifier::YearRepr::Full => ((4, if extended { 6 } else { 4 }), true),
+        modifier::YearRepr::Century => ((2, if extended { 4 } else { 2 }), true),
+        modifier::YearRepr::LastTwo => ((2, 2), false),
+    };
+    if modifiers.width <= digits.0 || modifiers.padding == modifier::Padding::None {
+        return None;
+    }
+
+    let Some(ParsedItem(input, (sign, year))) = n_to_m_digits_padded_to_width::<u32>(
+        input,
+        digits,
+        modifiers.padding,
+        modifiers.width,
+        signed,
+    ) else {
+        return Some(None);
+    };
+    if signed && sign.is_none() && modifiers.sign_is_mandatory {
+        return Some(None);
+    }
+
+    Some(Some(if sign == Some(b'-') {
+        ParsedItem(input, (-year.cast_signed(), true))
+    } else {
+        ParsedItem(input, (year.cast_signed(), false))
+    }))
+}
+
+/// Parse a number padded to the width in the modifiers, falling back to the natural width `N` when
+/// the width does not exceed it.
+fn parse_padded<const N: u8, T: Integer>(
+    input: &[u8],
+    padding: modifier::Padding,
+    width: u8,
+) -> Option<ParsedItem<'_, T>> {
+    if width > N && padding != modifier::Padding::None {
+        n_to_m_digits_padded_to_width::<T>(input, (N, N), padding, width, false)
+            .map(|parsed| parsed.map(|(_, value)| value))
+    } else {
+        exactly_n_digits_padded::<N, T>(padding)(input)
+    }
+}
+
+/// Parse the "month" component of a `Date`. The parsed value is `None` if the representation does
+/// not uniquely identify a month.
 pub(crate) fn parse_month(
     input: &[u8],
     modifiers: modifier::Month,
-) -> Option<ParsedItem<'_, Month>> {
+) -> Option<ParsedItem<'_, Option<Month>>> {
     use Month::*;
-    let ParsedItem(remaining, value) = first_match(
+    let ParsedItem(remaining, value) = first_match_in_case(
         match modifiers.repr {
             modifier::MonthRepr::Numerical => {
-                return exactly_n_digits_padded::<2, _>(modifiers.padding)(input)?
-                    .flat_map(|n| Month::from_number(n).ok());
+                return parse_padded::<2, _>(input, modifiers.padding, modifiers.width)?
+                    .flat_map(|n| Month::from_number(n).ok().map(Some));
+            }
+            modifier::MonthRepr::Narrow => {
+                return first_match_in_case(
+                    [b"J", b"F", b"M", b"A", b"S", b"O", b"N", b"D"]
+                        .map(|letter| (letter.as_slice(), None)),
+                    modifiers.case,
+                    modifiers.case_sensitive,
+                )(input);
             }
             modifier::MonthRepr::Long => [
                 (b"January".as_slice(), January),
@@ -117,9 +198,10 @@ pub(crate) fn parse_month(
                 (b"Dec".as_slice(), December),
             ],
         },
+        modifiers.case,
         modifiers.case_sensitive,
     )(input)?;
-    Some(ParsedItem(remaining, value))
+    Some(ParsedItem(remaining, Some(value)))
 }
 
 /// Parse the "week number" component of a `Date`.
@@ -127,16 +209,95 @@ pub(crate) fn parse_week_number(
     input: &[u8],
     modifiers: modifier::WeekNumber,
 ) -> Option<ParsedItem<'_, u8>> {
-    exactly_n_digits_padded::<2, _>(modifiers.padding)(input)
+    parse_padded::<2, _>(input, modifiers.padding, modifiers.width)
+}
+
+/// Parse the "week of month" component of a `Date`.
+pub(crate) fn parse_week_of_month(
+    input: &[u8],
+    _modifiers: modifier::WeekOfMonth,
+) -> Option<ParsedItem<'_, u8>> {
+    exactly_n_digits::<1, _>(input)
+}
+
+/// Parse the "weekday in month" component of a `Date`.
+pub(crate) fn parse_weekday_in_month(
+    input: &[u8],
+    _modifiers: modifier::WeekdayInMonth,
+) -> Option<ParsedItem<'_, NonZero<u8>>> {
+    exactly_n_digits::<1, _>(input)
+}
+
+/// Parse the "quarter" component of a `Date`.
+pub(crate) fn parse_quarter(
+    input: &[u8],
+    modifiers: modifier::Quarter,
+) -> Option<ParsedItem<'_, Quarter>> {
+    match modifiers.repr {
+        modifier::QuarterRepr::Numerical => {
+            exactly_n_digits::<1, _>(input)?.flat_map(|n| Quarter::from_number(n).ok())
+        }
+        modifier::QuarterRepr::Short => first_match(
+            [
+                (b"Q1".as_slice(), Quarter::First),
+                (b"Q2".as_slice(), Quarter::Second),
+                (b"Q3".as_slice(), Quarter::Third),
+                (b"Q4".as_slice(), Quarter::Fourth),
+            ],
+            modifiers.case_sensitive,
+        )(input),
+    }
+}
+
+/// Indicate whether the year is before or during the common era.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Era {
+    #[allow(clippy::missing_docs_in_private_items)]
+    Bce,
+    #[allow(clippy::missing_docs_in_private_items)]
+    Ce,
 }
 
-/// Parse the "weekday" component of a `Date`.
+/// Parse the "era" component of a `Date`.
+pub(crate) fn parse_era(input: &[u8], modifiers: modifier::Era) -> Option<ParsedItem<'_, Era>> {
+    match modifiers.repr {
+        modifier::EraRepr::Short => first_match(
+            [(b"BC".as_slice(), Era::Bce), (b"AD".as_slice(), Era::Ce)],
+            modifiers.case_sensitive,
+        )(input),
+        modifier::EraRepr::Common => first_match(
+            [(b"BCE".as_slice(), Era::Bce), (b"CE".as_slice(), Era::Ce)],
+            modifiers.case_sensitive,
+        )(input),
+        modifier::EraRepr::Long => first_match(
+            [
+                (b"Before Christ".as_slice(), Era::Bce),
+                (b"Anno Domini".as_slice(), Era::Ce),
+            ],
+            modifiers.case_sensitive,
+        )(input),
+        modifier::EraRepr::Narrow => first_match(
+            [(b"B".as_slice(), Era::Bce), (b"A".as_slice(), Era::Ce)],
+            modifiers.case_sensitive,
+        )(input),
+    }
+}
+
+/// Parse the "weekday" component of a `Date`. The parsed value is `None` if the representation
+/// does not uniquely identify a weekday.
 pub(crate) fn parse_weekday(
     input: &[u8],
     modifiers: modifier::Weekday,
-) -> Option<ParsedItem<'_, Weekday>> {
-    first_match(
+) -> Option<ParsedItem<'_, Option<Weekday>>> {
+    first_match_in_case(
         match (modifiers.repr, modifiers.one_indexed) {
+            (modifier::WeekdayRepr::Narrow, _) => {
+                return first_match_in_case(
+                    [b"M", b"T", b"W", b"F", b"S"].map(|letter| (letter.as_slice(), None)),
+                    modifiers.case,
+                    modifiers.case_sensitive,
+                )(input);
+            }
             (modifier::WeekdayRepr::Short, _) => [
                 (b"Mon".as_slice(), Weekday::Monday),
                 (b"Tue".as_slice(), Weekday::Tuesday),
@@ -192,8 +353,35 @@ pub(crate) fn parse_weekday(
                 (b"7".as_slice(), Weekday::Sunday),
             ],
         },
+        modifiers.case,
         modifiers.case_sensitive,
     )(input)
+    .map(|parsed| parsed.map(Some))
+}
+
+/// Consume the first matching textual value. The options are provided in title case. If parsing
+/// is case-sensitive, the input must be in the requested case.
+fn first_match_in_case<'a, T>(
+    options: impl IntoIterator<Item = (&'a [u8], T)>,
+    case: modifier::TextCase,
+    case_sensitive: bool,
+) -> impl FnMut(&'a [u8]) -> Option<ParsedItem<'a, T>> {
+    let mut first_match = first_match(options, case_sensitive && case == modifier::TextCase::Title);
+    move |input| {
+        let ParsedItem(remaining, value) = first_match(input)?;
+        if case_sensitive {
+            let matched = &input[..input.len() - remaining.len()];
+            let is_in_case = match case {
+                modifier::TextCase::Title => true,
+                modifier::TextCase::Upper => !matched.iter().any(u8::is_ascii_lowercase),
+                modifier::TextCase::Lower => !matched.iter().any(u8::is_ascii_uppercase),
+            };
+            if !is_in_case {
+                return None;
+            }
+        }
+        Some(ParsedItem(remaining, value))
+    }
 }
 
 /// Parse the "ordinal" component of a `Date`.
@@ -201,7 +389,7 @@ pub(crate) fn parse_ordinal(
     input: &[u8],
     modifiers: modifier::Ordinal,
 ) -> Option<ParsedItem<'_, NonZero<u16>>> {
-    exactly_n_digits_padded::<3, _>(modifiers.padding)(input)
+    parse_padded::<3, _>(input, modifiers.padding, modifiers.width)
 }
 
 /// Parse the "day" component of a `Date`.
@@ -209,7 +397,7 @@ pub(crate) fn parse_day(
     input: &[u8],
     modifiers: modifier::Day,
 ) -> Option<ParsedItem<'_, NonZero<u8>>> {
-    exactly_n_digits_padded::<2, _>(modifiers.padding)(input)
+    parse_padded::<2, _>(input, modifiers.padding, modifiers.width)
 }
 
 /// Indicate whether the hour is "am" or "pm".
@@ -223,7 +411,7 @@ pub(crate) enum Period {
 
 /// Parse the "hour" component of a `Time`.
 pub(crate) fn parse_hour(input: &[u8], modifiers: modifier::Hour) -> Option<ParsedItem<'_, u8>> {
-    exactly_n_digits_padded::<2, _>(modifiers.padding)(input)
+    parse_padded::<2, _>(input, modifiers.padding, modifiers.width)
 }
 
 /// Parse the "minute" component of a `Time`.
@@ -231,7 +419,7 @@ pub(crate) fn parse_minute(
     input: &[u8],
     modifiers: modifier::Minute,
 ) -> Option<ParsedItem<'_, u8>> {
-    exactly_n_digits_padded::<2, _>(modifiers.padding)(input)
+    parse_padded::<2, _>(input, modifiers.padding, modifiers.width)
 }
 
 /// Parse the "second" component of a `Time`.
@@ -239,7 +427,7 @@ pub(crate) fn parse_second(
     input: &[u8],
     modifiers: modifier::Second,
 ) -> Option<ParsedItem<'_, u8>> {
-    exactly_n_digits_padded::<2, _>(modifiers.padding)(input)
+    parse_padded::<2, _>(input, modifiers.padding, modifiers.width)
 }
 
 /// Parse the "period" component of a `Time`. Required if the hour is on a 12-hour clock.
@@ -247,18 +435,23 @@ pub(crate) fn parse_period(
     input: &[u8],
     modifiers: modifier::Period,
 ) -> Option<ParsedItem<'_, Period>> {
+    let options = if modifiers.is_uppercase {
+        [
+            (b"AM".as_slice(), Period::Am),
+            (b"PM".as_slice(), Period::Pm),
+        ]
+    } else {
+        [
+            (b"am".as_slice(), Period::Am),
+            (b"pm".as_slice(), Period::Pm),
+        ]
+    };
+    let len = match modifiers.repr {
+        modifier::PeriodRepr::Short => 2,
+        modifier::PeriodRepr::Narrow => 1,
+    };
     first_match(
-        if modifiers.is_uppercase {
-            [
-                (b"AM".as_slice(), Period::Am),
-                (b"PM".as_slice(), Period::Pm),
-            ]
-        } else {
-            [
-                (b"am".as_slice(), Period::Am),
-                (b"pm".as_slice(), Period::Pm),
-            ]
-        },
+        options.map(|(text, period)| (&text[..len], period)),
         modifiers.case_sensitive,
     )(input)
 }
@@ -367,6 +560,69 @@ pub(crate) fn parse_unix_timestamp(
     }
 }
 
+/// Parse the Julian day component without a fraction of the day, returning the Julian day.
+pub(crate) fn parse_julian_day(
+    input: &[u8],
+    modifiers: modifier::JulianDay,
+) -> Option<ParsedItem<'_, i32>> {
+    let ParsedItem(input, is_negative) = opt(ascii_char::<b'-'>)(input);
+    let ParsedItem(input, days) = n_to_m_digits::<1, 9, u32>(input)?;
+    let days = if is_negative.is_some() {
+        -days.cast_signed()
+    } else {
+        days.cast_signed()
+    };
+
+    Some(ParsedItem(
+        input,
+        match modifiers.repr {
+            modifier::JulianDayRepr::Julian => days,
+            modifier::JulianDayRepr::Modified => days + crate::date::MODIFIED_JULIAN_DAY_OFFSET,
+        },
+    ))
+}
+
+/// Parse the Julian day component with a fraction of the day, returning the moment as the number
+/// of nanoseconds since the Unix epoch.
+pub(crate) fn parse_julian_date(
+    input: &[u8],
+    modifiers: modifier::JulianDay,
+) -> Option<ParsedItem<'_, i128>> {
+    let ParsedItem(input, is_negative) = opt(ascii_char::<b'-'>)(input);
+    let ParsedItem(input, days) = n_to_m_digits::<1, 9, u128>(input)?;
+    let mut input = ascii_char::<b'.'>(input)?.into_inner();
+    let digits = modifiers.digits.min(14);
+    let mut fraction = 0_u128;
+    for _ in 0..digits {
+        let ParsedItem(remaining, digit) = any_digit(input)?;
+        fraction = fraction * 10 + (digit - b'0').extend::<u128>();
+        input = remaining;
+    }
+
+    // Use the earliest nanosecond that has the parsed fraction when truncated.
+    let nanos_per_day = Nanosecond::per_t::<u128>(Day);
+    let nanos =
+        days * nanos_per_day + (fraction * nanos_per_day).div_ceil(10_u128.pow(digits.extend()));
+    let nanos = if is_negative.is_some() {
+        -nanos.cast_signed()
+    } else {
+        nanos.cast_signed()
+    };
+
+    let nanos_per_day = nanos_per_day.cast_signed();
+    let epoch = match modifiers.repr {
+        // The Julian day begins at noon.
+        modifier::JulianDayRepr::Julian => {
+            Date::UNIX_EPOCH.to_julian_day().extend::<i128>() * nanos_per_day - nanos_per_day / 2
+        }
+        modifier::JulianDayRepr::Modified => {
+            Date::UNIX_EPOCH.to_modified_julian_day().extend::<i128>() * nanos_per_day
+        }
+    };
+
+    Some(ParsedItem(input, nanos - epoch))
+}
+
 /// Parse the `end` component, which represents the end of input. If any input is remaining, `None`
 /// is returned.
 pub(crate) const fn parse_end(input: &[u8], end: modifier::End) -> Option<ParsedItem<'_, ()>> {
@@ -378,3 +634,13 @@ pub(crate) const fn parse_end(input: &[u8], end: modifier::End) -> Option<Parsed
         None
     }
 }
+
+/// Parse the `time_zone_annotation` component, returning whether the critical flag was present and
+/// the time zone name or numeric offset.
+pub(crate) fn parse_time_zone_annotation(
+    input: &[u8],
+    modifiers: modifier::TimeZoneAnnotation,
+) -> Option<ParsedItem<'_, (bool, &[u8])>> {
+    let modifier::TimeZoneAnnotation {} = modifiers;
+    rfc9557::time_zone(input)
+}
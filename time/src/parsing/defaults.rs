@@ -0,0 +1,232 @@
+//! Default values for components that are missing from a [`Parsed`] struct.
+
+use core::num::NonZero;
+
+use num_conv::prelude::*;
+
+use crate::date::{MAX_YEAR, MIN_YEAR};
+use crate::format_description::modifier;
+use crate::internal_macros::expect_opt;
+use crate::parsing::Parsed;
+use crate::{error, Date, Time, UtcOffset};
+
+/// Default values used to fill in components that are missing from a [`Parsed`] struct before
+/// converting it to another type.
+///
+/// Each default is only used when the corresponding information is entirely absent. Components
+/// that are present are never overridden, and a value that is only partially present (such as an
+/// hour without a minute) is left for the conversion to accept or reject.
+///
+/// ```rust
+/// # use time::parsing::{Defaults, Parsed};
+/// # use time::{OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+/// # use time_macros::{datetime, format_description};
+/// // Syslog timestamps omit both the year and the UTC offset.
+/// let format = format_description!("[month repr:short] [day padding:space] [hour]:[minute]:[second]");
+/// let mut parsed = Parsed::new();
+/// parsed.parse_items(b"Mar  3 17:00:00", format)?;
+///
+/// let defaults = Defaults::NONE.set_year(2024).set_offset(UtcOffset::UTC);
+/// assert_eq!(
+///     defaults.resolve::<OffsetDateTime>(parsed)?,
+///     datetime!(2024-03-03 17:00 UTC)
+/// );
+///
+/// // Date-only input is placed at midnight.
+/// let mut parsed = Parsed::new();
+/// parsed.parse_items(b"2024-03-03", format_description!("[year]-[month]-[day]"))?;
+/// assert_eq!(
+///     Defaults::NONE
+///         .set_time(Time::MIDNIGHT)
+///         .resolve::<PrimitiveDateTime>(parsed)?,
+///     datetime!(2024-03-03 0:00)
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Defaults {
+    /// The date used when no date components are present.
+    date: Option<Date>,
+    /// The year used when the date is present but the year is not.
+    year: Option<i32>,
+    /// The time used when no time components are present.
+    time: Option<Time>,
+    /// The UTC offset used when no offset components are present.
+    offset: Option<UtcOffset>,
+}
+
+impl Defaults {
+    /// No defaults. Resolving with this is equivalent to converting with [`TryFrom`].
+    pub const NONE: Self = Self {
+        date: None,
+        year: None,
+        time: None,
+        offset: None,
+    };
+
+    /// Set the date used when no date components are present, such as for input that only
+    /// contains a time.
+    ///
+    /// If the year is missing but other date components are present, the year of this date is used
+    /// unless [`Defaults::set_year`] has been called.
+    pub const fn set_date(self, date: Date) -> Self {
+        Self {
+            date: Some(date),
+            ..self
+        }
+    }
+
+    /// Set the year used when other date components are present but the year is not, such as for
+    /// `Mar 3`.
+    ///
+    /// The year must be in the range permitted by [`Date`]; otherwise resolution fails.
+    pub const fn set_year(self, year: i32) -> Self {
+        Self {
+            year: Some(year),
+            ..self
+        }
+    }
+
+    /// Set the time used when no time components are present, such as for input that only contains
+    /// a date.
+    pub const fn set_time(self, time: Time) -> Self {
+        Self {
+            time: Some(time),
+            ..self
+        }
+    }
+
+    /// Set the UTC offset used when no offset components are present.
+    pub const fn set_offset(self, offset: UtcOffset) -> Self {
+        Self {
+            offset: Some(offset),
+            ..self
+        }
+    }
+
+    /// Fill in any missing components of the [`Parsed`] struct with the configured defaults.
+    pub fn apply(&self, mut parsed: Parsed) -> Result<Parsed, error::TryFromParsed> {
+        // A Unix timestamp contains all information needed on its own.
+        if parsed.unix_timestamp_nanos().is_some() {
+            return Ok(parsed);
+        }
+
+        // A sliding pivot is resolved relative to the default year when there is one, rather than
+        // the current year.
+        if let (modifier::YearPivot::SLIDING, Some(reference_year)) = (
+            parsed.year_pivot(),
+            self.year.or_else(|| self.date.map(Date::year)),
+        ) {
+            if let (None, None, Some(last_two)) =
+                (parsed.year(), parsed.year_century(), parsed.year_last_two())
+            {
+                if let Some(year) = parsed
+                    .year_pivot()
+                    .resolve_with_reference(last_two, reference_year)
+                {
+                    let _ = parsed.set_year(year);
+                }
+            }
+            if let (None, None, Some(last_two)) = (
+                parsed.iso_year(),
+                parsed.iso_year_century(),
+                parsed.iso_year_last_two(),
+            ) {
+                if let Some(iso_year) = parsed
+                    .year_pivot()
+                    .resolve_with_reference(last_two, reference_year)
+                {
+                    let _ = parsed.set_iso_year(iso_year);
+                }
+            }
+        }
+
+        let has_year = parsed.year().is_some()
+            || parsed.year_century().is_some()
+            || parsed.year_last_two().is_some()
+            || parsed.year_of_era().is_some()
+            || parsed.julian_day().is_some()
+            || parsed.iso_year().is_some()
+            || parsed.iso_year_century().is_some()
+            || parsed.iso_year_last_two().is_some();
+        let has_date = has_year
+            || parsed.month().is_some()
+            || parsed.quarter().is_some()
+            || parsed.day().is_some()
+            || parsed.ordinal().is_some()
+            || parsed.weekday().is_some()
+            || parsed.iso_week_number().is_some()
+            || parsed.sunday_week_number().is_some()
+            || parsed.monday_week_number().is_some()
+            || parsed.week_of_month().is_some()
+            || parsed.weekday_in_month().is_some()
+            || parsed.weekday_in_month_from_end().is_some();
+
+        if let (false, Some(date)) = (has_date, self.date) {
+            let (year, month, day) = date.to_calendar_date();
+            let _ = parsed.set_year(year);
+            let _ = parsed.set_month(month);
+            let _ = parsed.set_day(expect_opt!(NonZero::new(day), "day is never zero"));
+        } else if let (false, Some(year)) =
+            (has_year, self.year.or_else(|| self.date.map(Date::year)))
+        {
+            if !(MIN_YEAR..=MAX_YEAR).contains(&year) {
+                return Err(error::TryFromParsed::ComponentRange(
+                    error::ComponentRange {
+                        name: "year",
+                        minimum: MIN_YEAR.extend(),
+                        maximum: MAX_YEAR.extend(),
+                        value: year.extend(),
+                        conditional_message: None,
+                    },
+                ));
+            }
+            if parsed.iso_week_number().is_some() {
+                let _ = parsed.set_iso_year(year);
+            } else {
+                let _ = parsed.set_year(year);
+            }
+        }
+
+        let has_time = parsed.hour_24().is_some()
+            || parsed.hour_12().is_some()
+            || parsed.hour_12_is_pm().is_some()
+            || parsed.minute().is_some()
+            || parsed.second().is_some()
+            || parsed.subsecond().is_some();
+        if let (false, Some(time)) = (has_time, self.time) {
+            let (hour, minute, second, nanosecond) = time.as_hms_nano();
+            let _ = parsed.set_hour_24(hour);
+            let _ = parsed.set_minute(minute);
+            let _ = parsed.set_second(second);
+            let _ = parsed.set_subsecond(nanosecond);
+        }
+
+        let has_offset = parsed.offset_hour().is_some()
+            || parsed.offset_minute_signed().is_some()
+            || parsed.offset_second_signed().is_some();
+        if let (false, Some(offset)) = (has_offset, self.offset) {
+            let (hours, minutes, seconds) = offset.as_hms();
+            let _ = parsed.set_offset_hour(hours);
+            let _ = parsed.set_offset_minute_signed(minutes);
+            let _ = parsed.set_offset_second_signed(seconds);
+        }
+
+        Ok(parsed)
+    }
+
+    /// Convert the [`Parsed`] struct to another type, first filling in any missing components with
+    /// the configured defaults.
+    pub fn resolve<T>(&self, parsed: Parsed) -> Result<T, error::TryFromParsed>
+    where
+        T: TryFrom<Parsed, Error = error::TryFromParsed>,
+    {
+        self.apply(parsed)?.try_into()
+    }
+}
+
+impl Default for Defaults {
+    fn default() -> Self {
+        Self::NONE
+    }
+}
@@ -0,0 +1,754 @@
+//! A lenient parser that detects the layout of its input.
+
+use core::num::NonZero;
+
+use num_conv::prelude::*;
+
+use crate::error::ParseFromDescription::{AmbiguousComponent, InvalidComponent, InvalidLiteral};
+use crate::format_description::modifier::YearPivot;
+use crate::parsing::combinator::{ascii_char, exactly_n_digits, n_to_m_digits, sign};
+use crate::parsing::{Parsed, ParsedItem};
+use crate::{error, Date, Month, Weekday};
+
+/// The order of the day and month in a date written using only numbers, such as `03/04/2024`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DateOrder {
+    /// The day precedes the month, as in `31/12/2024`.
+    DayFirst,
+    /// The month precedes the day, as in `12/31/2024`.
+    MonthFirst,
+}
+
+/// A lenient parser that detects the layout of its input.
+///
+/// This is intended for input whose layout is not known in advance, such as dates entered by hand
+/// or exported from a spreadsheet. When the layout is known, a format description should be
+/// preferred, as it is both stricter and faster.
+///
+/// The following are recognized, in any reasonable combination and separated by whitespace or
+/// commas:
+///
+/// - numeric dates, such as `2024-03-03`, `2024/03/03`, `03/03/2024`, and `3.3.24`
+/// - dates containing the name of the month, such as `March 3, 2024`, `3 Mar 24`, `3-Mar-2024`, and
+///   `Mar 3rd 2024`
+/// - compact dates and date-times, such as `20240303` and `20240303T170000Z`
+/// - the name of the weekday, which must agree with the date
+/// - times, such as `17:00`, `5:00:00.25 PM`, and `5pm`
+/// - UTC offsets, such as `Z`, `UTC`, `GMT`, `+01:00`, and `-0500`
+///
+/// Names are matched without regard to case, and may be written in full or abbreviated to three
+/// letters.
+///
+/// When the year is written using two digits, years 69 through 99 are placed in the 1900s and years
+/// 00 through 68 are placed in the 2000s, as specified by POSIX.
+///
+/// A numeric date whose first two numbers could each be either the day or the month is ambiguous,
+/// and is rejected unless a [`DateOrder`] is provided with [`Heuristic::set_date_order`]. A date
+/// that is the same regardless of order, such as `03/03/2024`, is not ambiguous.
+///
+/// # Examples
+///
+/// ```rust
+/// # use time::parsing::{DateOrder, Heuristic};
+/// # use time_macros::{date, datetime};
+/// # use time::{Date, PrimitiveDateTime};
+/// assert_eq!(
+///     PrimitiveDateTime::parse("March 3, 2024 5pm", &Heuristic::DEFAULT)?,
+///     datetime!(2024-03-03 17:00)
+/// );
+/// assert!(Date::parse("03/04/2024", &Heuristic::DEFAULT).is_err());
+/// assert_eq!(
+///     Date::parse(
+///         "03/04/2024",
+///         &Heuristic::DEFAULT.set_date_order(DateOrder::DayFirst)
+///     )?,
+///     date!(2024-04-03)
+/// );
+/// # Ok::<_, time::Error>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Heuristic {
+    /// The order of the day and month in a numeric date, if known.
+    date_order: Option<DateOrder>,
+}
+
+impl Heuristic {
+    /// A parser that rejects ambiguous input.
+    pub const DEFAULT: Self = Self { date_order: None };
+
+    /// Set the order of the day and month used to resolve ambiguous numeric dates.
+    pub const fn set_date_order(self, date_order: DateOrder) -> Self {
+        Self {
+            date_order: Some(date_order),
+        }
+    }
+
+    /// Parse the input into a new [`Parsed`] struct, returning it along with the assumptions
+    /// needed to do so.
+    ///
+    /// The returned [`Parsed`] contains only the components present in the input. It can be
+    /// converted into the desired type using [`TryFrom`].
+    ///
+    /// ```rust
+    /// # use time::parsing::{DateOrder, Heuristic};
+    /// # use time_macros::date;
+    /// # use time::Date;
+    /// let (parsed, assumptions) = Heuristic::DEFAULT.parse("2024-03-04")?;
+    /// assert_eq!(Date::try_from(parsed)?, date!(2024-03-04));
+    /// assert!(assumptions.is_certain());
+    ///
+    /// let (parsed, assumptions) = Heuristic::DEFAULT
+    ///     .set_date_order(DateOrder::MonthFirst)
+    ///     .parse("3/4/24")?;
+    /// assert_eq!(Date::try_from(parsed)?, date!(2024-03-04));
+    /// assert!(assumptions.date_order());
+    /// assert!(assumptions.century());
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse(self, input: &str) -> Result<(Parsed, Assumptions), error::Parse> {
+        let mut parsed = Parsed::new();
+        let assumptions = self.parse_into(input.as_bytes(), &mut parsed)?;
+        Ok((parsed, assumptions))
+    }
+
+    /// Parse the entirety of the input into the provided [`Parsed`] struct, returning the
+    /// assumptions needed to do so.
+    pub(crate) fn parse_into(
+        self,
+        mut input: &[u8],
+        parsed: &mut Parsed,
+    ) -> Result<Assumptions, error::Parse> {
+        let mut state = State::default();
+
+        loop {
+            input = skip_separators(input);
+            input = match input {
+                [] => break,
+                [first, ..] if first.is_ascii_alphabetic() => state.word(input, parsed)?,
+                [first, ..] if first.is_ascii_digit() => self.number(input, parsed, &mut state)?,
+                [b'+' | b'-', ..] if state.has_time && !state.has_offset => {
+                    state.has_offset = true;
+                    offset(input, parsed)?
+                }
+                _ => return Err(InvalidLiteral.into()),
+            };
+        }
+
+        state.finish(parsed)?;
+        Ok(state.assumptions)
+    }
+
+    /// Consume a number, along with anything that is known to follow it.
+    fn number<'a>(
+        self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+        state: &mut State,
+    ) -> Result<&'a [u8], error::Parse> {
+        let ParsedItem(rest, digits) = digits(input);
+
+        match rest {
+            [b':', ..] if !state.has_time => {
+                state.has_time = true;
+                time(input, parsed)
+            }
+            [b'-' | b'/' | b'.', next, ..]
+                if !state.has_date() && (next.is_ascii_digit() || next.is_ascii_alphabetic()) =>
+            {
+                self.separated_date(input, parsed, state)
+            }
+            _ if digits.len() == 8 && !state.has_date() => {
+                state.has_numeric_date = true;
+                compact_date(digits, parsed)?;
+                Ok(rest)
+            }
+            _ if digits.len() == 14 && !state.has_date() && !state.has_time => {
+                state.has_numeric_date = true;
+                state.has_time = true;
+                compact_date(&digits[..8], parsed)?;
+                compact_time(&input[8..], parsed)
+            }
+            _ => {
+                if let Some(ParsedItem(rest, is_pm)) = period(rest) {
+                    if state.has_time {
+                        return Err(InvalidComponent("hour").into());
+                    }
+                    state.has_time = true;
+                    set_hour_12(parsed, value(digits)?, is_pm)?;
+                    return Ok(rest);
+                }
+
+                let (rest, is_ordinal) = match ordinal_suffix(rest) {
+                    Some(rest) => (rest, true),
+                    None => (rest, false),
+                };
+                state.push_loose(Loose {
+                    value: value(digits)?,
+                    num_digits: digits.len(),
+                    is_ordinal,
+                })?;
+                Ok(rest)
+            }
+        }
+    }
+
+    /// Consume a date whose parts are separated by `-`, `/`, or `.`. The middle part may be the
+    /// name of the month.
+    fn separated_date<'a>(
+        self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+        state: &mut State,
+    ) -> Result<&'a [u8], error::Parse> {
+        let ParsedItem(input, first) = digits(input);
+        let (separator, input) = match input {
+            [separator, rest @ ..] => (*separator, rest),
+            [] => return Err(InvalidLiteral.into()),
+        };
+
+        if input.first().is_some_and(u8::is_ascii_alphabetic) {
+            let ParsedItem(input, word) = word(input);
+            let month = month_name(word).ok_or(InvalidComponent("month"))?;
+            state.month = Some(month);
+            let input = match input {
+                [byte, rest @ ..] if *byte == separator => rest,
+                _ => return Err(InvalidLiteral.into()),
+            };
+            let ParsedItem(input, last) = digits(input);
+            if last.is_empty() {
+                return Err(InvalidComponent("year").into());
+            }
+            for part in [first, last] {
+                state.push_loose(Loose {
+                    value: value(part)?,
+                    num_digits: part.len(),
+                    is_ordinal: false,
+                })?;
+            }
+            return Ok(input);
+        }
+
+        let ParsedItem(input, second) = digits(input);
+        let input = match input {
+            [byte, rest @ ..] if *byte == separator => rest,
+            _ => return Err(InvalidLiteral.into()),
+        };
+        let ParsedItem(input, third) = digits(input);
+        if second.is_empty() || third.is_empty() {
+            return Err(InvalidLiteral.into());
+        }
+        state.has_numeric_date = true;
+
+        // A leading year is only ever followed by the month and then the day.
+        if first.len() > 2 {
+            set_year(parsed, value(first)?, first.len(), &mut state.assumptions)?;
+            set_month(parsed, value(second)?)?;
+            set_day(parsed, value(third)?)?;
+            return Ok(input);
+        }
+
+        set_year(parsed, value(third)?, third.len(), &mut state.assumptions)?;
+        let (first, second) = (value(first)?, value(second)?);
+        let (day, month) = match (first, second) {
+            _ if first == second => (first, second),
+            (13.., ..=12) => (first, second),
+            (..=12, 13..) => (second, first),
+            (13.., 13..) => return Err(InvalidComponent("month").into()),
+            _ => {
+                state.assumptions.date_order = true;
+                match self.date_order {
+                    Some(DateOrder::DayFirst) => (first, second),
+                    Some(DateOrder::MonthFirst) => (second, first),
+                    None => return Err(AmbiguousComponent("day").into()),
+                }
+            }
+        };
+        set_month(parsed, month)?;
+        set_day(parsed, day)?;
+        Ok(input)
+    }
+}
+
+/// The assumptions made by [`Heuristic`] in order to interpret its input.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Assumptions {
+    /// Whether the order of the day and month was taken from the configured [`DateOrder`].
+    date_order: bool,
+    /// Whether the century was inferred from a two-digit year.
+    century: bool,
+}
+
+impl Assumptions {
+    /// Whether the input was interpreted without making any assumptions. If this is the case, the
+    /// input has only one reasonable interpretation.
+    pub const fn is_certain(self) -> bool {
+        !self.date_order && !self.century
+    }
+
+    /// Whether the order of the day and month could not be determined from the input alone, and
+    /// was instead taken from the configured [`DateOrder`].
+    pub const fn date_order(self) -> bool {
+        self.date_order
+    }
+
+    /// Whether the year was written using two digits, such that the century had to be inferred.
+    pub const fn century(self) -> bool {
+        self.century
+    }
+}
+
+/// A number that was not part of a larger layout, and must be resolved once the rest of the input
+/// is known.
+#[derive(Clone, Copy)]
+struct Loose {
+    /// The value of the number.
+    value: u32,
+    /// The number of digits in the number, including any leading zeros.
+    num_digits: usize,
+    /// Whether the number was immediately followed by an ordinal suffix, such as `rd` in `3rd`.
+    is_ordinal: bool,
+}
+
+/// The state of the parser while consuming the input.
+#[derive(Default)]
+struct State {
+    /// The assumptions made so far.
+    assumptions: Assumptions,
+    /// Whether the year, month, and day have been parsed from a numeric or compact layout.
+    has_numeric_date: bool,
+    /// Whether the time has been parsed.
+    has_time: bool,
+    /// Whether the UTC offset has been parsed.
+    has_offset: bool,
+    /// The month, if it was provided by name.
+    month: Option<Month>,
+    /// The weekday, if it was provided by name.
+    weekday: Option<Weekday>,
+    /// Numbers that will be resolved to the day and year once the rest of the input is known.
+    loose: [Option<Loose>; 2],
+}
+
+impl State {
+    /// Whether the date, or some part of it, has been parsed.
+    const fn has_date(&self) -> bool {
+        self.has_numeric_date || self.month.is_some()
+    }
+
+    /// Store a number to be resolved later.
+    fn push_loose(&mut self, loose: Loose) -> Result<(), error::Parse> {
+        match &mut self.loose {
+            [slot @ None, _] | [Some(_), slot @ None] => {
+                *slot = Some(loose);
+                Ok(())
+            }
+            [Some(_), Some(_)] => Err(InvalidLiteral.into()),
+        }
+    }
+
+    /// Consume a word, such as the name of a month or weekday.
+    fn word<'a>(&mut self, input: &'a [u8], parsed: &mut Parsed) -> Result<&'a [u8], error::Parse> {
+        let ParsedItem(rest, word) = word(input);
+
+        if let Some(weekday) = weekday_name(word) {
+            if self.weekday.is_some() {
+                return Err(InvalidComponent("weekday").into());
+            }
+            self.weekday = Some(weekday);
+            return Ok(ascii_char::<b'.'>(rest).map_or(rest, |item| item.into_inner()));
+        }
+
+        if let Some(month) = month_name(word) {
+            if self.has_date() {
+                return Err(InvalidComponent("month").into());
+            }
+            self.month = Some(month);
+            return Ok(ascii_char::<b'.'>(rest).map_or(rest, |item| item.into_inner()));
+        }
+
+        if word.eq_ignore_ascii_case(b"t") && self.has_date() && !self.has_time {
+            let ParsedItem(after_digits, digits) = digits(rest);
+            // Only the compact form of the time is handled here. The extended form is handled
+            // when the number is consumed.
+            return if !digits.is_empty() && after_digits.first() != Some(&b':') {
+                self.has_time = true;
+                compact_time(rest, parsed)
+            } else {
+                Ok(rest)
+            };
+        }
+
+        if [b"z".as_slice(), b"ut", b"utc", b"gmt"]
+            .iter()
+            .any(|zone| word.eq_ignore_ascii_case(zone))
+        {
+            if self.has_offset {
+                return Err(InvalidComponent("offset hour").into());
+            }
+            self.has_offset = true;
+            set_offset(parsed, 0, 0)?;
+            return Ok(rest);
+        }
+
+        if [b"at".as_slice(), b"of", b"on"]
+            .iter()
+            .any(|filler| word.eq_ignore_ascii_case(filler))
+        {
+            return Ok(rest);
+        }
+
+        if period(input).is_some() {
+            return Err(InvalidComponent("period").into());
+        }
+
+        Err(InvalidLiteral.into())
+    }
+
+    /// Resolve the remaining parts of the date and store them in the provided [`Parsed`] struct.
+    fn finish(&mut self, parsed: &mut Parsed) -> Result<(), error::Parse> {
+        if let Some(month) = self.month {
+            parsed.set_month(month).ok_or(InvalidComponent("month"))?;
+
+            let (day, year) = match self.loose {
+                [None, _] => (None, None),
+                [Some(only), None] if only.is_ordinal || only.num_digits <= 2 => (Some(only), None),
+                [Some(only), None] => (None, Some(only)),
+                [Some(first), Some(second)] => match (first, second) {
+                    _ if first.is_ordinal && second.is_ordinal => {
+                        return Err(InvalidComponent("year").into())
+                    }
+                    _ if first.num_digits > 2 && second.num_digits > 2 => {
+                        return Err(InvalidComponent("day").into())
+                    }
+                    // A two-digit year is only ever written after the day.
+                    _ if first.num_digits > 2 || second.is_ordinal => (Some(second), Some(first)),
+                    _ => (Some(first), Some(second)),
+                },
+            };
+
+            if let Some(day) = day {
+                set_day(parsed, day.value)?;
+            }
+            if let Some(year) = year {
+                set_year(parsed, year.value, year.num_digits, &mut self.assumptions)?;
+            }
+        } else if self.loose[0].is_some() {
+            return Err(InvalidComponent("month").into());
+        }
+
+        if let Some(weekday) = self.weekday {
+            parsed
+                .set_weekday(weekday)
+                .ok_or(InvalidComponent("weekday"))?;
+            if let (Some(year), Some(month), Some(day)) =
+                (parsed.year(), parsed.month(), parsed.day())
+            {
+                // An invalid date is reported when converting the `Parsed` struct.
+                if Date::from_calendar_date(year, month, day.get())
+                    .is_ok_and(|date| date.weekday() != weekday)
+                {
+                    return Err(InvalidComponent("weekday").into());
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Skip any whitespace and commas.
+fn skip_separators(input: &[u8]) -> &[u8] {
+    let len = input
+        .iter()
+        .take_while(|byte| byte.is_ascii_whitespace() || **byte == b',')
+        .count();
+    &input[len..]
+}
+
+/// Consume any number of ASCII digits.
+fn digits(input: &[u8]) -> ParsedItem<'_, &[u8]> {
+    let len = input
+        .iter()
+        .take_while(|byte| byte.is_ascii_digit())
+        .count();
+    let (digits, rest) = input.split_at(len);
+    ParsedItem(rest, digits)
+}
+
+/// Consume any number of ASCII letters.
+fn word(input: &[u8]) -> ParsedItem<'_, &[u8]> {
+    let len = input
+        .iter()
+        .take_while(|byte| byte.is_ascii_alphabetic())
+        .count();
+    let (word, rest) = input.split_at(len);
+    ParsedItem(rest, word)
+}
+
+/// Obtain the value of a sequence of ASCII digits.
+fn value(digits: &[u8]) -> Result<u32, error::Parse> {
+    if digits.len() > 9 {
+        return Err(InvalidLiteral.into());
+    }
+    Ok(digits.iter().fold(0, |value, digit| {
+        value * 10 + (digit - b'0').extend::<u32>()
+    }))
+}
+
+/// Determine the month from its name, either in full or abbreviated.
+fn month_name(word: &[u8]) -> Option<Month> {
+    const NAMES: [&[u8]; 12] = [
+        b"january",
+        b"february",
+        b"march",
+        b"april",
+        b"may",
+        b"june",
+        b"july",
+        b"august",
+        b"september",
+        b"october",
+        b"november",
+        b"december",
+    ];
+
+    if word.eq_ignore_ascii_case(b"sept") {
+        return Some(Month::September);
+    }
+    let index = NAMES.iter().position(|name| {
+        word.eq_ignore_ascii_case(name)
+            || (word.len() == 3 && word.eq_ignore_ascii_case(&name[..3]))
+    })?;
+    Month::from_number(NonZero::new(index.truncate::<u8>() + 1)?).ok()
+}
+
+/// Determine the weekday from its name, either in full or abbreviated.
+fn weekday_name(word: &[u8]) -> Option<Weekday> {
+    const NAMES: [(&[u8], Weekday); 7] = [
+        (b"monday", Weekday::Monday),
+        (b"tuesday", Weekday::Tuesday),
+        (b"wednesday", Weekday::Wednesday),
+        (b"thursday", Weekday::Thursday),
+        (b"friday", Weekday::Friday),
+        (b"saturday", Weekday::Saturday),
+        (b"sunday", Weekday::Sunday),
+    ];
+
+    NAMES
+        .iter()
+        .find(|(name, _)| {
+            word.eq_ignore_ascii_case(name)
+                || (word.len() == 3 && word.eq_ignore_ascii_case(&name[..3]))
+        })
+        .map(|&(_, weekday)| weekday)
+}
+
+/// Consume an ordinal suffix, such as `rd` in `3rd`.
+fn ordinal_suffix(input: &[u8]) -> Option<&[u8]> {
+    let ParsedItem(rest, word) = word(input);
+    [b"st".as_slice(), b"nd", b"rd", b"th"]
+        .iter()
+        .any(|suffix| word.eq_ignore_ascii_case(suffix))
+        .then_some(rest)
+}
+
+/// Consume the period of the day, optionally preceded by whitespace, returning whether it is PM.
+fn period(input: &[u8]) -> Option<ParsedItem<'_, bool>> {
+    let len = input
+        .iter()
+        .take_while(|byte| byte.is_ascii_whitespace())
+        .count();
+    let input = &input[len..];
+
+    let (is_pm, rest) = match input {
+        [b'a' | b'A', b'.', b'm' | b'M', b'.', rest @ ..] => (false, rest),
+        [b'p' | b'P', b'.', b'm' | b'M', b'.', rest @ ..] => (true, rest),
+        [b'a' | b'A', b'm' | b'M', rest @ ..] => (false, rest),
+        [b'p' | b'P', b'm' | b'M', rest @ ..] => (true, rest),
+        _ => return None,
+    };
+    if rest.first().is_some_and(u8::is_ascii_alphanumeric) {
+        return None;
+    }
+    Some(ParsedItem(rest, is_pm))
+}
+
+/// Consume a time written with colons, such as `17:00` or `5:00:00.25 PM`.
+fn time<'a>(input: &'a [u8], parsed: &mut Parsed) -> Result<&'a [u8], error::Parse> {
+    let ParsedItem(input, hour) =
+        n_to_m_digits::<1, 2, u8>(input).ok_or(InvalidComponent("hour"))?;
+    let input = ascii_char::<b':'>(input)
+        .ok_or(InvalidLiteral)?
+        .into_inner();
+    let input = exactly_n_digits::<2, u8>(input)
+        .and_then(|item| item.consume_value(|value| parsed.set_minute(value)))
+        .ok_or(InvalidComponent("minute"))?;
+
+    let input = match ascii_char::<b':'>(input) {
+        Some(ParsedItem(input, ())) => {
+            let input = exactly_n_digits::<2, u8>(input)
+                .and_then(|item| item.consume_value(|value| parsed.set_second(value)))
+                .ok_or(InvalidComponent("second"))?;
+            subsecond(input, parsed)?
+        }
+        None => input,
+    };
+
+    match period(input) {
+        Some(ParsedItem(input, is_pm)) => {
+            set_hour_12(parsed, hour.extend(), is_pm)?;
+            Ok(input)
+        }
+        None => {
+            parsed.set_hour_24(hour).ok_or(InvalidComponent("hour"))?;
+            Ok(input)
+        }
+    }
+}
+
+/// Consume a time written without separators, such as `17`, `1700`, or `170000`.
+fn compact_time<'a>(input: &'a [u8], parsed: &mut Parsed) -> Result<&'a [u8], error::Parse> {
+    let ParsedItem(rest, digits) = digits(input);
+    if !matches!(digits.len(), 2 | 4 | 6) {
+        return Err(InvalidComponent("hour").into());
+    }
+
+    parsed
+        .set_hour_24(value(&digits[..2])?.truncate())
+        .ok_or(InvalidComponent("hour"))?;
+    if digits.len() >= 4 {
+        parsed
+            .set_minute(value(&digits[2..4])?.truncate())
+            .ok_or(InvalidComponent("minute"))?;
+    }
+    if digits.len() == 6 {
+        parsed
+            .set_second(value(&digits[4..])?.truncate())
+            .ok_or(InvalidComponent("second"))?;
+        return subsecond(rest, parsed);
+    }
+    Ok(rest)
+}
+
+/// Consume the subsecond, including its leading decimal sign, if present.
+fn subsecond<'a>(input: &'a [u8], parsed: &mut Parsed) -> Result<&'a [u8], error::Parse> {
+    let [b'.' | b',', rest @ ..] = input else {
+        return Ok(input);
+    };
+    let ParsedItem(rest, digits) = digits(rest);
+    if digits.is_empty() {
+        return Ok(input);
+    }
+
+    // Digits beyond nanosecond precision are discarded.
+    let mut subsecond = 0;
+    for i in 0..9 {
+        subsecond = subsecond * 10
+            + digits
+                .get(i)
+                .map_or(0, |digit| (digit - b'0').extend::<u32>());
+    }
+    parsed
+        .set_subsecond(subsecond)
+        .ok_or(InvalidComponent("subsecond"))?;
+    Ok(rest)
+}
+
+/// Consume a UTC offset with an explicit sign, such as `+01:00`, `-0500`, or `+01`.
+fn offset<'a>(input: &'a [u8], parsed: &mut Parsed) -> Result<&'a [u8], error::Parse> {
+    let ParsedItem(input, sign) = sign(input).ok_or(InvalidComponent("offset hour"))?;
+    let ParsedItem(input, hour) =
+        exactly_n_digits::<2, u8>(input).ok_or(InvalidComponent("offset hour"))?;
+    let ParsedItem(input, minute) = match ascii_char::<b':'>(input) {
+        Some(ParsedItem(input, ())) => {
+            exactly_n_digits::<2, u8>(input).ok_or(InvalidComponent("offset minute"))?
+        }
+        None => exactly_n_digits::<2, u8>(input).unwrap_or(ParsedItem(input, 0)),
+    };
+
+    let (hour, minute) = (hour.cast_signed(), minute.cast_signed());
+    if sign == b'-' {
+        set_offset(parsed, -hour, -minute)?;
+    } else {
+        set_offset(parsed, hour, minute)?;
+    }
+    Ok(input)
+}
+
+/// Interpret eight digits as a compact date, such as `20240303`.
+fn compact_date(digits: &[u8], parsed: &mut Parsed) -> Result<(), error::Parse> {
+    parsed
+        .set_year(value(&digits[..4])?.cast_signed())
+        .ok_or(InvalidComponent("year"))?;
+    set_month(parsed, value(&digits[4..6])?)?;
+    set_day(parsed, value(&digits[6..])?)
+}
+
+/// Set the year, inferring the century if only two digits are present.
+fn set_year(
+    parsed: &mut Parsed,
+    value: u32,
+    num_digits: usize,
+    assumptions: &mut Assumptions,
+) -> Result<(), error::Parse> {
+    let year = match num_digits {
+        2 => {
+            assumptions.century = true;
+            YearPivot::POSIX
+                .resolve(value.truncate())
+                .ok_or(InvalidComponent("year"))?
+        }
+        3.. => value.cast_signed(),
+        _ => return Err(InvalidComponent("year").into()),
+    };
+    parsed.set_year(year).ok_or(InvalidComponent("year"))?;
+    Ok(())
+}
+
+/// Set the month from its number.
+fn set_month(parsed: &mut Parsed, value: u32) -> Result<(), error::Parse> {
+    u8::try_from(value)
+        .ok()
+        .and_then(NonZero::new)
+        .and_then(|value| Month::from_number(value).ok())
+        .and_then(|month| parsed.set_month(month))
+        .ok_or(InvalidComponent("month"))?;
+    Ok(())
+}
+
+/// Set the day of the month.
+fn set_day(parsed: &mut Parsed, value: u32) -> Result<(), error::Parse> {
+    u8::try_from(value)
+        .ok()
+        .and_then(NonZero::new)
+        .and_then(|day| parsed.set_day(day))
+        .ok_or(InvalidComponent("day"))?;
+    Ok(())
+}
+
+/// Set the hour on a 12-hour clock along with the period of the day.
+fn set_hour_12(parsed: &mut Parsed, value: u32, is_pm: bool) -> Result<(), error::Parse> {
+    u8::try_from(value)
+        .ok()
+        .filter(|value| *value <= 12)
+        .and_then(NonZero::new)
+        .and_then(|hour| parsed.set_hour_12(hour))
+        .ok_or(InvalidComponent("hour"))?;
+    parsed
+        .set_hour_12_is_pm(is_pm)
+        .ok_or(InvalidComponent("period"))?;
+    Ok(())
+}
+
+/// Set the UTC offset to the provided hours and minutes.
+fn set_offset(parsed: &mut Parsed, hour: i8, minute: i8) -> Result<(), error::Parse> {
+    parsed
+        .set_offset_hour(hour)
+        .ok_or(InvalidComponent("offset hour"))?;
+    parsed
+        .set_offset_minute_signed(minute)
+        .ok_or(InvalidComponent("offset minute"))?;
+    parsed
+        .set_offset_second_signed(0)
+        .ok_or(InvalidComponent("offset second"))?;
+    Ok(())
+}
@@ -4,15 +4,16 @@
 use num_conv::prelude::*;
 
 use crate::convert::*;
-use crate::error;
 use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
 use crate::format_description::well_known::iso8601::EncodedConfig;
 use crate::format_description::well_known::Iso8601;
+use crate::interval::{Endpoint, Interval, NominalDuration, RepeatingInterval};
 use crate::parsing::combinator::rfc::iso8601::{
-    day, dayk, dayo, float, hour, min, month, week, year, ExtendedKind,
+    day, dayk, dayo, decimal_sign, float, hour, min, month, week, year, ExtendedKind,
 };
-use crate::parsing::combinator::{ascii_char, sign};
+use crate::parsing::combinator::{any_digit, ascii_char, n_to_m_digits, sign};
 use crate::parsing::{Parsed, ParsedItem};
+use crate::{error, Date, PrimitiveDateTime, Time, UtcOffset};
 
 impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
     // Basic: [year][month][day]
@@ -331,3 +332,306 @@ fn round_impl(value: f64) -> f64 {
         value - f + 1.
     }
 }
+
+impl<const CONFIG: EncodedConfig> Iso8601<CONFIG> {
+    /// Parse the time and UTC offset of an interval endpoint, if present, combining them with the
+    /// date. The entire input must be consumed. If no UTC offset is present, the provided default
+    /// is used.
+    fn finish_endpoint(
+        date: Date,
+        mut parsed: Parsed,
+        mut extended_kind: ExtendedKind,
+        input: &[u8],
+        date_is_present: bool,
+        default_offset: Option<UtcOffset>,
+    ) -> Result<Endpoint, error::Parse> {
+        if input.is_empty() && date_is_present {
+            return Ok(Endpoint::Date(date));
+        }
+
+        let input = Self::parse_time(&mut parsed, &mut extended_kind, date_is_present)(input)?;
+        let datetime = PrimitiveDateTime::new(date, Time::try_from(parsed)?);
+
+        let offset = if input.is_empty() {
+            default_offset
+        } else {
+            let input = Self::parse_offset(&mut parsed, &mut extended_kind)(input)?;
+            if !input.is_empty() {
+                return Err(error::Parse::ParseFromDescription(
+                    error::ParseFromDescription::UnexpectedTrailingCharacters,
+                ));
+            }
+            Some(UtcOffset::try_from(parsed)?)
+        };
+
+        Ok(match offset {
+            Some(offset) => Endpoint::OffsetDateTime(datetime.assume_offset(offset)),
+            None => Endpoint::PrimitiveDateTime(datetime),
+        })
+    }
+
+    /// Parse a date that makes up the entirety of the input.
+    fn parse_complete_date(input: &[u8]) -> Result<(Date, Parsed, ExtendedKind), error::Parse> {
+        let mut parsed = Parsed::new();
+        let mut extended_kind = ExtendedKind::Unknown;
+        let input = Self::parse_date(&mut parsed, &mut extended_kind)(input)?;
+        if !input.is_empty() {
+            return Err(error::Parse::ParseFromDescription(
+                error::ParseFromDescription::UnexpectedTrailingCharacters,
+            ));
+        }
+        Ok((Date::try_from(parsed)?, parsed, extended_kind))
+    }
+
+    /// Parse an interval endpoint that makes up the entirety of the input. The number of bytes
+    /// occupied by the date is also returned.
+    fn parse_endpoint(input: &[u8]) -> Result<(Endpoint, usize), error::Parse> {
+        let date_len = input.iter().position(|&c| c == b'T').unwrap_or(input.len());
+        let (date, parsed, extended_kind) = Self::parse_complete_date(&input[..date_len])?;
+        let endpoint =
+            Self::finish_endpoint(date, parsed, extended_kind, &input[date_len..], true, None)?;
+        Ok((endpoint, date_len))
+    }
+
+    /// Parse the end of an interval whose start is known. Leading components that are omitted
+    /// from the end, as well as the UTC offset, are taken from the start.
+    fn parse_end(
+        input: &[u8],
+        start: Endpoint,
+        start_date: &[u8],
+    ) -> Result<Endpoint, error::Parse> {
+        // Every component being omitted is not an abbreviation.
+        if input.is_empty() {
+            return Err(error::Parse::ParseFromDescription(InvalidComponent("year")));
+        }
+
+        let default_offset = match start {
+            Endpoint::Date(_) => None,
+            Endpoint::PrimitiveDateTime(_) => None,
+            Endpoint::OffsetDateTime(datetime) => Some(datetime.offset()),
+        };
+
+        let date_len = match input.iter().position(|&c| c == b'T') {
+            Some(date_len) => date_len,
+            // Only the time is present, so the date is that of the start.
+            None if !matches!(start, Endpoint::Date(_)) => {
+                return Self::finish_endpoint(
+                    start.date(),
+                    Parsed::new(),
+                    ExtendedKind::Unknown,
+                    input,
+                    false,
+                    default_offset,
+                );
+            }
+            None => input.len(),
+        };
+        let (date, rest) = input.split_at(date_len);
+
+        let (date, parsed, extended_kind) = match start_date.len().checked_sub(date.len()) {
+            Some(omitted @ 1..) => {
+                // Fill in the omitted leading components with those of the start. An expanded
+                // date is no more than 13 bytes.
+                let mut buf = [0; 16];
+                let buf = buf
+                    .get_mut(..start_date.len())
+                    .ok_or(InvalidComponent("year"))?;
+                buf[..omitted].copy_from_slice(&start_date[..omitted]);
+                buf[omitted..].copy_from_slice(date);
+                Self::parse_complete_date(buf)?
+            }
+            _ => Self::parse_complete_date(date)?,
+        };
+
+        Self::finish_endpoint(date, parsed, extended_kind, rest, true, default_offset)
+    }
+
+    /// The local date and time of an endpoint, with a date alone being midnight.
+    const fn local_endpoint(endpoint: Endpoint) -> PrimitiveDateTime {
+        match endpoint {
+            Endpoint::Date(date) => date.midnight(),
+            Endpoint::PrimitiveDateTime(datetime) => datetime,
+            Endpoint::OffsetDateTime(datetime) => {
+                PrimitiveDateTime::new(datetime.date(), datetime.time())
+            }
+        }
+    }
+
+    /// Parse an interval in any of its four forms.
+    pub(crate) fn parse_interval(input: &[u8]) -> Result<Interval, error::Parse> {
+        let Some(separator) = input.iter().position(|&c| c == b'/') else {
+            return Ok(Interval::Duration(parse_nominal_duration(input)?));
+        };
+        let (first, second) = (&input[..separator], &input[separator + 1..]);
+
+        if first.first() == Some(&b'P') {
+            let duration = parse_nominal_duration(first)?;
+            let (end, _) = Self::parse_endpoint(second)?;
+            return Ok(Interval::DurationEnd { duration, end });
+        }
+
+        let (start, start_date_len) = Self::parse_endpoint(first)?;
+        if second.first() == Some(&b'P') {
+            let duration = parse_nominal_duration(second)?;
+            return Ok(Interval::StartDuration { start, duration });
+        }
+
+        let end = Self::parse_end(second, start, &first[..start_date_len])?;
+        let reversed = match (start, end) {
+            (Endpoint::OffsetDateTime(start), Endpoint::OffsetDateTime(end)) => end < start,
+            // The end cannot be placed on the timeline relative to a start without an offset.
+            (_, Endpoint::OffsetDateTime(_)) => {
+                return Err(error::Parse::ParseFromDescription(InvalidComponent(
+                    "offset hour",
+                )));
+            }
+            (start, end) => Self::local_endpoint(end) < Self::local_endpoint(start),
+        };
+        if reversed {
+            return Err(error::Parse::ParseFromDescription(InvalidComponent("end")));
+        }
+        Ok(Interval::StartEnd { start, end })
+    }
+
+    /// Parse a repeating interval, such as `R5/2024-01-01T00:00Z/P1D`.
+    pub(crate) fn parse_repeating_interval(
+        input: &[u8],
+    ) -> Result<RepeatingInterval, error::Parse> {
+        let input = ascii_char::<b'R'>(input)
+            .ok_or(InvalidLiteral)?
+            .into_inner();
+        let (input, repetitions) = match n_to_m_digits::<1, 10, u32>(input) {
+            Some(ParsedItem(input, repetitions)) => (input, Some(repetitions)),
+            None if input.first().is_some_and(u8::is_ascii_digit) => {
+                return Err(error::Parse::ParseFromDescription(InvalidComponent(
+                    "repetitions",
+                )));
+            }
+            None => (input, None),
+        };
+        let input = ascii_char::<b'/'>(input)
+            .ok_or(InvalidLiteral)?
+            .into_inner();
+        Ok(RepeatingInterval::new(
+            repetitions,
+            Self::parse_interval(input)?,
+        ))
+    }
+}
+
+/// Parse a nominal duration that makes up the entirety of the input, such as `P1Y2M10DT2H30M`.
+///
+/// A decimal fraction is permitted on the last component, provided it is hours, minutes, or
+/// seconds. The fraction is carried into the smaller components.
+pub(crate) fn parse_nominal_duration(input: &[u8]) -> Result<NominalDuration, error::Parse> {
+    /// The designators of each component, in the order they must appear. The boolean indicates
+    /// whether the component is part of the time.
+    const DESIGNATORS: [(u8, bool); 7] = [
+        (b'Y', false),
+        (b'M', false),
+        (b'W', false),
+        (b'D', false),
+        (b'H', true),
+        (b'M', true),
+        (b'S', true),
+    ];
+
+    let mut input = ascii_char::<b'P'>(input)
+        .ok_or(InvalidLiteral)?
+        .into_inner();
+    let mut values = [0; 7];
+    let mut nanoseconds = 0;
+    let mut next_index = 0;
+    let mut time_is_present = false;
+    // Set when a component is required, which is after the "P" and after the "T".
+    let mut component_is_required = true;
+
+    if let Some(ParsedItem(new_input, ())) = ascii_char::<b'T'>(input) {
+        input = new_input;
+        time_is_present = true;
+        next_index = 4;
+    }
+
+    while !input.is_empty() {
+        let ParsedItem(new_input, value) =
+            n_to_m_digits::<1, 10, u32>(input).ok_or(InvalidComponent("duration"))?;
+        input = new_input;
+
+        // Up to nine digits of the fraction are kept, scaled to nanoseconds.
+        let fraction = match decimal_sign(input) {
+            Some(ParsedItem(new_input, ())) => {
+                any_digit(new_input).ok_or(InvalidComponent("duration"))?;
+                input = new_input;
+                let mut value = 0_u64;
+                let mut scale = Nanosecond::per_t::<u64>(Second);
+                while let Some(ParsedItem(new_input, digit)) = any_digit(input) {
+                    input = new_input;
+                    scale /= 10;
+                    value += (digit - b'0').extend::<u64>() * scale;
+                }
+                Some(value)
+            }
+            None => None,
+        };
+
+        let index = DESIGNATORS
+            .iter()
+            .enumerate()
+            .skip(next_index)
+            .find(|&(_, &(designator, is_time))| {
+                is_time == time_is_present && input.first() == Some(&designator)
+            })
+            .ok_or(InvalidComponent("duration"))?
+            .0;
+        input = &input[1..];
+        values[index] = value;
+        next_index = index + 1;
+        component_is_required = false;
+
+        if let Some(fraction) = fraction {
+            // Only the last component may have a fraction, and it must not be a date component.
+            if !input.is_empty() || index < 4 {
+                return Err(error::Parse::ParseFromDescription(InvalidComponent(
+                    "duration",
+                )));
+            }
+            let seconds_per_unit = match index {
+                4 => Second::per_t::<u64>(Hour),
+                5 => Second::per_t::<u64>(Minute),
+                _ => 1,
+            };
+            let fraction = fraction * seconds_per_unit;
+            let seconds = (fraction / Nanosecond::per_t::<u64>(Second)).truncate::<u32>();
+            values[5] += seconds / Second::per_t::<u32>(Minute);
+            values[6] += seconds % Second::per_t::<u32>(Minute);
+            nanoseconds = (fraction % Nanosecond::per_t::<u64>(Second)).truncate::<u32>();
+        }
+
+        if !time_is_present {
+            if let Some(ParsedItem(new_input, ())) = ascii_char::<b'T'>(input) {
+                input = new_input;
+                time_is_present = true;
+                next_index = 4;
+                component_is_required = true;
+            }
+        }
+    }
+
+    if component_is_required {
+        return Err(error::Parse::ParseFromDescription(InvalidComponent(
+            "duration",
+        )));
+    }
+
+    let [years, months, weeks, days, hours, minutes, seconds] = values;
+    Ok(NominalDuration::ZERO
+        .with_years(years)
+        .with_months(months)
+        .with_weeks(weeks)
+        .with_days(days)
+        .with_hours(hours)
+        .with_minutes(minutes)
+        .with_seconds(seconds)
+        .with_nanoseconds(nanoseconds)
+        .map_err(|_| InvalidComponent("duration"))?)
+}
@@ -1,12 +1,18 @@
 //! Parsing for various types.
 
+mod annotation;
 pub(crate) mod combinator;
 pub(crate) mod component;
-mod iso8601;
+mod defaults;
+mod heuristic;
+pub(crate) mod iso8601;
 pub(crate) mod parsable;
 mod parsed;
 pub(crate) mod shim;
 
+pub use self::annotation::{Annotation, AnnotationSuffix, Annotations};
+pub use self::defaults::Defaults;
+pub use self::heuristic::{Assumptions, DateOrder, Heuristic};
 pub use self::parsable::Parsable;
 pub use self::parsed::Parsed;
 
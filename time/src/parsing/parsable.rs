@@ -6,12 +6,12 @@ use num_conv::prelude::*;
 
 use crate::error::TryFromParsed;
 use crate::format_description::well_known::iso8601::EncodedConfig;
-use crate::format_description::well_known::{Iso8601, Rfc2822, Rfc3339};
+use crate::format_description::well_known::{HttpDate, Iso8601, Rfc2822, Rfc3339, Rfc9557};
 use crate::format_description::BorrowedFormatItem;
 #[cfg(feature = "alloc")]
 use crate::format_description::OwnedFormatItem;
 use crate::internal_macros::bug;
-use crate::parsing::{Parsed, ParsedItem};
+use crate::parsing::{AnnotationSuffix, Heuristic, Parsed, ParsedItem};
 use crate::{error, Date, Month, OffsetDateTime, Time, UtcOffset, Weekday};
 
 /// A type that can be parsed.
@@ -25,7 +25,10 @@ impl Parsable for OwnedFormatItem {}
 #[cfg(feature = "alloc")]
 impl Parsable for [OwnedFormatItem] {}
 impl Parsable for Rfc2822 {}
+impl Parsable for HttpDate {}
 impl Parsable for Rfc3339 {}
+impl Parsable for Rfc9557 {}
+impl Parsable for Heuristic {}
 impl<const CONFIG: EncodedConfig> Parsable for Iso8601<CONFIG> {}
 impl<T: Deref> Parsable for T where T::Target: Parsable {}
 
@@ -35,6 +38,21 @@ mod sealed {
     use super::*;
     use crate::{PrimitiveDateTime, UtcDateTime};
 
+    /// An error that occurred while parsing, along with where it occurred if known.
+    #[derive(Debug)]
+    pub struct Located<'a> {
+        /// The input at the point the error occurred.
+        pub(crate) at: Option<&'a [u8]>,
+        /// The error that occurred.
+        pub(crate) error: error::Parse,
+    }
+
+    impl From<Located<'_>> for error::Parse {
+        fn from(located: Located<'_>) -> Self {
+            located.error
+        }
+    }
+
     /// Parse the item using a format description and an input.
     pub trait Sealed {
         /// Parse the item into the provided [`Parsed`] struct.
@@ -51,8 +69,8 @@ mod sealed {
         /// This method can only be used to parse a complete value of a type. If any characters
         /// remain after parsing, an error will be returned.
         fn parse(&self, input: &[u8]) -> Result<Parsed, error::Parse> {
-            let mut parsed = Parsed::new();
-            if self.parse_into(input, &mut parsed)?.is_empty() {
+            let (parsed, remaining) = self.parse_prefix(input)?;
+            if remaining.is_empty() {
                 Ok(parsed)
             } else {
                 Err(error::Parse::ParseFromDescription(
@@ -61,6 +79,49 @@ mod sealed {
             }
         }
 
+        /// Parse the item from the start of the input into a new [`Parsed`] struct, returning it
+        /// along with the remaining input.
+        fn parse_prefix<'a>(&self, input: &'a [u8]) -> Result<(Parsed, &'a [u8]), error::Parse> {
+            let mut parsed = Parsed::new();
+            let remaining = self.parse_into(input, &mut parsed)?;
+            Ok((parsed, remaining))
+        }
+
+        /// Parse the item into the provided [`Parsed`] struct, retaining where in the input any
+        /// error occurred.
+        ///
+        /// By default, the location of the error is not known.
+        fn parse_into_located<'a>(
+            &self,
+            input: &'a [u8],
+            parsed: &mut Parsed,
+        ) -> Result<&'a [u8], Located<'a>> {
+            self.parse_into(input, parsed)
+                .map_err(|error| Located { at: None, error })
+        }
+
+        /// Parse the item into a new [`Parsed`] struct, locating any error that occurs.
+        fn parse_with_diagnostic(
+            &self,
+            input: &[u8],
+        ) -> Result<Parsed, error::ParseDiagnostic<'_>> {
+            let mut parsed = Parsed::new();
+            match self.parse_into_located(input, &mut parsed) {
+                Ok([]) => Ok(parsed),
+                Ok(remaining) => Err(error::ParseDiagnostic::located(
+                    input,
+                    remaining,
+                    error::ParseFromDescription::UnexpectedTrailingCharacters,
+                    error::Expected::End,
+                )),
+                Err(Located {
+                    at: Some(at),
+                    error,
+                }) => Err(error::ParseDiagnostic::at(input, at, error)),
+                Err(Located { at: None, error }) => Err(error.into()),
+            }
+        }
+
         /// Parse a [`Date`] from the format description.
         fn parse_date(&self, input: &[u8]) -> Result<Date, error::Parse> {
             Ok(self.parse(input)?.try_into()?)
@@ -96,6 +157,21 @@ mod sealed {
     }
 }
 
+/// Attach the location of an error to it.
+trait At<T> {
+    /// Record that the error occurred at the start of the provided input.
+    fn at(self, at: &[u8]) -> Result<T, sealed::Located<'_>>;
+}
+
+impl<T, E: Into<error::Parse>> At<T> for Result<T, E> {
+    fn at(self, at: &[u8]) -> Result<T, sealed::Located<'_>> {
+        self.map_err(|error| sealed::Located {
+            at: Some(at),
+            error: error.into(),
+        })
+    }
+}
+
 impl sealed::Sealed for BorrowedFormatItem<'_> {
     fn parse_into<'a>(
         &self,
@@ -104,6 +180,10 @@ impl sealed::Sealed for BorrowedFormatItem<'_> {
     ) -> Result<&'a [u8], error::Parse> {
         Ok(parsed.parse_item(input, self)?)
     }
+
+    fn parse_with_diagnostic(&self, input: &[u8]) -> Result<Parsed, error::ParseDiagnostic<'_>> {
+        Parsed::parse_items_with_diagnostic(input, core::slice::from_ref(self))
+    }
 }
 
 impl sealed::Sealed for [BorrowedFormatItem<'_>] {
@@ -114,6 +194,10 @@ impl sealed::Sealed for [BorrowedFormatItem<'_>] {
     ) -> Result<&'a [u8], error::Parse> {
         Ok(parsed.parse_items(input, self)?)
     }
+
+    fn parse_with_diagnostic(&self, input: &[u8]) -> Result<Parsed, error::ParseDiagnostic<'_>> {
+        Parsed::parse_items_with_diagnostic(input, self)
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -125,6 +209,10 @@ impl sealed::Sealed for OwnedFormatItem {
     ) -> Result<&'a [u8], error::Parse> {
         Ok(parsed.parse_item(input, self)?)
     }
+
+    fn parse_with_diagnostic(&self, input: &[u8]) -> Result<Parsed, error::ParseDiagnostic<'_>> {
+        Parsed::parse_items_with_diagnostic(input, core::slice::from_ref(self))
+    }
 }
 
 #[cfg(feature = "alloc")]
@@ -136,6 +224,21 @@ impl sealed::Sealed for [OwnedFormatItem] {
     ) -> Result<&'a [u8], error::Parse> {
         Ok(parsed.parse_items(input, self)?)
     }
+
+    fn parse_with_diagnostic(&self, input: &[u8]) -> Result<Parsed, error::ParseDiagnostic<'_>> {
+        Parsed::parse_items_with_diagnostic(input, self)
+    }
+}
+
+impl sealed::Sealed for Heuristic {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        Self::parse_into(*self, input, parsed)?;
+        Ok(&input[input.len()..])
+    }
 }
 
 impl<T> sealed::Sealed for T
@@ -149,6 +252,18 @@ where
     ) -> Result<&'a [u8], error::Parse> {
         self.deref().parse_into(input, parsed)
     }
+
+    fn parse_into_located<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], sealed::Located<'a>> {
+        self.deref().parse_into_located(input, parsed)
+    }
+
+    fn parse_with_diagnostic(&self, input: &[u8]) -> Result<Parsed, error::ParseDiagnostic<'_>> {
+        self.deref().parse_with_diagnostic(input)
+    }
 }
 
 impl sealed::Sealed for Rfc2822 {
@@ -157,8 +272,16 @@ impl sealed::Sealed for Rfc2822 {
         input: &'a [u8],
         parsed: &mut Parsed,
     ) -> Result<&'a [u8], error::Parse> {
+        Ok(self.parse_into_located(input, parsed)?)
+    }
+
+    fn parse_into_located<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], sealed::Located<'a>> {
         use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
-        use crate::parsing::combinator::rfc::rfc2822::{cfws, fws};
+        use crate::parsing::combinator::rfc::rfc2822::{cfws, year};
         use crate::parsing::combinator::{
             ascii_char, exactly_n_digits, first_match, n_to_m_digits, opt, sign,
         };
@@ -182,16 +305,19 @@ impl sealed::Sealed for Rfc2822 {
         let input = if let Some(item) = weekday {
             let input = item
                 .consume_value(|value| parsed.set_weekday(value))
-                .ok_or(InvalidComponent("weekday"))?;
-            let input = comma(input).ok_or(InvalidLiteral)?.into_inner();
+                .ok_or(InvalidComponent("weekday"))
+                .at(input)?;
+            let input = opt(cfws)(input).into_inner();
+            let input = comma(input).ok_or(InvalidLiteral).at(input)?.into_inner();
             opt(cfws)(input).into_inner()
         } else {
             input
         };
         let input = n_to_m_digits::<1, 2, _>(input)
             .and_then(|item| item.consume_value(|value| parsed.set_day(value)))
-            .ok_or(InvalidComponent("day"))?;
-        let input = cfws(input).ok_or(InvalidLiteral)?.into_inner();
+            .ok_or(InvalidComponent("day"))
+            .at(input)?;
+        let input = cfws(input).ok_or(InvalidLiteral).at(input)?.into_inner();
         let input = first_match(
             [
                 (b"Jan".as_slice(), Month::January),
@@ -210,59 +336,45 @@ impl sealed::Sealed for Rfc2822 {
             false,
         )(input)
         .and_then(|item| item.consume_value(|value| parsed.set_month(value)))
-        .ok_or(InvalidComponent("month"))?;
-        let input = cfws(input).ok_or(InvalidLiteral)?.into_inner();
-        let input = match exactly_n_digits::<4, u32>(input) {
-            Some(item) => {
-                let input = item
-                    .flat_map(|year| if year >= 1900 { Some(year) } else { None })
-                    .and_then(|item| {
-                        item.consume_value(|value| parsed.set_year(value.cast_signed()))
-                    })
-                    .ok_or(InvalidComponent("year"))?;
-                fws(input).ok_or(InvalidLiteral)?.into_inner()
-            }
-            None => {
-                let input = exactly_n_digits::<2, u32>(input)
-                    .and_then(|item| {
-                        item.map(|year| if year < 50 { year + 2000 } else { year + 1900 })
-                            .map(|year| year.cast_signed())
-                            .consume_value(|value| parsed.set_year(value))
-                    })
-                    .ok_or(InvalidComponent("year"))?;
-                cfws(input).ok_or(InvalidLiteral)?.into_inner()
-            }
-        };
+        .ok_or(InvalidComponent("month"))
+        .at(input)?;
+        let input = cfws(input).ok_or(InvalidLiteral).at(input)?.into_inner();
+        let input = year(input)
+            .and_then(|item| item.consume_value(|value| parsed.set_year(value)))
+            .ok_or(InvalidComponent("year"))
+            .at(input)?;
+        let input = cfws(input).ok_or(InvalidLiteral).at(input)?.into_inner();
 
         let input = exactly_n_digits::<2, _>(input)
             .and_then(|item| item.consume_value(|value| parsed.set_hour_24(value)))
-            .ok_or(InvalidComponent("hour"))?;
+            .ok_or(InvalidComponent("hour"))
+            .at(input)?;
         let input = opt(cfws)(input).into_inner();
-        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+        let input = colon(input).ok_or(InvalidLiteral).at(input)?.into_inner();
         let input = opt(cfws)(input).into_inner();
         let input = exactly_n_digits::<2, _>(input)
             .and_then(|item| item.consume_value(|value| parsed.set_minute(value)))
-            .ok_or(InvalidComponent("minute"))?;
+            .ok_or(InvalidComponent("minute"))
+            .at(input)?;
 
         let input = if let Some(input) = colon(opt(cfws)(input).into_inner()) {
             let input = input.into_inner(); // discard the colon
             let input = opt(cfws)(input).into_inner();
             let input = exactly_n_digits::<2, _>(input)
                 .and_then(|item| item.consume_value(|value| parsed.set_second(value)))
-                .ok_or(InvalidComponent("second"))?;
-            cfws(input).ok_or(InvalidLiteral)?.into_inner()
+                .ok_or(InvalidComponent("second"))
+                .at(input)?;
+            cfws(input).ok_or(InvalidLiteral).at(input)?.into_inner()
         } else {
-            cfws(input).ok_or(InvalidLiteral)?.into_inner()
+            cfws(input).ok_or(InvalidLiteral).at(input)?.into_inner()
         };
 
         // The RFC explicitly allows leap seconds.
         parsed.leap_second_allowed = true;
 
-        #[expect(
-            clippy::unnecessary_lazy_evaluations,
-            reason = "rust-lang/rust-clippy#8522"
-        )]
-        let zone_literal = first_match(
+        // The military time zones carry no reliable information, so RFC 5322 treats them as
+        // equivalent to `-0000`.
+        let (zone_literal, offset_is_unknown) = match first_match(
             [
                 (b"UT".as_slice(), 0),
                 (b"GMT".as_slice(), 0),
@@ -277,26 +389,38 @@ impl sealed::Sealed for Rfc2822 {
             ],
             false,
         )(input)
-        .or_else(|| match input {
-            [b'a'..=b'i' | b'k'..=b'z' | b'A'..=b'I' | b'K'..=b'Z', rest @ ..] => {
-                Some(ParsedItem(rest, 0))
-            }
-            _ => None,
-        });
+        {
+            Some(zone_literal) => (Some(zone_literal), false),
+            None => match input {
+                [b'a'..=b'i' | b'k'..=b'z' | b'A'..=b'I' | b'K'..=b'Z', rest @ ..] => {
+                    (Some(ParsedItem(rest, 0)), true)
+                }
+                _ => (None, false),
+            },
+        };
         if let Some(zone_literal) = zone_literal {
             let input = zone_literal
                 .consume_value(|value| parsed.set_offset_hour(value))
-                .ok_or(InvalidComponent("offset hour"))?;
+                .ok_or(InvalidComponent("offset hour"))
+                .at(input)?;
             parsed
                 .set_offset_minute_signed(0)
-                .ok_or(InvalidComponent("offset minute"))?;
+                .ok_or(InvalidComponent("offset minute"))
+                .at(input)?;
             parsed
                 .set_offset_second_signed(0)
-                .ok_or(InvalidComponent("offset second"))?;
+                .ok_or(InvalidComponent("offset second"))
+                .at(input)?;
+            if offset_is_unknown {
+                parsed.set_offset_is_unknown();
+            }
+            let input = opt(cfws)(input).into_inner();
             return Ok(input);
         }
 
-        let ParsedItem(input, offset_sign) = sign(input).ok_or(InvalidComponent("offset hour"))?;
+        let ParsedItem(input, offset_sign) = sign(input)
+            .ok_or(InvalidComponent("offset hour"))
+            .at(input)?;
         let input = exactly_n_digits::<2, u8>(input)
             .and_then(|item| {
                 item.map(|offset_hour| {
@@ -308,12 +432,21 @@ impl sealed::Sealed for Rfc2822 {
                 })
                 .consume_value(|value| parsed.set_offset_hour(value))
             })
-            .ok_or(InvalidComponent("offset hour"))?;
+            .ok_or(InvalidComponent("offset hour"))
+            .at(input)?;
         let input = exactly_n_digits::<2, u8>(input)
             .and_then(|item| {
                 item.consume_value(|value| parsed.set_offset_minute_signed(value.cast_signed()))
             })
-            .ok_or(InvalidComponent("offset minute"))?;
+            .ok_or(InvalidComponent("offset minute"))
+            .at(input)?;
+        // `-0000` indicates that the local offset is unknown, unlike `+0000`.
+        if offset_sign == b'-'
+            && parsed.offset_hour() == Some(0)
+            && parsed.offset_minute_signed() == Some(0)
+        {
+            parsed.set_offset_is_unknown();
+        }
 
         let input = opt(cfws)(input).into_inner();
 
@@ -322,7 +455,7 @@ impl sealed::Sealed for Rfc2822 {
 
     fn parse_offset_date_time(&self, input: &[u8]) -> Result<OffsetDateTime, error::Parse> {
         use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
-        use crate::parsing::combinator::rfc::rfc2822::{cfws, fws};
+        use crate::parsing::combinator::rfc::rfc2822::{cfws, year};
         use crate::parsing::combinator::{
             ascii_char, exactly_n_digits, first_match, n_to_m_digits, opt, sign,
         };
@@ -347,6 +480,7 @@ impl sealed::Sealed for Rfc2822 {
         )(input);
         let input = if let Some(item) = weekday {
             let input = item.into_inner();
+            let input = opt(cfws)(input).into_inner();
             let input = comma(input).ok_or(InvalidLiteral)?.into_inner();
             opt(cfws)(input).into_inner()
         } else {
@@ -374,22 +508,8 @@ impl sealed::Sealed for Rfc2822 {
         )(input)
         .ok_or(InvalidComponent("month"))?;
         let input = cfws(input).ok_or(InvalidLiteral)?.into_inner();
-        let (input, year) = match exactly_n_digits::<4, u32>(input) {
-            Some(item) => {
-                let ParsedItem(input, year) = item
-                    .flat_map(|year| if year >= 1900 { Some(year) } else { None })
-                    .ok_or(InvalidComponent("year"))?;
-                let input = fws(input).ok_or(InvalidLiteral)?.into_inner();
-                (input, year)
-            }
-            None => {
-                let ParsedItem(input, year) = exactly_n_digits::<2, u32>(input)
-                    .map(|item| item.map(|year| if year < 50 { year + 2000 } else { year + 1900 }))
-                    .ok_or(InvalidComponent("year"))?;
-                let input = cfws(input).ok_or(InvalidLiteral)?.into_inner();
-                (input, year)
-            }
-        };
+        let ParsedItem(input, year) = year(input).ok_or(InvalidComponent("year"))?;
+        let input = cfws(input).ok_or(InvalidLiteral)?.into_inner();
 
         let ParsedItem(input, hour) =
             exactly_n_digits::<2, _>(input).ok_or(InvalidComponent("hour"))?;
@@ -476,7 +596,7 @@ impl sealed::Sealed for Rfc2822 {
         };
 
         let dt = (|| {
-            let date = Date::from_calendar_date(year.cast_signed(), month, day)?;
+            let date = Date::from_calendar_date(year, month, day)?;
             let time = Time::from_hms_nano(hour, minute, second, nanosecond)?;
             let offset = UtcOffset::from_hms(offset_hour, offset_minute, 0)?;
             Ok(OffsetDateTime::new_in_offset(date, time, offset))
@@ -505,6 +625,14 @@ impl sealed::Sealed for Rfc3339 {
         input: &'a [u8],
         parsed: &mut Parsed,
     ) -> Result<&'a [u8], error::Parse> {
+        Ok(self.parse_into_located(input, parsed)?)
+    }
+
+    fn parse_into_located<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], sealed::Located<'a>> {
         use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
         use crate::parsing::combinator::{
             any_digit, ascii_char, ascii_char_ignore_case, exactly_n_digits, sign,
@@ -515,16 +643,19 @@ impl sealed::Sealed for Rfc3339 {
 
         let input = exactly_n_digits::<4, u32>(input)
             .and_then(|item| item.consume_value(|value| parsed.set_year(value.cast_signed())))
-            .ok_or(InvalidComponent("year"))?;
-        let input = dash(input).ok_or(InvalidLiteral)?.into_inner();
+            .ok_or(InvalidComponent("year"))
+            .at(input)?;
+        let input = dash(input).ok_or(InvalidLiteral).at(input)?.into_inner();
         let input = exactly_n_digits::<2, _>(input)
             .and_then(|item| item.flat_map(|value| Month::from_number(value).ok()))
             .and_then(|item| item.consume_value(|value| parsed.set_month(value)))
-            .ok_or(InvalidComponent("month"))?;
-        let input = dash(input).ok_or(InvalidLiteral)?.into_inner();
+            .ok_or(InvalidComponent("month"))
+            .at(input)?;
+        let input = dash(input).ok_or(InvalidLiteral).at(input)?.into_inner();
         let input = exactly_n_digits::<2, _>(input)
             .and_then(|item| item.consume_value(|value| parsed.set_day(value)))
-            .ok_or(InvalidComponent("day"))?;
+            .ok_or(InvalidComponent("day"))
+            .at(input)?;
 
         // RFC3339 allows any separator, not just `T`, not just `space`.
         // cf. Section 5.6: Internet Date/Time Format:
@@ -533,22 +664,29 @@ impl sealed::Sealed for Rfc3339 {
         //   readability, to specify a full-date and full-time separated by
         //   (say) a space character.
         // Specifically, rusqlite uses space separators.
-        let input = input.get(1..).ok_or(InvalidComponent("separator"))?;
+        let input = input
+            .get(1..)
+            .ok_or(InvalidComponent("separator"))
+            .at(input)?;
 
         let input = exactly_n_digits::<2, _>(input)
             .and_then(|item| item.consume_value(|value| parsed.set_hour_24(value)))
-            .ok_or(InvalidComponent("hour"))?;
-        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+            .ok_or(InvalidComponent("hour"))
+            .at(input)?;
+        let input = colon(input).ok_or(InvalidLiteral).at(input)?.into_inner();
         let input = exactly_n_digits::<2, _>(input)
             .and_then(|item| item.consume_value(|value| parsed.set_minute(value)))
-            .ok_or(InvalidComponent("minute"))?;
-        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+            .ok_or(InvalidComponent("minute"))
+            .at(input)?;
+        let input = colon(input).ok_or(InvalidLiteral).at(input)?.into_inner();
         let input = exactly_n_digits::<2, _>(input)
             .and_then(|item| item.consume_value(|value| parsed.set_second(value)))
-            .ok_or(InvalidComponent("second"))?;
+            .ok_or(InvalidComponent("second"))
+            .at(input)?;
         let input = if let Some(ParsedItem(input, ())) = ascii_char::<b'.'>(input) {
             let ParsedItem(mut input, mut value) = any_digit(input)
-                .ok_or(InvalidComponent("subsecond"))?
+                .ok_or(InvalidComponent("subsecond"))
+                .at(input)?
                 .map(|v| (v - b'0').extend::<u32>() * 100_000_000);
 
             let mut multiplier = 10_000_000;
@@ -560,7 +698,8 @@ impl sealed::Sealed for Rfc3339 {
 
             parsed
                 .set_subsecond(value)
-                .ok_or(InvalidComponent("subsecond"))?;
+                .ok_or(InvalidComponent("subsecond"))
+                .at(input)?;
             input
         } else {
             input
@@ -572,17 +711,22 @@ impl sealed::Sealed for Rfc3339 {
         if let Some(ParsedItem(input, ())) = ascii_char_ignore_case::<b'Z'>(input) {
             parsed
                 .set_offset_hour(0)
-                .ok_or(InvalidComponent("offset hour"))?;
+                .ok_or(InvalidComponent("offset hour"))
+                .at(input)?;
             parsed
                 .set_offset_minute_signed(0)
-                .ok_or(InvalidComponent("offset minute"))?;
+                .ok_or(InvalidComponent("offset minute"))
+                .at(input)?;
             parsed
                 .set_offset_second_signed(0)
-                .ok_or(InvalidComponent("offset second"))?;
+                .ok_or(InvalidComponent("offset second"))
+                .at(input)?;
             return Ok(input);
         }
 
-        let ParsedItem(input, offset_sign) = sign(input).ok_or(InvalidComponent("offset hour"))?;
+        let ParsedItem(input, offset_sign) = sign(input)
+            .ok_or(InvalidComponent("offset hour"))
+            .at(input)?;
         let input = exactly_n_digits::<2, u8>(input)
             .and_then(|item| {
                 item.filter(|&offset_hour| offset_hour <= 23)?
@@ -595,8 +739,9 @@ impl sealed::Sealed for Rfc3339 {
                     })
                     .consume_value(|value| parsed.set_offset_hour(value))
             })
-            .ok_or(InvalidComponent("offset hour"))?;
-        let input = colon(input).ok_or(InvalidLiteral)?.into_inner();
+            .ok_or(InvalidComponent("offset hour"))
+            .at(input)?;
+        let input = colon(input).ok_or(InvalidLiteral).at(input)?.into_inner();
         let input = exactly_n_digits::<2, u8>(input)
             .and_then(|item| {
                 item.map(|offset_minute| {
@@ -608,7 +753,8 @@ impl sealed::Sealed for Rfc3339 {
                 })
                 .consume_value(|value| parsed.set_offset_minute_signed(value))
             })
-            .ok_or(InvalidComponent("offset minute"))?;
+            .ok_or(InvalidComponent("offset minute"))
+            .at(input)?;
 
         Ok(input)
     }
@@ -744,12 +890,223 @@ impl sealed::Sealed for Rfc3339 {
     }
 }
 
+impl sealed::Sealed for Rfc9557 {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        Ok(self.parse_into_located(input, parsed)?)
+    }
+
+    fn parse_into_located<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], sealed::Located<'a>> {
+        parse_rfc9557_located(input, parsed).map(|(remaining, _)| remaining)
+    }
+}
+
+/// Parse RFC 9557 input, returning the annotations separately from the parsed values.
+pub(crate) fn parse_rfc9557<'a>(
+    input: &'a [u8],
+    parsed: &mut Parsed,
+) -> Result<(&'a [u8], AnnotationSuffix<'a>), error::Parse> {
+    Ok(parse_rfc9557_located(input, parsed)?)
+}
+
+/// Parse RFC 9557 input, returning the annotations separately from the parsed values and the
+/// location of any error.
+fn parse_rfc9557_located<'a>(
+    input: &'a [u8],
+    parsed: &mut Parsed,
+) -> Result<(&'a [u8], AnnotationSuffix<'a>), sealed::Located<'a>> {
+    let remaining = sealed::Sealed::parse_into_located(&Rfc3339, input, parsed)?;
+
+    // A `Z` indicates that the local offset is unknown, so it can never be inconsistent with a
+    // time zone annotation.
+    let offset_is_unknown = matches!(
+        input[..input.len() - remaining.len()].last(),
+        Some(b'Z' | b'z')
+    );
+
+    parsed
+        .parse_annotations_with(remaining, offset_is_unknown)
+        .at(remaining)
+}
+
+impl sealed::Sealed for HttpDate {
+    fn parse_into<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], error::Parse> {
+        Ok(self.parse_into_located(input, parsed)?)
+    }
+
+    fn parse_into_located<'a>(
+        &self,
+        input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], sealed::Located<'a>> {
+        use crate::error::ParseFromDescription::{InvalidComponent, InvalidLiteral};
+        use crate::parsing::combinator::rfc::rfc9110::{
+            asctime_day, day_name, day_name_l, month, resolve_rfc850_year, time_of_day,
+        };
+        use crate::parsing::combinator::{ascii_char, exactly_n_digits};
+
+        let space = ascii_char::<b' '>;
+        let dash = ascii_char::<b'-'>;
+        let comma = ascii_char::<b','>;
+
+        // The full name must be checked first, as the abbreviation is a prefix of it.
+        let (weekday, is_rfc850) = match day_name_l(input) {
+            Some(item) => (item, true),
+            None => (
+                day_name(input)
+                    .ok_or(InvalidComponent("weekday"))
+                    .at(input)?,
+                false,
+            ),
+        };
+        let input = weekday
+            .consume_value(|value| parsed.set_weekday(value))
+            .ok_or(InvalidComponent("weekday"))
+            .at(input)?;
+
+        let is_asctime = !is_rfc850 && comma(input).is_none();
+        let input = match comma(input) {
+            // rfc850-date = day-name-l "," SP date2 SP time-of-day SP GMT
+            Some(ParsedItem(input, ())) if is_rfc850 => {
+                let input = space(input).ok_or(InvalidLiteral).at(input)?.into_inner();
+                let input = exactly_n_digits::<2, _>(input)
+                    .and_then(|item| item.consume_value(|value| parsed.set_day(value)))
+                    .ok_or(InvalidComponent("day"))
+                    .at(input)?;
+                let input = dash(input).ok_or(InvalidLiteral).at(input)?.into_inner();
+                let input = month(input)
+                    .and_then(|item| item.consume_value(|value| parsed.set_month(value)))
+                    .ok_or(InvalidComponent("month"))
+                    .at(input)?;
+                let input = dash(input).ok_or(InvalidLiteral).at(input)?.into_inner();
+                let input = exactly_n_digits::<2, u8>(input)
+                    .and_then(|item| {
+                        item.map(resolve_rfc850_year)
+                            .consume_value(|value| parsed.set_year(value))
+                    })
+                    .ok_or(InvalidComponent("year"))
+                    .at(input)?;
+                space(input).ok_or(InvalidLiteral).at(input)?.into_inner()
+            }
+            // IMF-fixdate = day-name "," SP date1 SP time-of-day SP GMT
+            Some(ParsedItem(input, ())) => {
+                let input = space(input).ok_or(InvalidLiteral).at(input)?.into_inner();
+                let input = exactly_n_digits::<2, _>(input)
+                    .and_then(|item| item.consume_value(|value| parsed.set_day(value)))
+                    .ok_or(InvalidComponent("day"))
+                    .at(input)?;
+                let input = space(input).ok_or(InvalidLiteral).at(input)?.into_inner();
+                let input = month(input)
+                    .and_then(|item| item.consume_value(|value| parsed.set_month(value)))
+                    .ok_or(InvalidComponent("month"))
+                    .at(input)?;
+                let input = space(input).ok_or(InvalidLiteral).at(input)?.into_inner();
+                let input = exactly_n_digits::<4, u32>(input)
+                    .and_then(|item| {
+                        item.consume_value(|value| parsed.set_year(value.cast_signed()))
+                    })
+                    .ok_or(InvalidComponent("year"))
+                    .at(input)?;
+                space(input).ok_or(InvalidLiteral).at(input)?.into_inner()
+            }
+            // asctime-date = day-name SP date3 SP time-of-day SP year
+            None if is_asctime => {
+                let input = space(input).ok_or(InvalidLiteral).at(input)?.into_inner();
+                let input = month(input)
+                    .and_then(|item| item.consume_value(|value| parsed.set_month(value)))
+                    .ok_or(InvalidComponent("month"))
+                    .at(input)?;
+                let input = space(input).ok_or(InvalidLiteral).at(input)?.into_inner();
+                let input = asctime_day(input)
+                    .and_then(|item| item.consume_value(|value| parsed.set_day(value)))
+                    .ok_or(InvalidComponent("day"))
+                    .at(input)?;
+                space(input).ok_or(InvalidLiteral).at(input)?.into_inner()
+            }
+            None => return Err(InvalidLiteral).at(input),
+        };
+
+        let input = time_of_day(input)
+            .and_then(|item| {
+                item.consume_value(|(hour, minute, second)| {
+                    parsed.set_hour_24(hour)?;
+                    parsed.set_minute(minute)?;
+                    parsed.set_second(second)
+                })
+            })
+            .ok_or(InvalidComponent("time"))
+            .at(input)?;
+
+        // The RFC explicitly allows leap seconds.
+        parsed.leap_second_allowed = true;
+
+        let input = space(input).ok_or(InvalidLiteral).at(input)?.into_inner();
+        let input = if is_asctime {
+            exactly_n_digits::<4, u32>(input)
+                .and_then(|item| item.consume_value(|value| parsed.set_year(value.cast_signed())))
+                .ok_or(InvalidComponent("year"))
+                .at(input)?
+        } else {
+            input.strip_prefix(b"GMT").ok_or(InvalidLiteral).at(input)?
+        };
+
+        // The weekday is redundant, so it must agree with the date. An invalid date is reported
+        // when the value is constructed.
+        if let (Some(year), Some(month), Some(day), Some(weekday)) = (
+            parsed.year(),
+            parsed.month(),
+            parsed.day(),
+            parsed.weekday(),
+        ) {
+            if Date::from_calendar_date(year, month, day.get())
+                .is_ok_and(|date| date.weekday() != weekday)
+            {
+                return Err(InvalidComponent("weekday")).at(input);
+            }
+        }
+
+        parsed
+            .set_offset_hour(0)
+            .ok_or(InvalidComponent("offset hour"))
+            .at(input)?;
+        parsed
+            .set_offset_minute_signed(0)
+            .ok_or(InvalidComponent("offset minute"))
+            .at(input)?;
+        parsed
+            .set_offset_second_signed(0)
+            .ok_or(InvalidComponent("offset second"))
+            .at(input)?;
+
+        Ok(input)
+    }
+}
+
 impl<const CONFIG: EncodedConfig> sealed::Sealed for Iso8601<CONFIG> {
     fn parse_into<'a>(
         &self,
-        mut input: &'a [u8],
+        input: &'a [u8],
         parsed: &mut Parsed,
     ) -> Result<&'a [u8], error::Parse> {
+        Ok(self.parse_into_located(input, parsed)?)
+    }
+
+    fn parse_into_located<'a>(
+        &self,
+        mut input: &'a [u8],
+        parsed: &mut Parsed,
+    ) -> Result<&'a [u8], sealed::Located<'a>> {
         use crate::parsing::combinator::rfc::iso8601::ExtendedKind;
 
         let mut extended_kind = ExtendedKind::Unknown;
@@ -793,9 +1150,10 @@ impl<const CONFIG: EncodedConfig> sealed::Sealed for Iso8601<CONFIG> {
             }
         }
 
+        // No input has been consumed if nothing was parsed, so the error is located at the start.
         if !date_is_present && !time_is_present && !offset_is_present {
             match first_error {
-                Some(err) => return Err(err),
+                Some(err) => return Err(err).at(input),
                 None => bug!("an error should be present if no components were parsed"),
             }
         }
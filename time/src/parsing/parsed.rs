@@ -1,5 +1,7 @@
 //! Information parsed from an input and format description.
 
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use core::num::NonZero;
 
 use deranged::{
@@ -11,19 +13,26 @@ use num_conv::prelude::*;
 
 use crate::convert::{Day, Hour, Minute, Nanosecond, Second};
 use crate::date::{MAX_YEAR, MIN_YEAR};
+use crate::error::Expected;
+use crate::error::ParseFromDescription::InvalidComponent;
 use crate::error::TryFromParsed::InsufficientInformation;
 #[cfg(feature = "alloc")]
 use crate::format_description::OwnedFormatItem;
-use crate::format_description::{modifier, BorrowedFormatItem, Component};
+use crate::format_description::{modifier, BorrowedFormatItem, Component, Condition};
 use crate::internal_macros::{bug, const_try_opt};
+use crate::parsing::combinator::rfc::rfc9557::{suffix_tag, time_zone};
+use crate::parsing::combinator::{exactly_n_digits, sign};
 use crate::parsing::component::{
-    parse_day, parse_end, parse_hour, parse_ignore, parse_minute, parse_month, parse_offset_hour,
-    parse_offset_minute, parse_offset_second, parse_ordinal, parse_period, parse_second,
-    parse_subsecond, parse_unix_timestamp, parse_week_number, parse_weekday, parse_year, Period,
+    parse_day, parse_end, parse_era, parse_hour, parse_ignore, parse_julian_date, parse_julian_day,
+    parse_minute, parse_month, parse_offset_hour, parse_offset_minute, parse_offset_second,
+    parse_ordinal, parse_period, parse_quarter, parse_second, parse_subsecond,
+    parse_time_zone_annotation, parse_unix_timestamp, parse_week_number, parse_week_of_month,
+    parse_weekday, parse_weekday_in_month, parse_year, Era, Period,
 };
-use crate::parsing::ParsedItem;
+use crate::parsing::{AnnotationSuffix, Parsable, ParsedItem};
 use crate::{
-    error, Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcDateTime, UtcOffset, Weekday,
+    error, Date, Month, OffsetDateTime, PrimitiveDateTime, Quarter, Time, UtcDateTime, UtcOffset,
+    Weekday,
 };
 
 /// Sealed to prevent downstream implementations.
@@ -32,31 +41,89 @@ mod sealed {
 
     /// A trait to allow `parse_item` to be generic.
     pub trait AnyFormatItem {
-        /// Parse a single item, returning the remaining input on success.
-        fn parse_item<'a>(
-            &self,
+        /// Parse a single item, returning the remaining input on success. On failure, the error
+        /// is passed to the sink, which determines what is returned.
+        fn parse_item_with<'a, 'b, S: ErrorSink<'a, 'b>>(
+            &'b self,
             parsed: &mut Parsed,
             input: &'a [u8],
-        ) -> Result<&'a [u8], error::ParseFromDescription>;
+        ) -> Result<&'a [u8], S::Error>;
+    }
+
+    /// What to do with an error that occurred while parsing an item. This allows the same
+    /// traversal to be used whether or not the location of the error is needed.
+    pub trait ErrorSink<'a, 'b> {
+        /// The error returned by the traversal.
+        type Error;
+
+        /// Create the error returned by the traversal. `at` is the input at the point the item
+        /// that failed began to be parsed, and `expected` is what was expected there.
+        fn error(
+            at: &'a [u8],
+            error: error::ParseFromDescription,
+            expected: Expected<'b>,
+        ) -> Self::Error;
+    }
+
+    /// An error sink that discards the location of the error.
+    #[derive(Debug)]
+    pub(super) struct Unlocated;
+
+    impl ErrorSink<'_, '_> for Unlocated {
+        type Error = error::ParseFromDescription;
+
+        fn error(_: &[u8], error: error::ParseFromDescription, _: Expected<'_>) -> Self::Error {
+            error
+        }
+    }
+
+    /// An error that occurred while parsing an item, along with where it occurred.
+    #[derive(Debug)]
+    pub(super) struct Located<'a, 'b> {
+        /// The input at the point the item that failed began to be parsed.
+        pub(crate) at: &'a [u8],
+        /// The error that occurred.
+        pub(crate) error: error::ParseFromDescription,
+        /// What was expected at that point.
+        pub(crate) expected: Expected<'b>,
+    }
+
+    impl<'a, 'b> ErrorSink<'a, 'b> for Located<'a, 'b> {
+        type Error = Self;
+
+        fn error(
+            at: &'a [u8],
+            error: error::ParseFromDescription,
+            expected: Expected<'b>,
+        ) -> Self::Error {
+            Self {
+                at,
+                error,
+                expected,
+            }
+        }
     }
 }
 
 impl sealed::AnyFormatItem for BorrowedFormatItem<'_> {
-    fn parse_item<'a>(
-        &self,
+    fn parse_item_with<'a, 'b, S: sealed::ErrorSink<'a, 'b>>(
+        &'b self,
         parsed: &mut Parsed,
         input: &'a [u8],
-    ) -> Result<&'a [u8], error::ParseFromDescription> {
+    ) -> Result<&'a [u8], S::Error> {
         match self {
-            Self::Literal(literal) => Parsed::parse_literal(input, literal),
-            Self::Component(component) => parsed.parse_component(input, *component),
-            Self::Compound(compound) => parsed.parse_items(input, compound),
-            Self::Optional(item) => parsed.parse_item(input, *item).or(Ok(input)),
+            Self::Literal(literal) => Parsed::parse_literal(input, literal)
+                .map_err(|error| S::error(input, error, Expected::Literal(literal))),
+            Self::Component(component) => parsed
+                .parse_component(input, *component)
+                .map_err(|error| S::error(input, error, Expected::Component(*component))),
+            Self::Compound(compound) => parsed.parse_items_with::<S>(input, compound),
+            Self::Optional(item) => item.parse_item_with::<S>(parsed, input).or(Ok(input)),
             Self::First(items) => {
                 let mut first_err = None;
 
                 for item in items.iter() {
-                    match parsed.parse_item(input, item) {
+                    match item.parse_item_with::<S>(parsed, input) {
                         Ok(remaining_input) => return Ok(remaining_input),
                         Err(err) if first_err.is_none() => first_err = Some(err),
                         Err(_) => {}
@@ -70,27 +137,41 @@ impl sealed::AnyFormatItem for BorrowedFormatItem<'_> {
                     None => Ok(input),
                 }
             }
+            Self::Conditional {
+                condition,
+                then,
+                otherwise,
+            } => otherwise
+                .parse_item_with::<S>(parsed, input)
+                .or_else(|err| {
+                    parsed
+                        .parse_conditional_then(input, *then, *condition)
+                        .map_err(|_| err)
+                }),
         }
     }
 }
 
 #[cfg(feature = "alloc")]
 impl sealed::AnyFormatItem for OwnedFormatItem {
-    fn parse_item<'a>(
-        &self,
+    fn parse_item_with<'a, 'b, S: sealed::ErrorSink<'a, 'b>>(
+        &'b self,
         parsed: &mut Parsed,
         input: &'a [u8],
-    ) -> Result<&'a [u8], error::ParseFromDescription> {
+    ) -> Result<&'a [u8], S::Error> {
         match self {
-            Self::Literal(literal) => Parsed::parse_literal(input, literal),
-            Self::Component(component) => parsed.parse_component(input, *component),
-            Self::Compound(compound) => parsed.parse_items(input, compound),
-            Self::Optional(item) => parsed.parse_item(input, item.as_ref()).or(Ok(input)),
+            Self::Literal(literal) => Parsed::parse_literal(input, literal)
+                .map_err(|error| S::error(input, error, Expected::Literal(literal))),
+            Self::Component(component) => parsed
+                .parse_component(input, *component)
+                .map_err(|error| S::error(input, error, Expected::Component(*component))),
+            Self::Compound(compound) => parsed.parse_items_with::<S>(input, compound),
+            Self::Optional(item) => item.parse_item_with::<S>(parsed, input).or(Ok(input)),
             Self::First(items) => {
                 let mut first_err = None;
 
                 for item in items.iter() {
-                    match parsed.parse_item(input, item) {
+                    match item.parse_item_with::<S>(parsed, input) {
                         Ok(remaining_input) => return Ok(remaining_input),
                         Err(err) if first_err.is_none() => first_err = Some(err),
                         Err(_) => {}
@@ -104,10 +185,30 @@ impl sealed::AnyFormatItem for OwnedFormatItem {
                     None => Ok(input),
                 }
             }
+            Self::Conditional {
+                condition,
+                then,
+                otherwise,
+            } => otherwise
+                .parse_item_with::<S>(parsed, input)
+                .or_else(|err| {
+                    parsed
+                        .parse_conditional_then(input, then.as_ref(), *condition)
+                        .map_err(|_| err)
+                }),
         }
     }
 }
 
+/// Whether an extension annotation can be acted on. Critical annotations that cannot be acted on
+/// are rejected, as required by RFC 9557.
+///
+/// Only the ISO 8601 calendar is supported, so the only annotations that can be acted on are those
+/// that request it.
+fn annotation_is_understood(key: &[u8], value: &[u8]) -> bool {
+    key == b"u-ca" && matches!(value, b"iso8601" | b"gregory")
+}
+
 /// All information parsed.
 ///
 /// This information is directly used to construct the final values.
@@ -122,26 +223,49 @@ pub struct Parsed {
     year_century: OptionRangedI16<{ (MIN_YEAR / 100) as i16 }, { (MAX_YEAR / 100) as i16 }>,
     /// The last two digits of the calendar year.
     year_last_two: OptionRangedU8<0, 99>,
+    /// Year of the era, where 1 BCE is the astronomical year zero. This is typically used in
+    /// conjunction with the era, which is indicated by the `era_is_bce` field.
+    year_of_era: OptionRangedU32<1, { (1 - MIN_YEAR) as u32 }>,
+    /// Whether the `year_of_era` field indicates a year before the common era.
+    era_is_bce: Option<bool>,
     /// Year of the [ISO week date](https://en.wikipedia.org/wiki/ISO_week_date).
     iso_year: OptionRangedI32<{ MIN_YEAR }, { MAX_YEAR }>,
     /// All digits except the last two of the ISO week year.
     iso_year_century: OptionRangedI16<{ (MIN_YEAR / 100) as i16 }, { (MAX_YEAR / 100) as i16 }>,
     /// The last two digits of the ISO week year.
     iso_year_last_two: OptionRangedU8<0, 99>,
+    /// How the last two digits of the year and ISO week year are resolved when the century is not
+    /// known.
+    year_pivot: modifier::YearPivot,
     /// Month of the year.
     month: Option<Month>,
+    /// Quarter of the year.
+    quarter: Option<Quarter>,
     /// Week of the year, where week one begins on the first Sunday of the calendar year.
     sunday_week_number: OptionRangedU8<0, 53>,
     /// Week of the year, where week one begins on the first Monday of the calendar year.
     monday_week_number: OptionRangedU8<0, 53>,
     /// Week of the year, where week one is the Monday-to-Sunday period containing January 4.
     iso_week_number: OptionRangedU8<1, 53>,
+    /// Week of the month, as determined by `week_of_month_first_weekday` and
+    /// `week_of_month_minimal_days`.
+    week_of_month: OptionRangedU8<0, 6>,
+    /// The day of the week that each week of the month begins on.
+    week_of_month_first_weekday: Weekday,
+    /// The minimum number of days of the month in its first week.
+    week_of_month_minimal_days: RangedU8<1, 7>,
+    /// Occurrence of the weekday within the month, counted from the start of the month.
+    weekday_in_month: OptionRangedU8<1, 5>,
+    /// Occurrence of the weekday within the month, counted from the end of the month.
+    weekday_in_month_from_end: OptionRangedU8<1, 5>,
     /// Day of the week.
     weekday: Option<Weekday>,
     /// Day of the year.
     ordinal: OptionRangedU16<1, 366>,
     /// Day of the month.
     day: OptionRangedU8<1, 31>,
+    /// Day counted continuously from noon UTC on -4713-11-24.
+    julian_day: OptionRangedI32<{ Date::MIN.to_julian_day() }, { Date::MAX.to_julian_day() }>,
     /// Hour within the day.
     hour_24: OptionRangedU8<0, { Hour::per_t::<u8>(Day) - 1 }>,
     /// Hour within the 12-hour period (midnight to noon or vice versa). This is typically used in
@@ -202,16 +326,26 @@ impl Parsed {
             year: OptionRangedI32::None,
             year_century: OptionRangedI16::None,
             year_last_two: OptionRangedU8::None,
+            year_of_era: OptionRangedU32::None,
+            era_is_bce: None,
             iso_year: OptionRangedI32::None,
             iso_year_century: OptionRangedI16::None,
             iso_year_last_two: OptionRangedU8::None,
+            year_pivot: modifier::YearPivot::NONE,
             month: None,
+            quarter: None,
             sunday_week_number: OptionRangedU8::None,
             monday_week_number: OptionRangedU8::None,
             iso_week_number: OptionRangedU8::None,
+            week_of_month: OptionRangedU8::None,
+            week_of_month_first_weekday: Weekday::Monday,
+            week_of_month_minimal_days: RangedU8::new_static::<1>(),
+            weekday_in_month: OptionRangedU8::None,
+            weekday_in_month_from_end: OptionRangedU8::None,
             weekday: None,
             ordinal: OptionRangedU16::None,
             day: OptionRangedU8::None,
+            julian_day: OptionRangedI32::None,
             hour_24: OptionRangedU8::None,
             hour_12: OptionRangedU8::None,
             hour_12_is_pm: None,
@@ -239,7 +373,7 @@ impl Parsed {
         input: &'a [u8],
         item: &impl sealed::AnyFormatItem,
     ) -> Result<&'a [u8], error::ParseFromDescription> {
-        item.parse_item(self, input)
+        item.parse_item_with::<sealed::Unlocated>(self, input)
     }
 
     /// Parse a sequence of [`BorrowedFormatItem`]s or [`OwnedFormatItem`]s, mutating the struct.
@@ -249,19 +383,168 @@ impl Parsed {
     /// [`OwnedFormatItem`]s fail to parse. `self` will not be mutated in this instance.
     pub fn parse_items<'a>(
         &mut self,
-        mut input: &'a [u8],
+        input: &'a [u8],
         items: &[impl sealed::AnyFormatItem],
     ) -> Result<&'a [u8], error::ParseFromDescription> {
+        self.parse_items_with::<sealed::Unlocated>(input, items)
+    }
+
+    /// Parse a sequence of items, passing any error to the provided sink. `self` is only mutated
+    /// if all of the items are parsed successfully.
+    fn parse_items_with<'a, 'b, S: sealed::ErrorSink<'a, 'b>>(
+        &mut self,
+        mut input: &'a [u8],
+        items: &'b [impl sealed::AnyFormatItem],
+    ) -> Result<&'a [u8], S::Error> {
         // Make a copy that we can mutate. It will only be set to the user's copy if everything
         // succeeds.
         let mut this = *self;
         for item in items {
-            input = this.parse_item(input, item)?;
+            input = item.parse_item_with::<S>(&mut this, input)?;
         }
         *self = this;
         Ok(input)
     }
 
+    /// Parse the input using the first of the provided candidates that succeeds, returning its
+    /// index along with the parsed value.
+    ///
+    /// Candidates are attempted in order, and each must consume the entire input. Unlike
+    /// [`BorrowedFormatItem::First`], the error returned by every candidate is retained if none of
+    /// them succeed.
+    ///
+    /// ```rust
+    /// # use time::format_description::well_known::Rfc3339;
+    /// # use time::parsing::Parsed;
+    /// # use time::Date;
+    /// # use time_macros::{date, format_description};
+    /// let us = format_description!("[month]/[day]/[year]");
+    /// let eu = format_description!("[day].[month].[year]");
+    ///
+    /// let (index, parsed) = Parsed::parse_any(b"31.12.2024", &[&Rfc3339, &us, &eu]).unwrap();
+    /// assert_eq!(index, 2);
+    /// assert_eq!(Date::try_from(parsed)?, date!(2024-12-31));
+    ///
+    /// // Each candidate fails: `Rfc3339` requires a time, while the others require a different
+    /// // separator.
+    /// let err = Parsed::parse_any(b"2024-12-31", &[&Rfc3339, &us, &eu]).unwrap_err();
+    /// assert_eq!(err.errors().len(), 3);
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn parse_any(
+        input: &[u8],
+        candidates: &[&dyn Parsable],
+    ) -> Result<(usize, Self), error::ParseAny> {
+        let mut errors = Vec::with_capacity(candidates.len());
+        for (index, candidate) in candidates.iter().enumerate() {
+            match candidate.parse(input) {
+                Ok(parsed) => return Ok((index, parsed)),
+                Err(err) => errors.push(err),
+            }
+        }
+        Err(error::ParseAny {
+            errors: errors.into_boxed_slice(),
+        })
+    }
+
+    /// Parse the entirety of the input using the provided items, locating any error that occurs.
+    pub(crate) fn parse_items_with_diagnostic<'b>(
+        input: &[u8],
+        items: &'b [impl sealed::AnyFormatItem],
+    ) -> Result<Self, error::ParseDiagnostic<'b>> {
+        let mut parsed = Self::new();
+        match parsed.parse_items_with::<sealed::Located<'_, 'b>>(input, items) {
+            Ok([]) => Ok(parsed),
+            Ok(remaining) => Err(error::ParseDiagnostic::located(
+                input,
+                remaining,
+                error::ParseFromDescription::UnexpectedTrailingCharacters,
+                Expected::End,
+            )),
+            Err(sealed::Located {
+                at,
+                error,
+                expected,
+            }) => Err(error::ParseDiagnostic::located(input, at, error, expected)),
+        }
+    }
+
+    /// Parse the entirety of the input, returning a diagnostic that describes where and why
+    /// parsing failed if it does.
+    ///
+    /// The diagnostic includes the location of the error when a format description or well-known
+    /// format is used, and what was expected there when a format description is used. It can be
+    /// rendered with a caret under the failure point using
+    /// [`ParseDiagnostic::render`](error::ParseDiagnostic::render).
+    ///
+    /// ```rust
+    /// # use time::parsing::Parsed;
+    /// # use time::error::Expected;
+    /// # use time_macros::format_description;
+    /// let format = format_description!("[year]-[month]-[day]T[hour]:[minute]");
+    /// let err = Parsed::parse_with_diagnostic(b"2024-01-01 12:00", format).unwrap_err();
+    /// assert_eq!(err.span(), Some(10..11));
+    /// assert_eq!(err.expected(), Some(Expected::Literal(b"T")));
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "a character literal was not valid at byte 10; expected `T`"
+    /// );
+    /// ```
+    pub fn parse_with_diagnostic<'a>(
+        input: &[u8],
+        description: &'a (impl Parsable + ?Sized),
+    ) -> Result<Self, error::ParseDiagnostic<'a>> {
+        description.parse_with_diagnostic(input)
+    }
+
+    /// Parse the item used when a condition holds, setting the values implied by the condition
+    /// that were not otherwise parsed. `self` is only mutated if parsing succeeds.
+    fn parse_conditional_then<'a>(
+        &mut self,
+        input: &'a [u8],
+        then: &impl sealed::AnyFormatItem,
+        condition: Condition,
+    ) -> Result<&'a [u8], error::ParseFromDescription> {
+        let mut this = *self;
+        let remaining = this.parse_item(input, then)?;
+        match condition {
+            Condition::OffsetIsUtc => {
+                if this.offset_hour.is_none() {
+                    this.offset_hour = OptionRangedI8::Some(RangedI8::new_static::<0>());
+                }
+                if this.offset_minute.is_none() {
+                    this.offset_minute = OptionRangedI8::Some(RangedI8::new_static::<0>());
+                }
+                if this.offset_second.is_none() {
+                    this.offset_second = OptionRangedI8::Some(RangedI8::new_static::<0>());
+                }
+            }
+            Condition::OffsetMinuteIsZero => {
+                if this.offset_minute.is_none() {
+                    this.offset_minute = OptionRangedI8::Some(RangedI8::new_static::<0>());
+                }
+            }
+            Condition::OffsetSecondIsZero => {
+                if this.offset_second.is_none() {
+                    this.offset_second = OptionRangedI8::Some(RangedI8::new_static::<0>());
+                }
+            }
+            Condition::SecondIsZero => {
+                if this.second.is_none() {
+                    this.second = OptionRangedU8::Some(RangedU8::new_static::<0>());
+                }
+            }
+            Condition::SubsecondIsZero => {
+                if this.subsecond.is_none() {
+                    this.subsecond = OptionRangedU32::Some(RangedU32::new_static::<0>());
+                }
+            }
+        }
+        *self = this;
+        Ok(remaining)
+    }
+
     /// Parse a literal byte sequence. The remaining input is returned as the `Ok` value.
     pub fn parse_literal<'a>(
         input: &'a [u8],
@@ -279,20 +562,22 @@ impl Parsed {
         input: &'a [u8],
         component: Component,
     ) -> Result<&'a [u8], error::ParseFromDescription> {
-        use error::ParseFromDescription::InvalidComponent;
-
         match component {
             Component::Day(modifiers) => parse_day(input, modifiers)
                 .and_then(|parsed| parsed.consume_value(|value| self.set_day(value)))
                 .ok_or(InvalidComponent("day")),
             Component::Month(modifiers) => parse_month(input, modifiers)
-                .and_then(|parsed| parsed.consume_value(|value| self.set_month(value)))
+                .and_then(|parsed| {
+                    parsed.consume_value(|value| value.map_or(Some(()), |v| self.set_month(v)))
+                })
                 .ok_or(InvalidComponent("month")),
             Component::Ordinal(modifiers) => parse_ordinal(input, modifiers)
                 .and_then(|parsed| parsed.consume_value(|value| self.set_ordinal(value)))
                 .ok_or(InvalidComponent("ordinal")),
             Component::Weekday(modifiers) => parse_weekday(input, modifiers)
-                .and_then(|parsed| parsed.consume_value(|value| self.set_weekday(value)))
+                .and_then(|parsed| {
+                    parsed.consume_value(|value| value.map_or(Some(()), |v| self.set_weekday(v)))
+                })
                 .ok_or(InvalidComponent("weekday")),
             Component::WeekNumber(modifiers) => {
                 let ParsedItem(remaining, value) =
@@ -307,15 +592,50 @@ impl Parsed {
                 .ok_or(InvalidComponent("week number"))?;
                 Ok(remaining)
             }
+            Component::WeekOfMonth(modifiers) => {
+                let ParsedItem(remaining, value) = parse_week_of_month(input, modifiers)
+                    .ok_or(InvalidComponent("week of month"))?;
+                self.set_week_of_month(value)
+                    .ok_or(InvalidComponent("week of month"))?;
+                self.set_week_of_month_first_weekday(modifiers.first_weekday);
+                self.set_week_of_month_minimal_days(modifiers.minimal_days);
+                Ok(remaining)
+            }
+            Component::WeekdayInMonth(modifiers) => {
+                let ParsedItem(remaining, value) = parse_weekday_in_month(input, modifiers)
+                    .ok_or(InvalidComponent("weekday in month"))?;
+                if modifiers.from_end {
+                    self.set_weekday_in_month_from_end(value)
+                } else {
+                    self.set_weekday_in_month(value)
+                }
+                .ok_or(InvalidComponent("weekday in month"))?;
+                Ok(remaining)
+            }
+            Component::Quarter(modifiers) => parse_quarter(input, modifiers)
+                .and_then(|parsed| parsed.consume_value(|value| self.set_quarter(value)))
+                .ok_or(InvalidComponent("quarter")),
+            Component::Era(modifiers) => parse_era(input, modifiers)
+                .and_then(|parsed| {
+                    parsed.consume_value(|value| self.set_era_is_bce(value == Era::Bce))
+                })
+                .ok_or(InvalidComponent("era")),
             Component::Year(modifiers) => {
                 let ParsedItem(remaining, (value, is_negative)) =
                     parse_year(input, modifiers).ok_or(InvalidComponent("year"))?;
                 match (modifiers.iso_week_based, modifiers.repr) {
+                    (false, modifier::YearRepr::Full) if modifiers.era_based => {
+                        NonZero::new(value.cast_unsigned())
+                            .and_then(|value| self.set_year_of_era(value))
+                    }
                     (false, modifier::YearRepr::Full) => self.set_year(value),
                     (false, modifier::YearRepr::Century) => {
                         self.set_year_century(value.truncate(), is_negative)
                     }
                     (false, modifier::YearRepr::LastTwo) => {
+                        if modifiers.pivot != modifier::YearPivot::NONE {
+                            self.year_pivot = modifiers.pivot;
+                        }
                         self.set_year_last_two(value.cast_unsigned().truncate())
                     }
                     (true, modifier::YearRepr::Full) => self.set_iso_year(value),
@@ -323,6 +643,9 @@ impl Parsed {
                         self.set_iso_year_century(value.truncate(), is_negative)
                     }
                     (true, modifier::YearRepr::LastTwo) => {
+                        if modifiers.pivot != modifier::YearPivot::NONE {
+                            self.year_pivot = modifiers.pivot;
+                        }
                         self.set_iso_year_last_two(value.cast_unsigned().truncate())
                     }
                 }
@@ -381,11 +704,190 @@ impl Parsed {
                     parsed.consume_value(|value| self.set_unix_timestamp_nanos(value))
                 })
                 .ok_or(InvalidComponent("unix_timestamp")),
+            Component::JulianDay(modifiers) if modifiers.digits == 0 => {
+                parse_julian_day(input, modifiers)
+                    .and_then(|parsed| parsed.consume_value(|value| self.set_julian_day(value)))
+                    .ok_or(InvalidComponent("julian_day"))
+            }
+            Component::JulianDay(modifiers) => parse_julian_date(input, modifiers)
+                .and_then(|parsed| {
+                    parsed.consume_value(|value| self.set_unix_timestamp_nanos(value))
+                })
+                .ok_or(InvalidComponent("julian_day")),
             Component::End(modifiers) => parse_end(input, modifiers)
                 .map(ParsedItem::<()>::into_inner)
                 .ok_or(error::ParseFromDescription::UnexpectedTrailingCharacters),
+            Component::TimeZoneAnnotation(modifiers) => {
+                parse_time_zone_annotation(input, modifiers)
+                    .filter(|ParsedItem(_, (is_critical, value))| {
+                        !is_critical
+                            || self.offset_is_unknown()
+                            || self.time_zone_annotation_is_consistent(value)
+                    })
+                    .map(|ParsedItem(remaining, _)| remaining)
+                    .ok_or(InvalidComponent("time zone annotation"))
+            }
+            Component::ExtensionAnnotations(modifier::ExtensionAnnotations {}) => {
+                parse_extension_annotations(input).map(|ParsedItem(remaining, _)| remaining)
+            }
         }
     }
+
+    /// Parse the annotations defined in [RFC 9557] that follow a timestamp: an optional time zone
+    /// annotation and any number of extension annotations. The remaining input is returned along
+    /// with the annotations, which are borrowed from the input rather than stored in `self`.
+    ///
+    /// A critical time zone annotation with a numeric offset must agree with the UTC offset that
+    /// has already been parsed, unless that offset is unknown. Critical extension annotations must
+    /// be understood, and conflicting values for a key are rejected if either is critical. As only
+    /// the ISO 8601 calendar is supported, a critical `u-ca` annotation is accepted only if its
+    /// value is `iso8601` or `gregory`.
+    ///
+    /// ```rust
+    /// # use time::parsing::Parsed;
+    /// let (remaining, annotations) =
+    ///     Parsed::new().parse_annotations(b"[Europe/Paris][u-ca=hebrew]")?;
+    /// assert!(remaining.is_empty());
+    /// assert_eq!(annotations.time_zone_annotation(), Some("Europe/Paris"));
+    /// assert_eq!(annotations.annotation("u-ca"), Some("hebrew"));
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    ///
+    /// [RFC 9557]: https://datatracker.ietf.org/doc/html/rfc9557
+    pub fn parse_annotations<'a>(
+        &self,
+        input: &'a [u8],
+    ) -> Result<(&'a [u8], AnnotationSuffix<'a>), error::ParseFromDescription> {
+        self.parse_annotations_with(input, self.offset_is_unknown())
+    }
+
+    /// Parse a timestamp in the format of [RFC 9557], mutating the struct. The remaining input is
+    /// returned along with the annotations, which are borrowed from the input rather than stored in
+    /// `self`.
+    ///
+    /// This is the same as parsing with
+    /// [`Rfc9557`](crate::format_description::well_known::Rfc9557), except that the annotations
+    /// are made available and input may remain after parsing.
+    ///
+    /// ```rust
+    /// # use time::parsing::Parsed;
+    /// # use time::OffsetDateTime;
+    /// # use time_macros::datetime;
+    /// let mut parsed = Parsed::new();
+    /// let (remaining, annotations) =
+    ///     parsed.parse_rfc9557(b"2022-07-08T00:14:07+01:00[Europe/Paris][u-ca=hebrew]")?;
+    /// assert!(remaining.is_empty());
+    /// assert_eq!(annotations.time_zone_annotation(), Some("Europe/Paris"));
+    /// assert_eq!(annotations.annotation("u-ca"), Some("hebrew"));
+    /// assert_eq!(
+    ///     OffsetDateTime::try_from(parsed)?,
+    ///     datetime!(2022-07-08 00:14:07 +01:00)
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    ///
+    /// [RFC 9557]: https://datatracker.ietf.org/doc/html/rfc9557
+    pub fn parse_rfc9557<'a>(
+        &mut self,
+        input: &'a [u8],
+    ) -> Result<(&'a [u8], AnnotationSuffix<'a>), error::Parse> {
+        // Make a copy that we can mutate. It will only be set to the user's copy if everything
+        // succeeds.
+        let mut this = *self;
+        let parsed = crate::parsing::parsable::parse_rfc9557(input, &mut this)?;
+        *self = this;
+        Ok(parsed)
+    }
+
+    /// Parse the annotations that follow a timestamp, with the caller indicating whether the local
+    /// offset is unknown.
+    pub(crate) fn parse_annotations_with<'a>(
+        &self,
+        input: &'a [u8],
+        offset_is_unknown: bool,
+    ) -> Result<(&'a [u8], AnnotationSuffix<'a>), error::ParseFromDescription> {
+        let (input, time_zone) = match time_zone(input) {
+            Some(ParsedItem(input, (is_critical, value))) => {
+                if is_critical
+                    && !offset_is_unknown
+                    && !self.time_zone_annotation_is_consistent(value)
+                {
+                    return Err(InvalidComponent("time zone annotation"));
+                }
+                // The grammar only permits ASCII.
+                let Ok(value) = core::str::from_utf8(value) else {
+                    bug!("time zone annotations are ASCII");
+                };
+                (input, Some((value, is_critical)))
+            }
+            None => (input, None),
+        };
+        let ParsedItem(remaining, extensions) = parse_extension_annotations(input)?;
+        Ok((remaining, AnnotationSuffix::new(time_zone, extensions)))
+    }
+
+    /// Indicate that the local offset, which must already be set to zero, is unknown.
+    pub(crate) fn set_offset_is_unknown(&mut self) {
+        self.offset_is_negative = true;
+    }
+
+    /// Whether a time zone annotation is consistent with the parsed offset.
+    ///
+    /// Only numeric offsets can be checked, as there is no knowledge of time zone names. If the
+    /// offset is absent, there is no inconsistency.
+    fn time_zone_annotation_is_consistent(&self, annotation: &[u8]) -> bool {
+        let Some(offset_hour) = self.offset_hour() else {
+            return true;
+        };
+        let Some(ParsedItem(input, annotation_sign)) = sign(annotation) else {
+            return true;
+        };
+
+        let annotation = exactly_n_digits::<2, u8>(input).and_then(|ParsedItem(input, hour)| {
+            let ParsedItem(_, minute) = exactly_n_digits::<2, u8>(input.get(1..)?)?;
+            let (hour, minute) = (hour.cast_signed(), minute.cast_signed());
+            Some(if annotation_sign == b'-' {
+                (-hour, -minute)
+            } else {
+                (hour, minute)
+            })
+        });
+        let parsed = (
+            offset_hour,
+            self.offset_minute_signed().unwrap_or(0),
+            self.offset_second_signed().unwrap_or(0),
+        );
+
+        matches!(annotation, Some((hour, minute)) if parsed == (hour, minute, 0))
+    }
+}
+
+/// Parse any number of extension annotations, applying the rules of RFC 9557 for duplicate and
+/// critical annotations. The annotations are returned in their bracketed form.
+fn parse_extension_annotations(
+    input: &[u8],
+) -> Result<ParsedItem<'_, &[u8]>, error::ParseFromDescription> {
+    let mut remaining = input;
+    while let Some(ParsedItem(rest, (is_critical, key, value))) = suffix_tag(remaining) {
+        // An annotation that cannot be acted on is only an error if it is critical.
+        if is_critical && !annotation_is_understood(key, value) {
+            return Err(InvalidComponent("annotation"));
+        }
+        // Conflicting values for a key are only an error if either annotation is critical.
+        let preceding = AnnotationSuffix::new(None, &input[..input.len() - remaining.len()]);
+        if let Some(existing) = preceding.annotations().find(|existing| {
+            existing.key().as_bytes() == key && existing.value().as_bytes() != value
+        }) {
+            if is_critical || existing.is_critical() {
+                return Err(InvalidComponent("annotation"));
+            }
+        }
+        remaining = rest;
+    }
+    Ok(ParsedItem(
+        remaining,
+        &input[..input.len() - remaining.len()],
+    ))
 }
 
 /// Getter methods
@@ -419,6 +921,16 @@ impl Parsed {
         self.year_last_two.get_primitive()
     }
 
+    /// Obtain the `year_of_era` component.
+    pub const fn year_of_era(&self) -> Option<NonZero<u32>> {
+        NonZero::new(const_try_opt!(self.year_of_era.get_primitive()))
+    }
+
+    /// Obtain the `era_is_bce` component.
+    pub const fn era_is_bce(&self) -> Option<bool> {
+        self.era_is_bce
+    }
+
     /// Obtain the `iso_year` component.
     pub const fn iso_year(&self) -> Option<i32> {
         self.iso_year.get_primitive()
@@ -448,11 +960,22 @@ impl Parsed {
         self.iso_year_last_two.get_primitive()
     }
 
+    /// Obtain the pivot used to resolve `year_last_two` and `iso_year_last_two` when the century
+    /// is not known.
+    pub const fn year_pivot(&self) -> modifier::YearPivot {
+        self.year_pivot
+    }
+
     /// Obtain the `month` component.
     pub const fn month(&self) -> Option<Month> {
         self.month
     }
 
+    /// Obtain the `quarter` component.
+    pub const fn quarter(&self) -> Option<Quarter> {
+        self.quarter
+    }
+
     /// Obtain the `sunday_week_number` component.
     pub const fn sunday_week_number(&self) -> Option<u8> {
         self.sunday_week_number.get_primitive()
@@ -468,6 +991,35 @@ impl Parsed {
         NonZero::new(const_try_opt!(self.iso_week_number.get_primitive()))
     }
 
+    /// Obtain the `week_of_month` component.
+    pub const fn week_of_month(&self) -> Option<u8> {
+        self.week_of_month.get_primitive()
+    }
+
+    /// Obtain the day of the week that each week of the month begins on, as used to resolve
+    /// `week_of_month`.
+    pub const fn week_of_month_first_weekday(&self) -> Weekday {
+        self.week_of_month_first_weekday
+    }
+
+    /// Obtain the minimum number of days of the month in its first week, as used to resolve
+    /// `week_of_month`.
+    pub const fn week_of_month_minimal_days(&self) -> u8 {
+        self.week_of_month_minimal_days.get()
+    }
+
+    /// Obtain the `weekday_in_month` component.
+    pub const fn weekday_in_month(&self) -> Option<NonZero<u8>> {
+        NonZero::new(const_try_opt!(self.weekday_in_month.get_primitive()))
+    }
+
+    /// Obtain the `weekday_in_month_from_end` component.
+    pub const fn weekday_in_month_from_end(&self) -> Option<NonZero<u8>> {
+        NonZero::new(const_try_opt!(self
+            .weekday_in_month_from_end
+            .get_primitive()))
+    }
+
     /// Obtain the `weekday` component.
     pub const fn weekday(&self) -> Option<Weekday> {
         self.weekday
@@ -483,6 +1035,11 @@ impl Parsed {
         NonZero::new(const_try_opt!(self.day.get_primitive()))
     }
 
+    /// Obtain the `julian_day` component.
+    pub const fn julian_day(&self) -> Option<i32> {
+        self.julian_day.get_primitive()
+    }
+
     /// Obtain the `hour_24` component.
     pub const fn hour_24(&self) -> Option<u8> {
         self.hour_24.get_primitive()
@@ -550,6 +1107,19 @@ impl Parsed {
         }
     }
 
+    /// Obtain whether the input indicated that the local offset is unknown.
+    ///
+    /// This is the case when the offset is negative zero, such as `-0000` or `-00:00`, and for the
+    /// military time zones of [`Rfc2822`](crate::format_description::well_known::Rfc2822). The
+    /// offset components are zero when this is `true`, so the value is otherwise
+    /// indistinguishable from an explicit UTC offset.
+    pub const fn offset_is_unknown(&self) -> bool {
+        self.offset_is_negative
+            && matches!(self.offset_hour.get_primitive(), Some(0))
+            && matches!(self.offset_minute.get_primitive(), Some(0) | None)
+            && matches!(self.offset_second.get_primitive(), Some(0) | None)
+    }
+
     /// Obtain the `unix_timestamp_nanos` component.
     pub const fn unix_timestamp_nanos(&self) -> Option<i128> {
         self.unix_timestamp_nanos.get_primitive()
@@ -592,6 +1162,8 @@ impl Parsed {
 
     setters! {
         year_last_two set_year_last_two with_year_last_two u8;
+        year_of_era set_year_of_era with_year_of_era NonZero<u32>;
+        era_is_bce set_era_is_bce with_era_is_bce bool;
         iso_year set_iso_year with_iso_year i32;
         iso_year_last_two set_iso_year_last_two with_iso_year_last_two u8;
     }
@@ -610,14 +1182,45 @@ impl Parsed {
         Some(())
     }
 
+    /// Set the pivot used to resolve `year_last_two` and `iso_year_last_two` when the century is
+    /// not known.
+    pub fn set_year_pivot(&mut self, value: modifier::YearPivot) -> Option<()> {
+        self.year_pivot = value;
+        Some(())
+    }
+
     setters! {
         month set_month with_month Month;
+        quarter set_quarter with_quarter Quarter;
         sunday_week_number set_sunday_week_number with_sunday_week_number u8;
         monday_week_number set_monday_week_number with_monday_week_number u8;
         iso_week_number set_iso_week_number with_iso_week_number NonZero<u8>;
+        week_of_month set_week_of_month with_week_of_month u8;
+    }
+
+    /// Set the day of the week that each week of the month begins on, as used to resolve
+    /// `week_of_month`.
+    pub fn set_week_of_month_first_weekday(&mut self, value: Weekday) -> Option<()> {
+        self.week_of_month_first_weekday = value;
+        Some(())
+    }
+
+    /// Set the minimum number of days of the month in its first week, as used to resolve
+    /// `week_of_month`. A value of zero is treated as one, and values greater than seven are
+    /// treated as seven.
+    pub fn set_week_of_month_minimal_days(&mut self, value: u8) -> Option<()> {
+        *self = self.with_week_of_month_minimal_days(value)?;
+        Some(())
+    }
+
+    setters! {
+        weekday_in_month set_weekday_in_month with_weekday_in_month NonZero<u8>;
+        weekday_in_month_from_end set_weekday_in_month_from_end with_weekday_in_month_from_end
+            NonZero<u8>;
         weekday set_weekday with_weekday Weekday;
         ordinal set_ordinal with_ordinal NonZero<u16>;
         day set_day with_day NonZero<u8>;
+        julian_day set_julian_day with_julian_day i32;
         hour_24 set_hour_24 with_hour_24 u8;
         hour_12 set_hour_12 with_hour_12 NonZero<u8>;
         hour_12_is_pm set_hour_12_is_pm with_hour_12_is_pm bool;
@@ -690,6 +1293,18 @@ impl Parsed {
         Some(self)
     }
 
+    /// Set the `year_of_era` component and return `self`.
+    pub const fn with_year_of_era(mut self, value: NonZero<u32>) -> Option<Self> {
+        self.year_of_era = OptionRangedU32::Some(const_try_opt!(RangedU32::new(value.get())));
+        Some(self)
+    }
+
+    /// Set the `era_is_bce` component and return `self`.
+    pub const fn with_era_is_bce(mut self, value: bool) -> Option<Self> {
+        self.era_is_bce = Some(value);
+        Some(self)
+    }
+
     /// Set the `iso_year` component and return `self`.
     pub const fn with_iso_year(mut self, value: i32) -> Option<Self> {
         self.iso_year = OptionRangedI32::Some(const_try_opt!(RangedI32::new(value)));
@@ -710,6 +1325,13 @@ impl Parsed {
         Some(self)
     }
 
+    /// Set the pivot used to resolve `year_last_two` and `iso_year_last_two` when the century is
+    /// not known, and return `self`.
+    pub const fn with_year_pivot(mut self, value: modifier::YearPivot) -> Option<Self> {
+        self.year_pivot = value;
+        Some(self)
+    }
+
     /// Set the `iso_year_last_two` component and return `self`.
     pub const fn with_iso_year_last_two(mut self, value: u8) -> Option<Self> {
         self.iso_year_last_two = OptionRangedU8::Some(const_try_opt!(RangedU8::new(value)));
@@ -722,6 +1344,12 @@ impl Parsed {
         Some(self)
     }
 
+    /// Set the `quarter` component and return `self`.
+    pub const fn with_quarter(mut self, value: Quarter) -> Option<Self> {
+        self.quarter = Some(value);
+        Some(self)
+    }
+
     /// Set the `sunday_week_number` component and return `self`.
     pub const fn with_sunday_week_number(mut self, value: u8) -> Option<Self> {
         self.sunday_week_number = OptionRangedU8::Some(const_try_opt!(RangedU8::new(value)));
@@ -740,6 +1368,44 @@ impl Parsed {
         Some(self)
     }
 
+    /// Set the `week_of_month` component and return `self`.
+    pub const fn with_week_of_month(mut self, value: u8) -> Option<Self> {
+        self.week_of_month = OptionRangedU8::Some(const_try_opt!(RangedU8::new(value)));
+        Some(self)
+    }
+
+    /// Set the day of the week that each week of the month begins on, as used to resolve
+    /// `week_of_month`, and return `self`.
+    pub const fn with_week_of_month_first_weekday(mut self, value: Weekday) -> Option<Self> {
+        self.week_of_month_first_weekday = value;
+        Some(self)
+    }
+
+    /// Set the minimum number of days of the month in its first week, as used to resolve
+    /// `week_of_month`, and return `self`. A value of zero is treated as one, and values greater
+    /// than seven are treated as seven.
+    pub const fn with_week_of_month_minimal_days(mut self, value: u8) -> Option<Self> {
+        self.week_of_month_minimal_days = match RangedU8::new(value) {
+            Some(value) => value,
+            None if value == 0 => RangedU8::MIN,
+            None => RangedU8::MAX,
+        };
+        Some(self)
+    }
+
+    /// Set the `weekday_in_month` component and return `self`.
+    pub const fn with_weekday_in_month(mut self, value: NonZero<u8>) -> Option<Self> {
+        self.weekday_in_month = OptionRangedU8::Some(const_try_opt!(RangedU8::new(value.get())));
+        Some(self)
+    }
+
+    /// Set the `weekday_in_month_from_end` component and return `self`.
+    pub const fn with_weekday_in_month_from_end(mut self, value: NonZero<u8>) -> Option<Self> {
+        self.weekday_in_month_from_end =
+            OptionRangedU8::Some(const_try_opt!(RangedU8::new(value.get())));
+        Some(self)
+    }
+
     /// Set the `weekday` component and return `self`.
     pub const fn with_weekday(mut self, value: Weekday) -> Option<Self> {
         self.weekday = Some(value);
@@ -758,6 +1424,12 @@ impl Parsed {
         Some(self)
     }
 
+    /// Set the `julian_day` component and return `self`.
+    pub const fn with_julian_day(mut self, value: i32) -> Option<Self> {
+        self.julian_day = OptionRangedI32::Some(const_try_opt!(RangedI32::new(value)));
+        Some(self)
+    }
+
     /// Set the `hour_24` component and return `self`.
     pub const fn with_hour_24(mut self, value: u8) -> Option<Self> {
         self.hour_24 = OptionRangedU8::Some(const_try_opt!(RangedU8::new(value)));
@@ -857,7 +1529,7 @@ impl TryFrom<Parsed> for Date {
                 $catch_all
             };
             (($($name:ident),* $(,)?) => $arm:expr, $($rest:tt)*) => {
-                if let ($(Some($name)),*) = ($(parsed.$name()),*) {
+                if let ($(Some($name),)*) = ($(parsed.$name(),)*) {
                     $arm
                 } else {
                     match_!($($rest)*)
@@ -880,6 +1552,12 @@ impl TryFrom<Parsed> for Date {
             }
         }
 
+        /// Get the number of days from `start` until the next occurrence of `weekday`, which is
+        /// zero if they are the same.
+        const fn days_until(start: Weekday, weekday: Weekday) -> u8 {
+            (weekday.number_days_from_monday() + 7 - start.number_days_from_monday()) % 7
+        }
+
         // If we do not have the year but we have *both* the century and the last two digits, we can
         // construct the year. Likewise for the ISO year.
         if let (None, Some(century), Some(is_negative), Some(last_two)) = (
@@ -909,7 +1587,67 @@ impl TryFrom<Parsed> for Date {
             parsed.iso_year = OptionRangedI32::from(RangedI32::new(iso_year));
         }
 
+        // If the century is not known at all, the pivot may be able to resolve the year. A sliding
+        // pivot is relative to the current year, which is only known if the `std` feature is
+        // enabled.
+        #[cfg(feature = "std")]
+        let resolve = |last_two| {
+            parsed
+                .year_pivot
+                .resolve_with_reference(last_two, OffsetDateTime::now_utc().year())
+        };
+        #[cfg(not(feature = "std"))]
+        let resolve = |last_two| parsed.year_pivot.resolve(last_two);
+        if let (None, None, Some(last_two)) =
+            (parsed.year(), parsed.year_century(), parsed.year_last_two())
+        {
+            if let Some(year) = resolve(last_two) {
+                parsed.year = OptionRangedI32::from(RangedI32::new(year));
+            }
+        }
+        if let (None, None, Some(last_two)) = (
+            parsed.iso_year(),
+            parsed.iso_year_century(),
+            parsed.iso_year_last_two(),
+        ) {
+            if let Some(iso_year) = resolve(last_two) {
+                parsed.iso_year = OptionRangedI32::from(RangedI32::new(iso_year));
+            }
+        }
+
+        // If the year is only known within its era, the era determines the year. Absent an era, the
+        // common era is assumed.
+        if let (None, Some(year_of_era)) = (parsed.year(), parsed.year_of_era()) {
+            let year_of_era = year_of_era.get().cast_signed();
+            let year = if parsed.era_is_bce() == Some(true) {
+                1 - year_of_era
+            } else {
+                year_of_era
+            };
+            parsed.year = OptionRangedI32::from(RangedI32::new(year));
+        }
+        // A year that was parsed on its own must agree with the era, if one was parsed.
+        if let (Some(year), Some(era_is_bce)) = (parsed.year(), parsed.era_is_bce()) {
+            if (year <= 0) != era_is_bce {
+                let (minimum, maximum, conditional_message) = if era_is_bce {
+                    (MIN_YEAR, 0, "because the era is before the common era")
+                } else {
+                    (1, MAX_YEAR, "because the era is the common era")
+                };
+                return Err(error::TryFromParsed::ComponentRange(
+                    error::ComponentRange {
+                        name: "year",
+                        minimum: minimum.extend(),
+                        maximum: maximum.extend(),
+                        value: year.extend(),
+                        conditional_message: Some(conditional_message),
+                    },
+                ));
+            }
+        }
+
         match_! {
+            (julian_day) => Ok(Self::from_julian_day(julian_day)?),
             (year, ordinal) => Ok(Self::from_ordinal_date(year, ordinal.get())?),
             (year, month, day) => Ok(Self::from_calendar_date(year, month, day.get())?),
             (iso_year, iso_week_number, weekday) => Ok(Self::from_iso_week_date(
@@ -931,6 +1669,48 @@ impl TryFrom<Parsed> for Date {
                     - adjustment(year)
                     + 1).cast_unsigned(),
             )?),
+            (year, month, week_of_month, weekday) => {
+                let first_weekday = parsed.week_of_month_first_weekday();
+                let offset = days_until(
+                    first_weekday,
+                    Self::from_calendar_date(year, month, 1)?.weekday(),
+                );
+                let first_week = i8::from(7 - offset >= parsed.week_of_month_minimal_days());
+                Ok(Self::from_calendar_date(
+                    year,
+                    month,
+                    ((week_of_month.cast_signed() - first_week) * 7
+                        + days_until(first_weekday, weekday).cast_signed()
+                        - offset.cast_signed()
+                        + 1)
+                    .cast_unsigned(),
+                )?)
+            },
+            (year, month, weekday_in_month, weekday) => Ok(Self::from_calendar_date(
+                year,
+                month,
+                days_until(Self::from_calendar_date(year, month, 1)?.weekday(), weekday)
+                    + (weekday_in_month.get() - 1) * 7
+                    + 1,
+            )?),
+            (year, month, weekday_in_month_from_end, weekday) => {
+                let length = month.length(year);
+                let last_weekday = Self::from_calendar_date(year, month, length)?.weekday();
+                Ok(Self::from_calendar_date(
+                    year,
+                    month,
+                    (length.cast_signed()
+                        - days_until(weekday, last_weekday).cast_signed()
+                        - (weekday_in_month_from_end.get() - 1).cast_signed() * 7)
+                        .cast_unsigned(),
+                )?)
+            },
+            // Without any more specific information, a quarter refers to its first day. A day of
+            // the month cannot be placed without knowing the month.
+            (year, quarter) => match parsed.day() {
+                Some(_) => Err(InsufficientInformation),
+                None => Ok(Self::from_quarter_date(year, quarter, 1)?),
+            },
             _ => Err(InsufficientInformation),
         }
     }
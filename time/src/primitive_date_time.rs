@@ -8,16 +8,19 @@ use core::time::Duration as StdDuration;
 #[cfg(feature = "formatting")]
 use std::io;
 
+use num_conv::prelude::*;
 use powerfmt::ext::FormatterExt as _;
 use powerfmt::smart_display::{self, FormatterOptions, Metadata, SmartDisplay};
 
-#[cfg(feature = "formatting")]
-use crate::formatting::Formattable;
+use crate::convert::*;
+#[cfg(feature = "formatting-core")]
+use crate::formatting::{self, FmtOutput, Formattable, SliceOutput};
 use crate::internal_macros::{const_try, const_try_opt};
 #[cfg(feature = "parsing")]
 use crate::parsing::Parsable;
 use crate::{
-    error, util, Date, Duration, Month, OffsetDateTime, Time, UtcDateTime, UtcOffset, Weekday,
+    error, util, Date, Duration, Month, OffsetDateTime, SerialDateSystem, Time, UtcDateTime,
+    UtcOffset, Weekday,
 };
 
 /// Combined date and time.
@@ -106,6 +109,97 @@ impl PrimitiveDateTime {
         Self { date, time }
     }
 
+    /// Create a `PrimitiveDateTime` from its serial number in the provided [`SerialDateSystem`], as
+    /// used by spreadsheet applications. The integer part of the serial number indicates the date
+    /// and the fractional part indicates the time of day.
+    ///
+    /// Spreadsheet applications store times with millisecond precision, so the time is rounded to
+    /// the nearest millisecond. An error is returned if the serial number is not finite, or if the
+    /// date cannot be created using [`Date::from_serial_date`].
+    ///
+    /// ```rust
+    /// # use time::{PrimitiveDateTime, SerialDateSystem};
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     PrimitiveDateTime::from_serial_date(45_292.75, SerialDateSystem::Excel1900),
+    ///     Ok(datetime!(2024-01-01 18:00))
+    /// );
+    /// assert_eq!(
+    ///     PrimitiveDateTime::from_serial_date(-1.25, SerialDateSystem::OleAutomation),
+    ///     Ok(datetime!(1899-12-29 6:00))
+    /// );
+    /// assert_eq!(
+    ///     PrimitiveDateTime::from_serial_date(-1.25, SerialDateSystem::Excel1904),
+    ///     Ok(datetime!(1903-12-30 18:00))
+    /// );
+    /// ```
+    pub fn from_serial_date(
+        serial: f64,
+        system: SerialDateSystem,
+    ) -> Result<Self, error::ComponentRange> {
+        let millis_per_day = Millisecond::per_t::<i64>(Day);
+        let out_of_range = error::ComponentRange {
+            name: "serial",
+            minimum: Date::MIN.to_serial_date(system).extend(),
+            maximum: Date::MAX.to_serial_date(system).extend(),
+            value: serial as i64,
+            conditional_message: None,
+        };
+
+        if !serial.is_finite() {
+            return Err(out_of_range);
+        }
+
+        // The casts truncate toward zero and saturate, so large values remain out of range.
+        let (days, millis) = match system {
+            // The integer part is the date and the fractional part is the time of day, regardless
+            // of the sign. The time is rounded to the nearest millisecond, and a time that rounds
+            // to midnight is carried into the following day.
+            SerialDateSystem::OleAutomation => {
+                let days = serial as i64;
+                let fraction = serial - days as f64;
+                let fraction = if fraction < 0. { -fraction } else { fraction };
+                let millis = (fraction * millis_per_day as f64 + 0.5) as i64;
+                (
+                    days.saturating_add(millis / millis_per_day),
+                    millis % millis_per_day,
+                )
+            }
+            // Round half away from zero.
+            SerialDateSystem::Excel1900 | SerialDateSystem::Excel1904 => {
+                let millis = serial * millis_per_day as f64;
+                let millis = if millis < 0. {
+                    (millis - 0.5) as i64
+                } else {
+                    (millis + 0.5) as i64
+                };
+                (
+                    millis.div_euclid(millis_per_day),
+                    millis.rem_euclid(millis_per_day),
+                )
+            }
+        };
+        let Ok(days) = i32::try_from(days) else {
+            return Err(out_of_range);
+        };
+        let date = Date::from_serial_date(days, system)?;
+
+        let millis = millis.cast_unsigned().truncate::<u32>();
+        // Safety: The number of milliseconds is less than one day, so all components are in range.
+        let time = unsafe {
+            Time::__from_hms_nanos_unchecked(
+                (millis / Millisecond::per_t::<u32>(Hour)).truncate(),
+                (millis / Millisecond::per_t::<u32>(Minute) % Minute::per_t::<u32>(Hour))
+                    .truncate(),
+                (millis / Millisecond::per_t::<u32>(Second) % Second::per_t::<u32>(Minute))
+                    .truncate(),
+                millis % Millisecond::per_t::<u32>(Second) * Nanosecond::per_t::<u32>(Millisecond),
+            )
+        };
+
+        Ok(Self::new(date, time))
+    }
+
     /// Get the [`Date`] component of the `PrimitiveDateTime`.
     ///
     /// ```rust
@@ -314,6 +408,40 @@ impl PrimitiveDateTime {
         self.date().to_julian_day()
     }
 
+    /// Get the serial number of the date and time in the provided [`SerialDateSystem`], as used by
+    /// spreadsheet applications. The integer part of the serial number indicates the date and the
+    /// fractional part indicates the time of day.
+    ///
+    /// ```rust
+    /// # use time::SerialDateSystem;
+    /// # use time_macros::datetime;
+    /// assert_eq!(
+    ///     datetime!(2024-01-01 18:00).to_serial_date(SerialDateSystem::Excel1900),
+    ///     45_292.75
+    /// );
+    /// assert_eq!(
+    ///     datetime!(1899-12-29 6:00).to_serial_date(SerialDateSystem::OleAutomation),
+    ///     -1.25
+    /// );
+    /// ```
+    pub fn to_serial_date(self, system: SerialDateSystem) -> f64 {
+        let days = self.date().to_serial_date(system);
+        let (hour, minute, second, nanosecond) = self.as_hms_nano();
+        let nanos = hour.extend::<u64>() * Nanosecond::per_t::<u64>(Hour)
+            + minute.extend::<u64>() * Nanosecond::per_t::<u64>(Minute)
+            + second.extend::<u64>() * Nanosecond::per_t::<u64>(Second)
+            + nanosecond.extend::<u64>();
+        let fraction = nanos as f64 / Nanosecond::per_t::<u64>(Day) as f64;
+
+        match system {
+            // The fractional part is the time of day regardless of the sign.
+            SerialDateSystem::OleAutomation if days < 0 => days as f64 - fraction,
+            SerialDateSystem::OleAutomation
+            | SerialDateSystem::Excel1900
+            | SerialDateSystem::Excel1904 => days as f64 + fraction,
+        }
+    }
+
     /// Get the clock hour, minute, and second.
     ///
     /// ```rust
@@ -849,16 +977,17 @@ impl PrimitiveDateTime {
     }
 }
 
-#[cfg(feature = "formatting")]
+#[cfg(feature = "formatting-core")]
 impl PrimitiveDateTime {
     /// Format the `PrimitiveDateTime` using the provided [format
     /// description](crate::format_description).
+    #[cfg(feature = "formatting")]
     pub fn format_into(
         self,
         output: &mut (impl io::Write + ?Sized),
         format: &(impl Formattable + ?Sized),
     ) -> Result<usize, error::Format> {
-        format.format_into(output, Some(self.date), Some(self.time), None)
+        formatting::format_value_into_io(self, output, format)
     }
 
     /// Format the `PrimitiveDateTime` using the provided [format
@@ -874,8 +1003,55 @@ impl PrimitiveDateTime {
     /// );
     /// # Ok::<_, time::Error>(())
     /// ```
+    #[cfg(feature = "formatting")]
     pub fn format(self, format: &(impl Formattable + ?Sized)) -> Result<String, error::Format> {
-        format.format(Some(self.date), Some(self.time), None)
+        formatting::format_value_to_string(self, format)
+    }
+
+    /// Format the `PrimitiveDateTime` using the provided [format
+    /// description](crate::format_description), writing the output to a [`fmt::Write`]. This does
+    /// not require `std` or `alloc`.
+    ///
+    /// As `fmt::Write` only accepts strings, an error is returned if the format description
+    /// contains a literal that is not valid UTF-8.
+    ///
+    /// ```rust
+    /// # use time_macros::{datetime, format_description};
+    /// let format = format_description!("[year]-[month]-[day] [hour]:[minute]:[second]");
+    /// let mut output = String::new();
+    /// datetime!(2020-01-02 03:04:05).format_into_fmt(&mut output, format)?;
+    /// assert_eq!(output, "2020-01-02 03:04:05");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_fmt(
+        self,
+        output: &mut (impl fmt::Write + ?Sized),
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::FormatCore> {
+        formatting::format_value(self, &mut FmtOutput(output), format)
+    }
+
+    /// Format the `PrimitiveDateTime` using the provided [format
+    /// description](crate::format_description), writing the output to the start of the provided
+    /// buffer. The number of bytes written is returned. This does not require `std` or `alloc`.
+    ///
+    /// If the buffer is too small, [`error::FormatCore::InsufficientBufferSize`] is returned. The
+    /// contents of the buffer are unspecified in this situation.
+    ///
+    /// ```rust
+    /// # use time_macros::{datetime, format_description};
+    /// let format = format_description!("[year]-[month]-[day] [hour]:[minute]:[second]");
+    /// let mut buf = [0; 64];
+    /// let len = datetime!(2020-01-02 03:04:05).format_to_slice(&mut buf, format)?;
+    /// assert_eq!(&buf[..len], b"2020-01-02 03:04:05");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_to_slice(
+        self,
+        output: &mut [u8],
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::FormatCore> {
+        formatting::format_value(self, &mut SliceOutput::new(output), format)
     }
 }
 
@@ -900,6 +1076,53 @@ impl PrimitiveDateTime {
     ) -> Result<Self, error::Parse> {
         description.parse_primitive_date_time(input.as_bytes())
     }
+
+    /// Parse a `PrimitiveDateTime` from the start of the input using the provided [format
+    /// description](crate::format_description), returning it along with the remaining input.
+    ///
+    /// Unlike [`PrimitiveDateTime::parse`], it is not an error for input to remain after parsing.
+    /// This is useful when the value is followed by other data, such as in a log line.
+    ///
+    /// ```rust
+    /// # use time::PrimitiveDateTime;
+    /// # use time_macros::{datetime, format_description};
+    /// let format = format_description!("[year]-[month]-[day] [hour]:[minute]:[second]");
+    /// assert_eq!(
+    ///     PrimitiveDateTime::parse_prefix(b"2020-01-02 03:04:05 INFO", &format)?,
+    ///     (datetime!(2020-01-02 03:04:05), b" INFO".as_slice())
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_prefix<'a>(
+        input: &'a [u8],
+        description: &(impl Parsable + ?Sized),
+    ) -> Result<(Self, &'a [u8]), error::Parse> {
+        let (parsed, remaining) = description.parse_prefix(input)?;
+        Ok((parsed.try_into()?, remaining))
+    }
+
+    /// Parse a `PrimitiveDateTime` from the input using the provided [format
+    /// description](crate::format_description), returning a diagnostic that describes where and why
+    /// parsing failed if it does.
+    ///
+    /// See [`Parsed::parse_with_diagnostic`](crate::parsing::Parsed::parse_with_diagnostic) for
+    /// details.
+    ///
+    /// ```rust
+    /// # use time::PrimitiveDateTime;
+    /// # use time_macros::format_description;
+    /// let format = format_description!("[year]-[month]-[day] [hour]:[minute]");
+    /// let err = PrimitiveDateTime::parse_with_diagnostic("2020-01-02T03:04", &format).unwrap_err();
+    /// assert_eq!(err.span(), Some(10..11));
+    /// ```
+    pub fn parse_with_diagnostic<'a>(
+        input: &str,
+        description: &'a (impl Parsable + ?Sized),
+    ) -> Result<Self, error::ParseDiagnostic<'a>> {
+        Ok(description
+            .parse_with_diagnostic(input.as_bytes())?
+            .try_into()?)
+    }
 }
 
 impl SmartDisplay for PrimitiveDateTime {
@@ -0,0 +1,185 @@
+//! The `Quarter` enum and its associated `impl`s.
+
+use core::fmt;
+use core::num::NonZero;
+use core::str::FromStr;
+
+use powerfmt::smart_display::{FormatterOptions, Metadata, SmartDisplay};
+
+use self::Quarter::*;
+use crate::{error, Month};
+
+/// Quarters of the year.
+///
+/// Each quarter is three consecutive months, with the first quarter beginning in January.
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Quarter {
+    /// January through March.
+    First = 1,
+    /// April through June.
+    Second = 2,
+    /// July through September.
+    Third = 3,
+    /// October through December.
+    Fourth = 4,
+}
+
+impl Quarter {
+    /// Create a `Quarter` from its numerical value.
+    pub(crate) const fn from_number(n: NonZero<u8>) -> Result<Self, error::ComponentRange> {
+        match n.get() {
+            1 => Ok(First),
+            2 => Ok(Second),
+            3 => Ok(Third),
+            4 => Ok(Fourth),
+            n => Err(error::ComponentRange {
+                name: "quarter",
+                minimum: 1,
+                maximum: 4,
+                value: n as i64,
+                conditional_message: None,
+            }),
+        }
+    }
+
+    /// Get the first month of the quarter.
+    ///
+    /// ```rust
+    /// # use time::{Month, Quarter};
+    /// assert_eq!(Quarter::Third.first_month(), Month::July);
+    /// ```
+    pub const fn first_month(self) -> Month {
+        match self {
+            First => Month::January,
+            Second => Month::April,
+            Third => Month::July,
+            Fourth => Month::October,
+        }
+    }
+
+    /// Get the last month of the quarter.
+    ///
+    /// ```rust
+    /// # use time::{Month, Quarter};
+    /// assert_eq!(Quarter::Third.last_month(), Month::September);
+    /// ```
+    pub const fn last_month(self) -> Month {
+        match self {
+            First => Month::March,
+            Second => Month::June,
+            Third => Month::September,
+            Fourth => Month::December,
+        }
+    }
+
+    /// Get the number of days in the quarter of a given year.
+    ///
+    /// ```rust
+    /// # use time::Quarter;
+    /// assert_eq!(Quarter::First.length(2019), 90);
+    /// assert_eq!(Quarter::First.length(2020), 91);
+    /// assert_eq!(Quarter::Fourth.length(2020), 92);
+    /// ```
+    pub const fn length(self, year: i32) -> u8 {
+        let first_month = self.first_month();
+        first_month.length(year) + first_month.next().length(year) + self.last_month().length(year)
+    }
+
+    /// Get the previous quarter.
+    ///
+    /// ```rust
+    /// # use time::Quarter;
+    /// assert_eq!(Quarter::First.previous(), Quarter::Fourth);
+    /// ```
+    pub const fn previous(self) -> Self {
+        match self {
+            First => Fourth,
+            Second => First,
+            Third => Second,
+            Fourth => Third,
+        }
+    }
+
+    /// Get the next quarter.
+    ///
+    /// ```rust
+    /// # use time::Quarter;
+    /// assert_eq!(Quarter::Fourth.next(), Quarter::First);
+    /// ```
+    pub const fn next(self) -> Self {
+        match self {
+            First => Second,
+            Second => Third,
+            Third => Fourth,
+            Fourth => First,
+        }
+    }
+}
+
+mod private {
+    #[non_exhaustive]
+    #[derive(Debug, Clone, Copy)]
+    pub struct QuarterMetadata;
+}
+use private::QuarterMetadata;
+
+impl SmartDisplay for Quarter {
+    type Metadata = QuarterMetadata;
+
+    fn metadata(&self, _: FormatterOptions) -> Metadata<'_, Self> {
+        Metadata::new(2, self, QuarterMetadata)
+    }
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            First => "Q1",
+            Second => "Q2",
+            Third => "Q3",
+            Fourth => "Q4",
+        })
+    }
+}
+
+impl fmt::Display for Quarter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        SmartDisplay::fmt(self, f)
+    }
+}
+
+impl FromStr for Quarter {
+    type Err = error::InvalidVariant;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Q1" => Ok(First),
+            "Q2" => Ok(Second),
+            "Q3" => Ok(Third),
+            "Q4" => Ok(Fourth),
+            _ => Err(error::InvalidVariant),
+        }
+    }
+}
+
+impl From<Quarter> for u8 {
+    fn from(quarter: Quarter) -> Self {
+        quarter as Self
+    }
+}
+
+impl TryFrom<u8> for Quarter {
+    type Error = error::ComponentRange;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match NonZero::new(value) {
+            Some(value) => Self::from_number(value),
+            None => Err(error::ComponentRange {
+                name: "quarter",
+                minimum: 1,
+                maximum: 4,
+                value: 0,
+                conditional_message: None,
+            }),
+        }
+    }
+}
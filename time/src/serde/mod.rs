@@ -204,9 +204,17 @@ pub use time_macros::serde_format_description as format_description;
 
 use self::visitor::Visitor;
 #[cfg(feature = "parsing")]
-use crate::format_description::{modifier, BorrowedFormatItem, Component};
+use crate::format_description::BorrowedFormatItem;
+#[cfg(all(
+    feature = "alloc",
+    any(feature = "formatting-core", feature = "parsing")
+))]
+use crate::format_description::OwnedFormatItem;
+#[cfg(any(feature = "formatting-core", feature = "parsing"))]
+use crate::format_description::{modifier, Component};
 use crate::{
-    Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcDateTime, UtcOffset, Weekday,
+    Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Quarter, Time, UtcDateTime,
+    UtcOffset, Weekday,
 };
 
 /// The format used when serializing and deserializing a human-readable `Date`.
@@ -507,8 +515,6 @@ impl Serialize for Month {
     fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
         #[cfg(feature = "serde-human-readable")]
         if serializer.is_human_readable() {
-            #[cfg(not(feature = "std"))]
-            use alloc::string::String;
             return self.to_string().serialize(serializer);
         }
 
@@ -525,3 +531,115 @@ impl<'a> Deserialize<'a> for Month {
         }
     }
 }
+
+impl Serialize for Quarter {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        #[cfg(feature = "serde-human-readable")]
+        if serializer.is_human_readable() {
+            return self.to_string().serialize(serializer);
+        }
+
+        u8::from(*self).serialize(serializer)
+    }
+}
+
+impl<'a> Deserialize<'a> for Quarter {
+    fn deserialize<D: Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
+        if cfg!(feature = "serde-human-readable") && deserializer.is_human_readable() {
+            deserializer.deserialize_any(Visitor::<Self>(PhantomData))
+        } else {
+            deserializer.deserialize_u8(Visitor::<Self>(PhantomData))
+        }
+    }
+}
+
+#[cfg(all(
+    feature = "alloc",
+    any(feature = "formatting-core", feature = "parsing")
+))]
+impl Serialize for OwnedFormatItem {
+    /// Serialize the format description as a version 2 format description string.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(all(
+    feature = "alloc",
+    any(feature = "formatting-core", feature = "parsing")
+))]
+impl<'a> Deserialize<'a> for OwnedFormatItem {
+    /// Deserialize a version 2 format description string.
+    fn deserialize<D: Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(Visitor::<Self>(PhantomData))
+    }
+}
+
+#[cfg(any(feature = "formatting-core", feature = "parsing"))]
+impl Serialize for Component {
+    /// Serialize the component as it would appear in a version 2 format description.
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[cfg(all(
+    feature = "alloc",
+    any(feature = "formatting-core", feature = "parsing")
+))]
+impl<'a> Deserialize<'a> for Component {
+    /// Deserialize a version 2 format description string that consists of a single component.
+    fn deserialize<D: Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(Visitor::<Self>(PhantomData))
+    }
+}
+
+/// Implement `Serialize` and `Deserialize` for modifiers, using the component they correspond to.
+macro_rules! modifier_serde {
+    ($($variant:ident),* $(,)?) => {$(
+        #[cfg(any(feature = "formatting-core", feature = "parsing"))]
+        impl Serialize for modifier::$variant {
+            /// Serialize the modifier as the component that uses it would appear in a version 2
+            /// format description.
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                Component::$variant(*self).serialize(serializer)
+            }
+        }
+
+        #[cfg(all(feature = "alloc", any(feature = "formatting-core", feature = "parsing")))]
+        impl<'a> Deserialize<'a> for modifier::$variant {
+            /// Deserialize a version 2 format description string that consists of a single
+            /// component using this modifier.
+            fn deserialize<D: Deserializer<'a>>(deserializer: D) -> Result<Self, D::Error> {
+                deserializer.deserialize_str(Visitor::<Self>(PhantomData))
+            }
+        }
+    )*};
+}
+
+modifier_serde![
+    Day,
+    Month,
+    Ordinal,
+    Weekday,
+    WeekNumber,
+    WeekOfMonth,
+    WeekdayInMonth,
+    Quarter,
+    Era,
+    Year,
+    Hour,
+    Minute,
+    Period,
+    Second,
+    Subsecond,
+    OffsetHour,
+    OffsetMinute,
+    OffsetSecond,
+    Ignore,
+    UnixTimestamp,
+    JulianDay,
+    End,
+    TimeZoneAnnotation,
+    ExtensionAnnotations,
+];
@@ -15,8 +15,14 @@ use super::{
 use crate::error::ComponentRange;
 #[cfg(feature = "parsing")]
 use crate::format_description::well_known::*;
+#[cfg(all(
+    feature = "alloc",
+    any(feature = "formatting-core", feature = "parsing")
+))]
+use crate::format_description::{self, modifier, Component, OwnedFormatItem};
 use crate::{
-    Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcDateTime, UtcOffset, Weekday,
+    Date, Duration, Month, OffsetDateTime, PrimitiveDateTime, Quarter, Time, UtcDateTime,
+    UtcOffset, Weekday,
 };
 
 /// A serde visitor for various types.
@@ -297,6 +303,37 @@ impl de::Visitor<'_> for Visitor<Month> {
     }
 }
 
+impl de::Visitor<'_> for Visitor<Quarter> {
+    type Value = Quarter;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a `Quarter`")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Quarter, E> {
+        match value {
+            "Q1" => Ok(Quarter::First),
+            "Q2" => Ok(Quarter::Second),
+            "Q3" => Ok(Quarter::Third),
+            "Q4" => Ok(Quarter::Fourth),
+            _ => Err(E::invalid_value(de::Unexpected::Str(value), &"a `Quarter`")),
+        }
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Quarter, E> {
+        match value {
+            1 => Ok(Quarter::First),
+            2 => Ok(Quarter::Second),
+            3 => Ok(Quarter::Third),
+            4 => Ok(Quarter::Fourth),
+            _ => Err(E::invalid_value(
+                de::Unexpected::Unsigned(value),
+                &"a value in the range 1..=4",
+            )),
+        }
+    }
+}
+
 /// Implement a visitor for a well-known format.
 macro_rules! well_known {
     ($article:literal, $name:literal, $($ty:tt)+) => {
@@ -353,3 +390,88 @@ well_known!(
     "ISO 8601",
     Iso8601::<{ super::iso8601::SERDE_CONFIG }>
 );
+
+#[cfg(all(
+    feature = "alloc",
+    any(feature = "formatting-core", feature = "parsing")
+))]
+impl de::Visitor<'_> for Visitor<OwnedFormatItem> {
+    type Value = OwnedFormatItem;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a version 2 format description")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<OwnedFormatItem, E> {
+        format_description::parse_owned::<2>(value).map_err(E::custom)
+    }
+}
+
+#[cfg(all(
+    feature = "alloc",
+    any(feature = "formatting-core", feature = "parsing")
+))]
+impl de::Visitor<'_> for Visitor<Component> {
+    type Value = Component;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a version 2 format description consisting of a single component")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Component, E> {
+        let item = format_description::parse_owned::<2>(value).map_err(E::custom)?;
+        Component::try_from(item).map_err(|_| E::invalid_value(de::Unexpected::Str(value), &self))
+    }
+}
+
+/// Implement a visitor for modifiers, using the component they correspond to.
+macro_rules! modifier_visitor {
+    ($($variant:ident),* $(,)?) => {$(
+        #[cfg(all(feature = "alloc", any(feature = "formatting-core", feature = "parsing")))]
+        impl de::Visitor<'_> for Visitor<modifier::$variant> {
+            type Value = modifier::$variant;
+
+            fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+                formatter.write_str(concat!(
+                    "a version 2 format description consisting of a single `",
+                    stringify!($variant),
+                    "` component"
+                ))
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> Result<modifier::$variant, E> {
+                match Visitor::<Component>(PhantomData).visit_str(value)? {
+                    Component::$variant(modifier) => Ok(modifier),
+                    _ => Err(E::invalid_value(de::Unexpected::Str(value), &self)),
+                }
+            }
+        }
+    )*};
+}
+
+modifier_visitor![
+    Day,
+    Month,
+    Ordinal,
+    Weekday,
+    WeekNumber,
+    WeekOfMonth,
+    WeekdayInMonth,
+    Quarter,
+    Era,
+    Year,
+    Hour,
+    Minute,
+    Period,
+    Second,
+    Subsecond,
+    OffsetHour,
+    OffsetMinute,
+    OffsetSecond,
+    Ignore,
+    UnixTimestamp,
+    JulianDay,
+    End,
+    TimeZoneAnnotation,
+    ExtensionAnnotations,
+];
@@ -0,0 +1,26 @@
+//! The `SerialDateSystem` enum.
+
+/// A system of serial numbers for dates, as used by spreadsheet applications.
+///
+/// Each system counts days from a different epoch. The integer part of a serial number indicates
+/// the date and the fractional part indicates the time of day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SerialDateSystem {
+    /// The 1900 date system, which is the default in Microsoft Excel.
+    ///
+    /// Serial number 1 is 1900-01-01. For compatibility with Lotus 1-2-3, 1900 is treated as a
+    /// leap year, so serial number 60 is 1900-02-29, which does not exist. Serial number 61 is
+    /// 1900-03-01. Serial number 0 is 1899-12-31, which Excel displays as 1900-01-00.
+    Excel1900,
+    /// The 1904 date system, which was historically the default in Microsoft Excel for Mac.
+    ///
+    /// Serial number 0 is 1904-01-01.
+    Excel1904,
+    /// OLE Automation dates, which are used by the `DATE` type in COM and by
+    /// `DateTime.ToOADate` in .NET.
+    ///
+    /// Serial number 0 is 1899-12-30. The serial numbers match those of the 1900 date system from
+    /// 1900-03-01 onward. For negative serial numbers, the fractional part is the time of day
+    /// regardless of the sign, so -1.25 is 1899-12-29 06:00.
+    OleAutomation,
+}
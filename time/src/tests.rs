@@ -2,6 +2,7 @@
     feature = "default",
     feature = "alloc",
     feature = "formatting",
+    feature = "formatting-core",
     feature = "large-dates",
     feature = "local-offset",
     feature = "macros",
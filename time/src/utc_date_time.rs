@@ -8,14 +8,18 @@ use core::time::Duration as StdDuration;
 #[cfg(feature = "formatting")]
 use std::io;
 
-use deranged::RangedI64;
+use deranged::{RangedI32, RangedI64, RangedU64};
 use powerfmt::ext::FormatterExt as _;
 use powerfmt::smart_display::{self, FormatterOptions, Metadata, SmartDisplay};
 
 use crate::convert::*;
 use crate::date::{MAX_YEAR, MIN_YEAR};
-#[cfg(feature = "formatting")]
-use crate::formatting::Formattable;
+#[cfg(feature = "formatting-core")]
+use crate::format_description::well_known::iso8601::{Config, EncodedConfig};
+#[cfg(feature = "formatting-core")]
+use crate::format_description::well_known::{Iso8601, Rfc2822, Rfc3339};
+#[cfg(feature = "formatting-core")]
+use crate::formatting::{self, FmtOutput, Formattable, InlineString, SliceOutput};
 use crate::internal_macros::{
     cascade, const_try, const_try_opt, div_floor, ensure_ranged, expect_opt,
 };
@@ -28,6 +32,32 @@ use crate::{
 /// The Julian day of the Unix epoch.
 const UNIX_EPOCH_JULIAN_DAY: i32 = UtcDateTime::UNIX_EPOCH.to_julian_day();
 
+/// The number of nanoseconds since the start of a day.
+type NanosecondOfDay = RangedU64<0, { Nanosecond::per_t::<u64>(Day) - 1 }>;
+
+/// Get the number of nanoseconds from midnight until the provided time.
+const fn nanoseconds_since_midnight(time: Time) -> u64 {
+    let (hour, minute, second, nanosecond) = time.as_hms_nano();
+    hour as u64 * Nanosecond::per_t::<u64>(Hour)
+        + minute as u64 * Nanosecond::per_t::<u64>(Minute)
+        + second as u64 * Nanosecond::per_t::<u64>(Second)
+        + nanosecond as u64
+}
+
+/// Get the time that is the provided number of nanoseconds after midnight.
+const fn time_from_nanoseconds_since_midnight(nanos: NanosecondOfDay) -> Time {
+    let nanos = nanos.get();
+    // Safety: The number of nanoseconds is less than one day, so all components are in range.
+    unsafe {
+        Time::__from_hms_nanos_unchecked(
+            (nanos / Nanosecond::per_t::<u64>(Hour)) as u8,
+            (nanos / Nanosecond::per_t::<u64>(Minute) % Minute::per_t::<u64>(Hour)) as u8,
+            (nanos / Nanosecond::per_t::<u64>(Second) % Second::per_t::<u64>(Minute)) as u8,
+            (nanos % Nanosecond::per_t::<u64>(Second)) as u32,
+        )
+    }
+}
+
 /// A [`PrimitiveDateTime`] that is known to be UTC.
 ///
 /// `UtcDateTime` is guaranteed to be ABI-compatible with [`PrimitiveDateTime`], meaning that
@@ -247,6 +277,93 @@ impl UtcDateTime {
         ))
     }
 
+    /// Construct a `UtcDateTime` from the Julian date, given as the Julian day and the number of
+    /// nanoseconds since the start of that day. A Julian day begins at noon, so Julian date
+    /// 2451545.0 is noon on 2000-01-01.
+    ///
+    /// Unlike a floating-point Julian date, this representation is exact to the nanosecond.
+    ///
+    /// ```rust
+    /// # use time::UtcDateTime;
+    /// # use time_macros::utc_datetime;
+    /// assert_eq!(
+    ///     UtcDateTime::from_julian_date(2_451_545, 0),
+    ///     Ok(utc_datetime!(2000-01-01 12:00)),
+    /// );
+    /// assert_eq!(
+    ///     UtcDateTime::from_julian_date(2_451_545, 43_200_000_000_000),
+    ///     Ok(utc_datetime!(2000-01-02 0:00)),
+    /// );
+    /// ```
+    pub const fn from_julian_date(
+        julian_day: i32,
+        nanosecond: u64,
+    ) -> Result<Self, error::ComponentRange> {
+        type JulianDay = RangedI32<
+            { UtcDateTime::MIN.to_julian_date().0 },
+            { UtcDateTime::MAX.to_julian_date().0 },
+        >;
+        ensure_ranged!(JulianDay: julian_day);
+        let nanosecond = ensure_ranged!(NanosecondOfDay: nanosecond);
+
+        let half_day = Nanosecond::per_t::<u64>(Day) / 2;
+        let (date, nanosecond) = if nanosecond.get() < half_day {
+            (
+                Date::from_julian_day(julian_day),
+                nanosecond.get() + half_day,
+            )
+        } else {
+            (
+                Date::from_julian_day(julian_day + 1),
+                nanosecond.get() - half_day,
+            )
+        };
+        // The first and last Julian days are only partially representable.
+        let Ok(date) = date else {
+            return Err(error::ComponentRange {
+                name: "julian_day",
+                minimum: JulianDay::MIN.get() as i64,
+                maximum: JulianDay::MAX.get() as i64,
+                value: julian_day as i64,
+                conditional_message: Some("for the given nanosecond"),
+            });
+        };
+
+        // Safety: Shifting by half a day keeps the value within a single day.
+        let nanosecond = unsafe { NanosecondOfDay::new_unchecked(nanosecond) };
+        Ok(Self::new(
+            date,
+            time_from_nanoseconds_since_midnight(nanosecond),
+        ))
+    }
+
+    /// Construct a `UtcDateTime` from the modified Julian date, given as the modified Julian day
+    /// and the number of nanoseconds since midnight. Modified Julian date 0.0 is midnight on
+    /// 1858-11-17.
+    ///
+    /// Unlike a floating-point modified Julian date, this representation is exact to the
+    /// nanosecond.
+    ///
+    /// ```rust
+    /// # use time::UtcDateTime;
+    /// # use time_macros::utc_datetime;
+    /// assert_eq!(
+    ///     UtcDateTime::from_modified_julian_date(51_544, 43_200_000_000_000),
+    ///     Ok(utc_datetime!(2000-01-01 12:00)),
+    /// );
+    /// ```
+    pub const fn from_modified_julian_date(
+        modified_julian_day: i32,
+        nanosecond: u64,
+    ) -> Result<Self, error::ComponentRange> {
+        let date = const_try!(Date::from_modified_julian_day(modified_julian_day));
+        let nanosecond = ensure_ranged!(NanosecondOfDay: nanosecond);
+        Ok(Self::new(
+            date,
+            time_from_nanoseconds_since_midnight(nanosecond),
+        ))
+    }
+
     /// Convert the `UtcDateTime` from UTC to the provided [`UtcOffset`], returning an
     /// [`OffsetDateTime`].
     ///
@@ -588,6 +705,52 @@ impl UtcDateTime {
         self.date().to_julian_day()
     }
 
+    /// Get the Julian date as the Julian day and the number of nanoseconds since the start of that
+    /// day. A Julian day begins at noon, so Julian date 2451545.0 is noon on 2000-01-01.
+    ///
+    /// Unlike a floating-point Julian date, this representation is exact to the nanosecond.
+    ///
+    /// ```rust
+    /// # use time_macros::utc_datetime;
+    /// assert_eq!(
+    ///     utc_datetime!(2000-01-01 12:00).to_julian_date(),
+    ///     (2_451_545, 0)
+    /// );
+    /// assert_eq!(
+    ///     utc_datetime!(2000-01-01 0:00).to_julian_date(),
+    ///     (2_451_544, 43_200_000_000_000)
+    /// );
+    /// ```
+    pub const fn to_julian_date(self) -> (i32, u64) {
+        let nanosecond = nanoseconds_since_midnight(self.time());
+        let half_day = Nanosecond::per_t::<u64>(Day) / 2;
+        if nanosecond < half_day {
+            (self.to_julian_day() - 1, nanosecond + half_day)
+        } else {
+            (self.to_julian_day(), nanosecond - half_day)
+        }
+    }
+
+    /// Get the modified Julian date as the modified Julian day and the number of nanoseconds since
+    /// midnight. Modified Julian date 0.0 is midnight on 1858-11-17.
+    ///
+    /// Unlike a floating-point modified Julian date, this representation is exact to the
+    /// nanosecond.
+    ///
+    /// ```rust
+    /// # use time_macros::utc_datetime;
+    /// assert_eq!(
+    ///     utc_datetime!(2000-01-01 12:00).to_modified_julian_date(),
+    ///     (51_544, 43_200_000_000_000)
+    /// );
+    /// ```
+    pub const fn to_modified_julian_date(self) -> (i32, u64) {
+        (
+            self.date().to_modified_julian_day(),
+            nanoseconds_since_midnight(self.time()),
+        )
+    }
+
     /// Get the clock hour, minute, and second.
     ///
     /// ```rust
@@ -1020,21 +1183,17 @@ impl UtcDateTime {
     }
 }
 
-#[cfg(feature = "formatting")]
+#[cfg(feature = "formatting-core")]
 impl UtcDateTime {
     /// Format the `UtcDateTime` using the provided [format
     /// description](crate::format_description).
+    #[cfg(feature = "formatting")]
     pub fn format_into(
         self,
         output: &mut (impl io::Write + ?Sized),
         format: &(impl Formattable + ?Sized),
     ) -> Result<usize, error::Format> {
-        format.format_into(
-            output,
-            Some(self.date()),
-            Some(self.time()),
-            Some(UtcOffset::UTC),
-        )
+        formatting::format_value_into_io(self, output, format)
     }
 
     /// Format the `UtcDateTime` using the provided [format
@@ -1053,8 +1212,112 @@ impl UtcDateTime {
     /// );
     /// # Ok::<_, time::Error>(())
     /// ```
+    #[cfg(feature = "formatting")]
     pub fn format(self, format: &(impl Formattable + ?Sized)) -> Result<String, error::Format> {
-        format.format(Some(self.date()), Some(self.time()), Some(UtcOffset::UTC))
+        formatting::format_value_to_string(self, format)
+    }
+
+    /// Format the `UtcDateTime` using the provided [format description](crate::format_description),
+    /// writing the output to a [`fmt::Write`]. This does not require `std` or `alloc`.
+    ///
+    /// As `fmt::Write` only accepts strings, an error is returned if the format description
+    /// contains a literal that is not valid UTF-8.
+    ///
+    /// ```rust
+    /// # use time::format_description::well_known::Rfc3339;
+    /// # use time_macros::utc_datetime;
+    /// let mut output = String::new();
+    /// utc_datetime!(2020-01-02 03:04:05).format_into_fmt(&mut output, &Rfc3339)?;
+    /// assert_eq!(output, "2020-01-02T03:04:05Z");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_fmt(
+        self,
+        output: &mut (impl fmt::Write + ?Sized),
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::FormatCore> {
+        formatting::format_value(self, &mut FmtOutput(output), format)
+    }
+
+    /// Format the `UtcDateTime` using the provided [format description](crate::format_description),
+    /// writing the output to the start of the provided buffer. The number of bytes written is
+    /// returned. This does not require `std` or `alloc`.
+    ///
+    /// If the buffer is too small, [`error::FormatCore::InsufficientBufferSize`] is returned. The
+    /// contents of the buffer are unspecified in this situation.
+    ///
+    /// ```rust
+    /// # use time::format_description::well_known::Rfc3339;
+    /// # use time_macros::utc_datetime;
+    /// let mut buf = [0; 64];
+    /// let len = utc_datetime!(2020-01-02 03:04:05).format_to_slice(&mut buf, &Rfc3339)?;
+    /// assert_eq!(&buf[..len], b"2020-01-02T03:04:05Z");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_to_slice(
+        self,
+        output: &mut [u8],
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::FormatCore> {
+        formatting::format_value(self, &mut SliceOutput::new(output), format)
+    }
+
+    /// Format the `UtcDateTime` using the well-known [RFC3339 format](Rfc3339), returning a
+    /// string that is stored inline rather than on the heap. This does not require `std` or
+    /// `alloc`.
+    ///
+    /// ```rust
+    /// # use time_macros::utc_datetime;
+    /// assert_eq!(
+    ///     utc_datetime!(2020-01-02 03:04:05.6).format_rfc3339_inline()?,
+    ///     "2020-01-02T03:04:05.6Z"
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_rfc3339_inline(self) -> Result<InlineString<35>, error::FormatCore> {
+        InlineString::format(self, &Rfc3339)
+    }
+
+    /// Format the `UtcDateTime` using the well-known [RFC2822 format](Rfc2822), returning a
+    /// string that is stored inline rather than on the heap. This does not require `std` or
+    /// `alloc`.
+    ///
+    /// ```rust
+    /// # use time_macros::utc_datetime;
+    /// assert_eq!(
+    ///     utc_datetime!(2020-01-02 03:04:05).format_rfc2822_inline()?,
+    ///     "Thu, 02 Jan 2020 03:04:05 +0000"
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_rfc2822_inline(self) -> Result<InlineString<31>, error::FormatCore> {
+        InlineString::format(self, &Rfc2822)
+    }
+
+    /// Format the `UtcDateTime` using the well-known [ISO 8601 format](Iso8601) with the
+    /// provided configuration, returning a string that is stored inline rather than on the heap.
+    /// This does not require `std` or `alloc`.
+    ///
+    /// The capacity is sufficient for any configuration, including those with the maximum number
+    /// of decimal digits.
+    ///
+    /// ```rust
+    /// # use time::format_description::well_known::iso8601;
+    /// # use time_macros::utc_datetime;
+    /// const CONFIG: iso8601::EncodedConfig = iso8601::Config::DEFAULT
+    ///     .set_year_is_six_digits(true)
+    ///     .encode();
+    /// assert_eq!(
+    ///     utc_datetime!(2020-01-02 03:04:05).format_iso8601_inline::<CONFIG>()?,
+    ///     "+002020-01-02T03:04:05.000000000Z"
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_iso8601_inline<const CONFIG: EncodedConfig>(
+        self,
+    ) -> Result<InlineString<{ Config::MAX_FORMATTED_LEN }>, error::FormatCore> {
+        const { assert!(Iso8601::<CONFIG>::MAX_LEN <= Config::MAX_FORMATTED_LEN) };
+        InlineString::format(self, &Iso8601::<CONFIG>)
     }
 }
 
@@ -1081,6 +1344,53 @@ impl UtcDateTime {
         description.parse_utc_date_time(input.as_bytes())
     }
 
+    /// Parse an `UtcDateTime` from the start of the input using the provided [format
+    /// description](crate::format_description), returning it along with the remaining input.
+    ///
+    /// Unlike [`UtcDateTime::parse`], it is not an error for input to remain after parsing. This is
+    /// useful when the value is followed by other data, such as in a log line.
+    ///
+    /// ```rust
+    /// # use time::UtcDateTime;
+    /// # use time::format_description::well_known::Rfc3339;
+    /// # use time_macros::utc_datetime;
+    /// assert_eq!(
+    ///     UtcDateTime::parse_prefix(b"2020-01-02T03:04:05+01:00 INFO", &Rfc3339)?,
+    ///     (utc_datetime!(2020-01-02 02:04:05), b" INFO".as_slice())
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_prefix<'a>(
+        input: &'a [u8],
+        description: &(impl Parsable + ?Sized),
+    ) -> Result<(Self, &'a [u8]), error::Parse> {
+        let (parsed, remaining) = description.parse_prefix(input)?;
+        Ok((parsed.try_into()?, remaining))
+    }
+
+    /// Parse an `UtcDateTime` from the input using the provided [format
+    /// description](crate::format_description), returning a diagnostic that describes where and why
+    /// parsing failed if it does.
+    ///
+    /// See [`Parsed::parse_with_diagnostic`](crate::parsing::Parsed::parse_with_diagnostic) for
+    /// details.
+    ///
+    /// ```rust
+    /// # use time::UtcDateTime;
+    /// # use time_macros::format_description;
+    /// let format = format_description!("[year]-[month]-[day] [hour]:[minute]");
+    /// let err = UtcDateTime::parse_with_diagnostic("2020-01-02 3:04", &format).unwrap_err();
+    /// assert_eq!(err.span(), Some(11..12));
+    /// ```
+    pub fn parse_with_diagnostic<'a>(
+        input: &str,
+        description: &'a (impl Parsable + ?Sized),
+    ) -> Result<Self, error::ParseDiagnostic<'a>> {
+        Ok(description
+            .parse_with_diagnostic(input.as_bytes())?
+            .try_into()?)
+    }
+
     /// A helper method to check if the `UtcDateTime` is a valid representation of a leap second.
     /// Leap seconds, when parsed, are represented as the preceding nanosecond. However, leap
     /// seconds can only occur as the last second of a month UTC.
@@ -13,8 +13,8 @@ use powerfmt::smart_display::{self, FormatterOptions, Metadata, SmartDisplay};
 
 use crate::convert::*;
 use crate::error;
-#[cfg(feature = "formatting")]
-use crate::formatting::Formattable;
+#[cfg(feature = "formatting-core")]
+use crate::formatting::{self, FmtOutput, Formattable, SliceOutput};
 use crate::internal_macros::ensure_ranged;
 #[cfg(feature = "parsing")]
 use crate::parsing::Parsable;
@@ -358,15 +358,16 @@ impl UtcOffset {
     }
 }
 
-#[cfg(feature = "formatting")]
+#[cfg(feature = "formatting-core")]
 impl UtcOffset {
     /// Format the `UtcOffset` using the provided [format description](crate::format_description).
+    #[cfg(feature = "formatting")]
     pub fn format_into(
         self,
         output: &mut (impl io::Write + ?Sized),
         format: &(impl Formattable + ?Sized),
     ) -> Result<usize, error::Format> {
-        format.format_into(output, None, None, Some(self))
+        formatting::format_value_into_io(self, output, format)
     }
 
     /// Format the `UtcOffset` using the provided [format description](crate::format_description).
@@ -378,8 +379,54 @@ impl UtcOffset {
     /// assert_eq!(offset!(+1).format(&format)?, "+01:00");
     /// # Ok::<_, time::Error>(())
     /// ```
+    #[cfg(feature = "formatting")]
     pub fn format(self, format: &(impl Formattable + ?Sized)) -> Result<String, error::Format> {
-        format.format(None, None, Some(self))
+        formatting::format_value_to_string(self, format)
+    }
+
+    /// Format the `UtcOffset` using the provided [format description](crate::format_description),
+    /// writing the output to a [`fmt::Write`]. This does not require `std` or `alloc`.
+    ///
+    /// As `fmt::Write` only accepts strings, an error is returned if the format description
+    /// contains a literal that is not valid UTF-8.
+    ///
+    /// ```rust
+    /// # use time_macros::{format_description, offset};
+    /// let format = format_description!("[offset_hour sign:mandatory]:[offset_minute]");
+    /// let mut output = String::new();
+    /// offset!(+1).format_into_fmt(&mut output, format)?;
+    /// assert_eq!(output, "+01:00");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_into_fmt(
+        self,
+        output: &mut (impl fmt::Write + ?Sized),
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::FormatCore> {
+        formatting::format_value(self, &mut FmtOutput(output), format)
+    }
+
+    /// Format the `UtcOffset` using the provided [format description](crate::format_description),
+    /// writing the output to the start of the provided buffer. The number of bytes written is
+    /// returned. This does not require `std` or `alloc`.
+    ///
+    /// If the buffer is too small, [`error::FormatCore::InsufficientBufferSize`] is returned. The
+    /// contents of the buffer are unspecified in this situation.
+    ///
+    /// ```rust
+    /// # use time_macros::{format_description, offset};
+    /// let format = format_description!("[offset_hour sign:mandatory]:[offset_minute]");
+    /// let mut buf = [0; 64];
+    /// let len = offset!(+1).format_to_slice(&mut buf, format)?;
+    /// assert_eq!(&buf[..len], b"+01:00");
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn format_to_slice(
+        self,
+        output: &mut [u8],
+        format: &(impl Formattable + ?Sized),
+    ) -> Result<usize, error::FormatCore> {
+        formatting::format_value(self, &mut SliceOutput::new(output), format)
     }
 }
 
@@ -401,6 +448,53 @@ impl UtcOffset {
     ) -> Result<Self, error::Parse> {
         description.parse_offset(input.as_bytes())
     }
+
+    /// Parse a `UtcOffset` from the start of the input using the provided [format
+    /// description](crate::format_description), returning it along with the remaining input.
+    ///
+    /// Unlike [`UtcOffset::parse`], it is not an error for input to remain after parsing. This is
+    /// useful when the value is followed by other data, such as in a log line.
+    ///
+    /// ```rust
+    /// # use time::UtcOffset;
+    /// # use time_macros::{offset, format_description};
+    /// let format = format_description!("[offset_hour]:[offset_minute]");
+    /// assert_eq!(
+    ///     UtcOffset::parse_prefix(b"-03:42 EST", &format)?,
+    ///     (offset!(-3:42), b" EST".as_slice())
+    /// );
+    /// # Ok::<_, time::Error>(())
+    /// ```
+    pub fn parse_prefix<'a>(
+        input: &'a [u8],
+        description: &(impl Parsable + ?Sized),
+    ) -> Result<(Self, &'a [u8]), error::Parse> {
+        let (parsed, remaining) = description.parse_prefix(input)?;
+        Ok((parsed.try_into()?, remaining))
+    }
+
+    /// Parse an `UtcOffset` from the input using the provided [format
+    /// description](crate::format_description), returning a diagnostic that describes where and why
+    /// parsing failed if it does.
+    ///
+    /// See [`Parsed::parse_with_diagnostic`](crate::parsing::Parsed::parse_with_diagnostic) for
+    /// details.
+    ///
+    /// ```rust
+    /// # use time::UtcOffset;
+    /// # use time_macros::format_description;
+    /// let format = format_description!("[offset_hour sign:mandatory]:[offset_minute]");
+    /// let err = UtcOffset::parse_with_diagnostic("+01", &format).unwrap_err();
+    /// assert_eq!(err.span(), Some(3..3));
+    /// ```
+    pub fn parse_with_diagnostic<'a>(
+        input: &str,
+        description: &'a (impl Parsable + ?Sized),
+    ) -> Result<Self, error::ParseDiagnostic<'a>> {
+        Ok(description
+            .parse_with_diagnostic(input.as_bytes())?
+            .try_into()?)
+    }
 }
 
 mod private {